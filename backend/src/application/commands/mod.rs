@@ -1,9 +1,14 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use rust_decimal::Decimal;
 use serde::Deserialize;
 use uuid::Uuid;
 
-use crate::domain::value_objects::{Priority, ProjectStatus, TaskStatus, TeamMemberRole, UserRole};
+use crate::domain::entities::ChangeSet;
+use crate::domain::services::RoundingMode;
+use crate::domain::value_objects::{
+    PortfolioKind, Priority, ProjectStatus, RagStatus, RaidStatus, RaidType, TaskStatus,
+    TeamMemberRole, UserRole,
+};
 
 // ==================== USER COMMANDS ====================
 #[derive(Debug, Deserialize)]
@@ -31,6 +36,7 @@ pub struct CreateProjectCommand {
     pub start_date: Option<DateTime<Utc>>,
     pub end_date: Option<DateTime<Utc>>,
     pub budget: Option<Decimal>,
+    pub billable: Option<bool>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -42,6 +48,7 @@ pub struct UpdateProjectCommand {
     pub start_date: Option<DateTime<Utc>>,
     pub end_date: Option<DateTime<Utc>>,
     pub budget: Option<Decimal>,
+    pub billable: Option<bool>,
 }
 
 // ==================== TASK COMMANDS ====================
@@ -54,6 +61,8 @@ pub struct CreateTaskCommand {
     pub assignee_id: Option<Uuid>,
     pub due_date: Option<DateTime<Utc>>,
     pub estimated_hours: Option<f32>,
+    /// Leave unset to follow the project's billable default
+    pub billable: Option<bool>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -65,7 +74,20 @@ pub struct UpdateTaskCommand {
     pub assignee_id: Option<Uuid>,
     pub due_date: Option<DateTime<Utc>>,
     pub estimated_hours: Option<f32>,
+    /// Rejected when set; actual hours follow the task's time logs
     pub actual_hours: Option<f32>,
+    pub billable: Option<bool>,
+}
+
+// ==================== COMMENT COMMANDS ====================
+#[derive(Debug, Deserialize)]
+pub struct CreateCommentCommand {
+    pub content: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateCommentCommand {
+    pub content: String,
 }
 
 // ==================== TEAM COMMANDS ====================
@@ -80,6 +102,8 @@ pub struct CreateTeamCommand {
 pub struct UpdateTeamCommand {
     pub name: Option<String>,
     pub description: Option<String>,
+    /// Only accepted when unchanged, leadership moves through a member
+    /// update with `transfer`
     pub lead_id: Option<Uuid>,
 }
 
@@ -89,6 +113,14 @@ pub struct AddTeamMemberCommand {
     pub role: Option<TeamMemberRole>,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct UpdateTeamMemberCommand {
+    pub role: TeamMemberRole,
+    /// Hand the team over to this member, the current team lead becomes a member
+    #[serde(default)]
+    pub transfer: bool,
+}
+
 // ==================== AUTH COMMANDS ====================
 #[derive(Debug, Deserialize)]
 pub struct LoginCommand {
@@ -115,3 +147,259 @@ pub struct UpdateTagCommand {
 pub struct SetTaskTagsCommand {
     pub tag_ids: Vec<Uuid>,
 }
+
+// ==================== SPRINT COMMANDS ====================
+#[derive(Debug, Deserialize)]
+pub struct CreateSprintCommand {
+    pub name: String,
+    pub goal: Option<String>,
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateSprintCommand {
+    pub name: Option<String>,
+    pub goal: Option<String>,
+    pub start_date: Option<NaiveDate>,
+    pub end_date: Option<NaiveDate>,
+}
+
+// ==================== BUDGET COMMANDS ====================
+#[derive(Debug, Deserialize)]
+pub struct CreateExpenseCommand {
+    pub amount: Decimal,
+    pub category: String,
+    pub description: Option<String>,
+    pub date: NaiveDate,
+    pub receipt_attachment_id: Option<Uuid>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateExpenseCommand {
+    pub amount: Option<Decimal>,
+    pub category: Option<String>,
+    pub description: Option<String>,
+    pub date: Option<NaiveDate>,
+    pub receipt_attachment_id: Option<Uuid>,
+}
+
+/// Set the hourly rate of either one user or a whole role
+#[derive(Debug, Deserialize)]
+pub struct SetCostRateCommand {
+    pub user_id: Option<Uuid>,
+    pub role: Option<UserRole>,
+    pub hourly_rate: Decimal,
+}
+
+// ==================== BASELINE COMMANDS ====================
+#[derive(Debug, Deserialize)]
+pub struct CreateBaselineCommand {
+    pub name: String,
+    pub description: Option<String>,
+}
+
+// ==================== RAID COMMANDS ====================
+#[derive(Debug, Deserialize)]
+pub struct CreateRaidItemCommand {
+    pub item_type: RaidType,
+    pub title: String,
+    pub description: Option<String>,
+    pub owner_id: Option<Uuid>,
+    pub status: Option<RaidStatus>,
+    pub probability: Option<i16>,
+    pub impact: Option<i16>,
+    pub mitigation_plan: Option<String>,
+    pub review_date: Option<NaiveDate>,
+    pub task_ids: Option<Vec<Uuid>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateRaidItemCommand {
+    pub item_type: Option<RaidType>,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub owner_id: Option<Uuid>,
+    pub status: Option<RaidStatus>,
+    pub probability: Option<i16>,
+    pub impact: Option<i16>,
+    pub mitigation_plan: Option<String>,
+    pub review_date: Option<NaiveDate>,
+    pub task_ids: Option<Vec<Uuid>>,
+}
+
+// ==================== HEALTH COMMANDS ====================
+#[derive(Debug, Deserialize)]
+pub struct SetHealthOverrideCommand {
+    pub status: RagStatus,
+    pub comment: String,
+}
+
+// ==================== PORTFOLIO COMMANDS ====================
+#[derive(Debug, Deserialize)]
+pub struct CreatePortfolioCommand {
+    pub name: String,
+    pub description: Option<String>,
+    pub kind: PortfolioKind,
+    pub parent_id: Option<Uuid>,
+    /// Program manager; defaults to the creator
+    pub owner_id: Option<Uuid>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdatePortfolioCommand {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub parent_id: Option<Uuid>,
+    pub owner_id: Option<Uuid>,
+}
+
+// ==================== STATUS REPORT COMMANDS ====================
+#[derive(Debug, Deserialize)]
+pub struct CreateStatusReportCommand {
+    pub period_start: NaiveDate,
+    pub period_end: NaiveDate,
+    pub highlights: Option<String>,
+}
+
+// ==================== CHANGE REQUEST COMMANDS ====================
+#[derive(Debug, Deserialize)]
+pub struct CreateChangeRequestCommand {
+    #[serde(flatten)]
+    pub changes: ChangeSet,
+    pub justification: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DecideChangeRequestCommand {
+    pub comment: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SetChangeApproversCommand {
+    pub user_ids: Vec<Uuid>,
+}
+
+// ==================== TIMER COMMANDS ====================
+#[derive(Debug, Deserialize)]
+pub struct StartTimerCommand {
+    pub task_id: Uuid,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct StopTimerCommand {
+    /// Replaces the description given when the timer was started
+    pub description: Option<String>,
+    /// Round the logged time to a multiple of this many minutes, itself a
+    /// multiple of the time log increment it defaults to
+    pub round_to_minutes: Option<u32>,
+    #[serde(default)]
+    pub rounding: RoundingMode,
+}
+
+// ==================== TIMESHEET COMMANDS ====================
+#[derive(Debug, Deserialize)]
+pub struct SubmitTimesheetCommand {
+    /// Any date within the week to submit
+    pub week_start: NaiveDate,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DecideTimesheetCommand {
+    pub comment: Option<String>,
+}
+
+// ==================== BILLING COMMANDS ====================
+#[derive(Debug, Deserialize)]
+pub struct CreateBillingRateCommand {
+    pub project_id: Option<Uuid>,
+    pub user_id: Option<Uuid>,
+    pub role: Option<UserRole>,
+    pub hourly_rate: Decimal,
+    pub effective_from: NaiveDate,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PrepareInvoiceCommand {
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    /// Limit the invoice to one project
+    pub project_id: Option<Uuid>,
+}
+
+// ==================== CAPACITY COMMANDS ====================
+#[derive(Debug, Deserialize)]
+pub struct SetCapacityCommand {
+    pub weekly_hours: Option<f32>,
+    /// Hours per weekday, Monday first; overrides `weekly_hours`
+    pub day_hours: Option<Vec<f32>>,
+    /// Holiday calendar the user follows instead of the default one
+    pub holiday_calendar_id: Option<Uuid>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UtilizationReportCommand {
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    /// Limit the report to one team's members
+    pub team_id: Option<Uuid>,
+}
+
+// ==================== CALENDAR COMMANDS ====================
+#[derive(Debug, Deserialize)]
+pub struct CreateHolidayCalendarCommand {
+    pub name: String,
+    #[serde(default)]
+    pub is_default: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateHolidayCalendarCommand {
+    pub name: Option<String>,
+    pub is_default: Option<bool>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CreateHolidayCommand {
+    pub date: NaiveDate,
+    pub name: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RequestTimeOffCommand {
+    pub start_date: NaiveDate,
+    /// Last day off, inclusive
+    pub end_date: NaiveDate,
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DecideTimeOffCommand {
+    pub comment: Option<String>,
+}
+
+// ==================== ALLOCATION COMMANDS ====================
+#[derive(Debug, Deserialize)]
+pub struct CreateAllocationCommand {
+    pub user_id: Uuid,
+    pub project_id: Uuid,
+    pub start_date: NaiveDate,
+    /// Last allocated day, inclusive
+    pub end_date: NaiveDate,
+    /// Share of the user's capacity; give this or `hours_per_week`
+    pub percent: Option<f32>,
+    pub hours_per_week: Option<f32>,
+    pub note: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateAllocationCommand {
+    pub start_date: Option<NaiveDate>,
+    pub end_date: Option<NaiveDate>,
+    /// Replaces `hours_per_week` when set
+    pub percent: Option<f32>,
+    /// Replaces `percent` when set
+    pub hours_per_week: Option<f32>,
+    pub note: Option<String>,
+}
@@ -0,0 +1,398 @@
+use chrono::NaiveDate;
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::application::commands::{CreateAllocationCommand, UpdateAllocationCommand};
+use crate::application::services::input::{trimmed, validate_range};
+use crate::application::services::{CalendarAppService, ProjectAppService};
+use crate::domain::entities::{ResourceAllocation, User};
+use crate::domain::repositories::{ResourceAllocationRepository, TeamRepository, UserRepository};
+use crate::domain::services::{AllocationCalculator, AllocationWeek};
+use crate::shared::DomainError;
+
+/// Longest range a heatmap or team view may cover
+const MAX_VIEW_DAYS: i64 = 366;
+
+/// A saved allocation with the weeks it leaves its user over-allocated
+#[derive(Debug, Serialize)]
+pub struct AllocationWithConflicts {
+    #[serde(flatten)]
+    pub allocation: ResourceAllocation,
+    pub over_allocated_weeks: Vec<AllocationWeek>,
+}
+
+/// One row of the heatmap: a user's allocation week by week
+#[derive(Debug, Serialize)]
+pub struct UserAllocation {
+    pub user_id: Uuid,
+    pub user_name: String,
+    pub over_allocated_weeks: usize,
+    pub weeks: Vec<AllocationWeek>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AllocationHeatmap {
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    pub users: Vec<UserAllocation>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TeamAllocation {
+    pub team_id: Uuid,
+    pub team_name: String,
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    pub members: Vec<UserAllocation>,
+    /// Members' weeks summed
+    pub weeks: Vec<AllocationWeek>,
+}
+
+/// A week in which a user is allocated beyond their capacity
+#[derive(Debug, Serialize)]
+pub struct OverAllocation {
+    pub user_id: Uuid,
+    pub user_name: String,
+    #[serde(flatten)]
+    pub week: AllocationWeek,
+}
+
+pub struct AllocationAppService {
+    allocation_repository: Arc<dyn ResourceAllocationRepository>,
+    user_repository: Arc<dyn UserRepository>,
+    team_repository: Arc<dyn TeamRepository>,
+    project_service: Arc<ProjectAppService>,
+    calendar_service: Arc<CalendarAppService>,
+}
+
+impl AllocationAppService {
+    pub fn new(
+        allocation_repository: Arc<dyn ResourceAllocationRepository>,
+        user_repository: Arc<dyn UserRepository>,
+        team_repository: Arc<dyn TeamRepository>,
+        project_service: Arc<ProjectAppService>,
+        calendar_service: Arc<CalendarAppService>,
+    ) -> Self {
+        Self {
+            allocation_repository,
+            user_repository,
+            team_repository,
+            project_service,
+            calendar_service,
+        }
+    }
+
+    /// Check if user can access project
+    pub async fn can_access_project(
+        &self,
+        project_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<bool, DomainError> {
+        self.project_service
+            .can_user_access(project_id, user_id)
+            .await
+    }
+
+    /// Check if user can read project data (members and program managers)
+    pub async fn can_view_project(
+        &self,
+        project_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<bool, DomainError> {
+        self.project_service
+            .can_user_view(project_id, user_id)
+            .await
+    }
+
+    /// Check if user can access team (member or lead)
+    pub async fn can_access_team(&self, team_id: Uuid, user_id: Uuid) -> Result<bool, DomainError> {
+        self.team_repository.can_user_access(team_id, user_id).await
+    }
+
+    pub async fn get_allocation(&self, id: Uuid) -> Result<ResourceAllocation, DomainError> {
+        self.allocation_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("Allocation not found".into()))
+    }
+
+    pub async fn list_project_allocations(
+        &self,
+        project_id: Uuid,
+    ) -> Result<Vec<ResourceAllocation>, DomainError> {
+        self.allocation_repository.find_by_project(project_id).await
+    }
+
+    pub async fn list_user_allocations(
+        &self,
+        user_id: Uuid,
+    ) -> Result<Vec<ResourceAllocation>, DomainError> {
+        self.allocation_repository.find_by_user(user_id).await
+    }
+
+    /// Plan part of a user's time on a project. Over-allocation is allowed
+    /// but reported back so the planner can resolve it.
+    pub async fn create_allocation(
+        &self,
+        cmd: CreateAllocationCommand,
+        created_by: Uuid,
+    ) -> Result<AllocationWithConflicts, DomainError> {
+        self.user_repository
+            .find_by_id(cmd.user_id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound(format!("User {} not found", cmd.user_id)))?;
+        self.project_service
+            .ensure_project_writable(cmd.project_id)
+            .await?;
+
+        let allocation = ResourceAllocation::new(
+            cmd.user_id,
+            cmd.project_id,
+            cmd.start_date,
+            cmd.end_date,
+            cmd.percent,
+            cmd.hours_per_week,
+            trimmed(cmd.note),
+            created_by,
+        );
+        validate(&allocation)?;
+
+        let allocation = self.allocation_repository.create(&allocation).await?;
+        self.with_conflicts(allocation).await
+    }
+
+    pub async fn update_allocation(
+        &self,
+        id: Uuid,
+        cmd: UpdateAllocationCommand,
+    ) -> Result<AllocationWithConflicts, DomainError> {
+        let mut allocation = self.get_allocation(id).await?;
+        self.project_service
+            .ensure_project_writable(allocation.project_id)
+            .await?;
+
+        if let Some(start_date) = cmd.start_date {
+            allocation.start_date = start_date;
+        }
+        if let Some(end_date) = cmd.end_date {
+            allocation.end_date = end_date;
+        }
+        match (cmd.percent, cmd.hours_per_week) {
+            (Some(_), Some(_)) => {
+                return Err(DomainError::ValidationError(
+                    "Give either percent or hours_per_week, not both".into(),
+                ));
+            }
+            (Some(percent), None) => {
+                allocation.percent = Some(percent);
+                allocation.hours_per_week = None;
+            }
+            (None, Some(hours)) => {
+                allocation.percent = None;
+                allocation.hours_per_week = Some(hours);
+            }
+            (None, None) => {}
+        }
+        if cmd.note.is_some() {
+            allocation.note = trimmed(cmd.note);
+        }
+        validate(&allocation)?;
+
+        let allocation = self.allocation_repository.update(&allocation).await?;
+        self.with_conflicts(allocation).await
+    }
+
+    pub async fn delete_allocation(&self, id: Uuid) -> Result<(), DomainError> {
+        self.allocation_repository.delete(id).await
+    }
+
+    /// Allocation of every user, week by week
+    pub async fn heatmap(
+        &self,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<AllocationHeatmap, DomainError> {
+        validate_range(start, end, MAX_VIEW_DAYS)?;
+        let users = self.user_repository.find_all().await?;
+        let users = self.user_rows(users, start, end).await?;
+
+        Ok(AllocationHeatmap {
+            start_date: start,
+            end_date: end,
+            users,
+        })
+    }
+
+    /// Every week in the range in which a user is allocated beyond capacity
+    pub async fn over_allocations(
+        &self,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<Vec<OverAllocation>, DomainError> {
+        validate_range(start, end, MAX_VIEW_DAYS)?;
+        let allocated: HashSet<Uuid> = self
+            .allocation_repository
+            .find_in_range(start, end)
+            .await?
+            .into_iter()
+            .map(|a| a.user_id)
+            .collect();
+        let mut users = self.user_repository.find_all().await?;
+        users.retain(|u| allocated.contains(&u.id));
+
+        Ok(self
+            .user_rows(users, start, end)
+            .await?
+            .into_iter()
+            .flat_map(|row| {
+                let (user_id, user_name) = (row.user_id, row.user_name);
+                row.weeks
+                    .into_iter()
+                    .filter(|w| w.over_allocated)
+                    .map(move |week| OverAllocation {
+                        user_id,
+                        user_name: user_name.clone(),
+                        week,
+                    })
+            })
+            .collect())
+    }
+
+    /// Heatmap rows of a team's members and lead, with the team's weekly totals
+    pub async fn team_allocation(
+        &self,
+        team_id: Uuid,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<TeamAllocation, DomainError> {
+        validate_range(start, end, MAX_VIEW_DAYS)?;
+        let team = self
+            .team_repository
+            .find_by_id(team_id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound(format!("Team {} not found", team_id)))?;
+
+        let mut member_ids: HashSet<Uuid> = self
+            .team_repository
+            .find_members(team_id)
+            .await?
+            .into_iter()
+            .map(|m| m.user_id)
+            .collect();
+        member_ids.extend(team.lead_id);
+        let mut users = self.user_repository.find_all().await?;
+        users.retain(|u| member_ids.contains(&u.id));
+
+        let members = self.user_rows(users, start, end).await?;
+        let member_weeks: Vec<Vec<AllocationWeek>> =
+            members.iter().map(|m| m.weeks.clone()).collect();
+        let weeks = AllocationCalculator::new(start, end).combine(&member_weeks);
+
+        Ok(TeamAllocation {
+            team_id,
+            team_name: team.name,
+            start_date: start,
+            end_date: end,
+            members,
+            weeks,
+        })
+    }
+
+    async fn user_rows(
+        &self,
+        mut users: Vec<User>,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<Vec<UserAllocation>, DomainError> {
+        users.sort_by(|a, b| a.name.cmp(&b.name));
+        let user_ids: Vec<Uuid> = users.iter().map(|u| u.id).collect();
+
+        let calendars = self
+            .calendar_service
+            .user_calendars(&user_ids, start, end)
+            .await?;
+        let mut allocations: HashMap<Uuid, Vec<ResourceAllocation>> = HashMap::new();
+        for allocation in self
+            .allocation_repository
+            .find_by_users_in_range(&user_ids, start, end)
+            .await?
+        {
+            allocations
+                .entry(allocation.user_id)
+                .or_default()
+                .push(allocation);
+        }
+
+        let calculator = AllocationCalculator::new(start, end);
+        Ok(users
+            .into_iter()
+            .map(|user| {
+                let weeks = calculator.user_weeks(
+                    &calendars[&user.id],
+                    allocations
+                        .get(&user.id)
+                        .map(Vec::as_slice)
+                        .unwrap_or_default(),
+                );
+                UserAllocation {
+                    user_id: user.id,
+                    user_name: user.name,
+                    over_allocated_weeks: weeks.iter().filter(|w| w.over_allocated).count(),
+                    weeks,
+                }
+            })
+            .collect())
+    }
+
+    async fn with_conflicts(
+        &self,
+        allocation: ResourceAllocation,
+    ) -> Result<AllocationWithConflicts, DomainError> {
+        let (start, end) = (allocation.start_date, allocation.end_date);
+        let user_id = allocation.user_id;
+        let calendar = self
+            .calendar_service
+            .user_calendars(&[user_id], start, end)
+            .await?
+            .remove(&user_id)
+            .ok_or_else(|| DomainError::InternalError("Missing work calendar".into()))?;
+        let allocations = self
+            .allocation_repository
+            .find_by_users_in_range(&[user_id], start, end)
+            .await?;
+
+        let over_allocated_weeks = AllocationCalculator::new(start, end)
+            .user_weeks(&calendar, &allocations)
+            .into_iter()
+            .filter(|w| w.over_allocated)
+            .collect();
+
+        Ok(AllocationWithConflicts {
+            allocation,
+            over_allocated_weeks,
+        })
+    }
+}
+
+fn validate(allocation: &ResourceAllocation) -> Result<(), DomainError> {
+    if allocation.start_date > allocation.end_date {
+        return Err(DomainError::ValidationError(
+            "start_date must not be after end_date".into(),
+        ));
+    }
+    match (allocation.percent, allocation.hours_per_week) {
+        (Some(percent), None) if percent > 0.0 && percent <= 100.0 => Ok(()),
+        (Some(_), None) => Err(DomainError::ValidationError(
+            "Percent must be above 0 and at most 100".into(),
+        )),
+        (None, Some(hours)) if hours > 0.0 && hours <= 168.0 => Ok(()),
+        (None, Some(_)) => Err(DomainError::ValidationError(
+            "Hours per week must be above 0 and at most 168".into(),
+        )),
+        _ => Err(DomainError::ValidationError(
+            "Give either percent or hours_per_week".into(),
+        )),
+    }
+}
@@ -0,0 +1,26 @@
+use uuid::Uuid;
+
+use crate::domain::repositories::TeamRepository;
+use crate::domain::value_objects::UserRole;
+use crate::shared::DomainError;
+
+/// Check if user may decide the other user's timesheets and time off:
+/// admins and managers can approve anyone, team leads their team members,
+/// nobody their own
+pub async fn can_approve(
+    team_repository: &dyn TeamRepository,
+    approver_id: Uuid,
+    role: &UserRole,
+    user_id: Uuid,
+) -> Result<bool, DomainError> {
+    if approver_id == user_id {
+        return Ok(false);
+    }
+    if matches!(role, UserRole::Admin | UserRole::Manager) {
+        return Ok(true);
+    }
+    Ok(team_repository
+        .find_lead_ids_of_member(user_id)
+        .await?
+        .contains(&approver_id))
+}
@@ -3,12 +3,14 @@
  * Copyright (c) 2024 bimakw
  */
 
+use std::collections::HashSet;
 use std::path::PathBuf;
 use std::sync::Arc;
 use tokio::fs;
 use tokio::io::AsyncWriteExt;
 use uuid::Uuid;
 
+use crate::application::services::ProjectAppService;
 use crate::domain::entities::Attachment;
 use crate::domain::repositories::AttachmentRepository;
 use crate::shared::DomainError;
@@ -21,13 +23,19 @@ const ALLOWED_EXTENSIONS: &[&str] = &[
 
 pub struct AttachmentAppService {
     attachment_repository: Arc<dyn AttachmentRepository>,
+    project_service: Arc<ProjectAppService>,
     upload_dir: PathBuf,
 }
 
 impl AttachmentAppService {
-    pub fn new(attachment_repository: Arc<dyn AttachmentRepository>, upload_dir: PathBuf) -> Self {
+    pub fn new(
+        attachment_repository: Arc<dyn AttachmentRepository>,
+        project_service: Arc<ProjectAppService>,
+        upload_dir: PathBuf,
+    ) -> Self {
         Self {
             attachment_repository,
+            project_service,
             upload_dir,
         }
     }
@@ -54,6 +62,8 @@ impl AttachmentAppService {
         content_type: String,
         data: Vec<u8>,
     ) -> Result<Attachment, DomainError> {
+        self.project_service.ensure_task_writable(task_id).await?;
+
         let size_bytes = data.len() as i64;
 
         // Validate file size
@@ -119,6 +129,9 @@ impl AttachmentAppService {
             .find_by_id(id)
             .await?
             .ok_or_else(|| DomainError::NotFound("Attachment not found".into()))?;
+        self.project_service
+            .ensure_task_writable(attachment.task_id)
+            .await?;
 
         // Delete file from disk
         let file_path = self.upload_dir.join(&attachment.storage_path);
@@ -132,6 +145,46 @@ impl AttachmentAppService {
         self.attachment_repository.delete(id).await
     }
 
+    /// Remove the files of every attachment on a task from disk, even if
+    /// the task is in the trash. The database rows are left to be removed
+    /// with the task.
+    pub async fn remove_task_files(&self, task_id: Uuid) -> Result<(), DomainError> {
+        let attachments = self
+            .attachment_repository
+            .find_by_task_including_deleted(task_id)
+            .await?;
+        self.remove_files(&attachments).await
+    }
+
+    /// Remove the files of every attachment in a project from disk, trashed
+    /// or not
+    pub async fn remove_project_files(&self, project_id: Uuid) -> Result<(), DomainError> {
+        let attachments = self
+            .attachment_repository
+            .find_by_project_including_deleted(project_id)
+            .await?;
+        self.remove_files(&attachments).await
+    }
+
+    async fn remove_files(&self, attachments: &[Attachment]) -> Result<(), DomainError> {
+        for attachment in attachments {
+            let file_path = self.upload_dir.join(&attachment.storage_path);
+            if file_path.exists() {
+                fs::remove_file(&file_path).await.map_err(|e| {
+                    DomainError::InternalError(format!("Failed to delete file: {}", e))
+                })?;
+            }
+        }
+
+        // Task directories are only removed once empty
+        let task_dirs: HashSet<Uuid> = attachments.iter().map(|a| a.task_id).collect();
+        for task_id in task_dirs {
+            let _ = fs::remove_dir(self.upload_dir.join(task_id.to_string())).await;
+        }
+
+        Ok(())
+    }
+
     pub async fn get_file_path(&self, id: Uuid) -> Result<PathBuf, DomainError> {
         let attachment = self
             .attachment_repository
@@ -0,0 +1,148 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::application::commands::CreateBaselineCommand;
+use crate::application::services::ProjectAppService;
+use crate::domain::entities::{BaselineSnapshot, ProjectBaseline};
+use crate::domain::repositories::{BaselineRepository, TaskRepository};
+use crate::domain::services::{BaselineVariance, VarianceCalculator};
+use crate::shared::DomainError;
+
+/// Current plan of a project compared against one of its baselines
+#[derive(Debug, Serialize)]
+pub struct VarianceReport {
+    pub baseline_id: Uuid,
+    pub baseline_name: String,
+    pub baseline_created_at: DateTime<Utc>,
+    #[serde(flatten)]
+    pub variance: BaselineVariance,
+}
+
+pub struct BaselineAppService {
+    baseline_repository: Arc<dyn BaselineRepository>,
+    task_repository: Arc<dyn TaskRepository>,
+    project_service: Arc<ProjectAppService>,
+}
+
+impl BaselineAppService {
+    pub fn new(
+        baseline_repository: Arc<dyn BaselineRepository>,
+        task_repository: Arc<dyn TaskRepository>,
+        project_service: Arc<ProjectAppService>,
+    ) -> Self {
+        Self {
+            baseline_repository,
+            task_repository,
+            project_service,
+        }
+    }
+
+    /// Check if user can access project
+    pub async fn can_access_project(
+        &self,
+        project_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<bool, DomainError> {
+        self.project_service
+            .can_user_access(project_id, user_id)
+            .await
+    }
+
+    /// Check if user can read project data (members and program managers)
+    pub async fn can_view_project(
+        &self,
+        project_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<bool, DomainError> {
+        self.project_service
+            .can_user_view(project_id, user_id)
+            .await
+    }
+
+    /// Check if user is owner of project
+    pub async fn is_project_owner(
+        &self,
+        project_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<bool, DomainError> {
+        self.project_service.is_owner(project_id, user_id).await
+    }
+
+    pub async fn list_baselines(
+        &self,
+        project_id: Uuid,
+    ) -> Result<Vec<ProjectBaseline>, DomainError> {
+        self.project_service.get_project(project_id).await?;
+        self.baseline_repository.find_by_project(project_id).await
+    }
+
+    pub async fn get_baseline(&self, id: Uuid) -> Result<ProjectBaseline, DomainError> {
+        self.baseline_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("Baseline not found".into()))
+    }
+
+    /// Freeze the project's current plan under a name
+    pub async fn create_baseline(
+        &self,
+        project_id: Uuid,
+        actor_id: Uuid,
+        cmd: CreateBaselineCommand,
+    ) -> Result<ProjectBaseline, DomainError> {
+        let name = cmd.name.trim();
+        if name.is_empty() {
+            return Err(DomainError::ValidationError(
+                "Baseline name cannot be empty".into(),
+            ));
+        }
+
+        let snapshot = self.capture(project_id).await?;
+        let baseline = ProjectBaseline::new(
+            project_id,
+            name.to_string(),
+            cmd.description,
+            snapshot,
+            actor_id,
+        );
+        self.baseline_repository
+            .create(&baseline)
+            .await
+            .map_err(|e| match e {
+                DomainError::AlreadyExists(_) => DomainError::AlreadyExists(format!(
+                    "Baseline '{}' already exists for this project",
+                    baseline.name
+                )),
+                e => e,
+            })
+    }
+
+    pub async fn delete_baseline(&self, id: Uuid) -> Result<(), DomainError> {
+        self.get_baseline(id).await?;
+        self.baseline_repository.delete(id).await
+    }
+
+    pub async fn get_variance(&self, id: Uuid) -> Result<VarianceReport, DomainError> {
+        let baseline = self.get_baseline(id).await?;
+        let current = self.capture(baseline.project_id).await?;
+
+        Ok(VarianceReport {
+            baseline_id: baseline.id,
+            baseline_name: baseline.name,
+            baseline_created_at: baseline.created_at,
+            variance: VarianceCalculator::compare(&baseline.snapshot, &current),
+        })
+    }
+
+    async fn capture(&self, project_id: Uuid) -> Result<BaselineSnapshot, DomainError> {
+        let project = self.project_service.get_project(project_id).await?;
+        let tasks = self.task_repository.find_by_project(project_id).await?;
+        let milestones = self
+            .project_service
+            .get_project_milestones(project_id)
+            .await?;
+        Ok(BaselineSnapshot::capture(&project, &tasks, &milestones))
+    }
+}
@@ -0,0 +1,191 @@
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use serde::Serialize;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::application::commands::{CreateBillingRateCommand, PrepareInvoiceCommand};
+use crate::application::services::ProjectAppService;
+use crate::domain::entities::{BillableEntry, BillingRate, Invoice, InvoiceLine};
+use crate::domain::repositories::{BillingRateRepository, InvoiceRepository, UserRepository};
+use crate::domain::services::BillingCalculator;
+use crate::shared::DomainError;
+
+/// Uninvoiced billable time of a period, priced at the current rate cards
+#[derive(Debug, Serialize)]
+pub struct InvoicePreview {
+    pub project_id: Option<Uuid>,
+    pub period_start: NaiveDate,
+    pub period_end: NaiveDate,
+    pub total_hours: f32,
+    pub total_amount: Decimal,
+    pub lines: Vec<InvoiceLine>,
+    /// Billable time no rate covers; it blocks invoicing until rates are set
+    pub unrated: Vec<BillableEntry>,
+}
+
+pub struct BillingAppService {
+    billing_rate_repository: Arc<dyn BillingRateRepository>,
+    invoice_repository: Arc<dyn InvoiceRepository>,
+    user_repository: Arc<dyn UserRepository>,
+    project_service: Arc<ProjectAppService>,
+}
+
+impl BillingAppService {
+    pub fn new(
+        billing_rate_repository: Arc<dyn BillingRateRepository>,
+        invoice_repository: Arc<dyn InvoiceRepository>,
+        user_repository: Arc<dyn UserRepository>,
+        project_service: Arc<ProjectAppService>,
+    ) -> Self {
+        Self {
+            billing_rate_repository,
+            invoice_repository,
+            user_repository,
+            project_service,
+        }
+    }
+
+    pub async fn list_rates(&self) -> Result<Vec<BillingRate>, DomainError> {
+        self.billing_rate_repository.find_all().await
+    }
+
+    /// Add a rate to the rate card; it replaces the previous rate of the same
+    /// scope from its effective date on
+    pub async fn create_rate(
+        &self,
+        cmd: CreateBillingRateCommand,
+    ) -> Result<BillingRate, DomainError> {
+        if cmd.hourly_rate < Decimal::ZERO {
+            return Err(DomainError::ValidationError(
+                "Hourly rate cannot be negative".into(),
+            ));
+        }
+        if cmd.user_id.is_some() && cmd.role.is_some() {
+            return Err(DomainError::ValidationError(
+                "A rate applies to a user or a role, not both".into(),
+            ));
+        }
+        if cmd.project_id.is_none() && cmd.user_id.is_none() && cmd.role.is_none() {
+            return Err(DomainError::ValidationError(
+                "Specify a project, a user or a role".into(),
+            ));
+        }
+        if let Some(project_id) = cmd.project_id {
+            self.project_service.get_project(project_id).await?;
+        }
+        if let Some(user_id) = cmd.user_id {
+            self.user_repository
+                .find_by_id(user_id)
+                .await?
+                .ok_or_else(|| DomainError::NotFound("User not found".into()))?;
+        }
+
+        let rate = BillingRate::new(
+            cmd.project_id,
+            cmd.user_id,
+            cmd.role,
+            cmd.hourly_rate,
+            cmd.effective_from,
+        );
+        self.billing_rate_repository
+            .create(&rate)
+            .await
+            .map_err(|e| match e {
+                DomainError::AlreadyExists(_) => DomainError::AlreadyExists(
+                    "A rate for this scope already starts on that date".into(),
+                ),
+                other => other,
+            })
+    }
+
+    pub async fn delete_rate(&self, id: Uuid) -> Result<(), DomainError> {
+        self.billing_rate_repository.delete(id).await
+    }
+
+    pub async fn list_invoices(
+        &self,
+        project_id: Option<Uuid>,
+    ) -> Result<Vec<Invoice>, DomainError> {
+        self.invoice_repository.find_all(project_id).await
+    }
+
+    pub async fn get_invoice(&self, id: Uuid) -> Result<Invoice, DomainError> {
+        self.invoice_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("Invoice not found".into()))
+    }
+
+    /// Group the period's uninvoiced billable hours by project, task and user
+    /// and price them, without invoicing anything
+    pub async fn prepare_invoice(
+        &self,
+        cmd: &PrepareInvoiceCommand,
+    ) -> Result<InvoicePreview, DomainError> {
+        Ok(self.price(cmd).await?.0)
+    }
+
+    /// Invoice the period's billable hours, marking the time logs so they
+    /// can't be billed again
+    pub async fn create_invoice(
+        &self,
+        cmd: PrepareInvoiceCommand,
+        actor_id: Uuid,
+    ) -> Result<Invoice, DomainError> {
+        let (preview, entries) = self.price(&cmd).await?;
+        if !preview.unrated.is_empty() {
+            let hours: f32 = preview.unrated.iter().map(|e| e.hours).sum();
+            return Err(DomainError::ValidationError(format!(
+                "{} billable hours have no billing rate; add rates before invoicing",
+                hours
+            )));
+        }
+        if preview.lines.is_empty() {
+            return Err(DomainError::ValidationError(
+                "There is no uninvoiced billable time in this period".into(),
+            ));
+        }
+
+        let invoice = Invoice::new(
+            cmd.project_id,
+            cmd.start_date,
+            cmd.end_date,
+            preview.lines,
+            actor_id,
+        );
+        self.invoice_repository.create(&invoice, &entries).await
+    }
+
+    async fn price(
+        &self,
+        cmd: &PrepareInvoiceCommand,
+    ) -> Result<(InvoicePreview, Vec<BillableEntry>), DomainError> {
+        if cmd.end_date < cmd.start_date {
+            return Err(DomainError::ValidationError(
+                "End date must not be before the start date".into(),
+            ));
+        }
+        if let Some(project_id) = cmd.project_id {
+            self.project_service.get_project(project_id).await?;
+        }
+
+        let entries = self
+            .invoice_repository
+            .find_billable_entries(cmd.start_date, cmd.end_date, cmd.project_id)
+            .await?;
+        let rates = self.billing_rate_repository.find_all().await?;
+        let priced = BillingCalculator::price(&entries, &rates);
+
+        let preview = InvoicePreview {
+            project_id: cmd.project_id,
+            period_start: cmd.start_date,
+            period_end: cmd.end_date,
+            total_hours: priced.lines.iter().map(|l| l.hours).sum(),
+            total_amount: priced.lines.iter().map(|l| l.amount).sum(),
+            lines: priced.lines,
+            unrated: priced.unrated,
+        };
+        Ok((preview, entries))
+    }
+}
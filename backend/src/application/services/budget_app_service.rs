@@ -0,0 +1,433 @@
+use chrono::{NaiveDate, Utc};
+use rust_decimal::Decimal;
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::application::commands::{
+    CreateExpenseCommand, SetCostRateCommand, UpdateExpenseCommand,
+};
+use crate::application::services::{NotificationAppService, ProjectAppService};
+use crate::domain::entities::{CostRate, Expense, NotificationType};
+use crate::domain::repositories::{
+    AttachmentRepository, BudgetAlertRepository, CostRateRepository, ExpenseRepository,
+    TaskRepository, TimeLogRepository, UserRepository,
+};
+use crate::domain::services::{BudgetCalculator, LaborCost};
+use crate::domain::value_objects::UserRole;
+use crate::shared::DomainError;
+
+#[derive(Debug, Serialize)]
+pub struct CategoryTotal {
+    pub category: String,
+    pub total: Decimal,
+}
+
+/// Spend of a project against its budget, from expenses plus logged hours at cost rates
+#[derive(Debug, Serialize)]
+pub struct BudgetSummary {
+    pub project_id: Uuid,
+    pub budget: Option<Decimal>,
+    pub expenses_total: Decimal,
+    pub labor_cost: Decimal,
+    pub spent: Decimal,
+    pub remaining: Option<Decimal>,
+    pub percent_used: Option<Decimal>,
+    pub burn_rate_per_day: Decimal,
+    pub burn_rate_per_week: Decimal,
+    pub projected_exhaustion_date: Option<NaiveDate>,
+    pub logged_hours: f32,
+    /// Hours logged by users with no cost rate; not included in `labor_cost`
+    pub unrated_hours: f32,
+    pub expenses_by_category: Vec<CategoryTotal>,
+}
+
+pub struct BudgetAppService {
+    expense_repository: Arc<dyn ExpenseRepository>,
+    cost_rate_repository: Arc<dyn CostRateRepository>,
+    budget_alert_repository: Arc<dyn BudgetAlertRepository>,
+    time_log_repository: Arc<dyn TimeLogRepository>,
+    task_repository: Arc<dyn TaskRepository>,
+    attachment_repository: Arc<dyn AttachmentRepository>,
+    user_repository: Arc<dyn UserRepository>,
+    project_service: Arc<ProjectAppService>,
+    notification_service: Arc<NotificationAppService>,
+    alert_thresholds: Vec<u32>,
+}
+
+impl BudgetAppService {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        expense_repository: Arc<dyn ExpenseRepository>,
+        cost_rate_repository: Arc<dyn CostRateRepository>,
+        budget_alert_repository: Arc<dyn BudgetAlertRepository>,
+        time_log_repository: Arc<dyn TimeLogRepository>,
+        task_repository: Arc<dyn TaskRepository>,
+        attachment_repository: Arc<dyn AttachmentRepository>,
+        user_repository: Arc<dyn UserRepository>,
+        project_service: Arc<ProjectAppService>,
+        notification_service: Arc<NotificationAppService>,
+        alert_thresholds: Vec<u32>,
+    ) -> Self {
+        Self {
+            expense_repository,
+            cost_rate_repository,
+            budget_alert_repository,
+            time_log_repository,
+            task_repository,
+            attachment_repository,
+            user_repository,
+            project_service,
+            notification_service,
+            alert_thresholds,
+        }
+    }
+
+    /// Check if user can access project
+    pub async fn can_access_project(
+        &self,
+        project_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<bool, DomainError> {
+        self.project_service
+            .can_user_access(project_id, user_id)
+            .await
+    }
+
+    /// Check if user can read project data (members and program managers)
+    pub async fn can_view_project(
+        &self,
+        project_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<bool, DomainError> {
+        self.project_service
+            .can_user_view(project_id, user_id)
+            .await
+    }
+
+    /// Check if user may change an expense: its author or the project owner
+    pub async fn can_manage_expense(
+        &self,
+        expense_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<bool, DomainError> {
+        let expense = self.get_expense(expense_id).await?;
+        if expense.created_by == Some(user_id) {
+            return self.can_access_project(expense.project_id, user_id).await;
+        }
+        self.project_service
+            .is_owner(expense.project_id, user_id)
+            .await
+    }
+
+    pub async fn list_expenses(&self, project_id: Uuid) -> Result<Vec<Expense>, DomainError> {
+        self.project_service.get_project(project_id).await?;
+        self.expense_repository.find_by_project(project_id).await
+    }
+
+    pub async fn get_expense(&self, id: Uuid) -> Result<Expense, DomainError> {
+        self.expense_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("Expense not found".into()))
+    }
+
+    pub async fn create_expense(
+        &self,
+        project_id: Uuid,
+        actor_id: Uuid,
+        cmd: CreateExpenseCommand,
+    ) -> Result<Expense, DomainError> {
+        self.project_service
+            .ensure_project_writable(project_id)
+            .await?;
+        validate_amount(cmd.amount)?;
+        let category = validate_category(&cmd.category)?;
+        if let Some(attachment_id) = cmd.receipt_attachment_id {
+            self.validate_receipt(project_id, attachment_id).await?;
+        }
+
+        let expense = Expense::new(
+            project_id,
+            cmd.amount,
+            category,
+            cmd.description,
+            cmd.date,
+            cmd.receipt_attachment_id,
+            actor_id,
+        );
+        let expense = self.expense_repository.create(&expense).await?;
+        self.check_thresholds(project_id).await;
+        Ok(expense)
+    }
+
+    pub async fn update_expense(
+        &self,
+        id: Uuid,
+        cmd: UpdateExpenseCommand,
+    ) -> Result<Expense, DomainError> {
+        let mut expense = self.get_expense(id).await?;
+        self.project_service
+            .ensure_project_writable(expense.project_id)
+            .await?;
+
+        if let Some(amount) = cmd.amount {
+            validate_amount(amount)?;
+            expense.amount = amount;
+        }
+        if let Some(category) = cmd.category {
+            expense.category = validate_category(&category)?;
+        }
+        if let Some(description) = cmd.description {
+            expense.description = Some(description);
+        }
+        if let Some(date) = cmd.date {
+            expense.date = date;
+        }
+        if let Some(attachment_id) = cmd.receipt_attachment_id {
+            self.validate_receipt(expense.project_id, attachment_id)
+                .await?;
+            expense.receipt_attachment_id = Some(attachment_id);
+        }
+
+        let expense = self.expense_repository.update(&expense).await?;
+        self.check_thresholds(expense.project_id).await;
+        Ok(expense)
+    }
+
+    pub async fn delete_expense(&self, id: Uuid) -> Result<(), DomainError> {
+        let expense = self.get_expense(id).await?;
+        self.project_service
+            .ensure_project_writable(expense.project_id)
+            .await?;
+
+        self.expense_repository.delete(id).await?;
+        self.check_thresholds(expense.project_id).await;
+        Ok(())
+    }
+
+    pub async fn list_cost_rates(&self) -> Result<Vec<CostRate>, DomainError> {
+        self.cost_rate_repository.find_all().await
+    }
+
+    /// Create or replace the rate of a user or a role
+    pub async fn set_cost_rate(&self, cmd: SetCostRateCommand) -> Result<CostRate, DomainError> {
+        if cmd.hourly_rate < Decimal::ZERO {
+            return Err(DomainError::ValidationError(
+                "Hourly rate cannot be negative".into(),
+            ));
+        }
+
+        let rate = match (cmd.user_id, cmd.role) {
+            (Some(user_id), None) => {
+                self.user_repository
+                    .find_by_id(user_id)
+                    .await?
+                    .ok_or_else(|| DomainError::NotFound("User not found".into()))?;
+                CostRate::for_user(user_id, cmd.hourly_rate)
+            }
+            (None, Some(role)) => CostRate::for_role(role, cmd.hourly_rate),
+            _ => {
+                return Err(DomainError::ValidationError(
+                    "Specify either user_id or role".into(),
+                ))
+            }
+        };
+        self.cost_rate_repository.upsert(&rate).await
+    }
+
+    pub async fn delete_cost_rate(&self, id: Uuid) -> Result<(), DomainError> {
+        self.cost_rate_repository.delete(id).await
+    }
+
+    /// A project's logged hours priced at cost rates, one entry per time log
+    pub async fn labor_costs(&self, project_id: Uuid) -> Result<Vec<LaborCost>, DomainError> {
+        let time_logs = self.time_log_repository.find_by_project(project_id).await?;
+        let rates = self.cost_rate_repository.find_all().await?;
+
+        let mut roles: HashMap<Uuid, UserRole> = HashMap::new();
+        for log in &time_logs {
+            if roles.contains_key(&log.user_id) {
+                continue;
+            }
+            if let Some(user) = self.user_repository.find_by_id(log.user_id).await? {
+                roles.insert(user.id, user.role);
+            }
+        }
+
+        Ok(BudgetCalculator::price_logs(&time_logs, &roles, &rates))
+    }
+
+    pub async fn get_budget_summary(&self, project_id: Uuid) -> Result<BudgetSummary, DomainError> {
+        let project = self.project_service.get_project(project_id).await?;
+        let expenses = self.expense_repository.find_by_project(project_id).await?;
+        let labor = self.labor_costs(project_id).await?;
+
+        let mut labor_cost = Decimal::ZERO;
+        let mut logged_hours = 0.0;
+        let mut unrated_hours = 0.0;
+        for entry in &labor {
+            logged_hours += entry.hours;
+            match entry.cost {
+                Some(cost) => labor_cost += cost,
+                None => unrated_hours += entry.hours,
+            }
+        }
+
+        let mut by_category: BTreeMap<String, Decimal> = BTreeMap::new();
+        for expense in &expenses {
+            *by_category.entry(expense.category.clone()).or_default() += expense.amount;
+        }
+        let expenses_total: Decimal = by_category.values().sum();
+        let spent = expenses_total + labor_cost;
+
+        // Burn is measured from the project start, or from the first recorded spend
+        let today = Utc::now().date_naive();
+        let since = project
+            .start_date
+            .map(|d| d.date_naive())
+            .or_else(|| {
+                expenses
+                    .iter()
+                    .map(|e| e.date)
+                    .chain(labor.iter().map(|l| l.date))
+                    .min()
+            })
+            .unwrap_or(today)
+            .min(today);
+        let burn_rate_per_day = BudgetCalculator::burn_rate_per_day(spent, since, today);
+
+        let remaining = project.budget.map(|budget| budget - spent);
+        Ok(BudgetSummary {
+            project_id,
+            budget: project.budget,
+            expenses_total,
+            labor_cost,
+            spent,
+            remaining,
+            percent_used: project
+                .budget
+                .and_then(|budget| BudgetCalculator::percent_used(spent, budget)),
+            burn_rate_per_day,
+            burn_rate_per_week: burn_rate_per_day * Decimal::from(7),
+            projected_exhaustion_date: remaining.and_then(|remaining| {
+                BudgetCalculator::projected_exhaustion(remaining, burn_rate_per_day, today)
+            }),
+            logged_hours,
+            unrated_hours,
+            expenses_by_category: by_category
+                .into_iter()
+                .map(|(category, total)| CategoryTotal { category, total })
+                .collect(),
+        })
+    }
+
+    /// Re-check budget thresholds after time was logged on a task
+    pub async fn check_task_thresholds(&self, task_id: Uuid) {
+        match self.task_repository.find_by_id(task_id).await {
+            Ok(Some(task)) => self.check_thresholds(task.project_id).await,
+            Ok(None) => {}
+            Err(e) => tracing::warn!(task_id = %task_id, "Failed to check budget: {}", e),
+        }
+    }
+
+    /// Notify the project owner once for each budget threshold spend has reached.
+    /// Failures are logged and never fail the write that triggered the check.
+    pub async fn check_thresholds(&self, project_id: Uuid) {
+        if let Err(e) = self.notify_reached_thresholds(project_id).await {
+            tracing::warn!(project_id = %project_id, "Failed to check budget: {}", e);
+        }
+    }
+
+    async fn notify_reached_thresholds(&self, project_id: Uuid) -> Result<(), DomainError> {
+        let summary = self.get_budget_summary(project_id).await?;
+        let reached = match summary.budget {
+            Some(budget) => {
+                BudgetCalculator::reached_thresholds(summary.spent, budget, &self.alert_thresholds)
+            }
+            None => Vec::new(),
+        };
+        let reached: Vec<i32> = reached.into_iter().map(|t| t as i32).collect();
+
+        // Thresholds spend has dropped back under may alert again later
+        self.budget_alert_repository
+            .retain(project_id, &reached)
+            .await?;
+
+        let mut newly_reached = None;
+        for &threshold in &reached {
+            if self
+                .budget_alert_repository
+                .record(project_id, threshold)
+                .await?
+            {
+                newly_reached = Some(threshold);
+            }
+        }
+
+        // Only the highest new threshold is announced when several are crossed at once
+        if let Some(threshold) = newly_reached {
+            let project = self.project_service.get_project(project_id).await?;
+            self.notification_service
+                .create_notification(
+                    project.owner_id,
+                    NotificationType::BudgetAlert,
+                    format!("Budget {}% used", threshold),
+                    format!(
+                        "Project '{}' has spent {} of its {} budget ({}%)",
+                        project.name,
+                        summary.spent,
+                        summary.budget.unwrap_or_default(),
+                        summary.percent_used.unwrap_or_default()
+                    ),
+                    Some(format!("/projects/{}/budget", project_id)),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+
+    /// A receipt must be an attachment on one of the project's tasks
+    async fn validate_receipt(
+        &self,
+        project_id: Uuid,
+        attachment_id: Uuid,
+    ) -> Result<(), DomainError> {
+        let attachment = self
+            .attachment_repository
+            .find_by_id(attachment_id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("Receipt attachment not found".into()))?;
+        let in_project = self
+            .task_repository
+            .find_by_id(attachment.task_id)
+            .await?
+            .is_some_and(|task| task.project_id == project_id);
+        if !in_project {
+            return Err(DomainError::ValidationError(
+                "Receipt must be attached to a task of the same project".into(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+fn validate_amount(amount: Decimal) -> Result<(), DomainError> {
+    if amount <= Decimal::ZERO {
+        return Err(DomainError::ValidationError(
+            "Expense amount must be positive".into(),
+        ));
+    }
+    Ok(())
+}
+
+fn validate_category(category: &str) -> Result<String, DomainError> {
+    let category = category.trim();
+    if category.is_empty() {
+        return Err(DomainError::ValidationError(
+            "Expense category cannot be empty".into(),
+        ));
+    }
+    Ok(category.to_string())
+}
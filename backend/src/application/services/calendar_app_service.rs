@@ -0,0 +1,615 @@
+use chrono::NaiveDate;
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::application::commands::{
+    CreateHolidayCalendarCommand, CreateHolidayCommand, DecideTimeOffCommand,
+    RequestTimeOffCommand, UpdateHolidayCalendarCommand,
+};
+use crate::application::services::input::{trimmed, validate_range};
+use crate::application::services::{approval, NotificationAppService};
+use crate::domain::entities::{
+    Holiday, HolidayCalendar, NotificationType, TimeOffRequest, UserCapacity,
+};
+use crate::domain::repositories::{
+    HolidayRepository, TeamRepository, TimeOffRepository, UserCapacityRepository, UserRepository,
+};
+use crate::domain::services::WorkCalendar;
+use crate::domain::value_objects::{TimeOffStatus, UserRole};
+use crate::shared::DomainError;
+
+/// Longest range a time off request or availability view may cover
+const MAX_RANGE_DAYS: i64 = 366;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AbsenceKind {
+    Holiday,
+    TimeOff,
+}
+
+/// A day a team member is, or may be, out
+#[derive(Debug, Serialize)]
+pub struct Absence {
+    pub date: NaiveDate,
+    pub kind: AbsenceKind,
+    /// Holiday name or time off reason
+    pub label: Option<String>,
+    pub time_off_id: Option<Uuid>,
+    /// Pending time off is listed but still counts as a working day
+    pub time_off_status: Option<TimeOffStatus>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct MemberAvailability {
+    pub user_id: Uuid,
+    pub user_name: String,
+    pub working_days: usize,
+    pub capacity_hours: f32,
+    pub absences: Vec<Absence>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TeamAvailability {
+    pub team_id: Uuid,
+    pub team_name: String,
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    pub members: Vec<MemberAvailability>,
+}
+
+/// Capacities, holidays and time off needed to build users' work calendars
+struct CalendarInputs {
+    capacities: HashMap<Uuid, UserCapacity>,
+    default_calendar_id: Option<Uuid>,
+    holidays: HashMap<Uuid, Vec<Holiday>>,
+    time_off: HashMap<Uuid, Vec<TimeOffRequest>>,
+    default_weekly_hours: f32,
+}
+
+impl CalendarInputs {
+    fn capacity(&self, user_id: Uuid) -> UserCapacity {
+        self.capacities
+            .get(&user_id)
+            .cloned()
+            .unwrap_or_else(|| UserCapacity::new(user_id, self.default_weekly_hours, None))
+    }
+
+    fn holidays(&self, capacity: &UserCapacity) -> &[Holiday] {
+        capacity
+            .holiday_calendar_id
+            .or(self.default_calendar_id)
+            .and_then(|id| self.holidays.get(&id))
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    fn time_off(&self, user_id: Uuid) -> &[TimeOffRequest] {
+        self.time_off
+            .get(&user_id)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Holidays and approved time off are days off
+    fn calendar(&self, user_id: Uuid, start: NaiveDate, end: NaiveDate) -> WorkCalendar {
+        let capacity = self.capacity(user_id);
+        let holidays = self.holidays(&capacity).iter().map(|h| h.date);
+        let time_off = self
+            .time_off(user_id)
+            .iter()
+            .filter(|r| r.status == TimeOffStatus::Approved)
+            .flat_map(|r| r.days_within(start, end));
+        WorkCalendar::for_capacity(&capacity, holidays.chain(time_off))
+    }
+}
+
+pub struct CalendarAppService {
+    holiday_repository: Arc<dyn HolidayRepository>,
+    time_off_repository: Arc<dyn TimeOffRepository>,
+    capacity_repository: Arc<dyn UserCapacityRepository>,
+    user_repository: Arc<dyn UserRepository>,
+    team_repository: Arc<dyn TeamRepository>,
+    notification_service: Arc<NotificationAppService>,
+    /// Capacity of users without one of their own
+    default_weekly_hours: f32,
+}
+
+impl CalendarAppService {
+    pub fn new(
+        holiday_repository: Arc<dyn HolidayRepository>,
+        time_off_repository: Arc<dyn TimeOffRepository>,
+        capacity_repository: Arc<dyn UserCapacityRepository>,
+        user_repository: Arc<dyn UserRepository>,
+        team_repository: Arc<dyn TeamRepository>,
+        notification_service: Arc<NotificationAppService>,
+        default_weekly_hours: f32,
+    ) -> Self {
+        Self {
+            holiday_repository,
+            time_off_repository,
+            capacity_repository,
+            user_repository,
+            team_repository,
+            notification_service,
+            default_weekly_hours,
+        }
+    }
+
+    /// Check if user can access team (member or lead)
+    pub async fn can_access_team(&self, team_id: Uuid, user_id: Uuid) -> Result<bool, DomainError> {
+        self.team_repository.can_user_access(team_id, user_id).await
+    }
+
+    /// Check if user may decide the other user's time off: admins and
+    /// managers can approve anyone, team leads their team members
+    pub async fn can_approve(
+        &self,
+        approver_id: Uuid,
+        role: &UserRole,
+        user_id: Uuid,
+    ) -> Result<bool, DomainError> {
+        approval::can_approve(self.team_repository.as_ref(), approver_id, role, user_id).await
+    }
+
+    pub async fn list_calendars(&self) -> Result<Vec<HolidayCalendar>, DomainError> {
+        self.holiday_repository.find_calendars().await
+    }
+
+    pub async fn get_calendar(&self, id: Uuid) -> Result<HolidayCalendar, DomainError> {
+        self.holiday_repository
+            .find_calendar_by_id(id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("Holiday calendar not found".into()))
+    }
+
+    pub async fn create_calendar(
+        &self,
+        cmd: CreateHolidayCalendarCommand,
+    ) -> Result<HolidayCalendar, DomainError> {
+        let name = cmd.name.trim();
+        if name.is_empty() {
+            return Err(DomainError::ValidationError(
+                "Calendar name is required".into(),
+            ));
+        }
+        let calendar = HolidayCalendar::new(name.to_string(), cmd.is_default);
+        self.holiday_repository.create_calendar(&calendar).await
+    }
+
+    pub async fn update_calendar(
+        &self,
+        id: Uuid,
+        cmd: UpdateHolidayCalendarCommand,
+    ) -> Result<HolidayCalendar, DomainError> {
+        let mut calendar = self.get_calendar(id).await?;
+        if let Some(name) = cmd.name {
+            let name = name.trim();
+            if name.is_empty() {
+                return Err(DomainError::ValidationError(
+                    "Calendar name is required".into(),
+                ));
+            }
+            calendar.name = name.to_string();
+        }
+        if let Some(is_default) = cmd.is_default {
+            calendar.is_default = is_default;
+        }
+        self.holiday_repository.update_calendar(&calendar).await
+    }
+
+    pub async fn delete_calendar(&self, id: Uuid) -> Result<(), DomainError> {
+        self.holiday_repository.delete_calendar(id).await
+    }
+
+    pub async fn list_holidays(&self, calendar_id: Uuid) -> Result<Vec<Holiday>, DomainError> {
+        self.get_calendar(calendar_id).await?;
+        self.holiday_repository.find_holidays(calendar_id).await
+    }
+
+    /// Add a holiday, renaming the one already on that date
+    pub async fn add_holiday(
+        &self,
+        calendar_id: Uuid,
+        cmd: CreateHolidayCommand,
+    ) -> Result<Holiday, DomainError> {
+        self.get_calendar(calendar_id).await?;
+        let name = cmd.name.trim();
+        if name.is_empty() {
+            return Err(DomainError::ValidationError(
+                "Holiday name is required".into(),
+            ));
+        }
+        let holiday = Holiday::new(calendar_id, cmd.date, name.to_string());
+        self.holiday_repository
+            .upsert_holidays(&[holiday])
+            .await?
+            .pop()
+            .ok_or_else(|| DomainError::InternalError("Holiday was not saved".into()))
+    }
+
+    pub async fn delete_holiday(&self, id: Uuid) -> Result<(), DomainError> {
+        self.holiday_repository.delete_holiday(id).await
+    }
+
+    /// Add every day of an imported calendar's events as a holiday, given as
+    /// dates with the name of their event. Dates the calendar already has are
+    /// renamed; a day covered by several events keeps the first one's name.
+    pub async fn import_holidays(
+        &self,
+        calendar_id: Uuid,
+        days: Vec<(NaiveDate, String)>,
+    ) -> Result<Vec<Holiday>, DomainError> {
+        self.get_calendar(calendar_id).await?;
+
+        let mut seen = HashSet::new();
+        let holidays: Vec<Holiday> = days
+            .into_iter()
+            .filter(|(date, _)| seen.insert(*date))
+            .map(|(date, summary)| {
+                let name = match summary.trim() {
+                    "" => "Holiday".to_string(),
+                    summary => summary.to_string(),
+                };
+                Holiday::new(calendar_id, date, name)
+            })
+            .collect();
+        if holidays.is_empty() {
+            return Err(DomainError::ValidationError(
+                "The calendar has no events to import".into(),
+            ));
+        }
+
+        self.holiday_repository.upsert_holidays(&holidays).await
+    }
+
+    pub async fn get_time_off(&self, id: Uuid) -> Result<TimeOffRequest, DomainError> {
+        self.time_off_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("Time off request not found".into()))
+    }
+
+    pub async fn list_user_time_off(
+        &self,
+        user_id: Uuid,
+    ) -> Result<Vec<TimeOffRequest>, DomainError> {
+        self.time_off_repository.find_by_user(user_id).await
+    }
+
+    /// Pending requests the user may decide, earliest first
+    pub async fn list_pending(
+        &self,
+        approver_id: Uuid,
+        role: &UserRole,
+    ) -> Result<Vec<TimeOffRequest>, DomainError> {
+        let mut pending = self
+            .time_off_repository
+            .find_by_status(TimeOffStatus::Pending)
+            .await?;
+        pending.retain(|r| r.user_id != approver_id);
+
+        if !matches!(role, UserRole::Admin | UserRole::Manager) {
+            let members = self
+                .team_repository
+                .find_member_ids_led_by(approver_id)
+                .await?;
+            pending.retain(|r| members.contains(&r.user_id));
+        }
+        Ok(pending)
+    }
+
+    /// Ask for days off, notifying the user's team leads
+    pub async fn request_time_off(
+        &self,
+        user_id: Uuid,
+        cmd: RequestTimeOffCommand,
+    ) -> Result<TimeOffRequest, DomainError> {
+        validate_range(cmd.start_date, cmd.end_date, MAX_RANGE_DAYS)?;
+        let overlapping = self
+            .time_off_repository
+            .find_active_in_range(&[user_id], cmd.start_date, cmd.end_date)
+            .await?;
+        if !overlapping.is_empty() {
+            return Err(DomainError::Conflict(
+                "Time off overlaps a pending or approved request".into(),
+            ));
+        }
+
+        let request =
+            TimeOffRequest::new(user_id, cmd.start_date, cmd.end_date, trimmed(cmd.reason));
+        let request = self.time_off_repository.create(&request).await?;
+
+        let leads = self
+            .team_repository
+            .find_lead_ids_of_member(user_id)
+            .await?;
+        self.notification_service
+            .notify_users(
+                &leads,
+                user_id,
+                NotificationType::System,
+                "Time off requested",
+                &format!(
+                    "Time off from {} to {} is waiting for approval",
+                    request.start_date, request.end_date
+                ),
+                Some(&format!("/time-off/{}", request.id)),
+            )
+            .await;
+
+        Ok(request)
+    }
+
+    pub async fn approve_time_off(
+        &self,
+        id: Uuid,
+        approver_id: Uuid,
+        cmd: DecideTimeOffCommand,
+    ) -> Result<TimeOffRequest, DomainError> {
+        let mut request = self.pending(id).await?;
+
+        request.approve(approver_id, trimmed(cmd.comment));
+        let request = self.time_off_repository.update(&request).await?;
+        self.notify_owner(
+            &request,
+            "Time off approved",
+            format!(
+                "Your time off from {} to {} was approved",
+                request.start_date, request.end_date
+            ),
+        )
+        .await;
+
+        Ok(request)
+    }
+
+    pub async fn reject_time_off(
+        &self,
+        id: Uuid,
+        approver_id: Uuid,
+        cmd: DecideTimeOffCommand,
+    ) -> Result<TimeOffRequest, DomainError> {
+        let Some(comment) = trimmed(cmd.comment) else {
+            return Err(DomainError::ValidationError(
+                "A comment explaining the rejection is required".into(),
+            ));
+        };
+        let mut request = self.pending(id).await?;
+
+        request.reject(approver_id, comment.clone());
+        let request = self.time_off_repository.update(&request).await?;
+        self.notify_owner(
+            &request,
+            "Time off rejected",
+            format!(
+                "Your time off from {} to {} was rejected: {}",
+                request.start_date, request.end_date, comment
+            ),
+        )
+        .await;
+
+        Ok(request)
+    }
+
+    /// Withdraw one of the user's own pending or approved requests
+    pub async fn cancel_time_off(
+        &self,
+        id: Uuid,
+        user_id: Uuid,
+    ) -> Result<TimeOffRequest, DomainError> {
+        let mut request = self.get_time_off(id).await?;
+        if request.user_id != user_id {
+            return Err(DomainError::Forbidden(
+                "You can only cancel your own time off".into(),
+            ));
+        }
+        if !request.is_active() {
+            return Err(DomainError::Conflict(
+                "Only pending or approved time off can be cancelled".into(),
+            ));
+        }
+
+        request.cancel();
+        self.time_off_repository.update(&request).await
+    }
+
+    /// Each user's working hours between two dates, less the holidays of
+    /// their calendar and their approved time off
+    pub async fn user_calendars(
+        &self,
+        user_ids: &[Uuid],
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<HashMap<Uuid, WorkCalendar>, DomainError> {
+        let inputs = self.load_inputs(user_ids, start, end).await?;
+        Ok(user_ids
+            .iter()
+            .map(|id| (*id, inputs.calendar(*id, start, end)))
+            .collect())
+    }
+
+    /// Weekdays less the default calendar's holidays, for project schedules
+    pub async fn project_calendar(
+        &self,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<WorkCalendar, DomainError> {
+        let holidays = match self.holiday_repository.find_default_calendar().await? {
+            Some(calendar) => {
+                self.holiday_repository
+                    .find_holidays_in_range(&[calendar.id], start, end)
+                    .await?
+            }
+            None => Vec::new(),
+        };
+        Ok(WorkCalendar::weekdays(holidays.into_iter().map(|h| h.date)))
+    }
+
+    /// Who on a team is out when: holidays and time off of each member and
+    /// lead, with the working days and hours left in the range
+    pub async fn team_availability(
+        &self,
+        team_id: Uuid,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<TeamAvailability, DomainError> {
+        validate_range(start, end, MAX_RANGE_DAYS)?;
+        let team = self
+            .team_repository
+            .find_by_id(team_id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound(format!("Team {} not found", team_id)))?;
+
+        let mut member_ids: HashSet<Uuid> = self
+            .team_repository
+            .find_members(team_id)
+            .await?
+            .into_iter()
+            .map(|m| m.user_id)
+            .collect();
+        member_ids.extend(team.lead_id);
+        let mut users = self.user_repository.find_all().await?;
+        users.retain(|u| member_ids.contains(&u.id));
+        users.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let user_ids: Vec<Uuid> = users.iter().map(|u| u.id).collect();
+        let inputs = self.load_inputs(&user_ids, start, end).await?;
+
+        let members = users
+            .into_iter()
+            .map(|user| {
+                let calendar = inputs.calendar(user.id, start, end);
+                let capacity = inputs.capacity(user.id);
+
+                let mut absences: BTreeMap<NaiveDate, Absence> = BTreeMap::new();
+                for holiday in inputs.holidays(&capacity) {
+                    absences.insert(
+                        holiday.date,
+                        Absence {
+                            date: holiday.date,
+                            kind: AbsenceKind::Holiday,
+                            label: Some(holiday.name.clone()),
+                            time_off_id: None,
+                            time_off_status: None,
+                        },
+                    );
+                }
+                for request in inputs.time_off(user.id) {
+                    for date in request.days_within(start, end) {
+                        // Holidays aren't taken as leave
+                        absences.entry(date).or_insert_with(|| Absence {
+                            date,
+                            kind: AbsenceKind::TimeOff,
+                            label: request.reason.clone(),
+                            time_off_id: Some(request.id),
+                            time_off_status: Some(request.status),
+                        });
+                    }
+                }
+
+                let days = start.iter_days().take_while(|d| *d <= end);
+                MemberAvailability {
+                    user_id: user.id,
+                    user_name: user.name,
+                    working_days: days.clone().filter(|d| calendar.is_working_day(*d)).count(),
+                    capacity_hours: days.map(|d| calendar.hours_on(d)).sum(),
+                    absences: absences.into_values().collect(),
+                }
+            })
+            .collect();
+
+        Ok(TeamAvailability {
+            team_id,
+            team_name: team.name,
+            start_date: start,
+            end_date: end,
+            members,
+        })
+    }
+
+    async fn load_inputs(
+        &self,
+        user_ids: &[Uuid],
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<CalendarInputs, DomainError> {
+        let capacities: HashMap<Uuid, UserCapacity> = self
+            .capacity_repository
+            .find_all()
+            .await?
+            .into_iter()
+            .filter(|c| user_ids.contains(&c.user_id))
+            .map(|c| (c.user_id, c))
+            .collect();
+        let default_calendar_id = self
+            .holiday_repository
+            .find_default_calendar()
+            .await?
+            .map(|c| c.id);
+
+        let calendar_ids: Vec<Uuid> = capacities
+            .values()
+            .filter_map(|c| c.holiday_calendar_id)
+            .chain(default_calendar_id)
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        let mut holidays: HashMap<Uuid, Vec<Holiday>> = HashMap::new();
+        for holiday in self
+            .holiday_repository
+            .find_holidays_in_range(&calendar_ids, start, end)
+            .await?
+        {
+            holidays
+                .entry(holiday.calendar_id)
+                .or_default()
+                .push(holiday);
+        }
+
+        let mut time_off: HashMap<Uuid, Vec<TimeOffRequest>> = HashMap::new();
+        for request in self
+            .time_off_repository
+            .find_active_in_range(user_ids, start, end)
+            .await?
+        {
+            time_off.entry(request.user_id).or_default().push(request);
+        }
+
+        Ok(CalendarInputs {
+            capacities,
+            default_calendar_id,
+            holidays,
+            time_off,
+            default_weekly_hours: self.default_weekly_hours,
+        })
+    }
+
+    async fn pending(&self, id: Uuid) -> Result<TimeOffRequest, DomainError> {
+        let request = self.get_time_off(id).await?;
+        if !request.is_pending() {
+            return Err(DomainError::Conflict(
+                "Only pending time off can be approved or rejected".into(),
+            ));
+        }
+        Ok(request)
+    }
+
+    async fn notify_owner(&self, request: &TimeOffRequest, title: &str, message: String) {
+        if let Err(e) = self
+            .notification_service
+            .create_notification(
+                request.user_id,
+                NotificationType::System,
+                title.to_string(),
+                message,
+                Some(format!("/time-off/{}", request.id)),
+            )
+            .await
+        {
+            tracing::warn!(user_id = %request.user_id, "Failed to create notification: {}", e);
+        }
+    }
+}
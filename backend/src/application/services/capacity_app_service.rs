@@ -0,0 +1,267 @@
+use chrono::{NaiveDate, Utc};
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::application::commands::{SetCapacityCommand, UtilizationReportCommand};
+use crate::application::services::CalendarAppService;
+use crate::domain::entities::{DailyHours, Task, Team, UserCapacity};
+use crate::domain::repositories::{
+    TaskRepository, TeamRepository, TimeLogRepository, UserCapacityRepository, UserRepository,
+};
+use crate::domain::services::{UtilizationCalculator, UtilizationWeek};
+use crate::shared::DomainError;
+
+/// Longest range a utilization report may cover
+const MAX_REPORT_DAYS: i64 = 366;
+
+#[derive(Debug, Serialize)]
+pub struct UserUtilization {
+    pub user_id: Uuid,
+    pub user_name: String,
+    pub weekly_capacity_hours: f32,
+    pub total: UtilizationWeek,
+    pub weeks: Vec<UtilizationWeek>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TeamUtilization {
+    pub team_id: Uuid,
+    pub team_name: String,
+    pub member_count: usize,
+    pub total: UtilizationWeek,
+    pub weeks: Vec<UtilizationWeek>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct UtilizationReport {
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    pub users: Vec<UserUtilization>,
+    pub teams: Vec<TeamUtilization>,
+}
+
+pub struct CapacityAppService {
+    capacity_repository: Arc<dyn UserCapacityRepository>,
+    user_repository: Arc<dyn UserRepository>,
+    team_repository: Arc<dyn TeamRepository>,
+    task_repository: Arc<dyn TaskRepository>,
+    time_log_repository: Arc<dyn TimeLogRepository>,
+    calendar_service: Arc<CalendarAppService>,
+    /// Capacity of users without one of their own
+    default_weekly_hours: f32,
+}
+
+impl CapacityAppService {
+    pub fn new(
+        capacity_repository: Arc<dyn UserCapacityRepository>,
+        user_repository: Arc<dyn UserRepository>,
+        team_repository: Arc<dyn TeamRepository>,
+        task_repository: Arc<dyn TaskRepository>,
+        time_log_repository: Arc<dyn TimeLogRepository>,
+        calendar_service: Arc<CalendarAppService>,
+        default_weekly_hours: f32,
+    ) -> Self {
+        Self {
+            capacity_repository,
+            user_repository,
+            team_repository,
+            task_repository,
+            time_log_repository,
+            calendar_service,
+            default_weekly_hours,
+        }
+    }
+
+    /// Check if user is lead of team
+    pub async fn is_team_lead(&self, team_id: Uuid, user_id: Uuid) -> Result<bool, DomainError> {
+        self.team_repository.is_lead(team_id, user_id).await
+    }
+
+    /// The user's capacity, falling back to the default schedule
+    pub async fn get_capacity(&self, user_id: Uuid) -> Result<UserCapacity, DomainError> {
+        self.user_repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound(format!("User {} not found", user_id)))?;
+        Ok(self
+            .capacity_repository
+            .find_by_user(user_id)
+            .await?
+            .unwrap_or_else(|| self.default_capacity(user_id)))
+    }
+
+    /// Change the given parts of a user's capacity, keeping the rest
+    pub async fn set_capacity(
+        &self,
+        user_id: Uuid,
+        cmd: SetCapacityCommand,
+    ) -> Result<UserCapacity, DomainError> {
+        let current = self.get_capacity(user_id).await?;
+
+        if let Some(days) = &cmd.day_hours {
+            if days.len() != 7 {
+                return Err(DomainError::ValidationError(
+                    "day_hours must list 7 days, Monday first".into(),
+                ));
+            }
+            if days.iter().any(|h| !(0.0..=24.0).contains(h)) {
+                return Err(DomainError::ValidationError(
+                    "Daily hours must be between 0 and 24".into(),
+                ));
+            }
+        }
+        if let Some(hours) = cmd.weekly_hours {
+            if !(0.0..=168.0).contains(&hours) {
+                return Err(DomainError::ValidationError(
+                    "Weekly hours must be between 0 and 168".into(),
+                ));
+            }
+        }
+        if let Some(calendar_id) = cmd.holiday_calendar_id {
+            self.calendar_service.get_calendar(calendar_id).await?;
+        }
+        if cmd.weekly_hours.is_none()
+            && cmd.day_hours.is_none()
+            && cmd.holiday_calendar_id.is_none()
+        {
+            return Err(DomainError::ValidationError(
+                "Provide weekly_hours, day_hours or holiday_calendar_id".into(),
+            ));
+        }
+
+        // New weekly hours replace a day schedule unless one is given too
+        let day_hours = match (cmd.day_hours, cmd.weekly_hours) {
+            (Some(days), _) => Some(days),
+            (None, Some(_)) => None,
+            (None, None) => current.day_hours,
+        };
+        let mut capacity = UserCapacity::new(
+            user_id,
+            cmd.weekly_hours.unwrap_or(current.weekly_hours),
+            day_hours,
+        );
+        capacity.holiday_calendar_id = cmd.holiday_calendar_id.or(current.holiday_calendar_id);
+        self.capacity_repository.upsert(&capacity).await
+    }
+
+    /// Capacity against logged and planned hours per user and team, in weekly buckets
+    pub async fn utilization_report(
+        &self,
+        cmd: &UtilizationReportCommand,
+    ) -> Result<UtilizationReport, DomainError> {
+        if cmd.start_date > cmd.end_date {
+            return Err(DomainError::ValidationError(
+                "start_date must not be after end_date".into(),
+            ));
+        }
+        if (cmd.end_date - cmd.start_date).num_days() >= MAX_REPORT_DAYS {
+            return Err(DomainError::ValidationError(format!(
+                "Reports can cover at most {} days",
+                MAX_REPORT_DAYS
+            )));
+        }
+
+        let teams = match cmd.team_id {
+            Some(team_id) => vec![self
+                .team_repository
+                .find_by_id(team_id)
+                .await?
+                .ok_or_else(|| DomainError::NotFound(format!("Team {} not found", team_id)))?],
+            None => self.team_repository.find_all().await?,
+        };
+        let mut team_members: Vec<(Team, HashSet<Uuid>)> = Vec::new();
+        for team in teams {
+            let mut members: HashSet<Uuid> = self
+                .team_repository
+                .find_members(team.id)
+                .await?
+                .into_iter()
+                .map(|m| m.user_id)
+                .collect();
+            members.extend(team.lead_id);
+            team_members.push((team, members));
+        }
+
+        let mut users = self.user_repository.find_all().await?;
+        if cmd.team_id.is_some() {
+            let members = &team_members[0].1;
+            users.retain(|u| members.contains(&u.id));
+        }
+        users.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let user_ids: Vec<Uuid> = users.iter().map(|u| u.id).collect();
+        let calendars = self
+            .calendar_service
+            .user_calendars(&user_ids, cmd.start_date, cmd.end_date)
+            .await?;
+        let mut logged: HashMap<Uuid, Vec<DailyHours>> = HashMap::new();
+        for entry in self
+            .time_log_repository
+            .sum_by_user_and_date(cmd.start_date, cmd.end_date)
+            .await?
+        {
+            logged.entry(entry.user_id).or_default().push(entry);
+        }
+        let mut assigned: HashMap<Uuid, Vec<Task>> = HashMap::new();
+        for task in self.task_repository.find_all(false).await? {
+            if let Some(user_id) = task.assignee_id {
+                assigned.entry(user_id).or_default().push(task);
+            }
+        }
+
+        let calculator =
+            UtilizationCalculator::new(cmd.start_date, cmd.end_date, Utc::now().date_naive());
+        let mut weeks_by_user: HashMap<Uuid, Vec<UtilizationWeek>> = HashMap::new();
+        let mut user_reports = Vec::with_capacity(users.len());
+        for user in users {
+            let calendar = &calendars[&user.id];
+            let weeks = calculator.user_weeks(
+                calendar,
+                logged.get(&user.id).map(Vec::as_slice).unwrap_or_default(),
+                assigned
+                    .get(&user.id)
+                    .map(Vec::as_slice)
+                    .unwrap_or_default(),
+            );
+            weeks_by_user.insert(user.id, weeks.clone());
+            user_reports.push(UserUtilization {
+                user_id: user.id,
+                user_name: user.name,
+                weekly_capacity_hours: calendar.weekly_hours(),
+                total: calculator.total(&weeks),
+                weeks,
+            });
+        }
+
+        let team_reports = team_members
+            .into_iter()
+            .map(|(team, members)| {
+                let member_weeks: Vec<Vec<UtilizationWeek>> = members
+                    .iter()
+                    .filter_map(|id| weeks_by_user.get(id).cloned())
+                    .collect();
+                let weeks = calculator.combine(&member_weeks);
+                TeamUtilization {
+                    team_id: team.id,
+                    team_name: team.name,
+                    member_count: member_weeks.len(),
+                    total: calculator.total(&weeks),
+                    weeks,
+                }
+            })
+            .collect();
+
+        Ok(UtilizationReport {
+            start_date: cmd.start_date,
+            end_date: cmd.end_date,
+            users: user_reports,
+            teams: team_reports,
+        })
+    }
+
+    fn default_capacity(&self, user_id: Uuid) -> UserCapacity {
+        UserCapacity::new(user_id, self.default_weekly_hours, None)
+    }
+}
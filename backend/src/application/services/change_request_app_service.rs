@@ -0,0 +1,466 @@
+use rust_decimal::Decimal;
+use serde_json::json;
+use std::collections::HashSet;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::application::commands::{
+    CreateChangeRequestCommand, DecideChangeRequestCommand, SetChangeApproversCommand,
+};
+use crate::application::services::input::trimmed;
+use crate::application::services::{NotificationAppService, ProjectAppService};
+use crate::domain::entities::{
+    ActivityLog, ActivityLogWithDetails, ChangeRequest, ChangeSet, MilestoneDateChange,
+    NotificationType,
+};
+use crate::domain::repositories::{ActivityLogRepository, ChangeRequestRepository};
+use crate::domain::value_objects::ChangeRequestStatus;
+use crate::shared::DomainError;
+
+/// Activity log entity type of the change request audit trail
+const CHANGE_REQUEST_ENTITY: &str = "change_request";
+/// Activity log entity type for changes to a project's approver list
+const CHANGE_CONTROL_ENTITY: &str = "change_control";
+
+pub struct ChangeRequestAppService {
+    change_request_repository: Arc<dyn ChangeRequestRepository>,
+    activity_repository: Arc<dyn ActivityLogRepository>,
+    project_service: Arc<ProjectAppService>,
+    notification_service: Arc<NotificationAppService>,
+}
+
+impl ChangeRequestAppService {
+    pub fn new(
+        change_request_repository: Arc<dyn ChangeRequestRepository>,
+        activity_repository: Arc<dyn ActivityLogRepository>,
+        project_service: Arc<ProjectAppService>,
+        notification_service: Arc<NotificationAppService>,
+    ) -> Self {
+        Self {
+            change_request_repository,
+            activity_repository,
+            project_service,
+            notification_service,
+        }
+    }
+
+    /// Check if user can access project
+    pub async fn can_access_project(
+        &self,
+        project_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<bool, DomainError> {
+        self.project_service
+            .can_user_access(project_id, user_id)
+            .await
+    }
+
+    /// Check if user can read project data (members and program managers)
+    pub async fn can_view_project(
+        &self,
+        project_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<bool, DomainError> {
+        self.project_service
+            .can_user_view(project_id, user_id)
+            .await
+    }
+
+    /// Check if user is owner of project
+    pub async fn is_project_owner(
+        &self,
+        project_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<bool, DomainError> {
+        self.project_service.is_owner(project_id, user_id).await
+    }
+
+    pub async fn get_approvers(&self, project_id: Uuid) -> Result<Vec<Uuid>, DomainError> {
+        self.project_service.get_project(project_id).await?;
+        self.change_request_repository
+            .find_approvers(project_id)
+            .await
+    }
+
+    /// Replace the designated approvers; an empty list lifts change control
+    pub async fn set_approvers(
+        &self,
+        project_id: Uuid,
+        actor_id: Uuid,
+        cmd: SetChangeApproversCommand,
+    ) -> Result<Vec<Uuid>, DomainError> {
+        self.project_service
+            .ensure_project_writable(project_id)
+            .await?;
+
+        let mut seen = HashSet::new();
+        let user_ids: Vec<Uuid> = cmd
+            .user_ids
+            .into_iter()
+            .filter(|id| seen.insert(*id))
+            .collect();
+        for &user_id in &user_ids {
+            if !self
+                .project_service
+                .can_user_access(project_id, user_id)
+                .await?
+            {
+                return Err(DomainError::ValidationError(
+                    "Approvers must be members of the project".into(),
+                ));
+            }
+        }
+
+        self.change_request_repository
+            .set_approvers(project_id, &user_ids)
+            .await?;
+        self.record(
+            actor_id,
+            project_id,
+            "approvers_updated",
+            CHANGE_CONTROL_ENTITY,
+            project_id,
+            json!({ "approver_ids": user_ids }),
+        )
+        .await?;
+
+        Ok(user_ids)
+    }
+
+    pub async fn get_request(&self, id: Uuid) -> Result<ChangeRequest, DomainError> {
+        self.change_request_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("Change request not found".into()))
+    }
+
+    pub async fn list_requests(
+        &self,
+        project_id: Uuid,
+        status: Option<ChangeRequestStatus>,
+    ) -> Result<Vec<ChangeRequest>, DomainError> {
+        self.project_service.get_project(project_id).await?;
+        self.change_request_repository
+            .find_by_project(project_id, status)
+            .await
+    }
+
+    /// Audit trail of a request, oldest first
+    pub async fn get_history(&self, id: Uuid) -> Result<Vec<ActivityLogWithDetails>, DomainError> {
+        self.activity_repository
+            .find_by_entity(CHANGE_REQUEST_ENTITY, id)
+            .await
+    }
+
+    pub async fn submit_request(
+        &self,
+        project_id: Uuid,
+        actor_id: Uuid,
+        cmd: CreateChangeRequestCommand,
+    ) -> Result<ChangeRequest, DomainError> {
+        self.project_service
+            .ensure_project_writable(project_id)
+            .await?;
+        let approvers = self
+            .change_request_repository
+            .find_approvers(project_id)
+            .await?;
+        if approvers.is_empty() {
+            return Err(DomainError::ValidationError(
+                "Project is not under change control; edit it directly".into(),
+            ));
+        }
+
+        let justification = cmd.justification.trim().to_string();
+        if justification.is_empty() {
+            return Err(DomainError::ValidationError(
+                "A justification is required".into(),
+            ));
+        }
+        let previous = self.validate_changes(project_id, &cmd.changes).await?;
+
+        let request =
+            ChangeRequest::new(project_id, cmd.changes, previous, justification, actor_id);
+        let request = self.change_request_repository.create(&request).await?;
+        self.record(
+            actor_id,
+            project_id,
+            "submitted",
+            CHANGE_REQUEST_ENTITY,
+            request.id,
+            json!({
+                "proposed": request.proposed,
+                "previous": request.previous,
+                "justification": request.justification,
+            }),
+        )
+        .await?;
+
+        let project = self.project_service.get_project(project_id).await?;
+        self.notify(
+            &approvers,
+            actor_id,
+            &request,
+            "Change request awaiting approval",
+            &format!("A change to '{}' needs your approval", project.name),
+        )
+        .await;
+
+        Ok(request)
+    }
+
+    pub async fn approve_request(
+        &self,
+        id: Uuid,
+        actor_id: Uuid,
+        is_admin: bool,
+        cmd: DecideChangeRequestCommand,
+    ) -> Result<ChangeRequest, DomainError> {
+        let mut request = self.pending_for_decision(id, actor_id, is_admin).await?;
+        self.project_service
+            .ensure_project_writable(request.project_id)
+            .await?;
+
+        request.approve(actor_id, trimmed(cmd.comment));
+        let request = self
+            .change_request_repository
+            .approve_and_apply(&request)
+            .await?;
+        self.record(
+            actor_id,
+            request.project_id,
+            "approved",
+            CHANGE_REQUEST_ENTITY,
+            request.id,
+            json!({ "comment": request.decision_comment, "applied": request.proposed }),
+        )
+        .await?;
+
+        let requester: Vec<Uuid> = request.requested_by.into_iter().collect();
+        self.notify(
+            &requester,
+            actor_id,
+            &request,
+            "Change request approved",
+            "Your change request was approved and applied",
+        )
+        .await;
+
+        Ok(request)
+    }
+
+    pub async fn reject_request(
+        &self,
+        id: Uuid,
+        actor_id: Uuid,
+        is_admin: bool,
+        cmd: DecideChangeRequestCommand,
+    ) -> Result<ChangeRequest, DomainError> {
+        let Some(comment) = trimmed(cmd.comment) else {
+            return Err(DomainError::ValidationError(
+                "A comment explaining the rejection is required".into(),
+            ));
+        };
+        let mut request = self.pending_for_decision(id, actor_id, is_admin).await?;
+
+        request.reject(actor_id, Some(comment));
+        let request = self
+            .change_request_repository
+            .save_decision(&request)
+            .await?;
+        self.record(
+            actor_id,
+            request.project_id,
+            "rejected",
+            CHANGE_REQUEST_ENTITY,
+            request.id,
+            json!({ "comment": request.decision_comment }),
+        )
+        .await?;
+
+        let requester: Vec<Uuid> = request.requested_by.into_iter().collect();
+        self.notify(
+            &requester,
+            actor_id,
+            &request,
+            "Change request rejected",
+            &format!(
+                "Your change request was rejected: {}",
+                request.decision_comment.as_deref().unwrap_or_default()
+            ),
+        )
+        .await;
+
+        Ok(request)
+    }
+
+    /// Only the requester can pull back a request still awaiting a decision
+    pub async fn withdraw_request(
+        &self,
+        id: Uuid,
+        actor_id: Uuid,
+    ) -> Result<ChangeRequest, DomainError> {
+        let mut request = self.get_request(id).await?;
+        if request.requested_by != Some(actor_id) {
+            return Err(DomainError::Forbidden(
+                "Only the requester can withdraw a change request".into(),
+            ));
+        }
+        if !request.is_pending() {
+            return Err(DomainError::Conflict(
+                "Change request has already been decided".into(),
+            ));
+        }
+
+        request.withdraw();
+        let request = self
+            .change_request_repository
+            .save_decision(&request)
+            .await?;
+        self.record(
+            actor_id,
+            request.project_id,
+            "withdrawn",
+            CHANGE_REQUEST_ENTITY,
+            request.id,
+            json!({}),
+        )
+        .await?;
+
+        Ok(request)
+    }
+
+    /// Load a pending request the actor may decide on: designated approvers
+    /// and admins, but never the requester themselves
+    async fn pending_for_decision(
+        &self,
+        id: Uuid,
+        actor_id: Uuid,
+        is_admin: bool,
+    ) -> Result<ChangeRequest, DomainError> {
+        let request = self.get_request(id).await?;
+        if !request.is_pending() {
+            return Err(DomainError::Conflict(
+                "Change request has already been decided".into(),
+            ));
+        }
+        if request.requested_by == Some(actor_id) {
+            return Err(DomainError::Forbidden(
+                "You can't decide on your own change request".into(),
+            ));
+        }
+        if !is_admin
+            && !self
+                .change_request_repository
+                .find_approvers(request.project_id)
+                .await?
+                .contains(&actor_id)
+        {
+            return Err(DomainError::Forbidden(
+                "Only designated approvers can decide on change requests".into(),
+            ));
+        }
+
+        Ok(request)
+    }
+
+    /// Check the proposed values and return the current values of the same fields
+    async fn validate_changes(
+        &self,
+        project_id: Uuid,
+        changes: &ChangeSet,
+    ) -> Result<ChangeSet, DomainError> {
+        if changes.is_empty() {
+            return Err(DomainError::ValidationError(
+                "Propose a new budget, end date or milestone date".into(),
+            ));
+        }
+        if changes.budget.flatten().is_some_and(|b| b < Decimal::ZERO) {
+            return Err(DomainError::ValidationError(
+                "Budget must not be negative".into(),
+            ));
+        }
+
+        let project = self.project_service.get_project(project_id).await?;
+        if let (Some(start), Some(end)) = (project.start_date, changes.end_date.flatten()) {
+            if end < start {
+                return Err(DomainError::ValidationError(
+                    "End date must not be before the project start date".into(),
+                ));
+            }
+        }
+
+        let milestones = self
+            .project_service
+            .get_project_milestones(project_id)
+            .await?;
+        let mut seen = HashSet::new();
+        let mut previous_dates = Vec::new();
+        for change in &changes.milestone_dates {
+            if !seen.insert(change.milestone_id) {
+                return Err(DomainError::ValidationError(
+                    "Each milestone can only be changed once per request".into(),
+                ));
+            }
+            let milestone = milestones
+                .iter()
+                .find(|m| m.id == change.milestone_id)
+                .ok_or_else(|| {
+                    DomainError::ValidationError(
+                        "Milestones must belong to the same project".into(),
+                    )
+                })?;
+            previous_dates.push(MilestoneDateChange {
+                milestone_id: milestone.id,
+                due_date: milestone.due_date,
+            });
+        }
+
+        Ok(ChangeSet {
+            budget: changes.budget.map(|_| project.budget),
+            end_date: changes.end_date.map(|_| project.end_date),
+            milestone_dates: previous_dates,
+        })
+    }
+
+    async fn record(
+        &self,
+        actor_id: Uuid,
+        project_id: Uuid,
+        action: &str,
+        entity_type: &str,
+        entity_id: Uuid,
+        details: serde_json::Value,
+    ) -> Result<(), DomainError> {
+        let log = ActivityLog::new(
+            Some(actor_id),
+            Some(project_id),
+            action.to_string(),
+            entity_type.to_string(),
+            entity_id,
+            Some(details),
+        );
+        self.activity_repository.create(&log).await?;
+        Ok(())
+    }
+
+    async fn notify(
+        &self,
+        recipients: &[Uuid],
+        actor_id: Uuid,
+        request: &ChangeRequest,
+        title: &str,
+        message: &str,
+    ) {
+        self.notification_service
+            .notify_users(
+                recipients,
+                actor_id,
+                NotificationType::ProjectUpdated,
+                title,
+                message,
+                Some(&format!("/change-requests/{}", request.id)),
+            )
+            .await;
+    }
+}
@@ -0,0 +1,177 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::application::commands::{CreateCommentCommand, UpdateCommentCommand};
+use crate::application::services::{
+    MentionAppService, NotificationAppService, ProjectAppService, WithMentions,
+};
+use crate::domain::entities::{NotificationType, TaskComment, Watcher};
+use crate::domain::repositories::{TaskCommentRepository, TaskRepository, WatcherRepository};
+use crate::domain::value_objects::{MentionSourceType, WatchEntityType};
+use crate::shared::DomainError;
+
+pub struct CommentAppService {
+    comment_repository: Arc<dyn TaskCommentRepository>,
+    task_repository: Arc<dyn TaskRepository>,
+    watcher_repository: Arc<dyn WatcherRepository>,
+    project_service: Arc<ProjectAppService>,
+    mention_service: Arc<MentionAppService>,
+    notification_service: Arc<NotificationAppService>,
+}
+
+impl CommentAppService {
+    pub fn new(
+        comment_repository: Arc<dyn TaskCommentRepository>,
+        task_repository: Arc<dyn TaskRepository>,
+        watcher_repository: Arc<dyn WatcherRepository>,
+        project_service: Arc<ProjectAppService>,
+        mention_service: Arc<MentionAppService>,
+        notification_service: Arc<NotificationAppService>,
+    ) -> Self {
+        Self {
+            comment_repository,
+            task_repository,
+            watcher_repository,
+            project_service,
+            mention_service,
+            notification_service,
+        }
+    }
+
+    /// Check if user can access task (via project access)
+    pub async fn can_user_access(&self, task_id: Uuid, user_id: Uuid) -> Result<bool, DomainError> {
+        self.task_repository.can_user_access(task_id, user_id).await
+    }
+
+    /// Check if user can read the task's comments (members and program managers)
+    pub async fn can_user_view(&self, task_id: Uuid, user_id: Uuid) -> Result<bool, DomainError> {
+        self.task_repository.can_user_view(task_id, user_id).await
+    }
+
+    pub async fn get_comment(&self, id: Uuid) -> Result<TaskComment, DomainError> {
+        self.comment_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("Comment not found".into()))
+    }
+
+    pub async fn get_task_comments(&self, task_id: Uuid) -> Result<Vec<TaskComment>, DomainError> {
+        self.comment_repository.find_by_task(task_id).await
+    }
+
+    pub async fn add_comment(
+        &self,
+        task_id: Uuid,
+        cmd: CreateCommentCommand,
+        author_id: Uuid,
+    ) -> Result<WithMentions<TaskComment>, DomainError> {
+        if cmd.content.trim().is_empty() {
+            return Err(DomainError::ValidationError(
+                "Comment cannot be empty".into(),
+            ));
+        }
+
+        let task = self
+            .task_repository
+            .find_by_id(task_id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("Task not found".into()))?;
+        self.project_service
+            .ensure_project_writable(task.project_id)
+            .await?;
+
+        let comment = TaskComment::new(task_id, author_id, cmd.content);
+        let comment = self.comment_repository.create(&comment).await?;
+
+        // Commenters follow the conversation they joined
+        let watcher = Watcher::new(author_id, WatchEntityType::Task, task_id);
+        self.watcher_repository.watch(&watcher).await?;
+
+        let mentions = self
+            .mention_service
+            .process_mentions(
+                &task,
+                MentionSourceType::Comment,
+                comment.id,
+                &comment.content,
+                author_id,
+            )
+            .await?;
+
+        // Mentioned users already received a more specific notification
+        let recipients: Vec<Uuid> = self
+            .watcher_repository
+            .find_by_entity(WatchEntityType::Task, task_id)
+            .await?
+            .into_iter()
+            .map(|w| w.user_id)
+            .filter(|id| !mentions.mentioned_user_ids.contains(id))
+            .collect();
+        self.notification_service
+            .notify_users(
+                &recipients,
+                author_id,
+                NotificationType::CommentAdded,
+                "New comment",
+                &format!("A new comment was added to '{}'", task.title),
+                Some(&format!("/tasks/{}", task_id)),
+            )
+            .await;
+
+        Ok(WithMentions::new(comment, mentions.unresolved))
+    }
+
+    pub async fn update_comment(
+        &self,
+        id: Uuid,
+        cmd: UpdateCommentCommand,
+        actor_id: Uuid,
+    ) -> Result<WithMentions<TaskComment>, DomainError> {
+        if cmd.content.trim().is_empty() {
+            return Err(DomainError::ValidationError(
+                "Comment cannot be empty".into(),
+            ));
+        }
+
+        let mut comment = self.get_comment(id).await?;
+        if comment.user_id != actor_id {
+            return Err(DomainError::Forbidden(
+                "Only the author can edit this comment".into(),
+            ));
+        }
+
+        let task = self
+            .task_repository
+            .find_by_id(comment.task_id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("Task not found".into()))?;
+        self.project_service
+            .ensure_project_writable(task.project_id)
+            .await?;
+
+        comment.content = cmd.content;
+        let comment = self.comment_repository.update(&comment).await?;
+
+        let mentions = self
+            .mention_service
+            .process_mentions(
+                &task,
+                MentionSourceType::Comment,
+                comment.id,
+                &comment.content,
+                actor_id,
+            )
+            .await?;
+
+        Ok(WithMentions::new(comment, mentions.unresolved))
+    }
+
+    pub async fn delete_comment(&self, id: Uuid) -> Result<(), DomainError> {
+        let comment = self.get_comment(id).await?;
+        self.project_service
+            .ensure_task_writable(comment.task_id)
+            .await?;
+
+        self.comment_repository.delete(id).await
+    }
+}
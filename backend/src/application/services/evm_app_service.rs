@@ -0,0 +1,140 @@
+use chrono::{NaiveDate, Utc};
+use rust_decimal::prelude::ToPrimitive;
+use serde::Serialize;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::application::services::{BudgetAppService, ProjectAppService};
+use crate::domain::repositories::TaskRepository;
+use crate::domain::services::{EarnedValueCalculator, EvmCost, EvmSnapshot, EvmTask};
+use crate::domain::value_objects::TaskStatus;
+use crate::shared::DomainError;
+
+#[derive(Debug, Serialize)]
+pub struct ProjectEvm {
+    pub project_id: Uuid,
+    pub as_of: NaiveDate,
+    pub budget_at_completion: f64,
+    #[serde(flatten)]
+    pub current: EvmSnapshot,
+    /// Snapshots at the end of each week since the project start
+    pub series: Vec<EvmSnapshot>,
+    /// Hours logged by users with no cost rate; not included in actual cost
+    pub unrated_hours: f32,
+    /// Tasks with no due date in a project with no end date; they earn value
+    /// when done but are left out of planned value
+    pub unscheduled_task_ids: Vec<Uuid>,
+}
+
+pub struct EvmAppService {
+    task_repository: Arc<dyn TaskRepository>,
+    project_service: Arc<ProjectAppService>,
+    budget_service: Arc<BudgetAppService>,
+}
+
+impl EvmAppService {
+    pub fn new(
+        task_repository: Arc<dyn TaskRepository>,
+        project_service: Arc<ProjectAppService>,
+        budget_service: Arc<BudgetAppService>,
+    ) -> Self {
+        Self {
+            task_repository,
+            project_service,
+            budget_service,
+        }
+    }
+
+    /// Check if user can access project
+    pub async fn can_access_project(
+        &self,
+        project_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<bool, DomainError> {
+        self.project_service
+            .can_user_access(project_id, user_id)
+            .await
+    }
+
+    /// Check if user can read project data (members and program managers)
+    pub async fn can_view_project(
+        &self,
+        project_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<bool, DomainError> {
+        self.project_service
+            .can_user_view(project_id, user_id)
+            .await
+    }
+
+    /// Earned value of a project as of a date (default today). Each task's
+    /// value is planned to accrue from the project start to its due date, or
+    /// to the project end date for tasks without one. Actual cost is logged
+    /// hours at cost rates, as in the budget summary.
+    pub async fn get_project_evm(
+        &self,
+        project_id: Uuid,
+        as_of: Option<NaiveDate>,
+    ) -> Result<ProjectEvm, DomainError> {
+        let project = self.project_service.get_project(project_id).await?;
+        let budget_at_completion = project
+            .budget
+            .and_then(|b| b.to_f64())
+            .filter(|b| *b > 0.0)
+            .ok_or_else(|| {
+                DomainError::ValidationError("Project needs a budget for earned value".into())
+            })?;
+
+        let start = project
+            .start_date
+            .unwrap_or(project.created_at)
+            .date_naive();
+        let end = project.end_date.map(|d| d.date_naive());
+
+        let mut tasks = Vec::new();
+        let mut unscheduled_task_ids = Vec::new();
+        for task in self.task_repository.find_by_project(project_id).await? {
+            let finish = task.due_date.map(|d| d.date_naive()).or(end);
+            if finish.is_none() {
+                unscheduled_task_ids.push(task.id);
+            }
+            // Tasks finished before their timestamp was tracked count as done when last updated
+            let completed_on = (task.status == TaskStatus::Done)
+                .then(|| task.completed_at.unwrap_or(task.updated_at).date_naive());
+            tasks.push(EvmTask {
+                estimated_hours: task.estimated_hours.unwrap_or(0.0),
+                planned_start: start,
+                planned_finish: finish.map(|finish| finish.max(start)),
+                completed_on,
+            });
+        }
+
+        let calculator =
+            EarnedValueCalculator::new(budget_at_completion, &tasks).ok_or_else(|| {
+                DomainError::ValidationError("Project tasks need estimated hours".into())
+            })?;
+
+        let mut costs = Vec::new();
+        let mut unrated_hours = 0.0;
+        for entry in self.budget_service.labor_costs(project_id).await? {
+            match entry.cost {
+                Some(cost) => costs.push(EvmCost {
+                    date: entry.date,
+                    cost: cost.to_f64().unwrap_or_default(),
+                }),
+                None => unrated_hours += entry.hours,
+            }
+        }
+
+        let as_of = as_of.unwrap_or_else(|| Utc::now().date_naive());
+        Ok(ProjectEvm {
+            project_id,
+            as_of,
+            budget_at_completion,
+            current: calculator.snapshot(&tasks, &costs, as_of),
+            series: calculator.weekly_series(&tasks, &costs, start, as_of),
+            unrated_hours,
+            unscheduled_task_ids,
+        })
+    }
+}
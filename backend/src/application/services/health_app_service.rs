@@ -0,0 +1,230 @@
+use chrono::{DateTime, Datelike, Duration, NaiveDate, Utc};
+use rust_decimal::prelude::ToPrimitive;
+use serde::Serialize;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::application::commands::SetHealthOverrideCommand;
+use crate::application::services::{BudgetAppService, ProjectAppService, RaidAppService};
+use crate::domain::entities::{HealthOverride, HealthSnapshot, Project};
+use crate::domain::repositories::ProjectHealthRepository;
+use crate::domain::services::{HealthAssessment, HealthCalculator, HealthInputs};
+use crate::domain::value_objects::RagStatus;
+use crate::shared::DomainError;
+
+/// Default number of weeks returned by the health history
+pub const DEFAULT_HISTORY_WEEKS: i64 = 12;
+
+/// Current health of a project: the computed model and any manual override
+#[derive(Debug, Serialize)]
+pub struct ProjectHealth {
+    pub project_id: Uuid,
+    pub project_name: String,
+    /// Status in effect: the override when set, the computed status otherwise
+    pub status: RagStatus,
+    pub computed: HealthAssessment,
+    #[serde(rename = "override")]
+    pub health_override: Option<HealthOverride>,
+}
+
+pub struct HealthAppService {
+    health_repository: Arc<dyn ProjectHealthRepository>,
+    project_service: Arc<ProjectAppService>,
+    budget_service: Arc<BudgetAppService>,
+    raid_service: Arc<RaidAppService>,
+}
+
+impl HealthAppService {
+    pub fn new(
+        health_repository: Arc<dyn ProjectHealthRepository>,
+        project_service: Arc<ProjectAppService>,
+        budget_service: Arc<BudgetAppService>,
+        raid_service: Arc<RaidAppService>,
+    ) -> Self {
+        Self {
+            health_repository,
+            project_service,
+            budget_service,
+            raid_service,
+        }
+    }
+
+    /// Check if user can access project
+    pub async fn can_access_project(
+        &self,
+        project_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<bool, DomainError> {
+        self.project_service
+            .can_user_access(project_id, user_id)
+            .await
+    }
+
+    /// Check if user can read project data (members and program managers)
+    pub async fn can_view_project(
+        &self,
+        project_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<bool, DomainError> {
+        self.project_service
+            .can_user_view(project_id, user_id)
+            .await
+    }
+
+    /// Check if user is owner of project
+    pub async fn is_project_owner(
+        &self,
+        project_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<bool, DomainError> {
+        self.project_service.is_owner(project_id, user_id).await
+    }
+
+    pub async fn get_project_health(&self, project_id: Uuid) -> Result<ProjectHealth, DomainError> {
+        let project = self.project_service.get_project(project_id).await?;
+        self.assess(&project).await
+    }
+
+    /// Health of every non-archived project the user can see
+    pub async fn list_project_health(
+        &self,
+        user_id: Uuid,
+        is_admin: bool,
+    ) -> Result<Vec<ProjectHealth>, DomainError> {
+        let projects = if is_admin {
+            self.project_service.list_projects(false).await?
+        } else {
+            self.project_service
+                .list_accessible_projects(user_id, false)
+                .await?
+        };
+
+        let mut health = Vec::with_capacity(projects.len());
+        for project in &projects {
+            health.push(self.assess(project).await?);
+        }
+        Ok(health)
+    }
+
+    pub async fn set_override(
+        &self,
+        project_id: Uuid,
+        actor_id: Uuid,
+        cmd: SetHealthOverrideCommand,
+    ) -> Result<ProjectHealth, DomainError> {
+        let comment = cmd.comment.trim();
+        if comment.is_empty() {
+            return Err(DomainError::ValidationError(
+                "A comment is required when overriding project health".into(),
+            ));
+        }
+        self.project_service
+            .ensure_project_writable(project_id)
+            .await?;
+
+        let health_override =
+            HealthOverride::new(project_id, cmd.status, comment.to_string(), actor_id);
+        self.health_repository
+            .save_override(&health_override)
+            .await?;
+        self.get_project_health(project_id).await
+    }
+
+    pub async fn clear_override(&self, project_id: Uuid) -> Result<ProjectHealth, DomainError> {
+        self.project_service
+            .ensure_project_writable(project_id)
+            .await?;
+        self.health_repository.delete_override(project_id).await?;
+        self.get_project_health(project_id).await
+    }
+
+    /// Weekly snapshots, newest first
+    pub async fn get_history(
+        &self,
+        project_id: Uuid,
+        weeks: Option<i64>,
+    ) -> Result<Vec<HealthSnapshot>, DomainError> {
+        self.project_service.get_project(project_id).await?;
+        let weeks = weeks.unwrap_or(DEFAULT_HISTORY_WEEKS).clamp(1, 520);
+        self.health_repository
+            .find_snapshots(project_id, weeks)
+            .await
+    }
+
+    /// Record this week's snapshot for every non-archived project. Each run
+    /// replaces the week's earlier snapshot, so it ends up holding the last
+    /// health seen that week. Returns the number of projects recorded.
+    pub async fn record_weekly_snapshots(&self) -> Result<usize, DomainError> {
+        let now = Utc::now();
+        let week_start = week_start(now.date_naive());
+        let projects = self.project_service.list_projects(false).await?;
+
+        for project in &projects {
+            let health = self.assess(project).await?;
+            let factors = serde_json::to_value(&health.computed.factors)
+                .map_err(|e| DomainError::InternalError(format!("Invalid factors: {}", e)))?;
+            let snapshot = HealthSnapshot {
+                id: Uuid::new_v4(),
+                project_id: project.id,
+                week_start,
+                status: health.status,
+                computed_status: health.computed.status,
+                score: health.computed.score as i16,
+                factors,
+                overridden: health.health_override.is_some(),
+                recorded_at: now,
+            };
+            self.health_repository.save_snapshot(&snapshot).await?;
+        }
+        Ok(projects.len())
+    }
+
+    async fn assess(&self, project: &Project) -> Result<ProjectHealth, DomainError> {
+        let tasks = self.project_service.get_project_tasks(project.id).await?;
+        let milestones = self
+            .project_service
+            .get_project_milestones(project.id)
+            .await?;
+        let budget = self.budget_service.get_budget_summary(project.id).await?;
+        let risks = self.raid_service.get_risk_matrix(project.id).await?;
+
+        let open: Vec<_> = tasks.iter().filter(|t| !t.is_done()).collect();
+        let inputs = HealthInputs {
+            open_tasks: open.len(),
+            overdue_tasks: open.iter().filter(|t| t.is_overdue()).count(),
+            blocked_tasks: open.iter().filter(|t| t.is_blocked()).count(),
+            overdue_milestones: milestones.iter().filter(|m| m.is_overdue()).count(),
+            budget_used_percent: budget.percent_used.and_then(|p| p.to_f64()),
+            schedule_elapsed_percent: schedule_elapsed_percent(project, Utc::now()),
+            open_high_risks: risks.matrix.by_level.high,
+            open_critical_risks: risks.matrix.by_level.critical,
+        };
+        let computed = HealthCalculator::assess(&inputs);
+        let health_override = self.health_repository.find_override(project.id).await?;
+
+        Ok(ProjectHealth {
+            project_id: project.id,
+            project_name: project.name.clone(),
+            status: health_override
+                .as_ref()
+                .map_or(computed.status, |o| o.status),
+            computed,
+            health_override,
+        })
+    }
+}
+
+/// Share of the planned schedule elapsed at `now`, in percent
+fn schedule_elapsed_percent(project: &Project, now: DateTime<Utc>) -> Option<f64> {
+    let (start, end) = (project.start_date?, project.end_date?);
+    let total = (end - start).num_seconds();
+    if total <= 0 {
+        return None;
+    }
+    let elapsed = (now - start).num_seconds().clamp(0, total);
+    Some(elapsed as f64 * 100.0 / total as f64)
+}
+
+fn week_start(date: NaiveDate) -> NaiveDate {
+    date - Duration::days(date.weekday().num_days_from_monday() as i64)
+}
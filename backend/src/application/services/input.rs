@@ -0,0 +1,24 @@
+use chrono::NaiveDate;
+
+use crate::shared::DomainError;
+
+/// Trim optional free text such as a comment, dropping it if blank
+pub fn trimmed(text: Option<String>) -> Option<String> {
+    text.map(|t| t.trim().to_string()).filter(|t| !t.is_empty())
+}
+
+/// Check a date range is in order and covers at most `max_days` days
+pub fn validate_range(start: NaiveDate, end: NaiveDate, max_days: i64) -> Result<(), DomainError> {
+    if start > end {
+        return Err(DomainError::ValidationError(
+            "start_date must not be after end_date".into(),
+        ));
+    }
+    if (end - start).num_days() >= max_days {
+        return Err(DomainError::ValidationError(format!(
+            "The range can cover at most {} days",
+            max_days
+        )));
+    }
+    Ok(())
+}
@@ -0,0 +1,115 @@
+use serde::Serialize;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::application::services::NotificationAppService;
+use crate::domain::entities::{Mention, NotificationType, Task};
+use crate::domain::repositories::{MentionRepository, UserRepository};
+use crate::domain::services::MentionParser;
+use crate::domain::value_objects::MentionSourceType;
+use crate::shared::DomainError;
+
+/// Response wrapper that reports `@handles` which could not be resolved
+#[derive(Debug, Serialize)]
+pub struct WithMentions<T> {
+    #[serde(flatten)]
+    pub item: T,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub unresolved_mentions: Vec<String>,
+}
+
+impl<T> WithMentions<T> {
+    pub fn new(item: T, unresolved_mentions: Vec<String>) -> Self {
+        Self {
+            item,
+            unresolved_mentions,
+        }
+    }
+}
+
+/// Result of resolving the mentions in a piece of text
+#[derive(Debug, Default)]
+pub struct MentionOutcome {
+    pub mentioned_user_ids: Vec<Uuid>,
+    pub unresolved: Vec<String>,
+}
+
+pub struct MentionAppService {
+    mention_repository: Arc<dyn MentionRepository>,
+    user_repository: Arc<dyn UserRepository>,
+    notification_service: Arc<NotificationAppService>,
+}
+
+impl MentionAppService {
+    pub fn new(
+        mention_repository: Arc<dyn MentionRepository>,
+        user_repository: Arc<dyn UserRepository>,
+        notification_service: Arc<NotificationAppService>,
+    ) -> Self {
+        Self {
+            mention_repository,
+            user_repository,
+            notification_service,
+        }
+    }
+
+    /// Resolve `@handles` in text against the task's project members, store new
+    /// mentions and notify the newly mentioned users. Users already mentioned by
+    /// the same source are not notified again.
+    pub async fn process_mentions(
+        &self,
+        task: &Task,
+        source_type: MentionSourceType,
+        source_id: Uuid,
+        text: &str,
+        actor_id: Uuid,
+    ) -> Result<MentionOutcome, DomainError> {
+        let handles = MentionParser::extract_handles(text);
+        if handles.is_empty() {
+            return Ok(MentionOutcome::default());
+        }
+
+        let members = self
+            .user_repository
+            .find_by_project(task.project_id)
+            .await?;
+        let mut outcome = MentionOutcome::default();
+        let mut newly_mentioned = Vec::new();
+
+        for handle in handles {
+            let matches: Vec<_> = members
+                .iter()
+                .filter(|u| MentionParser::matches_user(&handle, &u.name, u.email.as_str()))
+                .collect();
+
+            // Ambiguous handles are reported rather than guessed
+            let [user] = matches.as_slice() else {
+                outcome.unresolved.push(handle);
+                continue;
+            };
+
+            outcome.mentioned_user_ids.push(user.id);
+            let mention = Mention::new(user.id, source_type, source_id, task.id, actor_id);
+            if self.mention_repository.create(&mention).await? {
+                newly_mentioned.push(user.id);
+            }
+        }
+
+        let location = match source_type {
+            MentionSourceType::Task => "the description of",
+            MentionSourceType::Comment => "a comment on",
+        };
+        self.notification_service
+            .notify_users(
+                &newly_mentioned,
+                actor_id,
+                NotificationType::Mention,
+                "You were mentioned",
+                &format!("You were mentioned in {} '{}'", location, task.title),
+                Some(&format!("/tasks/{}", task.id)),
+            )
+            .await;
+
+        Ok(outcome)
+    }
+}
@@ -1,19 +1,72 @@
 mod activity_app_service;
+mod allocation_app_service;
+mod approval;
 mod attachment_app_service;
 mod auth_app_service;
+mod baseline_app_service;
+mod billing_app_service;
+mod budget_app_service;
+mod calendar_app_service;
+mod capacity_app_service;
+mod change_request_app_service;
+mod comment_app_service;
+mod evm_app_service;
+mod health_app_service;
+mod input;
+mod mention_app_service;
 mod notification_app_service;
+mod portfolio_app_service;
 mod project_app_service;
+mod raid_app_service;
+mod schedule_app_service;
+mod sprint_app_service;
+mod status_report_app_service;
 mod tag_app_service;
 mod task_app_service;
 mod team_app_service;
 mod time_log_app_service;
+mod timer_app_service;
+mod timesheet_app_service;
+mod trash_app_service;
 
 pub use activity_app_service::ActivityAppService;
+pub use allocation_app_service::{
+    AllocationAppService, AllocationHeatmap, AllocationWithConflicts, OverAllocation,
+    TeamAllocation, UserAllocation,
+};
 pub use attachment_app_service::AttachmentAppService;
 pub use auth_app_service::{AuthAppService, AuthResponse, Claims};
+pub use baseline_app_service::{BaselineAppService, VarianceReport};
+pub use billing_app_service::{BillingAppService, InvoicePreview};
+pub use budget_app_service::{BudgetAppService, BudgetSummary, CategoryTotal};
+pub use calendar_app_service::{
+    Absence, AbsenceKind, CalendarAppService, MemberAvailability, TeamAvailability,
+};
+pub use capacity_app_service::{
+    CapacityAppService, TeamUtilization, UserUtilization, UtilizationReport,
+};
+pub use change_request_app_service::ChangeRequestAppService;
+pub use comment_app_service::CommentAppService;
+pub use evm_app_service::{EvmAppService, ProjectEvm};
+pub use health_app_service::{HealthAppService, ProjectHealth, DEFAULT_HISTORY_WEEKS};
+pub use mention_app_service::{MentionAppService, MentionOutcome, WithMentions};
 pub use notification_app_service::NotificationAppService;
+pub use portfolio_app_service::{
+    MilestoneTimelineEntry, PortfolioAppService, PortfolioRollup, ProjectRollup,
+};
 pub use project_app_service::ProjectAppService;
+pub use raid_app_service::{ProjectRiskMatrix, RaidAppService};
+pub use schedule_app_service::{
+    MilestoneSchedule, ProjectSchedule, ScheduleAppService, TaskSchedule,
+};
+pub use sprint_app_service::{SprintAppService, SprintVelocity, VelocityReport};
+pub use status_report_app_service::StatusReportAppService;
 pub use tag_app_service::TagAppService;
-pub use task_app_service::TaskAppService;
+pub use task_app_service::{TaskAppService, TaskHistoryEntry, TASK_ENTITY};
 pub use team_app_service::TeamAppService;
-pub use time_log_app_service::{CreateTimeLogDto, TimeLogAppService, UpdateTimeLogDto};
+pub use time_log_app_service::{
+    CreateTimeLogDto, TimeLogAppService, TimeLogEncoder, UpdateTimeLogDto,
+};
+pub use timer_app_service::{TimerAppService, TimerState};
+pub use timesheet_app_service::{TimesheetAppService, TimesheetWeek};
+pub use trash_app_service::{PurgeSummary, TrashAppService, TrashContents};
@@ -1,3 +1,4 @@
+use std::collections::HashSet;
 use std::sync::Arc;
 use uuid::Uuid;
 
@@ -48,6 +49,37 @@ impl NotificationAppService {
         self.notification_repository.create(&notification).await
     }
 
+    /// Send the same notification to every recipient except the actor.
+    /// Delivery failures are logged and do not abort the fan-out.
+    pub async fn notify_users(
+        &self,
+        recipients: &[Uuid],
+        actor_id: Uuid,
+        notification_type: NotificationType,
+        title: &str,
+        message: &str,
+        link: Option<&str>,
+    ) {
+        let mut seen = HashSet::new();
+        for &user_id in recipients {
+            if user_id == actor_id || !seen.insert(user_id) {
+                continue;
+            }
+            if let Err(e) = self
+                .create_notification(
+                    user_id,
+                    notification_type.clone(),
+                    title.to_string(),
+                    message.to_string(),
+                    link.map(str::to_string),
+                )
+                .await
+            {
+                tracing::warn!(user_id = %user_id, "Failed to create notification: {}", e);
+            }
+        }
+    }
+
     pub async fn mark_as_read(&self, id: Uuid, user_id: Uuid) -> Result<(), DomainError> {
         // Verify the notification belongs to the user
         let notification = self.notification_repository.find_by_id(id).await?;
@@ -0,0 +1,311 @@
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::Serialize;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::application::commands::{CreatePortfolioCommand, UpdatePortfolioCommand};
+use crate::application::services::{BudgetAppService, HealthAppService, ProjectAppService};
+use crate::domain::entities::{Portfolio, Project};
+use crate::domain::repositories::{PortfolioRepository, ProjectRepository, UserRepository};
+use crate::domain::services::{
+    BudgetCalculator, RagCounts, ResourceLoad, RollupCalculator, StatusCount,
+};
+use crate::domain::value_objects::{ProjectStatus, RagStatus};
+use crate::shared::DomainError;
+
+/// One member project in a roll-up
+#[derive(Debug, Serialize)]
+pub struct ProjectRollup {
+    pub project_id: Uuid,
+    pub name: String,
+    pub portfolio_id: Option<Uuid>,
+    pub status: ProjectStatus,
+    pub health: RagStatus,
+    pub budget: Option<Decimal>,
+    pub spent: Decimal,
+    pub start_date: Option<DateTime<Utc>>,
+    pub end_date: Option<DateTime<Utc>>,
+}
+
+/// A milestone on the combined timeline of the member projects
+#[derive(Debug, Serialize)]
+pub struct MilestoneTimelineEntry {
+    pub milestone_id: Uuid,
+    pub project_id: Uuid,
+    pub project_name: String,
+    pub name: String,
+    pub due_date: Option<DateTime<Utc>>,
+    pub completed: bool,
+    pub overdue: bool,
+}
+
+/// Aggregates over every project grouped under a portfolio or program,
+/// including the projects of a portfolio's programs
+#[derive(Debug, Serialize)]
+pub struct PortfolioRollup {
+    pub portfolio: Portfolio,
+    pub project_count: usize,
+    pub status_counts: Vec<StatusCount>,
+    pub health_counts: RagCounts,
+    /// Sum of the budgets of projects that have one
+    pub budget_total: Decimal,
+    pub spent_total: Decimal,
+    pub percent_used: Option<Decimal>,
+    pub projects: Vec<ProjectRollup>,
+    pub milestones: Vec<MilestoneTimelineEntry>,
+    pub resource_load: Vec<ResourceLoad>,
+}
+
+pub struct PortfolioAppService {
+    portfolio_repository: Arc<dyn PortfolioRepository>,
+    project_repository: Arc<dyn ProjectRepository>,
+    user_repository: Arc<dyn UserRepository>,
+    project_service: Arc<ProjectAppService>,
+    budget_service: Arc<BudgetAppService>,
+    health_service: Arc<HealthAppService>,
+}
+
+impl PortfolioAppService {
+    pub fn new(
+        portfolio_repository: Arc<dyn PortfolioRepository>,
+        project_repository: Arc<dyn ProjectRepository>,
+        user_repository: Arc<dyn UserRepository>,
+        project_service: Arc<ProjectAppService>,
+        budget_service: Arc<BudgetAppService>,
+        health_service: Arc<HealthAppService>,
+    ) -> Self {
+        Self {
+            portfolio_repository,
+            project_repository,
+            user_repository,
+            project_service,
+            budget_service,
+            health_service,
+        }
+    }
+
+    /// Check if user manages the portfolio (owner, or owner of its parent)
+    pub async fn is_manager(&self, id: Uuid, user_id: Uuid) -> Result<bool, DomainError> {
+        self.portfolio_repository.is_manager(id, user_id).await
+    }
+
+    /// Check if user is owner of project
+    pub async fn is_project_owner(
+        &self,
+        project_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<bool, DomainError> {
+        self.project_service.is_owner(project_id, user_id).await
+    }
+
+    pub async fn list_portfolios(&self) -> Result<Vec<Portfolio>, DomainError> {
+        self.portfolio_repository.find_all().await
+    }
+
+    pub async fn list_managed_portfolios(
+        &self,
+        user_id: Uuid,
+    ) -> Result<Vec<Portfolio>, DomainError> {
+        self.portfolio_repository.find_managed_by(user_id).await
+    }
+
+    pub async fn get_portfolio(&self, id: Uuid) -> Result<Portfolio, DomainError> {
+        self.portfolio_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("Portfolio not found".into()))
+    }
+
+    pub async fn create_portfolio(
+        &self,
+        actor_id: Uuid,
+        cmd: CreatePortfolioCommand,
+    ) -> Result<Portfolio, DomainError> {
+        validate_name(&cmd.name)?;
+        let owner_id = cmd.owner_id.unwrap_or(actor_id);
+        self.ensure_user_exists(owner_id).await?;
+
+        let portfolio = Portfolio::new(
+            cmd.name.trim().to_string(),
+            cmd.description,
+            cmd.kind,
+            cmd.parent_id,
+            owner_id,
+        );
+        self.validate_parent(&portfolio).await?;
+        self.portfolio_repository.create(&portfolio).await
+    }
+
+    pub async fn update_portfolio(
+        &self,
+        id: Uuid,
+        cmd: UpdatePortfolioCommand,
+    ) -> Result<Portfolio, DomainError> {
+        let mut portfolio = self.get_portfolio(id).await?;
+
+        if let Some(name) = cmd.name {
+            validate_name(&name)?;
+            portfolio.name = name.trim().to_string();
+        }
+        if let Some(description) = cmd.description {
+            portfolio.description = Some(description);
+        }
+        if let Some(parent_id) = cmd.parent_id {
+            portfolio.parent_id = Some(parent_id);
+            self.validate_parent(&portfolio).await?;
+        }
+        if let Some(owner_id) = cmd.owner_id {
+            self.ensure_user_exists(owner_id).await?;
+            portfolio.owner_id = owner_id;
+        }
+
+        self.portfolio_repository.update(&portfolio).await
+    }
+
+    /// Delete a portfolio. Its programs and projects are kept, ungrouped.
+    pub async fn delete_portfolio(&self, id: Uuid) -> Result<(), DomainError> {
+        self.get_portfolio(id).await?;
+        self.portfolio_repository.delete(id).await
+    }
+
+    /// Projects grouped under the portfolio, including those of its programs
+    pub async fn get_projects(&self, id: Uuid) -> Result<Vec<Project>, DomainError> {
+        let portfolio = self.get_portfolio(id).await?;
+        self.member_projects(&portfolio).await
+    }
+
+    pub async fn add_project(&self, id: Uuid, project_id: Uuid) -> Result<Project, DomainError> {
+        self.get_portfolio(id).await?;
+        self.project_service.get_project(project_id).await?;
+        self.project_repository
+            .set_portfolio(project_id, Some(id))
+            .await
+    }
+
+    pub async fn remove_project(&self, id: Uuid, project_id: Uuid) -> Result<(), DomainError> {
+        let project = self.project_service.get_project(project_id).await?;
+        if project.portfolio_id != Some(id) {
+            return Err(DomainError::NotFound(
+                "Project is not in this portfolio".into(),
+            ));
+        }
+        self.project_repository
+            .set_portfolio(project_id, None)
+            .await?;
+        Ok(())
+    }
+
+    pub async fn get_rollup(&self, id: Uuid) -> Result<PortfolioRollup, DomainError> {
+        let portfolio = self.get_portfolio(id).await?;
+        let projects = self.member_projects(&portfolio).await?;
+
+        let mut rollups = Vec::with_capacity(projects.len());
+        let mut milestones = Vec::new();
+        let mut tasks = Vec::new();
+        let (mut budget_total, mut spent_total) = (Decimal::ZERO, Decimal::ZERO);
+        for project in &projects {
+            let budget = self.budget_service.get_budget_summary(project.id).await?;
+            let health = self.health_service.get_project_health(project.id).await?;
+            if let Some(project_budget) = budget.budget {
+                budget_total += project_budget;
+                spent_total += budget.spent;
+            }
+
+            milestones.extend(
+                self.project_service
+                    .get_project_milestones(project.id)
+                    .await?
+                    .into_iter()
+                    .map(|m| MilestoneTimelineEntry {
+                        overdue: m.is_overdue(),
+                        milestone_id: m.id,
+                        project_id: project.id,
+                        project_name: project.name.clone(),
+                        name: m.name,
+                        due_date: m.due_date,
+                        completed: m.completed,
+                    }),
+            );
+            tasks.extend(self.project_service.get_project_tasks(project.id).await?);
+
+            rollups.push(ProjectRollup {
+                project_id: project.id,
+                name: project.name.clone(),
+                portfolio_id: project.portfolio_id,
+                status: project.status.clone(),
+                health: health.status,
+                budget: budget.budget,
+                spent: budget.spent,
+                start_date: project.start_date,
+                end_date: project.end_date,
+            });
+        }
+        // Undated milestones go last
+        milestones.sort_by_key(|m| (m.due_date.is_none(), m.due_date));
+
+        let health: Vec<RagStatus> = rollups.iter().map(|p| p.health).collect();
+        Ok(PortfolioRollup {
+            project_count: projects.len(),
+            status_counts: RollupCalculator::status_counts(&projects),
+            health_counts: RollupCalculator::rag_counts(&health),
+            budget_total,
+            spent_total,
+            percent_used: BudgetCalculator::percent_used(spent_total, budget_total),
+            resource_load: RollupCalculator::resource_load(&tasks),
+            projects: rollups,
+            milestones,
+            portfolio,
+        })
+    }
+
+    async fn member_projects(&self, portfolio: &Portfolio) -> Result<Vec<Project>, DomainError> {
+        let mut ids = vec![portfolio.id];
+        if portfolio.is_portfolio() {
+            ids.extend(
+                self.portfolio_repository
+                    .find_children(portfolio.id)
+                    .await?
+                    .into_iter()
+                    .map(|p| p.id),
+            );
+        }
+        self.project_repository.find_by_portfolios(&ids).await
+    }
+
+    /// Programs may sit under a portfolio; portfolios are always top level
+    async fn validate_parent(&self, portfolio: &Portfolio) -> Result<(), DomainError> {
+        let Some(parent_id) = portfolio.parent_id else {
+            return Ok(());
+        };
+        if portfolio.is_portfolio() {
+            return Err(DomainError::ValidationError(
+                "Portfolios cannot have a parent".into(),
+            ));
+        }
+        let parent = self.get_portfolio(parent_id).await?;
+        if !parent.is_portfolio() {
+            return Err(DomainError::ValidationError(
+                "A program can only belong to a portfolio".into(),
+            ));
+        }
+        Ok(())
+    }
+
+    async fn ensure_user_exists(&self, user_id: Uuid) -> Result<(), DomainError> {
+        self.user_repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("User not found".into()))?;
+        Ok(())
+    }
+}
+
+fn validate_name(name: &str) -> Result<(), DomainError> {
+    if name.trim().is_empty() {
+        return Err(DomainError::ValidationError(
+            "Portfolio name cannot be empty".into(),
+        ));
+    }
+    Ok(())
+}
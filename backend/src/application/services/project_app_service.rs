@@ -2,31 +2,44 @@ use std::sync::Arc;
 use uuid::Uuid;
 
 use crate::application::commands::{CreateProjectCommand, UpdateProjectCommand};
-use crate::domain::entities::{Milestone, Project, Task};
-use crate::domain::repositories::ProjectRepository;
+use crate::application::services::NotificationAppService;
+use crate::domain::entities::{Milestone, NotificationType, Project, Task, Watcher};
+use crate::domain::repositories::{ProjectRepository, WatcherRepository};
+use crate::domain::value_objects::WatchEntityType;
 use crate::shared::DomainError;
 
 pub struct ProjectAppService {
     project_repository: Arc<dyn ProjectRepository>,
+    watcher_repository: Arc<dyn WatcherRepository>,
+    notification_service: Arc<NotificationAppService>,
 }
 
 impl ProjectAppService {
-    pub fn new(project_repository: Arc<dyn ProjectRepository>) -> Self {
-        Self { project_repository }
+    pub fn new(
+        project_repository: Arc<dyn ProjectRepository>,
+        watcher_repository: Arc<dyn WatcherRepository>,
+        notification_service: Arc<NotificationAppService>,
+    ) -> Self {
+        Self {
+            project_repository,
+            watcher_repository,
+            notification_service,
+        }
     }
 
     /// List all projects (admin only - use list_accessible_projects for regular users)
-    pub async fn list_projects(&self) -> Result<Vec<Project>, DomainError> {
-        self.project_repository.find_all().await
+    pub async fn list_projects(&self, include_archived: bool) -> Result<Vec<Project>, DomainError> {
+        self.project_repository.find_all(include_archived).await
     }
 
     /// List projects accessible by user (owner OR member)
     pub async fn list_accessible_projects(
         &self,
         user_id: Uuid,
+        include_archived: bool,
     ) -> Result<Vec<Project>, DomainError> {
         self.project_repository
-            .find_accessible_by_user(user_id)
+            .find_accessible_by_user(user_id, include_archived)
             .await
     }
 
@@ -41,6 +54,24 @@ impl ProjectAppService {
             .await
     }
 
+    /// Check if user can read project (also granted to its program managers)
+    pub async fn can_user_view(
+        &self,
+        project_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<bool, DomainError> {
+        self.project_repository
+            .can_user_view(project_id, user_id)
+            .await
+    }
+
+    /// Check if project budget and dates require approved change requests
+    pub async fn is_change_controlled(&self, project_id: Uuid) -> Result<bool, DomainError> {
+        self.project_repository
+            .is_change_controlled(project_id)
+            .await
+    }
+
     /// Check if user is owner of project
     pub async fn is_owner(&self, project_id: Uuid, user_id: Uuid) -> Result<bool, DomainError> {
         self.project_repository.is_owner(project_id, user_id).await
@@ -53,12 +84,42 @@ impl ProjectAppService {
             .ok_or_else(|| DomainError::NotFound("Project not found".into()))
     }
 
+    /// Fail if the project is archived and therefore read-only
+    pub async fn ensure_project_writable(&self, project_id: Uuid) -> Result<(), DomainError> {
+        if self.get_project(project_id).await?.is_archived() {
+            return Err(archived_error());
+        }
+        Ok(())
+    }
+
+    /// Fail if the task belongs to an archived project
+    pub async fn ensure_task_writable(&self, task_id: Uuid) -> Result<(), DomainError> {
+        if self.project_repository.is_task_archived(task_id).await? {
+            return Err(archived_error());
+        }
+        Ok(())
+    }
+
+    /// Fail unless new tasks may be added to the project
+    pub async fn ensure_can_add_tasks(&self, project_id: Uuid) -> Result<(), DomainError> {
+        let project = self.get_project(project_id).await?;
+        if project.is_archived() {
+            return Err(archived_error());
+        }
+        if !project.can_add_tasks() {
+            return Err(DomainError::ValidationError(
+                "Cannot add tasks to a completed or cancelled project".into(),
+            ));
+        }
+        Ok(())
+    }
+
     pub async fn create_project(
         &self,
         cmd: CreateProjectCommand,
         owner_id: Uuid,
     ) -> Result<Project, DomainError> {
-        let project = Project::new(
+        let mut project = Project::new(
             cmd.name,
             cmd.description,
             owner_id,
@@ -68,20 +129,37 @@ impl ProjectAppService {
             cmd.end_date,
             cmd.budget,
         );
+        if let Some(billable) = cmd.billable {
+            project.billable = billable;
+        }
+
+        let project = self.project_repository.create(&project).await?;
+
+        // Owner is subscribed automatically
+        self.watch_project(project.id, owner_id).await?;
 
-        self.project_repository.create(&project).await
+        Ok(project)
     }
 
     pub async fn update_project(
         &self,
         id: Uuid,
         cmd: UpdateProjectCommand,
+        actor_id: Uuid,
     ) -> Result<Project, DomainError> {
-        let mut project = self
-            .project_repository
-            .find_by_id(id)
-            .await?
-            .ok_or_else(|| DomainError::NotFound("Project not found".into()))?;
+        let mut project = self.get_project(id).await?;
+        if project.is_archived() {
+            return Err(archived_error());
+        }
+        let before = project.clone();
+
+        let changes_governed_fields = cmd.budget.is_some_and(|b| project.budget != Some(b))
+            || cmd.end_date.is_some_and(|d| project.end_date != Some(d));
+        if changes_governed_fields && self.project_repository.is_change_controlled(id).await? {
+            return Err(DomainError::Conflict(
+                "Budget and end date of this project change through change requests".into(),
+            ));
+        }
 
         if let Some(name) = cmd.name {
             project.name = name;
@@ -104,18 +182,73 @@ impl ProjectAppService {
         if let Some(budget) = cmd.budget {
             project.budget = Some(budget);
         }
+        if let Some(billable) = cmd.billable {
+            project.billable = billable;
+        }
 
-        self.project_repository.update(&project).await
+        let project = self.project_repository.update(&project).await?;
+        let changed = changed_fields(&before, &project);
+        if !changed.is_empty() {
+            self.notify_watchers(
+                &project,
+                actor_id,
+                "Project updated",
+                &format!("'{}' was updated: {}", project.name, changed.join(", ")),
+            )
+            .await?;
+        }
+
+        Ok(project)
     }
 
-    pub async fn delete_project(&self, id: Uuid) -> Result<(), DomainError> {
-        // Check if project exists
-        self.project_repository
-            .find_by_id(id)
-            .await?
-            .ok_or_else(|| DomainError::NotFound("Project not found".into()))?;
+    /// Archive a project, making it and everything in it read-only
+    pub async fn archive_project(&self, id: Uuid, actor_id: Uuid) -> Result<Project, DomainError> {
+        let mut project = self.get_project(id).await?;
+        if project.is_archived() {
+            return Err(DomainError::Conflict("Project is already archived".into()));
+        }
+
+        project.archive();
+        let project = self.project_repository.set_archived(&project).await?;
+        self.notify_watchers(
+            &project,
+            actor_id,
+            "Project archived",
+            &format!("'{}' was archived and is now read-only", project.name),
+        )
+        .await?;
 
-        self.project_repository.delete(id).await
+        Ok(project)
+    }
+
+    pub async fn unarchive_project(
+        &self,
+        id: Uuid,
+        actor_id: Uuid,
+    ) -> Result<Project, DomainError> {
+        let mut project = self.get_project(id).await?;
+        if !project.is_archived() {
+            return Err(DomainError::Conflict("Project is not archived".into()));
+        }
+
+        project.unarchive();
+        let project = self.project_repository.set_archived(&project).await?;
+        self.notify_watchers(
+            &project,
+            actor_id,
+            "Project unarchived",
+            &format!("'{}' was restored from the archive", project.name),
+        )
+        .await?;
+
+        Ok(project)
+    }
+
+    /// Move a project to the trash; it is purged after the retention period
+    pub async fn delete_project(&self, id: Uuid, actor_id: Uuid) -> Result<(), DomainError> {
+        self.ensure_project_writable(id).await?;
+
+        self.project_repository.soft_delete(id, actor_id).await
     }
 
     pub async fn get_project_tasks(&self, project_id: Uuid) -> Result<Vec<Task>, DomainError> {
@@ -140,4 +273,88 @@ impl ProjectAppService {
 
         self.project_repository.find_milestones(project_id).await
     }
+
+    pub async fn get_project_watchers(
+        &self,
+        project_id: Uuid,
+    ) -> Result<Vec<Watcher>, DomainError> {
+        self.watcher_repository
+            .find_by_entity(WatchEntityType::Project, project_id)
+            .await
+    }
+
+    pub async fn watch_project(&self, project_id: Uuid, user_id: Uuid) -> Result<(), DomainError> {
+        let watcher = Watcher::new(user_id, WatchEntityType::Project, project_id);
+        self.watcher_repository.watch(&watcher).await
+    }
+
+    pub async fn unwatch_project(
+        &self,
+        project_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<(), DomainError> {
+        self.watcher_repository
+            .unwatch(user_id, WatchEntityType::Project, project_id)
+            .await
+    }
+
+    async fn notify_watchers(
+        &self,
+        project: &Project,
+        actor_id: Uuid,
+        title: &str,
+        message: &str,
+    ) -> Result<(), DomainError> {
+        let recipients: Vec<Uuid> = self
+            .watcher_repository
+            .find_by_entity(WatchEntityType::Project, project.id)
+            .await?
+            .into_iter()
+            .map(|w| w.user_id)
+            .collect();
+        self.notification_service
+            .notify_users(
+                &recipients,
+                actor_id,
+                NotificationType::ProjectUpdated,
+                title,
+                message,
+                Some(&format!("/projects/{}", project.id)),
+            )
+            .await;
+        Ok(())
+    }
+}
+
+fn archived_error() -> DomainError {
+    DomainError::Conflict("Project is archived and read-only".into())
+}
+
+fn changed_fields(before: &Project, after: &Project) -> Vec<&'static str> {
+    let mut changed = Vec::new();
+    if before.name != after.name {
+        changed.push("name");
+    }
+    if before.description != after.description {
+        changed.push("description");
+    }
+    if before.status != after.status {
+        changed.push("status");
+    }
+    if before.priority != after.priority {
+        changed.push("priority");
+    }
+    if before.start_date != after.start_date {
+        changed.push("start date");
+    }
+    if before.end_date != after.end_date {
+        changed.push("end date");
+    }
+    if before.budget != after.budget {
+        changed.push("budget");
+    }
+    if before.billable != after.billable {
+        changed.push("billable");
+    }
+    changed
 }
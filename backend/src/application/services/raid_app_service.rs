@@ -0,0 +1,315 @@
+use chrono::{NaiveDate, Utc};
+use serde::Serialize;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::application::commands::{CreateRaidItemCommand, UpdateRaidItemCommand};
+use crate::application::services::{NotificationAppService, ProjectAppService};
+use crate::domain::entities::{NotificationType, RaidItem};
+use crate::domain::repositories::{RaidItemRepository, TaskRepository};
+use crate::domain::services::{RiskMatrix, RISK_SCALE};
+use crate::domain::value_objects::{RaidStatus, RaidType};
+use crate::shared::DomainError;
+
+/// Risk matrix of a project's open risks
+#[derive(Debug, Serialize)]
+pub struct ProjectRiskMatrix {
+    pub project_id: Uuid,
+    pub open_risks: usize,
+    #[serde(flatten)]
+    pub matrix: RiskMatrix,
+}
+
+pub struct RaidAppService {
+    raid_item_repository: Arc<dyn RaidItemRepository>,
+    task_repository: Arc<dyn TaskRepository>,
+    project_service: Arc<ProjectAppService>,
+    notification_service: Arc<NotificationAppService>,
+}
+
+impl RaidAppService {
+    pub fn new(
+        raid_item_repository: Arc<dyn RaidItemRepository>,
+        task_repository: Arc<dyn TaskRepository>,
+        project_service: Arc<ProjectAppService>,
+        notification_service: Arc<NotificationAppService>,
+    ) -> Self {
+        Self {
+            raid_item_repository,
+            task_repository,
+            project_service,
+            notification_service,
+        }
+    }
+
+    /// Check if user can access project
+    pub async fn can_access_project(
+        &self,
+        project_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<bool, DomainError> {
+        self.project_service
+            .can_user_access(project_id, user_id)
+            .await
+    }
+
+    /// Check if user can read project data (members and program managers)
+    pub async fn can_view_project(
+        &self,
+        project_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<bool, DomainError> {
+        self.project_service
+            .can_user_view(project_id, user_id)
+            .await
+    }
+
+    /// Check if user is owner of project
+    pub async fn is_project_owner(
+        &self,
+        project_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<bool, DomainError> {
+        self.project_service.is_owner(project_id, user_id).await
+    }
+
+    pub async fn get_item(&self, id: Uuid) -> Result<RaidItem, DomainError> {
+        self.raid_item_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("RAID item not found".into()))
+    }
+
+    /// Items of a project, optionally narrowed to one type and/or status
+    pub async fn list_items(
+        &self,
+        project_id: Uuid,
+        item_type: Option<RaidType>,
+        status: Option<RaidStatus>,
+    ) -> Result<Vec<RaidItem>, DomainError> {
+        self.project_service.get_project(project_id).await?;
+        let items = self
+            .raid_item_repository
+            .find_by_project(project_id)
+            .await?
+            .into_iter()
+            .filter(|i| item_type.is_none_or(|t| i.item_type == t))
+            .filter(|i| status.is_none_or(|s| i.status == s))
+            .collect();
+        Ok(items)
+    }
+
+    pub async fn create_item(
+        &self,
+        project_id: Uuid,
+        actor_id: Uuid,
+        cmd: CreateRaidItemCommand,
+    ) -> Result<RaidItem, DomainError> {
+        self.project_service
+            .ensure_project_writable(project_id)
+            .await?;
+        validate_title(&cmd.title)?;
+
+        let mut item = RaidItem::new(
+            project_id,
+            cmd.item_type,
+            cmd.title.trim().to_string(),
+            cmd.description,
+            cmd.owner_id,
+            actor_id,
+        );
+        item.status = cmd.status.unwrap_or_default();
+        item.probability = cmd.probability;
+        item.impact = cmd.impact;
+        item.mitigation_plan = cmd.mitigation_plan;
+        item.review_date = cmd.review_date;
+        item.task_ids = cmd.task_ids.unwrap_or_default();
+        self.validate_item(&mut item).await?;
+
+        self.raid_item_repository.create(&item).await
+    }
+
+    pub async fn update_item(
+        &self,
+        id: Uuid,
+        cmd: UpdateRaidItemCommand,
+    ) -> Result<RaidItem, DomainError> {
+        let mut item = self.get_item(id).await?;
+        self.project_service
+            .ensure_project_writable(item.project_id)
+            .await?;
+
+        if let Some(item_type) = cmd.item_type {
+            item.item_type = item_type;
+        }
+        if let Some(title) = cmd.title {
+            validate_title(&title)?;
+            item.title = title.trim().to_string();
+        }
+        if let Some(description) = cmd.description {
+            item.description = Some(description);
+        }
+        if let Some(owner_id) = cmd.owner_id {
+            item.owner_id = Some(owner_id);
+        }
+        if let Some(status) = cmd.status {
+            item.status = status;
+        }
+        if let Some(probability) = cmd.probability {
+            item.probability = Some(probability);
+        }
+        if let Some(impact) = cmd.impact {
+            item.impact = Some(impact);
+        }
+        if let Some(mitigation_plan) = cmd.mitigation_plan {
+            item.mitigation_plan = Some(mitigation_plan);
+        }
+        if let Some(review_date) = cmd.review_date {
+            item.review_date = Some(review_date);
+        }
+        if let Some(task_ids) = cmd.task_ids {
+            item.task_ids = task_ids;
+        }
+        self.validate_item(&mut item).await?;
+
+        self.raid_item_repository.update(&item).await
+    }
+
+    pub async fn delete_item(&self, id: Uuid) -> Result<(), DomainError> {
+        let item = self.get_item(id).await?;
+        self.project_service
+            .ensure_project_writable(item.project_id)
+            .await?;
+        self.raid_item_repository.delete(id).await
+    }
+
+    pub async fn get_risk_matrix(
+        &self,
+        project_id: Uuid,
+    ) -> Result<ProjectRiskMatrix, DomainError> {
+        let items = self
+            .list_items(project_id, Some(RaidType::Risk), None)
+            .await?;
+        Ok(ProjectRiskMatrix {
+            project_id,
+            open_risks: items.iter().filter(|i| i.is_open()).count(),
+            matrix: RiskMatrix::build(&items),
+        })
+    }
+
+    /// Remind owners of open items whose review date has passed, once per
+    /// review date. Returns the number of reminders sent; an item that fails
+    /// is logged and retried on the next run.
+    pub async fn send_review_reminders(&self) -> Result<usize, DomainError> {
+        let today = Utc::now().date_naive();
+        let items = self.raid_item_repository.find_review_overdue(today).await?;
+
+        let mut sent = 0;
+        for item in items.iter().filter(|i| i.needs_review_reminder(today)) {
+            let Some(review_date) = item.review_date else {
+                continue;
+            };
+            if let Err(e) = self.send_review_reminder(item, review_date).await {
+                tracing::warn!(raid_item_id = %item.id, "Failed to send review reminder: {}", e);
+                continue;
+            }
+            sent += 1;
+        }
+        Ok(sent)
+    }
+
+    async fn send_review_reminder(
+        &self,
+        item: &RaidItem,
+        review_date: NaiveDate,
+    ) -> Result<(), DomainError> {
+        let project = self.project_service.get_project(item.project_id).await?;
+        let recipient = item
+            .owner_id
+            .or(item.created_by)
+            .unwrap_or(project.owner_id);
+
+        self.notification_service
+            .create_notification(
+                recipient,
+                NotificationType::System,
+                format!("Review overdue: {}", item.title),
+                review_message(item, &project.name, review_date),
+                Some(format!("/raid/{}", item.id)),
+            )
+            .await?;
+        self.raid_item_repository
+            .mark_reminded(item.id, review_date)
+            .await
+    }
+
+    /// Check scores, owner and task links of an item about to be saved
+    async fn validate_item(&self, item: &mut RaidItem) -> Result<(), DomainError> {
+        if item.is_risk() {
+            for score in [item.probability, item.impact].into_iter().flatten() {
+                if !(1..=RISK_SCALE).contains(&score) {
+                    return Err(DomainError::ValidationError(format!(
+                        "Probability and impact must be between 1 and {}",
+                        RISK_SCALE
+                    )));
+                }
+            }
+        } else if item.probability.is_some() || item.impact.is_some() {
+            return Err(DomainError::ValidationError(
+                "Only risks can have probability and impact scores".into(),
+            ));
+        }
+
+        if let Some(owner_id) = item.owner_id {
+            if !self
+                .project_service
+                .can_user_access(item.project_id, owner_id)
+                .await?
+            {
+                return Err(DomainError::ValidationError(
+                    "Owner must be a member of the project".into(),
+                ));
+            }
+        }
+
+        item.task_ids.sort_unstable();
+        item.task_ids.dedup();
+        for &task_id in &item.task_ids {
+            let task = self
+                .task_repository
+                .find_by_id(task_id)
+                .await?
+                .ok_or_else(|| DomainError::NotFound(format!("Task {} not found", task_id)))?;
+            if task.project_id != item.project_id {
+                return Err(DomainError::ValidationError(
+                    "Linked tasks must belong to the same project".into(),
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+fn review_message(item: &RaidItem, project_name: &str, review_date: NaiveDate) -> String {
+    format!(
+        "The {} '{}' in project '{}' was due for review on {}",
+        match item.item_type {
+            RaidType::Risk => "risk",
+            RaidType::Assumption => "assumption",
+            RaidType::Issue => "issue",
+            RaidType::Decision => "decision",
+        },
+        item.title,
+        project_name,
+        review_date
+    )
+}
+
+fn validate_title(title: &str) -> Result<(), DomainError> {
+    if title.trim().is_empty() {
+        return Err(DomainError::ValidationError(
+            "RAID item title cannot be empty".into(),
+        ));
+    }
+    Ok(())
+}
@@ -0,0 +1,305 @@
+use chrono::{Duration, NaiveDate};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::application::services::{CalendarAppService, ProjectAppService};
+use crate::domain::entities::{Task, TaskDependency};
+use crate::domain::repositories::{TaskDependencyRepository, TaskRepository};
+use crate::domain::services::{CriticalPathScheduler, ScheduleNode};
+use crate::domain::value_objects::TaskStatus;
+use crate::shared::DomainError;
+
+/// Computed dates for one task. Finish dates are inclusive.
+#[derive(Debug, Serialize)]
+pub struct TaskSchedule {
+    pub task_id: Uuid,
+    pub title: String,
+    pub status: TaskStatus,
+    pub milestone_id: Option<Uuid>,
+    pub dependencies: Vec<Uuid>,
+    pub duration_days: i64,
+    pub start_date: NaiveDate,
+    pub finish_date: NaiveDate,
+    pub latest_start_date: NaiveDate,
+    pub latest_finish_date: NaiveDate,
+    pub slack_days: i64,
+    pub is_critical: bool,
+    pub due_date: Option<NaiveDate>,
+    pub misses_due_date: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct MilestoneSchedule {
+    pub milestone_id: Uuid,
+    pub name: String,
+    pub completed: bool,
+    pub due_date: Option<NaiveDate>,
+    /// Finish date of the last task in the milestone
+    pub projected_date: Option<NaiveDate>,
+    pub on_track: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ProjectSchedule {
+    pub project_id: Uuid,
+    pub start_date: NaiveDate,
+    pub projected_finish_date: NaiveDate,
+    pub end_date: Option<NaiveDate>,
+    pub duration_days: i64,
+    pub can_finish_on_time: bool,
+    pub days_late: i64,
+    /// Critical tasks ordered by start date
+    pub critical_path: Vec<Uuid>,
+    pub tasks: Vec<TaskSchedule>,
+    pub milestones: Vec<MilestoneSchedule>,
+}
+
+pub struct ScheduleAppService {
+    task_repository: Arc<dyn TaskRepository>,
+    dependency_repository: Arc<dyn TaskDependencyRepository>,
+    project_service: Arc<ProjectAppService>,
+    calendar_service: Arc<CalendarAppService>,
+}
+
+impl ScheduleAppService {
+    pub fn new(
+        task_repository: Arc<dyn TaskRepository>,
+        dependency_repository: Arc<dyn TaskDependencyRepository>,
+        project_service: Arc<ProjectAppService>,
+        calendar_service: Arc<CalendarAppService>,
+    ) -> Self {
+        Self {
+            task_repository,
+            dependency_repository,
+            project_service,
+            calendar_service,
+        }
+    }
+
+    /// Check if user can access project
+    pub async fn can_access_project(
+        &self,
+        project_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<bool, DomainError> {
+        self.project_service
+            .can_user_access(project_id, user_id)
+            .await
+    }
+
+    /// Check if user can read project data (members and program managers)
+    pub async fn can_view_project(
+        &self,
+        project_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<bool, DomainError> {
+        self.project_service
+            .can_user_view(project_id, user_id)
+            .await
+    }
+
+    /// Check if user can access task (via project access)
+    pub async fn can_access_task(&self, task_id: Uuid, user_id: Uuid) -> Result<bool, DomainError> {
+        self.task_repository.can_user_access(task_id, user_id).await
+    }
+
+    /// Check if user can read task data (members and program managers)
+    pub async fn can_view_task(&self, task_id: Uuid, user_id: Uuid) -> Result<bool, DomainError> {
+        self.task_repository.can_user_view(task_id, user_id).await
+    }
+
+    /// Schedule every task of a project from its start date using the
+    /// critical path method. Durations come from estimated hours and are laid
+    /// out over weekdays, skipping the default calendar's holidays.
+    pub async fn get_project_schedule(
+        &self,
+        project_id: Uuid,
+    ) -> Result<ProjectSchedule, DomainError> {
+        let project = self.project_service.get_project(project_id).await?;
+        let tasks = self.task_repository.find_by_project(project_id).await?;
+        let milestones = self
+            .project_service
+            .get_project_milestones(project_id)
+            .await?;
+
+        let mut predecessors: HashMap<Uuid, Vec<Uuid>> = HashMap::new();
+        for dependency in self
+            .dependency_repository
+            .find_by_project(project_id)
+            .await?
+        {
+            predecessors
+                .entry(dependency.task_id)
+                .or_default()
+                .push(dependency.depends_on_id);
+        }
+
+        let nodes: Vec<ScheduleNode> = tasks
+            .iter()
+            .map(|task| ScheduleNode {
+                id: task.id,
+                duration_days: CriticalPathScheduler::duration_days(task.estimated_hours),
+                predecessors: predecessors.get(&task.id).cloned().unwrap_or_default(),
+            })
+            .collect();
+        let scheduled = CriticalPathScheduler::schedule(&nodes)?;
+
+        let start_date = project
+            .start_date
+            .unwrap_or(project.created_at)
+            .date_naive();
+        let duration_days = scheduled
+            .iter()
+            .map(|n| n.earliest_finish)
+            .max()
+            .unwrap_or(0);
+        // Room for weekends and a generous number of holidays
+        let calendar = self
+            .calendar_service
+            .project_calendar(
+                start_date,
+                start_date + Duration::days(duration_days * 2 + 60),
+            )
+            .await?;
+        let day = |offset: i64| calendar.working_day(start_date, offset);
+        let tasks_by_id: HashMap<Uuid, _> = tasks.iter().map(|t| (t.id, t)).collect();
+
+        let task_schedules: Vec<TaskSchedule> = scheduled
+            .iter()
+            .map(|node| {
+                let task = tasks_by_id[&node.id];
+                let finish_date = day(node.earliest_finish - 1);
+                let due_date = task.due_date.map(|d| d.date_naive());
+                TaskSchedule {
+                    task_id: task.id,
+                    title: task.title.clone(),
+                    status: task.status.clone(),
+                    milestone_id: task.milestone_id,
+                    dependencies: predecessors.get(&task.id).cloned().unwrap_or_default(),
+                    duration_days: node.earliest_finish - node.earliest_start,
+                    start_date: day(node.earliest_start),
+                    finish_date,
+                    latest_start_date: day(node.latest_start),
+                    latest_finish_date: day(node.latest_finish - 1),
+                    slack_days: node.slack,
+                    is_critical: node.is_critical(),
+                    due_date,
+                    misses_due_date: due_date.is_some_and(|due| finish_date > due),
+                }
+            })
+            .collect();
+
+        let projected_finish_date = if duration_days > 0 {
+            day(duration_days - 1)
+        } else {
+            start_date
+        };
+        let end_date = project.end_date.map(|d| d.date_naive());
+        let days_late = end_date
+            .map(|end| (projected_finish_date - end).num_days().max(0))
+            .unwrap_or(0);
+
+        let mut critical: Vec<&TaskSchedule> =
+            task_schedules.iter().filter(|t| t.is_critical).collect();
+        critical.sort_by_key(|t| t.start_date);
+        let critical_path = critical.into_iter().map(|t| t.task_id).collect();
+
+        let milestones = milestones
+            .into_iter()
+            .map(|milestone| {
+                let due_date = milestone.due_date.map(|d| d.date_naive());
+                let projected_date = task_schedules
+                    .iter()
+                    .filter(|t| t.milestone_id == Some(milestone.id))
+                    .map(|t| t.finish_date)
+                    .max();
+                let on_track = match (due_date, projected_date) {
+                    (Some(due), Some(projected)) => milestone.completed || projected <= due,
+                    _ => true,
+                };
+                MilestoneSchedule {
+                    milestone_id: milestone.id,
+                    name: milestone.name,
+                    completed: milestone.completed,
+                    due_date,
+                    projected_date,
+                    on_track,
+                }
+            })
+            .collect();
+
+        Ok(ProjectSchedule {
+            project_id,
+            start_date,
+            projected_finish_date,
+            end_date,
+            duration_days,
+            can_finish_on_time: days_late == 0,
+            days_late,
+            critical_path,
+            tasks: task_schedules,
+            milestones,
+        })
+    }
+
+    pub async fn get_task_dependencies(
+        &self,
+        task_id: Uuid,
+    ) -> Result<Vec<TaskDependency>, DomainError> {
+        self.dependency_repository.find_by_task(task_id).await
+    }
+
+    /// Make `task_id` wait for `depends_on_id` to finish
+    pub async fn add_dependency(
+        &self,
+        task_id: Uuid,
+        depends_on_id: Uuid,
+    ) -> Result<TaskDependency, DomainError> {
+        let task = self.find_task(task_id).await?;
+        let depends_on = self.find_task(depends_on_id).await?;
+        if task.project_id != depends_on.project_id {
+            return Err(DomainError::ValidationError(
+                "Dependencies must be between tasks of the same project".into(),
+            ));
+        }
+        self.project_service
+            .ensure_project_writable(task.project_id)
+            .await?;
+
+        let edges: Vec<(Uuid, Uuid)> = self
+            .dependency_repository
+            .find_by_project(task.project_id)
+            .await?
+            .into_iter()
+            .map(|d| (d.task_id, d.depends_on_id))
+            .collect();
+        if CriticalPathScheduler::creates_cycle(&edges, task_id, depends_on_id) {
+            return Err(DomainError::ValidationError(
+                "Dependency would create a cycle".into(),
+            ));
+        }
+
+        let dependency = TaskDependency::new(task_id, depends_on_id);
+        self.dependency_repository.create(&dependency).await
+    }
+
+    pub async fn remove_dependency(
+        &self,
+        task_id: Uuid,
+        depends_on_id: Uuid,
+    ) -> Result<(), DomainError> {
+        self.project_service.ensure_task_writable(task_id).await?;
+        self.dependency_repository
+            .delete(task_id, depends_on_id)
+            .await
+    }
+
+    async fn find_task(&self, id: Uuid) -> Result<Task, DomainError> {
+        self.task_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("Task not found".into()))
+    }
+}
@@ -0,0 +1,393 @@
+use chrono::{NaiveDate, Utc};
+use serde::Serialize;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::application::commands::{CreateSprintCommand, UpdateSprintCommand};
+use crate::application::services::ProjectAppService;
+use crate::domain::entities::{Sprint, Task};
+use crate::domain::repositories::{SprintRepository, TaskRepository};
+use crate::domain::services::{BurndownCalculator, BurndownItem, BurndownPoint};
+use crate::domain::value_objects::TaskStatus;
+use crate::shared::DomainError;
+
+/// Committed versus completed work of one closed sprint
+#[derive(Debug, Serialize)]
+pub struct SprintVelocity {
+    pub sprint_id: Uuid,
+    pub name: String,
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    pub committed_hours: f32,
+    pub committed_tasks: i32,
+    pub completed_hours: f32,
+    pub completed_tasks: i32,
+}
+
+#[derive(Debug, Serialize)]
+pub struct VelocityReport {
+    pub project_id: Uuid,
+    pub sprints: Vec<SprintVelocity>,
+    pub average_hours: f32,
+    pub average_tasks: f32,
+}
+
+pub struct SprintAppService {
+    sprint_repository: Arc<dyn SprintRepository>,
+    task_repository: Arc<dyn TaskRepository>,
+    project_service: Arc<ProjectAppService>,
+}
+
+impl SprintAppService {
+    pub fn new(
+        sprint_repository: Arc<dyn SprintRepository>,
+        task_repository: Arc<dyn TaskRepository>,
+        project_service: Arc<ProjectAppService>,
+    ) -> Self {
+        Self {
+            sprint_repository,
+            task_repository,
+            project_service,
+        }
+    }
+
+    /// Check if user can access project
+    pub async fn can_access_project(
+        &self,
+        project_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<bool, DomainError> {
+        self.project_service
+            .can_user_access(project_id, user_id)
+            .await
+    }
+
+    /// Check if user can read project data (members and program managers)
+    pub async fn can_view_project(
+        &self,
+        project_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<bool, DomainError> {
+        self.project_service
+            .can_user_view(project_id, user_id)
+            .await
+    }
+
+    /// Check if user can access sprint (via project access)
+    pub async fn can_access_sprint(
+        &self,
+        sprint_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<bool, DomainError> {
+        let sprint = self.get_sprint(sprint_id).await?;
+        self.can_access_project(sprint.project_id, user_id).await
+    }
+
+    /// Check if user can read sprint data (via project view access)
+    pub async fn can_view_sprint(
+        &self,
+        sprint_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<bool, DomainError> {
+        let sprint = self.get_sprint(sprint_id).await?;
+        self.can_view_project(sprint.project_id, user_id).await
+    }
+
+    pub async fn get_sprint(&self, id: Uuid) -> Result<Sprint, DomainError> {
+        self.sprint_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("Sprint not found".into()))
+    }
+
+    pub async fn list_sprints(&self, project_id: Uuid) -> Result<Vec<Sprint>, DomainError> {
+        self.project_service.get_project(project_id).await?;
+        self.sprint_repository.find_by_project(project_id).await
+    }
+
+    pub async fn create_sprint(
+        &self,
+        project_id: Uuid,
+        cmd: CreateSprintCommand,
+    ) -> Result<Sprint, DomainError> {
+        self.project_service
+            .ensure_project_writable(project_id)
+            .await?;
+        validate_name(&cmd.name)?;
+        validate_dates(cmd.start_date, cmd.end_date)?;
+
+        let sprint = Sprint::new(project_id, cmd.name, cmd.goal, cmd.start_date, cmd.end_date);
+        self.sprint_repository.create(&sprint).await
+    }
+
+    pub async fn update_sprint(
+        &self,
+        id: Uuid,
+        cmd: UpdateSprintCommand,
+    ) -> Result<Sprint, DomainError> {
+        let mut sprint = self.get_sprint(id).await?;
+        self.project_service
+            .ensure_project_writable(sprint.project_id)
+            .await?;
+        ensure_not_closed(&sprint)?;
+
+        if let Some(name) = cmd.name {
+            validate_name(&name)?;
+            sprint.name = name;
+        }
+        if let Some(goal) = cmd.goal {
+            sprint.goal = Some(goal);
+        }
+        if let Some(start_date) = cmd.start_date {
+            sprint.start_date = start_date;
+        }
+        if let Some(end_date) = cmd.end_date {
+            sprint.end_date = end_date;
+        }
+        validate_dates(sprint.start_date, sprint.end_date)?;
+
+        self.sprint_repository.update(&sprint).await
+    }
+
+    /// Delete a sprint that has not been started. Its tasks return to the backlog.
+    pub async fn delete_sprint(&self, id: Uuid) -> Result<(), DomainError> {
+        let sprint = self.get_sprint(id).await?;
+        self.project_service
+            .ensure_project_writable(sprint.project_id)
+            .await?;
+        if !sprint.is_planned() {
+            return Err(DomainError::Conflict(
+                "Only planned sprints can be deleted".into(),
+            ));
+        }
+        self.sprint_repository.delete(id).await
+    }
+
+    pub async fn get_sprint_tasks(&self, id: Uuid) -> Result<Vec<Task>, DomainError> {
+        self.get_sprint(id).await?;
+        self.task_repository.find_by_sprint(id).await
+    }
+
+    pub async fn add_task(&self, id: Uuid, task_id: Uuid) -> Result<(), DomainError> {
+        let sprint = self.get_sprint(id).await?;
+        ensure_not_closed(&sprint)?;
+        let task = self
+            .task_repository
+            .find_by_id(task_id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("Task not found".into()))?;
+        if task.project_id != sprint.project_id {
+            return Err(DomainError::ValidationError(
+                "Task belongs to a different project".into(),
+            ));
+        }
+        self.project_service
+            .ensure_project_writable(sprint.project_id)
+            .await?;
+
+        self.task_repository.set_sprint(task_id, Some(id)).await
+    }
+
+    /// Move a task from the sprint back to the backlog
+    pub async fn remove_task(&self, id: Uuid, task_id: Uuid) -> Result<(), DomainError> {
+        let sprint = self.get_sprint(id).await?;
+        ensure_not_closed(&sprint)?;
+        let task = self
+            .task_repository
+            .find_by_id(task_id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("Task not found".into()))?;
+        if task.sprint_id != Some(id) {
+            return Err(DomainError::NotFound("Task is not in this sprint".into()));
+        }
+        self.project_service
+            .ensure_project_writable(sprint.project_id)
+            .await?;
+
+        self.task_repository.set_sprint(task_id, None).await
+    }
+
+    /// Start a planned sprint, recording its scope as the commitment.
+    /// A sprint that is still active in the project is closed first and its
+    /// unfinished work rolls over into the one being started.
+    pub async fn start_sprint(&self, id: Uuid) -> Result<Sprint, DomainError> {
+        let mut sprint = self.get_sprint(id).await?;
+        self.project_service
+            .ensure_project_writable(sprint.project_id)
+            .await?;
+        if !sprint.is_planned() {
+            return Err(DomainError::Conflict(
+                "Only planned sprints can be started".into(),
+            ));
+        }
+
+        if let Some(active) = self
+            .sprint_repository
+            .find_active_by_project(sprint.project_id)
+            .await?
+        {
+            self.finish_sprint(active, Some(id)).await?;
+        }
+
+        let tasks = self.task_repository.find_by_sprint(id).await?;
+        sprint.start(total_hours(&tasks), tasks.len() as i32);
+        self.sprint_repository.update(&sprint).await
+    }
+
+    /// Close an active sprint and roll its unfinished tasks over to
+    /// `rollover_to`, or the next planned sprint, or the backlog if there is none.
+    pub async fn close_sprint(
+        &self,
+        id: Uuid,
+        rollover_to: Option<Uuid>,
+    ) -> Result<Sprint, DomainError> {
+        let sprint = self.get_sprint(id).await?;
+        self.project_service
+            .ensure_project_writable(sprint.project_id)
+            .await?;
+        if !sprint.is_active() {
+            return Err(DomainError::Conflict(
+                "Only active sprints can be closed".into(),
+            ));
+        }
+
+        let target = match rollover_to {
+            Some(target_id) => {
+                let target = self.get_sprint(target_id).await?;
+                if target.id == sprint.id
+                    || target.project_id != sprint.project_id
+                    || !target.is_planned()
+                {
+                    return Err(DomainError::ValidationError(
+                        "Unfinished work can only roll over to a planned sprint of the same project"
+                            .into(),
+                    ));
+                }
+                Some(target.id)
+            }
+            None => self
+                .sprint_repository
+                .find_next_planned(sprint.project_id, sprint.id)
+                .await?
+                .map(|s| s.id),
+        };
+
+        self.finish_sprint(sprint, target).await
+    }
+
+    /// Burndown of a sprint. Closed sprints return the burndown frozen at close.
+    pub async fn get_burndown(&self, id: Uuid) -> Result<Vec<BurndownPoint>, DomainError> {
+        let sprint = self.get_sprint(id).await?;
+        if let Some(burndown) = &sprint.burndown {
+            return serde_json::from_value(burndown.clone())
+                .map_err(|e| DomainError::InternalError(format!("Invalid burndown: {}", e)));
+        }
+
+        let tasks = self.task_repository.find_by_sprint(id).await?;
+        Ok(compute_burndown(&sprint, &tasks, Utc::now().date_naive()))
+    }
+
+    pub async fn get_velocity(&self, project_id: Uuid) -> Result<VelocityReport, DomainError> {
+        self.project_service.get_project(project_id).await?;
+        let sprints: Vec<SprintVelocity> = self
+            .sprint_repository
+            .find_closed_by_project(project_id)
+            .await?
+            .into_iter()
+            .map(|s| SprintVelocity {
+                sprint_id: s.id,
+                name: s.name,
+                start_date: s.start_date,
+                end_date: s.end_date,
+                committed_hours: s.committed_hours.unwrap_or(0.0),
+                committed_tasks: s.committed_tasks.unwrap_or(0),
+                completed_hours: s.completed_hours.unwrap_or(0.0),
+                completed_tasks: s.completed_tasks.unwrap_or(0),
+            })
+            .collect();
+
+        let hours: Vec<f32> = sprints.iter().map(|s| s.completed_hours).collect();
+        let tasks: Vec<f32> = sprints.iter().map(|s| s.completed_tasks as f32).collect();
+        Ok(VelocityReport {
+            project_id,
+            average_hours: BurndownCalculator::average(&hours),
+            average_tasks: BurndownCalculator::average(&tasks),
+            sprints,
+        })
+    }
+
+    /// Freeze the results of a sprint, then move its unfinished tasks on
+    async fn finish_sprint(
+        &self,
+        mut sprint: Sprint,
+        rollover_to: Option<Uuid>,
+    ) -> Result<Sprint, DomainError> {
+        let tasks = self.task_repository.find_by_sprint(sprint.id).await?;
+        let done: Vec<Task> = tasks
+            .iter()
+            .filter(|t| t.status == TaskStatus::Done)
+            .cloned()
+            .collect();
+        let burndown = compute_burndown(&sprint, &tasks, Utc::now().date_naive());
+        let burndown = serde_json::to_value(burndown)
+            .map_err(|e| DomainError::InternalError(format!("Invalid burndown: {}", e)))?;
+
+        sprint.close(total_hours(&done), done.len() as i32, burndown);
+        let sprint = self.sprint_repository.update(&sprint).await?;
+
+        let moved = self
+            .task_repository
+            .move_unfinished(sprint.id, rollover_to)
+            .await?;
+        tracing::info!(
+            sprint_id = %sprint.id,
+            rollover_to = ?rollover_to,
+            moved,
+            "Sprint closed"
+        );
+        Ok(sprint)
+    }
+}
+
+fn compute_burndown(sprint: &Sprint, tasks: &[Task], today: NaiveDate) -> Vec<BurndownPoint> {
+    let items: Vec<BurndownItem> = tasks
+        .iter()
+        .map(|t| BurndownItem {
+            estimated_hours: t.estimated_hours.unwrap_or(0.0),
+            completed_on: t
+                .completed_at
+                .filter(|_| t.status == TaskStatus::Done)
+                .map(|at| at.date_naive()),
+        })
+        .collect();
+    BurndownCalculator::compute(sprint.start_date, sprint.end_date, today, &items)
+}
+
+fn total_hours(tasks: &[Task]) -> f32 {
+    tasks.iter().filter_map(|t| t.estimated_hours).sum()
+}
+
+fn validate_name(name: &str) -> Result<(), DomainError> {
+    if name.trim().is_empty() {
+        return Err(DomainError::ValidationError(
+            "Sprint name cannot be empty".into(),
+        ));
+    }
+    Ok(())
+}
+
+fn validate_dates(start_date: NaiveDate, end_date: NaiveDate) -> Result<(), DomainError> {
+    if end_date < start_date {
+        return Err(DomainError::ValidationError(
+            "Sprint end date must not be before its start date".into(),
+        ));
+    }
+    Ok(())
+}
+
+fn ensure_not_closed(sprint: &Sprint) -> Result<(), DomainError> {
+    if sprint.is_closed() {
+        return Err(DomainError::Conflict("Sprint is closed".into()));
+    }
+    Ok(())
+}
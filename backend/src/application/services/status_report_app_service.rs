@@ -0,0 +1,249 @@
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use std::collections::HashSet;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::application::commands::CreateStatusReportCommand;
+use crate::application::services::{
+    BudgetAppService, ProjectAppService, RaidAppService, TASK_ENTITY,
+};
+use crate::domain::entities::{
+    ActivityLog, FieldChange, ReportBudget, ReportMilestone, ReportRisk, ReportTask, StatusReport,
+    StatusReportContent, Task,
+};
+use crate::domain::repositories::{
+    ActivityLogRepository, StatusReportRepository, TimeLogRepository,
+};
+use crate::domain::value_objects::{RaidType, TaskStatus};
+use crate::shared::DomainError;
+
+/// Longest period a single report may cover
+const MAX_PERIOD_DAYS: i64 = 93;
+/// How far past the period end milestones count as upcoming
+const UPCOMING_MILESTONE_DAYS: i64 = 14;
+/// Number of open risks listed in a report
+const TOP_RISKS: usize = 5;
+
+pub struct StatusReportAppService {
+    status_report_repository: Arc<dyn StatusReportRepository>,
+    activity_repository: Arc<dyn ActivityLogRepository>,
+    time_log_repository: Arc<dyn TimeLogRepository>,
+    project_service: Arc<ProjectAppService>,
+    budget_service: Arc<BudgetAppService>,
+    raid_service: Arc<RaidAppService>,
+}
+
+impl StatusReportAppService {
+    pub fn new(
+        status_report_repository: Arc<dyn StatusReportRepository>,
+        activity_repository: Arc<dyn ActivityLogRepository>,
+        time_log_repository: Arc<dyn TimeLogRepository>,
+        project_service: Arc<ProjectAppService>,
+        budget_service: Arc<BudgetAppService>,
+        raid_service: Arc<RaidAppService>,
+    ) -> Self {
+        Self {
+            status_report_repository,
+            activity_repository,
+            time_log_repository,
+            project_service,
+            budget_service,
+            raid_service,
+        }
+    }
+
+    /// Check if user can read project data (members and program managers)
+    pub async fn can_view_project(
+        &self,
+        project_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<bool, DomainError> {
+        self.project_service
+            .can_user_view(project_id, user_id)
+            .await
+    }
+
+    /// Check if user is owner of project
+    pub async fn is_project_owner(
+        &self,
+        project_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<bool, DomainError> {
+        self.project_service.is_owner(project_id, user_id).await
+    }
+
+    pub async fn get_report(&self, id: Uuid) -> Result<StatusReport, DomainError> {
+        self.status_report_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("Status report not found".into()))
+    }
+
+    pub async fn list_reports(&self, project_id: Uuid) -> Result<Vec<StatusReport>, DomainError> {
+        self.project_service.get_project(project_id).await?;
+        self.status_report_repository
+            .find_by_project(project_id)
+            .await
+    }
+
+    /// Assemble the report for the period from the project's current data and save it
+    pub async fn create_report(
+        &self,
+        project_id: Uuid,
+        actor_id: Uuid,
+        cmd: CreateStatusReportCommand,
+    ) -> Result<StatusReport, DomainError> {
+        if cmd.period_end < cmd.period_start {
+            return Err(DomainError::ValidationError(
+                "Report period end must not be before its start".into(),
+            ));
+        }
+        if (cmd.period_end - cmd.period_start).num_days() >= MAX_PERIOD_DAYS {
+            return Err(DomainError::ValidationError(format!(
+                "A report can cover at most {} days",
+                MAX_PERIOD_DAYS
+            )));
+        }
+
+        let content = self
+            .assemble(project_id, cmd.period_start, cmd.period_end)
+            .await?;
+        let highlights = cmd
+            .highlights
+            .map(|h| h.trim().to_string())
+            .filter(|h| !h.is_empty());
+        let report = StatusReport::new(
+            project_id,
+            cmd.period_start,
+            cmd.period_end,
+            highlights,
+            content,
+            actor_id,
+        );
+        self.status_report_repository.create(&report).await
+    }
+
+    async fn assemble(
+        &self,
+        project_id: Uuid,
+        period_start: NaiveDate,
+        period_end: NaiveDate,
+    ) -> Result<StatusReportContent, DomainError> {
+        let project = self.project_service.get_project(project_id).await?;
+        let from = start_of_day(period_start);
+        let to = start_of_day(period_end) + Duration::days(1);
+
+        let tasks = self.project_service.get_project_tasks(project_id).await?;
+        let tasks_completed = tasks
+            .iter()
+            .filter(|t| t.is_done() && t.completed_at.is_some_and(|at| at >= from && at < to))
+            .map(report_task)
+            .collect();
+
+        let history = self
+            .activity_repository
+            .find_by_project_between(project_id, TASK_ENTITY, from, to)
+            .await?;
+        let tasks_started = transitioned_to(&history, &tasks, TaskStatus::InProgress);
+        let newly_blocked = transitioned_to(&history, &tasks, TaskStatus::Blocked);
+
+        let horizon = to + Duration::days(UPCOMING_MILESTONE_DAYS);
+        let mut upcoming_milestones: Vec<ReportMilestone> = self
+            .project_service
+            .get_project_milestones(project_id)
+            .await?
+            .into_iter()
+            .filter(|m| !m.completed && m.due_date.is_some_and(|due| due < horizon))
+            .map(|m| ReportMilestone {
+                overdue: m.due_date.is_some_and(|due| due < to),
+                id: m.id,
+                name: m.name,
+                due_date: m.due_date,
+            })
+            .collect();
+        upcoming_milestones.sort_by_key(|m| m.due_date);
+
+        let hours_logged = self
+            .time_log_repository
+            .find_by_project(project_id)
+            .await?
+            .iter()
+            .filter(|l| l.date >= period_start && l.date <= period_end)
+            .map(|l| l.hours)
+            .sum();
+
+        let summary = self.budget_service.get_budget_summary(project_id).await?;
+        let budget = summary.budget.map(|budget| ReportBudget {
+            budget,
+            spent: summary.spent,
+            remaining: summary.remaining.unwrap_or(budget - summary.spent),
+            percent_used: summary.percent_used,
+        });
+
+        let mut risks = self
+            .raid_service
+            .list_items(project_id, Some(RaidType::Risk), None)
+            .await?;
+        risks.retain(|r| r.is_open());
+        risks.sort_by_key(|r| std::cmp::Reverse(r.risk_score()));
+        let top_risks = risks
+            .into_iter()
+            .take(TOP_RISKS)
+            .map(|r| ReportRisk {
+                score: r.risk_score(),
+                id: r.id,
+                title: r.title,
+                status: r.status,
+                probability: r.probability,
+                impact: r.impact,
+            })
+            .collect();
+
+        Ok(StatusReportContent {
+            project_name: project.name,
+            tasks_completed,
+            tasks_started,
+            newly_blocked,
+            upcoming_milestones,
+            hours_logged,
+            budget,
+            top_risks,
+        })
+    }
+}
+
+/// Current tasks whose history shows a change into `status`, in the order of
+/// their first such change
+fn transitioned_to(history: &[ActivityLog], tasks: &[Task], status: TaskStatus) -> Vec<ReportTask> {
+    let mut seen = HashSet::new();
+    history
+        .iter()
+        .filter(|log| status_changed_to(log, &status))
+        .filter(|log| seen.insert(log.entity_id))
+        .filter_map(|log| tasks.iter().find(|t| t.id == log.entity_id))
+        .map(report_task)
+        .collect()
+}
+
+fn status_changed_to(log: &ActivityLog, status: &TaskStatus) -> bool {
+    let Some(changes) = log.details.as_ref().and_then(|d| d.get("changes")) else {
+        return false;
+    };
+    let changes: Vec<FieldChange> = serde_json::from_value(changes.clone()).unwrap_or_default();
+    changes.iter().any(|c| {
+        c.field == "status"
+            && serde_json::from_value::<TaskStatus>(c.to.clone()).is_ok_and(|to| &to == status)
+    })
+}
+
+fn report_task(task: &Task) -> ReportTask {
+    ReportTask {
+        id: task.id,
+        title: task.title.clone(),
+        assignee_id: task.assignee_id,
+    }
+}
+
+fn start_of_day(date: NaiveDate) -> DateTime<Utc> {
+    date.and_hms_opt(0, 0, 0).unwrap_or_default().and_utc()
+}
@@ -7,17 +7,25 @@ use std::sync::Arc;
 use uuid::Uuid;
 
 use crate::application::commands::{CreateTagCommand, UpdateTagCommand};
+use crate::application::services::ProjectAppService;
 use crate::domain::entities::{Tag, TaskTag};
 use crate::domain::repositories::TagRepository;
 use crate::shared::DomainError;
 
 pub struct TagAppService {
     tag_repository: Arc<dyn TagRepository>,
+    project_service: Arc<ProjectAppService>,
 }
 
 impl TagAppService {
-    pub fn new(tag_repository: Arc<dyn TagRepository>) -> Self {
-        Self { tag_repository }
+    pub fn new(
+        tag_repository: Arc<dyn TagRepository>,
+        project_service: Arc<ProjectAppService>,
+    ) -> Self {
+        Self {
+            tag_repository,
+            project_service,
+        }
     }
 
     pub async fn list_tags(&self) -> Result<Vec<Tag>, DomainError> {
@@ -84,6 +92,8 @@ impl TagAppService {
         task_id: Uuid,
         tag_id: Uuid,
     ) -> Result<TaskTag, DomainError> {
+        self.project_service.ensure_task_writable(task_id).await?;
+
         // Verify tag exists
         self.tag_repository
             .find_by_id(tag_id)
@@ -99,6 +109,8 @@ impl TagAppService {
         task_id: Uuid,
         tag_id: Uuid,
     ) -> Result<(), DomainError> {
+        self.project_service.ensure_task_writable(task_id).await?;
+
         self.tag_repository
             .remove_tag_from_task(task_id, tag_id)
             .await
@@ -109,6 +121,8 @@ impl TagAppService {
         task_id: Uuid,
         tag_ids: Vec<Uuid>,
     ) -> Result<Vec<Tag>, DomainError> {
+        self.project_service.ensure_task_writable(task_id).await?;
+
         // Verify all tags exist
         for tag_id in &tag_ids {
             self.tag_repository
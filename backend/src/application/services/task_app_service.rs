@@ -1,27 +1,88 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::Serialize;
+use serde_json::json;
+use std::collections::HashSet;
 use std::sync::Arc;
 use uuid::Uuid;
 
 use crate::application::commands::{CreateTaskCommand, UpdateTaskCommand};
-use crate::domain::entities::Task;
-use crate::domain::repositories::TaskRepository;
+use crate::application::services::{
+    CalendarAppService, MentionAppService, NotificationAppService, ProjectAppService, WithMentions,
+};
+use crate::domain::entities::{
+    ActivityLog, ActualHoursDrift, FieldChange, NotificationType, Task, Watcher,
+};
+use crate::domain::repositories::{ActivityLogRepository, TaskRepository, WatcherRepository};
+use crate::domain::value_objects::{MentionSourceType, WatchEntityType};
 use crate::shared::DomainError;
 
+/// Entity type of task entries in the activity log
+pub const TASK_ENTITY: &str = "task";
+
+/// Calendar days past the reminder window searched for due tasks, enough to
+/// cover weekends, holidays and time off between today and the due date
+const DUE_SOON_LOOKAHEAD_DAYS: i64 = 31;
+
+/// One version of a task, as recorded in the activity log
+#[derive(Debug, Serialize)]
+pub struct TaskHistoryEntry {
+    pub id: Uuid,
+    pub action: String,
+    pub user_id: Option<Uuid>,
+    pub user_name: Option<String>,
+    pub changes: Vec<FieldChange>,
+    pub created_at: DateTime<Utc>,
+}
+
 pub struct TaskAppService {
     task_repository: Arc<dyn TaskRepository>,
+    activity_repository: Arc<dyn ActivityLogRepository>,
+    watcher_repository: Arc<dyn WatcherRepository>,
+    project_service: Arc<ProjectAppService>,
+    mention_service: Arc<MentionAppService>,
+    notification_service: Arc<NotificationAppService>,
+    calendar_service: Arc<CalendarAppService>,
+    /// Working days before the due date assignees are reminded
+    due_soon_working_days: i64,
 }
 
 impl TaskAppService {
-    pub fn new(task_repository: Arc<dyn TaskRepository>) -> Self {
-        Self { task_repository }
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        task_repository: Arc<dyn TaskRepository>,
+        activity_repository: Arc<dyn ActivityLogRepository>,
+        watcher_repository: Arc<dyn WatcherRepository>,
+        project_service: Arc<ProjectAppService>,
+        mention_service: Arc<MentionAppService>,
+        notification_service: Arc<NotificationAppService>,
+        calendar_service: Arc<CalendarAppService>,
+        due_soon_working_days: i64,
+    ) -> Self {
+        Self {
+            task_repository,
+            activity_repository,
+            watcher_repository,
+            project_service,
+            mention_service,
+            notification_service,
+            calendar_service,
+            due_soon_working_days,
+        }
     }
 
-    pub async fn list_tasks(&self) -> Result<Vec<Task>, DomainError> {
-        self.task_repository.find_all().await
+    pub async fn list_tasks(&self, include_archived: bool) -> Result<Vec<Task>, DomainError> {
+        self.task_repository.find_all(include_archived).await
     }
 
     /// List tasks from projects user can access (owner OR member)
-    pub async fn list_accessible_tasks(&self, user_id: Uuid) -> Result<Vec<Task>, DomainError> {
-        self.task_repository.find_accessible_by_user(user_id).await
+    pub async fn list_accessible_tasks(
+        &self,
+        user_id: Uuid,
+        include_archived: bool,
+    ) -> Result<Vec<Task>, DomainError> {
+        self.task_repository
+            .find_accessible_by_user(user_id, include_archived)
+            .await
     }
 
     /// Check if user can access task (via project access)
@@ -29,6 +90,11 @@ impl TaskAppService {
         self.task_repository.can_user_access(task_id, user_id).await
     }
 
+    /// Check if user can read task (also granted to program managers)
+    pub async fn can_user_view(&self, task_id: Uuid, user_id: Uuid) -> Result<bool, DomainError> {
+        self.task_repository.can_user_view(task_id, user_id).await
+    }
+
     /// Check if user is owner of the project containing the task
     pub async fn is_project_owner(
         &self,
@@ -58,8 +124,16 @@ impl TaskAppService {
             .ok_or_else(|| DomainError::NotFound("Task not found".into()))
     }
 
-    pub async fn create_task(&self, cmd: CreateTaskCommand) -> Result<Task, DomainError> {
-        let task = Task::new(
+    pub async fn create_task(
+        &self,
+        cmd: CreateTaskCommand,
+        creator_id: Uuid,
+    ) -> Result<WithMentions<Task>, DomainError> {
+        self.project_service
+            .ensure_can_add_tasks(cmd.project_id)
+            .await?;
+
+        let mut task = Task::new(
             cmd.project_id,
             cmd.title,
             cmd.description,
@@ -68,16 +142,40 @@ impl TaskAppService {
             cmd.due_date,
             cmd.estimated_hours,
         );
+        task.billable = cmd.billable;
+
+        let task = self.task_repository.create(&task).await?;
+        self.record_history(&task, creator_id, "created", Vec::new(), None)
+            .await?;
+
+        // Creator and assignee are subscribed automatically
+        self.watch_task(task.id, creator_id).await?;
+        if let Some(assignee_id) = task.assignee_id {
+            self.watch_task(task.id, assignee_id).await?;
+            self.notify_assignee(&task, assignee_id, creator_id).await;
+        }
+
+        let unresolved = self.process_description_mentions(&task, creator_id).await?;
 
-        self.task_repository.create(&task).await
+        Ok(WithMentions::new(task, unresolved))
     }
 
-    pub async fn update_task(&self, id: Uuid, cmd: UpdateTaskCommand) -> Result<Task, DomainError> {
-        let mut task = self
-            .task_repository
-            .find_by_id(id)
-            .await?
-            .ok_or_else(|| DomainError::NotFound("Task not found".into()))?;
+    pub async fn update_task(
+        &self,
+        id: Uuid,
+        cmd: UpdateTaskCommand,
+        actor_id: Uuid,
+    ) -> Result<WithMentions<Task>, DomainError> {
+        if cmd.actual_hours.is_some() {
+            return Err(DomainError::ValidationError(
+                "Actual hours are derived from time logs; log time instead".into(),
+            ));
+        }
+        let mut task = self.get_task(id).await?;
+        self.project_service
+            .ensure_project_writable(task.project_id)
+            .await?;
+        let before = task.clone();
 
         if let Some(title) = cmd.title {
             task.title = title;
@@ -100,21 +198,192 @@ impl TaskAppService {
         if let Some(estimated_hours) = cmd.estimated_hours {
             task.estimated_hours = Some(estimated_hours);
         }
-        if let Some(actual_hours) = cmd.actual_hours {
-            task.actual_hours = Some(actual_hours);
+        if let Some(billable) = cmd.billable {
+            task.billable = Some(billable);
         }
 
-        self.task_repository.update(&task).await
+        let task = self.task_repository.update(&task).await?;
+        let changes = before.diff(&task);
+        if !changes.is_empty() {
+            self.record_history(&task, actor_id, "updated", changes, None)
+                .await?;
+        }
+        self.notify_task_changes(&before, &task, actor_id).await?;
+
+        let unresolved = if before.description != task.description {
+            self.process_description_mentions(&task, actor_id).await?
+        } else {
+            Vec::new()
+        };
+
+        Ok(WithMentions::new(task, unresolved))
     }
 
-    pub async fn delete_task(&self, id: Uuid) -> Result<(), DomainError> {
-        // Check if task exists
-        self.task_repository
-            .find_by_id(id)
+    pub async fn get_task_history(
+        &self,
+        task_id: Uuid,
+    ) -> Result<Vec<TaskHistoryEntry>, DomainError> {
+        let logs = self
+            .activity_repository
+            .find_by_entity(TASK_ENTITY, task_id)
+            .await?;
+
+        Ok(logs
+            .into_iter()
+            .map(|log| {
+                let changes = log
+                    .details
+                    .as_ref()
+                    .and_then(|d| d.get("changes"))
+                    .and_then(|c| serde_json::from_value(c.clone()).ok())
+                    .unwrap_or_default();
+                TaskHistoryEntry {
+                    id: log.id,
+                    action: log.action,
+                    user_id: log.user_id,
+                    user_name: log.user_name,
+                    changes,
+                    created_at: log.created_at,
+                }
+            })
+            .collect())
+    }
+
+    /// Restore a task to the state recorded by one of its history entries
+    pub async fn restore_task_version(
+        &self,
+        task_id: Uuid,
+        history_id: Uuid,
+        actor_id: Uuid,
+    ) -> Result<Task, DomainError> {
+        let mut task = self.get_task(task_id).await?;
+        self.project_service
+            .ensure_project_writable(task.project_id)
+            .await?;
+
+        let entry = self
+            .activity_repository
+            .find_by_id(history_id)
             .await?
-            .ok_or_else(|| DomainError::NotFound("Task not found".into()))?;
+            .filter(|log| log.entity_type == TASK_ENTITY && log.entity_id == task_id)
+            .ok_or_else(|| DomainError::NotFound("History entry not found".into()))?;
+        let version: Task = entry
+            .details
+            .and_then(|d| d.get("snapshot").cloned())
+            .and_then(|snapshot| serde_json::from_value(snapshot).ok())
+            .ok_or_else(|| {
+                DomainError::ValidationError("History entry has no restorable snapshot".into())
+            })?;
+
+        let before = task.clone();
+        task.restore_from(&version);
+
+        let task = self.task_repository.update(&task).await?;
+        let changes = before.diff(&task);
+        if !changes.is_empty() {
+            self.record_history(&task, actor_id, "restored", changes, Some(history_id))
+                .await?;
+        }
+        self.notify_task_changes(&before, &task, actor_id).await?;
 
-        self.task_repository.delete(id).await
+        Ok(task)
+    }
+
+    /// Move a task to the trash; it is purged after the retention period
+    pub async fn delete_task(&self, id: Uuid, actor_id: Uuid) -> Result<(), DomainError> {
+        let task = self.get_task(id).await?;
+        self.project_service
+            .ensure_project_writable(task.project_id)
+            .await?;
+
+        self.task_repository.soft_delete(id, actor_id).await
+    }
+
+    /// Reset actual hours that drifted from the task's time logs, or only
+    /// report them when `dry_run` is set
+    pub async fn reconcile_actual_hours(
+        &self,
+        dry_run: bool,
+    ) -> Result<Vec<ActualHoursDrift>, DomainError> {
+        self.task_repository.reconcile_actual_hours(dry_run).await
+    }
+
+    /// Remind assignees of open tasks due within the configured number of
+    /// their working days, once per due date. Holidays and approved time off
+    /// don't count. Returns the number of reminders sent; a task that fails
+    /// is logged and retried on the next run.
+    pub async fn send_due_soon_reminders(&self) -> Result<usize, DomainError> {
+        let today = Utc::now().date_naive();
+        let horizon = today + Duration::days(self.due_soon_working_days + DUE_SOON_LOOKAHEAD_DAYS);
+        let tasks = self
+            .task_repository
+            .find_due_unreminded(
+                today.and_time(chrono::NaiveTime::MIN).and_utc(),
+                horizon.and_time(chrono::NaiveTime::MIN).and_utc(),
+            )
+            .await?;
+
+        let assignees: Vec<Uuid> = tasks
+            .iter()
+            .filter_map(|t| t.assignee_id)
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        let calendars = self
+            .calendar_service
+            .user_calendars(&assignees, today, horizon)
+            .await?;
+
+        let mut sent = 0;
+        for task in &tasks {
+            let (Some(assignee_id), Some(due_date)) = (task.assignee_id, task.due_date) else {
+                continue;
+            };
+            let due = due_date.date_naive();
+            let days_left = calendars[&assignee_id].working_days_between(today, due);
+            if days_left > self.due_soon_working_days {
+                continue;
+            }
+
+            let message = match days_left {
+                0 => format!("'{}' is due today", task.title),
+                1 => format!("'{}' is due on {}, 1 working day left", task.title, due),
+                n => format!(
+                    "'{}' is due on {}, {} working days left",
+                    task.title, due, n
+                ),
+            };
+            if let Err(e) = self
+                .send_due_soon_reminder(task, assignee_id, due_date, message)
+                .await
+            {
+                tracing::warn!(task_id = %task.id, "Failed to send due soon reminder: {}", e);
+                continue;
+            }
+            sent += 1;
+        }
+        Ok(sent)
+    }
+
+    async fn send_due_soon_reminder(
+        &self,
+        task: &Task,
+        assignee_id: Uuid,
+        due_date: DateTime<Utc>,
+        message: String,
+    ) -> Result<(), DomainError> {
+        self.notification_service
+            .create_notification(
+                assignee_id,
+                NotificationType::TaskDueSoon,
+                "Task due soon".to_string(),
+                message,
+                Some(task_link(task.id)),
+            )
+            .await?;
+        self.task_repository
+            .mark_due_reminded(task.id, due_date)
+            .await
     }
 
     pub async fn get_tasks_by_project(&self, project_id: Uuid) -> Result<Vec<Task>, DomainError> {
@@ -124,4 +393,157 @@ impl TaskAppService {
     pub async fn get_tasks_by_assignee(&self, user_id: Uuid) -> Result<Vec<Task>, DomainError> {
         self.task_repository.find_by_assignee(user_id).await
     }
+
+    pub async fn get_task_watchers(&self, task_id: Uuid) -> Result<Vec<Watcher>, DomainError> {
+        self.watcher_repository
+            .find_by_entity(WatchEntityType::Task, task_id)
+            .await
+    }
+
+    pub async fn watch_task(&self, task_id: Uuid, user_id: Uuid) -> Result<(), DomainError> {
+        let watcher = Watcher::new(user_id, WatchEntityType::Task, task_id);
+        self.watcher_repository.watch(&watcher).await
+    }
+
+    pub async fn unwatch_task(&self, task_id: Uuid, user_id: Uuid) -> Result<(), DomainError> {
+        self.watcher_repository
+            .unwatch(user_id, WatchEntityType::Task, task_id)
+            .await
+    }
+
+    /// Write an activity log entry holding the field changes and a snapshot
+    /// of the resulting task, so any version can later be restored
+    async fn record_history(
+        &self,
+        task: &Task,
+        actor_id: Uuid,
+        action: &str,
+        changes: Vec<FieldChange>,
+        restored_from: Option<Uuid>,
+    ) -> Result<(), DomainError> {
+        let mut details = json!({
+            "changes": changes,
+            "snapshot": task,
+        });
+        if let Some(restored_from) = restored_from {
+            details["restored_from"] = json!(restored_from);
+        }
+
+        let log = ActivityLog::new(
+            Some(actor_id),
+            Some(task.project_id),
+            action.to_string(),
+            TASK_ENTITY.to_string(),
+            task.id,
+            Some(details),
+        );
+        self.activity_repository.create(&log).await?;
+        Ok(())
+    }
+
+    async fn process_description_mentions(
+        &self,
+        task: &Task,
+        actor_id: Uuid,
+    ) -> Result<Vec<String>, DomainError> {
+        let Some(description) = &task.description else {
+            return Ok(Vec::new());
+        };
+        let outcome = self
+            .mention_service
+            .process_mentions(
+                task,
+                MentionSourceType::Task,
+                task.id,
+                description,
+                actor_id,
+            )
+            .await?;
+        Ok(outcome.unresolved)
+    }
+
+    async fn notify_assignee(&self, task: &Task, assignee_id: Uuid, actor_id: Uuid) {
+        self.notification_service
+            .notify_users(
+                &[assignee_id],
+                actor_id,
+                NotificationType::TaskAssigned,
+                "Task assigned to you",
+                &format!("You have been assigned to '{}'", task.title),
+                Some(&task_link(task.id)),
+            )
+            .await;
+    }
+
+    /// Fan out a notification to watchers of the task and its project
+    async fn notify_task_changes(
+        &self,
+        before: &Task,
+        after: &Task,
+        actor_id: Uuid,
+    ) -> Result<(), DomainError> {
+        let changed: Vec<String> = before
+            .diff(after)
+            .into_iter()
+            .map(|c| c.field.replace('_', " "))
+            .collect();
+        if changed.is_empty() {
+            return Ok(());
+        }
+
+        if before.assignee_id != after.assignee_id {
+            if let Some(assignee_id) = after.assignee_id {
+                self.watch_task(after.id, assignee_id).await?;
+                self.notify_assignee(after, assignee_id, actor_id).await;
+            }
+        }
+
+        let mut recipients: Vec<Uuid> = self
+            .watcher_repository
+            .find_by_entity(WatchEntityType::Task, after.id)
+            .await?
+            .into_iter()
+            .chain(
+                self.watcher_repository
+                    .find_by_entity(WatchEntityType::Project, after.project_id)
+                    .await?,
+            )
+            .map(|w| w.user_id)
+            .collect();
+        // The new assignee already received a dedicated notification
+        if before.assignee_id != after.assignee_id {
+            recipients.retain(|id| Some(*id) != after.assignee_id);
+        }
+
+        let (notification_type, title, message) = if after.is_done() && !before.is_done() {
+            (
+                NotificationType::TaskCompleted,
+                "Task completed",
+                format!("'{}' has been completed", after.title),
+            )
+        } else {
+            (
+                NotificationType::TaskUpdated,
+                "Task updated",
+                format!("'{}' was updated: {}", after.title, changed.join(", ")),
+            )
+        };
+
+        self.notification_service
+            .notify_users(
+                &recipients,
+                actor_id,
+                notification_type,
+                title,
+                &message,
+                Some(&task_link(after.id)),
+            )
+            .await;
+
+        Ok(())
+    }
+}
+
+fn task_link(task_id: Uuid) -> String {
+    format!("/tasks/{}", task_id)
 }
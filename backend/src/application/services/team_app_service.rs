@@ -1,7 +1,9 @@
 use std::sync::Arc;
 use uuid::Uuid;
 
-use crate::application::commands::{AddTeamMemberCommand, CreateTeamCommand, UpdateTeamCommand};
+use crate::application::commands::{
+    AddTeamMemberCommand, CreateTeamCommand, UpdateTeamCommand, UpdateTeamMemberCommand,
+};
 use crate::domain::entities::{Team, TeamMember};
 use crate::domain::repositories::TeamRepository;
 use crate::shared::DomainError;
@@ -59,8 +61,13 @@ impl TeamAppService {
         if let Some(description) = cmd.description {
             team.description = Some(description);
         }
-        if cmd.lead_id.is_some() {
-            team.set_lead(cmd.lead_id);
+        if cmd
+            .lead_id
+            .is_some_and(|lead_id| team.lead_id != Some(lead_id))
+        {
+            return Err(DomainError::ValidationError(
+                "Change the team lead by transferring leadership to a member".into(),
+            ));
         }
 
         self.team_repository.update(&team).await
@@ -91,16 +98,26 @@ impl TeamAppService {
         team_id: Uuid,
         cmd: AddTeamMemberCommand,
     ) -> Result<TeamMember, DomainError> {
-        // Verify team exists
-        self.team_repository
-            .find_by_id(team_id)
-            .await?
-            .ok_or_else(|| DomainError::NotFound("Team not found".into()))?;
+        self.get_team(team_id).await?;
 
+        // A team without a lead is led by its first lead member
         let member = TeamMember::new(team_id, cmd.user_id, cmd.role);
         self.team_repository.add_member(&member).await
     }
 
+    /// Change a member's role, keeping `lead_id` on one of the team's leads
+    pub async fn update_team_member(
+        &self,
+        team_id: Uuid,
+        user_id: Uuid,
+        cmd: UpdateTeamMemberCommand,
+    ) -> Result<TeamMember, DomainError> {
+        self.team_repository
+            .change_member_role(team_id, user_id, cmd.role, cmd.transfer)
+            .await
+    }
+
+    /// Remove a member, refusing to leave the team without a lead
     pub async fn remove_team_member(
         &self,
         team_id: Uuid,
@@ -1,10 +1,13 @@
 use chrono::{NaiveDate, Utc};
 use std::sync::Arc;
+use tokio::io::{AsyncWriteExt, DuplexStream};
 use uuid::Uuid;
 
-use crate::domain::entities::TimeLog;
-use crate::domain::repositories::TimeLogRepository;
-use crate::shared::DomainError;
+use crate::application::services::{BudgetAppService, ProjectAppService};
+use crate::domain::entities::{Project, Task, TimeLog};
+use crate::domain::repositories::{TaskRepository, TimeLogFilter, TimeLogRepository};
+use crate::domain::services::{BillingCalculator, TimeLogPolicy};
+use crate::shared::{DomainError, FieldError};
 
 #[derive(Debug)]
 pub struct CreateTimeLogDto {
@@ -13,6 +16,8 @@ pub struct CreateTimeLogDto {
     pub hours: f32,
     pub date: NaiveDate,
     pub description: Option<String>,
+    /// Defaults to the task's billable setting, else the project's
+    pub billable: Option<bool>,
 }
 
 #[derive(Debug)]
@@ -20,19 +25,79 @@ pub struct UpdateTimeLogDto {
     pub hours: Option<f32>,
     pub date: Option<NaiveDate>,
     pub description: Option<String>,
+    pub billable: Option<bool>,
 }
 
+/// Encodes exported time logs in a file format, one page at a time
+pub trait TimeLogEncoder: Send {
+    /// Bytes that come before the first log, such as a header row
+    fn start(&mut self) -> Result<Vec<u8>, DomainError>;
+    fn write(&mut self, time_logs: &[TimeLog]) -> Result<Vec<u8>, DomainError>;
+    /// Bytes that come after the last log
+    fn finish(&mut self) -> Result<Vec<u8>, DomainError>;
+}
+
+/// Rows fetched per query while streaming an export
+const EXPORT_PAGE_SIZE: i64 = 500;
+/// Bytes buffered between the export task and the response body
+const EXPORT_BUFFER_BYTES: usize = 64 * 1024;
+
 pub struct TimeLogAppService {
     time_log_repository: Arc<dyn TimeLogRepository>,
+    task_repository: Arc<dyn TaskRepository>,
+    project_service: Arc<ProjectAppService>,
+    budget_service: Arc<BudgetAppService>,
+    policy: TimeLogPolicy,
 }
 
 impl TimeLogAppService {
-    pub fn new(time_log_repository: Arc<dyn TimeLogRepository>) -> Self {
+    pub fn new(
+        time_log_repository: Arc<dyn TimeLogRepository>,
+        task_repository: Arc<dyn TaskRepository>,
+        project_service: Arc<ProjectAppService>,
+        budget_service: Arc<BudgetAppService>,
+        policy: TimeLogPolicy,
+    ) -> Self {
         Self {
             time_log_repository,
+            task_repository,
+            project_service,
+            budget_service,
+            policy,
         }
     }
 
+    pub fn policy(&self) -> TimeLogPolicy {
+        self.policy
+    }
+
+    /// Check if user can log time on the task
+    pub async fn can_access_task(&self, task_id: Uuid, user_id: Uuid) -> Result<bool, DomainError> {
+        self.task_repository.can_user_access(task_id, user_id).await
+    }
+
+    /// Check if user can access project
+    pub async fn can_access_project(
+        &self,
+        project_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<bool, DomainError> {
+        self.project_service
+            .can_user_access(project_id, user_id)
+            .await
+    }
+
+    /// Check if user can read project data (members and program managers)
+    pub async fn can_view_project(
+        &self,
+        project_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<bool, DomainError> {
+        self.project_service
+            .can_user_view(project_id, user_id)
+            .await
+    }
+
     pub async fn get_time_log(&self, id: Uuid) -> Result<Option<TimeLog>, DomainError> {
         self.time_log_repository.find_by_id(id).await
     }
@@ -63,23 +128,71 @@ impl TimeLogAppService {
             .await
     }
 
+    /// Stream the logs matching a filter, oldest first, through the encoder.
+    /// The first page is read up front so query errors still fail the request;
+    /// the rest is paged into the returned pipe by a background task.
+    pub async fn export_time_logs(
+        &self,
+        filter: TimeLogFilter,
+        mut encoder: Box<dyn TimeLogEncoder>,
+    ) -> Result<DuplexStream, DomainError> {
+        if let (Some(start), Some(end)) = (filter.start_date, filter.end_date) {
+            if start > end {
+                return Err(DomainError::ValidationError(
+                    "start_date must not be after end_date".into(),
+                ));
+            }
+        }
+
+        let first_page = self
+            .time_log_repository
+            .find_page(&filter, None, EXPORT_PAGE_SIZE)
+            .await?;
+        let repository = self.time_log_repository.clone();
+        let (mut writer, reader) = tokio::io::duplex(EXPORT_BUFFER_BYTES);
+        tokio::spawn(async move {
+            if let Err(e) = write_export(
+                repository.as_ref(),
+                &filter,
+                encoder.as_mut(),
+                first_page,
+                &mut writer,
+            )
+            .await
+            {
+                tracing::warn!("Time log export aborted: {}", e);
+            }
+        });
+        Ok(reader)
+    }
+
     pub async fn create_time_log(&self, dto: CreateTimeLogDto) -> Result<TimeLog, DomainError> {
-        let now = Utc::now();
-        let time_log = TimeLog {
-            id: Uuid::new_v4(),
-            task_id: dto.task_id,
-            user_id: dto.user_id,
-            hours: dto.hours,
-            date: dto.date,
-            description: dto.description,
-            created_at: now,
-            updated_at: now,
-            task_name: None,
-            project_name: None,
-            user_name: None,
-        };
+        self.project_service
+            .ensure_task_writable(dto.task_id)
+            .await?;
+        let (task, project) = self.task_and_project(dto.task_id).await?;
+
+        let billable = dto.billable.unwrap_or_else(|| {
+            BillingCalculator::default_billable(project.billable, task.billable)
+        });
+        let time_log = TimeLog::new(
+            dto.task_id,
+            dto.user_id,
+            dto.hours,
+            dto.date,
+            dto.description,
+            billable,
+        );
+        self.validate(&time_log, &project).await?;
 
-        self.time_log_repository.create(&time_log).await
+        let time_log = self
+            .time_log_repository
+            .create(&time_log, &self.policy)
+            .await?;
+        self.budget_service
+            .check_task_thresholds(time_log.task_id)
+            .await;
+        Ok(time_log)
     }
 
     pub async fn update_time_log(
@@ -92,25 +205,135 @@ impl TimeLogAppService {
             .find_by_id(id)
             .await?
             .ok_or_else(|| DomainError::NotFound(format!("Time log with id {} not found", id)))?;
+        ensure_not_invoiced(&existing)?;
+        self.project_service
+            .ensure_task_writable(existing.task_id)
+            .await?;
 
         let updated = TimeLog {
             hours: dto.hours.unwrap_or(existing.hours),
             date: dto.date.unwrap_or(existing.date),
             description: dto.description.or(existing.description),
+            billable: dto.billable.unwrap_or(existing.billable),
             updated_at: Utc::now(),
             ..existing
         };
+        let (_, project) = self.task_and_project(updated.task_id).await?;
+        self.validate(&updated, &project).await?;
 
-        self.time_log_repository.update(&updated).await
+        let updated = self
+            .time_log_repository
+            .update(&updated, &self.policy)
+            .await?;
+        self.budget_service
+            .check_task_thresholds(updated.task_id)
+            .await;
+        Ok(updated)
     }
 
     pub async fn delete_time_log(&self, id: Uuid) -> Result<(), DomainError> {
-        // Check if exists
-        self.time_log_repository
+        let existing = self
+            .time_log_repository
             .find_by_id(id)
             .await?
             .ok_or_else(|| DomainError::NotFound(format!("Time log with id {} not found", id)))?;
+        ensure_not_invoiced(&existing)?;
+        self.project_service
+            .ensure_task_writable(existing.task_id)
+            .await?;
+
+        self.time_log_repository.delete(id).await?;
+        self.budget_service
+            .check_task_thresholds(existing.task_id)
+            .await;
+        Ok(())
+    }
+
+    /// Apply the time log policy and reject logs on completed or cancelled
+    /// projects, reporting every broken rule at once. The repository checks
+    /// the daily cap again when it stores the log.
+    async fn validate(&self, time_log: &TimeLog, project: &Project) -> Result<(), DomainError> {
+        let other_hours: f32 = self
+            .time_log_repository
+            .find_by_date_range(time_log.user_id, time_log.date, time_log.date)
+            .await?
+            .iter()
+            .filter(|l| l.id != time_log.id)
+            .map(|l| l.hours)
+            .sum();
+
+        let mut errors = self.policy.check(
+            time_log.hours,
+            time_log.date,
+            Utc::now().date_naive(),
+            other_hours,
+        );
+        if !project.can_add_tasks() {
+            errors.push(FieldError::new(
+                "task_id",
+                "project_closed",
+                "Time can't be logged on an archived, completed or cancelled project",
+            ));
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(DomainError::InvalidFields(errors))
+        }
+    }
+
+    async fn task_and_project(&self, task_id: Uuid) -> Result<(Task, Project), DomainError> {
+        let task = self
+            .task_repository
+            .find_by_id(task_id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("Task not found".into()))?;
+        let project = self.project_service.get_project(task.project_id).await?;
+        Ok((task, project))
+    }
+}
+
+/// Encode the first page and every page after it into the pipe, then close it
+async fn write_export(
+    repository: &dyn TimeLogRepository,
+    filter: &TimeLogFilter,
+    encoder: &mut dyn TimeLogEncoder,
+    mut page: Vec<TimeLog>,
+    writer: &mut DuplexStream,
+) -> Result<(), DomainError> {
+    write_chunk(writer, &encoder.start()?).await?;
+    loop {
+        write_chunk(writer, &encoder.write(&page)?).await?;
+        let after = match page.last() {
+            Some(last) if page.len() as i64 == EXPORT_PAGE_SIZE => (last.date, last.id),
+            _ => break,
+        };
+        page = repository
+            .find_page(filter, Some(after), EXPORT_PAGE_SIZE)
+            .await?;
+    }
+    write_chunk(writer, &encoder.finish()?).await?;
+    writer
+        .shutdown()
+        .await
+        .map_err(|e| DomainError::InternalError(format!("Failed to finish export: {}", e)))
+}
+
+/// Fails once the client has gone away and the read half is dropped
+async fn write_chunk(writer: &mut DuplexStream, chunk: &[u8]) -> Result<(), DomainError> {
+    writer
+        .write_all(chunk)
+        .await
+        .map_err(|e| DomainError::InternalError(format!("Failed to write export: {}", e)))
+}
 
-        self.time_log_repository.delete(id).await
+/// Invoiced time can't change, or it would be billed differently than invoiced
+fn ensure_not_invoiced(time_log: &TimeLog) -> Result<(), DomainError> {
+    if time_log.invoice_id.is_some() {
+        return Err(DomainError::Conflict(
+            "This time log has been invoiced and can no longer be changed".into(),
+        ));
     }
+    Ok(())
 }
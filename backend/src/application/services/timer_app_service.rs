@@ -0,0 +1,256 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::Serialize;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::application::commands::{StartTimerCommand, StopTimerCommand};
+use crate::application::services::{
+    CreateTimeLogDto, NotificationAppService, ProjectAppService, TimeLogAppService,
+};
+use crate::domain::entities::{NotificationType, TimeLog, Timer};
+use crate::domain::repositories::{TaskRepository, TimerRepository};
+use crate::domain::services::{RoundingMode, TimerRounding};
+use crate::shared::DomainError;
+
+/// A timer with its tracked time as of the request
+#[derive(Debug, Serialize)]
+pub struct TimerState {
+    #[serde(flatten)]
+    pub timer: Timer,
+    pub running: bool,
+    pub elapsed_seconds: i64,
+}
+
+impl TimerState {
+    fn at(timer: Timer, now: DateTime<Utc>) -> Self {
+        Self {
+            running: timer.is_running(),
+            elapsed_seconds: timer.elapsed_seconds(now),
+            timer,
+        }
+    }
+}
+
+pub struct TimerAppService {
+    timer_repository: Arc<dyn TimerRepository>,
+    task_repository: Arc<dyn TaskRepository>,
+    project_service: Arc<ProjectAppService>,
+    time_log_service: Arc<TimeLogAppService>,
+    notification_service: Arc<NotificationAppService>,
+    auto_stop_hours: i64,
+}
+
+impl TimerAppService {
+    pub fn new(
+        timer_repository: Arc<dyn TimerRepository>,
+        task_repository: Arc<dyn TaskRepository>,
+        project_service: Arc<ProjectAppService>,
+        time_log_service: Arc<TimeLogAppService>,
+        notification_service: Arc<NotificationAppService>,
+        auto_stop_hours: i64,
+    ) -> Self {
+        Self {
+            timer_repository,
+            task_repository,
+            project_service,
+            time_log_service,
+            notification_service,
+            auto_stop_hours,
+        }
+    }
+
+    pub async fn get_current(&self, user_id: Uuid) -> Result<Option<TimerState>, DomainError> {
+        let timer = self.timer_repository.find_by_user(user_id).await?;
+        Ok(timer.map(|t| TimerState::at(t, Utc::now())))
+    }
+
+    pub async fn start_timer(
+        &self,
+        user_id: Uuid,
+        cmd: StartTimerCommand,
+    ) -> Result<TimerState, DomainError> {
+        self.task_repository
+            .find_by_id(cmd.task_id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("Task not found".into()))?;
+        if !self
+            .task_repository
+            .can_user_access(cmd.task_id, user_id)
+            .await?
+        {
+            return Err(DomainError::Forbidden(
+                "You don't have access to this task".into(),
+            ));
+        }
+        self.project_service
+            .ensure_task_writable(cmd.task_id)
+            .await?;
+        if self.timer_repository.find_by_user(user_id).await?.is_some() {
+            return Err(already_running());
+        }
+
+        let timer = Timer::start(user_id, cmd.task_id, cmd.description);
+        let timer = self
+            .timer_repository
+            .create(&timer)
+            .await
+            .map_err(|e| match e {
+                DomainError::AlreadyExists(_) => already_running(),
+                other => other,
+            })?;
+        Ok(TimerState::at(timer, Utc::now()))
+    }
+
+    pub async fn pause_timer(&self, user_id: Uuid) -> Result<TimerState, DomainError> {
+        let mut timer = self.current(user_id).await?;
+        if !timer.is_running() {
+            return Err(DomainError::Conflict("Timer is already paused".into()));
+        }
+
+        let now = Utc::now();
+        timer.pause(now);
+        let timer = self.timer_repository.update(&timer).await?;
+        Ok(TimerState::at(timer, now))
+    }
+
+    pub async fn resume_timer(&self, user_id: Uuid) -> Result<TimerState, DomainError> {
+        let mut timer = self.current(user_id).await?;
+        if timer.is_running() {
+            return Err(DomainError::Conflict("Timer is already running".into()));
+        }
+
+        let now = Utc::now();
+        timer.resume(now);
+        let timer = self.timer_repository.update(&timer).await?;
+        Ok(TimerState::at(timer, now))
+    }
+
+    /// Log the tracked time on the timer's task and remove the timer
+    pub async fn stop_timer(
+        &self,
+        user_id: Uuid,
+        cmd: StopTimerCommand,
+    ) -> Result<TimeLog, DomainError> {
+        let policy = self.time_log_service.policy();
+        if let Some(error) = cmd.round_to_minutes.and_then(|m| policy.check_rounding(m)) {
+            return Err(DomainError::InvalidFields(vec![error]));
+        }
+
+        let mut timer = self.current(user_id).await?;
+        if cmd.description.is_some() {
+            timer.description = cmd.description;
+        }
+
+        let seconds = timer.elapsed_seconds(Utc::now());
+        let increment = cmd.round_to_minutes.unwrap_or(policy.increment_minutes);
+        let hours = TimerRounding::hours(seconds, Some(increment), cmd.rounding);
+        if hours <= 0.0 {
+            return Err(DomainError::ValidationError(
+                "Not enough time tracked to log; discard the timer instead".into(),
+            ));
+        }
+
+        let time_log = self.log_time(&timer, hours).await?;
+        self.timer_repository.delete(timer.id).await?;
+        Ok(time_log)
+    }
+
+    /// Throw away the timer without logging anything
+    pub async fn discard_timer(&self, user_id: Uuid) -> Result<(), DomainError> {
+        let timer = self.current(user_id).await?;
+        self.timer_repository.delete(timer.id).await
+    }
+
+    /// Stop timers that have run without a break for longer than the
+    /// configured limit, logging time up to that limit. Timers whose time
+    /// can't be logged are paused instead so the owner can deal with them.
+    pub async fn auto_stop_stale(&self) -> Result<usize, DomainError> {
+        let now = Utc::now();
+        let stale = self
+            .timer_repository
+            .find_running_since_before(now - Duration::hours(self.auto_stop_hours))
+            .await?;
+
+        let mut stopped = 0;
+        for mut timer in stale {
+            let Some(stop_at) = timer.stale_at(self.auto_stop_hours) else {
+                continue;
+            };
+            let hours = TimerRounding::hours(
+                timer.elapsed_seconds(stop_at),
+                Some(self.time_log_service.policy().increment_minutes),
+                RoundingMode::Down,
+            );
+
+            match self.log_time(&timer, hours).await {
+                Ok(time_log) => {
+                    self.timer_repository.delete(timer.id).await?;
+                    self.notify(
+                        timer.user_id,
+                        &format!(
+                            "Your timer ran for over {} hours and was stopped; {} hours were logged",
+                            self.auto_stop_hours, time_log.hours
+                        ),
+                    )
+                    .await;
+                    stopped += 1;
+                }
+                Err(e) => {
+                    tracing::warn!(timer_id = %timer.id, "Failed to log stale timer: {}", e);
+                    timer.pause(stop_at);
+                    self.timer_repository.update(&timer).await?;
+                    self.notify(
+                        timer.user_id,
+                        &format!(
+                            "Your timer ran for over {} hours and was paused; its time could not be logged",
+                            self.auto_stop_hours
+                        ),
+                    )
+                    .await;
+                }
+            }
+        }
+
+        Ok(stopped)
+    }
+
+    async fn current(&self, user_id: Uuid) -> Result<Timer, DomainError> {
+        self.timer_repository
+            .find_by_user(user_id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("You have no active timer".into()))
+    }
+
+    async fn log_time(&self, timer: &Timer, hours: f32) -> Result<TimeLog, DomainError> {
+        self.time_log_service
+            .create_time_log(CreateTimeLogDto {
+                task_id: timer.task_id,
+                user_id: timer.user_id,
+                hours,
+                date: timer.started_at.date_naive(),
+                description: timer.description.clone(),
+                billable: None,
+            })
+            .await
+    }
+
+    async fn notify(&self, user_id: Uuid, message: &str) {
+        if let Err(e) = self
+            .notification_service
+            .create_notification(
+                user_id,
+                NotificationType::System,
+                "Stale timer".to_string(),
+                message.to_string(),
+                Some("/timer".to_string()),
+            )
+            .await
+        {
+            tracing::warn!(user_id = %user_id, "Failed to create notification: {}", e);
+        }
+    }
+}
+
+fn already_running() -> DomainError {
+    DomainError::Conflict("You already have an active timer; stop it first".into())
+}
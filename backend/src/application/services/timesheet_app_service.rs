@@ -0,0 +1,242 @@
+use chrono::{NaiveDate, Utc};
+use serde::Serialize;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::application::commands::DecideTimesheetCommand;
+use crate::application::services::input::trimmed;
+use crate::application::services::{approval, NotificationAppService};
+use crate::domain::entities::{NotificationType, TimeLog, Timesheet};
+use crate::domain::repositories::{TeamRepository, TimeLogRepository, TimesheetRepository};
+use crate::domain::value_objects::{TimesheetStatus, UserRole};
+use crate::shared::DomainError;
+
+/// A timesheet together with the time logs of its week
+#[derive(Debug, Serialize)]
+pub struct TimesheetWeek {
+    #[serde(flatten)]
+    pub timesheet: Timesheet,
+    pub week_end: NaiveDate,
+    pub total_hours: f32,
+    pub time_logs: Vec<TimeLog>,
+}
+
+pub struct TimesheetAppService {
+    timesheet_repository: Arc<dyn TimesheetRepository>,
+    time_log_repository: Arc<dyn TimeLogRepository>,
+    team_repository: Arc<dyn TeamRepository>,
+    notification_service: Arc<NotificationAppService>,
+}
+
+impl TimesheetAppService {
+    pub fn new(
+        timesheet_repository: Arc<dyn TimesheetRepository>,
+        time_log_repository: Arc<dyn TimeLogRepository>,
+        team_repository: Arc<dyn TeamRepository>,
+        notification_service: Arc<NotificationAppService>,
+    ) -> Self {
+        Self {
+            timesheet_repository,
+            time_log_repository,
+            team_repository,
+            notification_service,
+        }
+    }
+
+    /// Check if user may approve the other user's timesheets: admins and
+    /// managers can approve anyone, team leads their team members
+    pub async fn can_approve(
+        &self,
+        approver_id: Uuid,
+        role: &UserRole,
+        user_id: Uuid,
+    ) -> Result<bool, DomainError> {
+        approval::can_approve(self.team_repository.as_ref(), approver_id, role, user_id).await
+    }
+
+    pub async fn get_timesheet(&self, id: Uuid) -> Result<Timesheet, DomainError> {
+        self.timesheet_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("Timesheet not found".into()))
+    }
+
+    pub async fn list_user_timesheets(&self, user_id: Uuid) -> Result<Vec<Timesheet>, DomainError> {
+        self.timesheet_repository.find_by_user(user_id).await
+    }
+
+    /// The user's timesheet for the week containing `date` (today by default)
+    pub async fn get_week(
+        &self,
+        user_id: Uuid,
+        date: Option<NaiveDate>,
+    ) -> Result<TimesheetWeek, DomainError> {
+        let date = date.unwrap_or_else(|| Utc::now().date_naive());
+        let timesheet = self
+            .timesheet_repository
+            .find_or_create(&Timesheet::new(user_id, date))
+            .await?;
+        self.with_logs(timesheet).await
+    }
+
+    pub async fn get_timesheet_week(&self, id: Uuid) -> Result<TimesheetWeek, DomainError> {
+        let timesheet = self.get_timesheet(id).await?;
+        self.with_logs(timesheet).await
+    }
+
+    /// Submitted timesheets the user may approve, oldest week first
+    pub async fn list_pending(
+        &self,
+        approver_id: Uuid,
+        role: &UserRole,
+    ) -> Result<Vec<Timesheet>, DomainError> {
+        let mut pending = self
+            .timesheet_repository
+            .find_by_status(TimesheetStatus::Submitted)
+            .await?;
+        pending.retain(|t| t.user_id != approver_id);
+
+        if !matches!(role, UserRole::Admin | UserRole::Manager) {
+            let members = self
+                .team_repository
+                .find_member_ids_led_by(approver_id)
+                .await?;
+            pending.retain(|t| members.contains(&t.user_id));
+        }
+        Ok(pending)
+    }
+
+    /// Submit the user's week for approval, locking its time logs
+    pub async fn submit_week(
+        &self,
+        user_id: Uuid,
+        date: NaiveDate,
+    ) -> Result<TimesheetWeek, DomainError> {
+        let mut timesheet = self
+            .timesheet_repository
+            .find_or_create(&Timesheet::new(user_id, date))
+            .await?;
+        if !timesheet.can_submit() {
+            return Err(DomainError::Conflict(
+                "Timesheet is already submitted or approved".into(),
+            ));
+        }
+
+        timesheet.submit();
+        let timesheet = self.timesheet_repository.update(&timesheet).await?;
+
+        let leads = self
+            .team_repository
+            .find_lead_ids_of_member(user_id)
+            .await?;
+        self.notification_service
+            .notify_users(
+                &leads,
+                user_id,
+                NotificationType::System,
+                "Timesheet submitted",
+                &format!(
+                    "A timesheet for the week of {} is waiting for approval",
+                    timesheet.week_start
+                ),
+                Some(&format!("/timesheets/{}", timesheet.id)),
+            )
+            .await;
+
+        self.with_logs(timesheet).await
+    }
+
+    pub async fn approve_timesheet(
+        &self,
+        id: Uuid,
+        approver_id: Uuid,
+        cmd: DecideTimesheetCommand,
+    ) -> Result<Timesheet, DomainError> {
+        let mut timesheet = self.submitted(id).await?;
+
+        timesheet.approve(approver_id, trimmed(cmd.comment));
+        let timesheet = self.timesheet_repository.update(&timesheet).await?;
+        self.notify_owner(
+            &timesheet,
+            "Timesheet approved",
+            format!(
+                "Your timesheet for the week of {} was approved",
+                timesheet.week_start
+            ),
+        )
+        .await;
+
+        Ok(timesheet)
+    }
+
+    /// Send the week back to its owner with a comment, unlocking its time logs
+    pub async fn reject_timesheet(
+        &self,
+        id: Uuid,
+        approver_id: Uuid,
+        cmd: DecideTimesheetCommand,
+    ) -> Result<Timesheet, DomainError> {
+        let Some(comment) = trimmed(cmd.comment) else {
+            return Err(DomainError::ValidationError(
+                "A comment explaining the rejection is required".into(),
+            ));
+        };
+        let mut timesheet = self.submitted(id).await?;
+
+        timesheet.reject(approver_id, comment.clone());
+        let timesheet = self.timesheet_repository.update(&timesheet).await?;
+        self.notify_owner(
+            &timesheet,
+            "Timesheet rejected",
+            format!(
+                "Your timesheet for the week of {} was rejected: {}",
+                timesheet.week_start, comment
+            ),
+        )
+        .await;
+
+        Ok(timesheet)
+    }
+
+    async fn submitted(&self, id: Uuid) -> Result<Timesheet, DomainError> {
+        let timesheet = self.get_timesheet(id).await?;
+        if timesheet.status != TimesheetStatus::Submitted {
+            return Err(DomainError::Conflict(
+                "Only submitted timesheets can be approved or rejected".into(),
+            ));
+        }
+        Ok(timesheet)
+    }
+
+    async fn with_logs(&self, timesheet: Timesheet) -> Result<TimesheetWeek, DomainError> {
+        let week_end = timesheet.week_end();
+        let time_logs = self
+            .time_log_repository
+            .find_by_date_range(timesheet.user_id, timesheet.week_start, week_end)
+            .await?;
+        let total_hours = time_logs.iter().map(|l| l.hours).sum();
+
+        Ok(TimesheetWeek {
+            timesheet,
+            week_end,
+            total_hours,
+            time_logs,
+        })
+    }
+
+    async fn notify_owner(&self, timesheet: &Timesheet, title: &str, message: String) {
+        if let Err(e) = self
+            .notification_service
+            .create_notification(
+                timesheet.user_id,
+                NotificationType::System,
+                title.to_string(),
+                message,
+                Some(format!("/timesheets/{}", timesheet.id)),
+            )
+            .await
+        {
+            tracing::warn!(user_id = %timesheet.user_id, "Failed to create notification: {}", e);
+        }
+    }
+}
@@ -0,0 +1,169 @@
+use chrono::{Duration, Utc};
+use serde::Serialize;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::application::services::AttachmentAppService;
+use crate::domain::entities::{Project, Task};
+use crate::domain::repositories::{ProjectRepository, TaskRepository, WatcherRepository};
+use crate::shared::DomainError;
+
+/// Projects and tasks currently in the trash
+#[derive(Debug, Serialize)]
+pub struct TrashContents {
+    pub projects: Vec<Project>,
+    pub tasks: Vec<Task>,
+}
+
+/// Number of items permanently removed by one purge run
+#[derive(Debug, Default)]
+pub struct PurgeSummary {
+    pub projects: usize,
+    pub tasks: usize,
+}
+
+pub struct TrashAppService {
+    project_repository: Arc<dyn ProjectRepository>,
+    task_repository: Arc<dyn TaskRepository>,
+    watcher_repository: Arc<dyn WatcherRepository>,
+    attachment_service: Arc<AttachmentAppService>,
+    retention: Duration,
+}
+
+impl TrashAppService {
+    pub fn new(
+        project_repository: Arc<dyn ProjectRepository>,
+        task_repository: Arc<dyn TaskRepository>,
+        watcher_repository: Arc<dyn WatcherRepository>,
+        attachment_service: Arc<AttachmentAppService>,
+        retention_days: i64,
+    ) -> Self {
+        Self {
+            project_repository,
+            task_repository,
+            watcher_repository,
+            attachment_service,
+            retention: Duration::days(retention_days),
+        }
+    }
+
+    /// Check if user can access a (non-trashed) project
+    pub async fn can_user_access(
+        &self,
+        project_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<bool, DomainError> {
+        self.project_repository
+            .can_user_access(project_id, user_id)
+            .await
+    }
+
+    /// Check if user is owner of a (non-trashed) project
+    pub async fn is_project_owner(
+        &self,
+        project_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<bool, DomainError> {
+        self.project_repository.is_owner(project_id, user_id).await
+    }
+
+    /// Everything the user has moved to the trash
+    pub async fn get_user_trash(&self, user_id: Uuid) -> Result<TrashContents, DomainError> {
+        let projects = self
+            .project_repository
+            .find_deleted_by_user(user_id)
+            .await?;
+        let tasks = self.task_repository.find_deleted_by_user(user_id).await?;
+        Ok(TrashContents { projects, tasks })
+    }
+
+    /// Trashed tasks of a project
+    pub async fn get_project_trash(&self, project_id: Uuid) -> Result<Vec<Task>, DomainError> {
+        self.project_repository
+            .find_by_id(project_id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("Project not found".into()))?;
+
+        self.task_repository
+            .find_deleted_by_project(project_id)
+            .await
+    }
+
+    pub async fn get_deleted_project(&self, id: Uuid) -> Result<Project, DomainError> {
+        self.project_repository
+            .find_deleted_by_id(id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("Project not found in trash".into()))
+    }
+
+    pub async fn get_deleted_task(&self, id: Uuid) -> Result<Task, DomainError> {
+        self.task_repository
+            .find_deleted_by_id(id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("Task not found in trash".into()))
+    }
+
+    /// Restore a project together with every task that was not trashed on its own
+    pub async fn restore_project(&self, id: Uuid) -> Result<Project, DomainError> {
+        self.get_deleted_project(id).await?;
+        self.project_repository.restore(id).await
+    }
+
+    pub async fn restore_task(&self, id: Uuid) -> Result<Task, DomainError> {
+        let task = self.get_deleted_task(id).await?;
+
+        // A task cannot come back into a project that is itself in the trash
+        let project = self
+            .project_repository
+            .find_by_id(task.project_id)
+            .await?
+            .ok_or_else(|| {
+                DomainError::ValidationError(
+                    "Restore the task's project before restoring the task".into(),
+                )
+            })?;
+        if project.is_archived() {
+            return Err(DomainError::Conflict(
+                "Project is archived and read-only".into(),
+            ));
+        }
+
+        self.task_repository.restore(id).await
+    }
+
+    /// Permanently delete everything that has been in the trash longer than
+    /// the retention period, removing attachment files from disk first.
+    /// Items whose files cannot be removed are kept for the next run.
+    pub async fn purge_expired(&self) -> Result<PurgeSummary, DomainError> {
+        let cutoff = Utc::now() - self.retention;
+        let mut summary = PurgeSummary::default();
+
+        for task in self.task_repository.find_deleted_before(cutoff).await? {
+            if let Err(e) = self.attachment_service.remove_task_files(task.id).await {
+                tracing::warn!(task_id = %task.id, "Skipping purge of task: {}", e);
+                continue;
+            }
+            self.task_repository.delete(task.id).await?;
+            summary.tasks += 1;
+        }
+
+        for project in self.project_repository.find_deleted_before(cutoff).await? {
+            if let Err(e) = self
+                .attachment_service
+                .remove_project_files(project.id)
+                .await
+            {
+                tracing::warn!(project_id = %project.id, "Skipping purge of project: {}", e);
+                continue;
+            }
+            self.project_repository.delete(project.id).await?;
+            summary.projects += 1;
+        }
+
+        if summary.tasks > 0 || summary.projects > 0 {
+            self.watcher_repository.delete_orphaned().await?;
+        }
+
+        Ok(summary)
+    }
+}
@@ -50,3 +50,21 @@ pub struct ActivityLogWithDetails {
     pub details: Option<JsonValue>,
     pub created_at: DateTime<Utc>,
 }
+
+/// A single field-level change, stored in `ActivityLog.details`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FieldChange {
+    pub field: String,
+    pub from: JsonValue,
+    pub to: JsonValue,
+}
+
+impl FieldChange {
+    pub fn new(field: impl Into<String>, from: JsonValue, to: JsonValue) -> Self {
+        Self {
+            field: field.into(),
+            from,
+            to,
+        }
+    }
+}
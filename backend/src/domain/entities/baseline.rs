@@ -0,0 +1,96 @@
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::{Milestone, Project, Task};
+
+/// A frozen copy of a project's plan, compared against later to report variance
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectBaseline {
+    pub id: Uuid,
+    pub project_id: Uuid,
+    pub name: String,
+    pub description: Option<String>,
+    pub snapshot: BaselineSnapshot,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl ProjectBaseline {
+    pub fn new(
+        project_id: Uuid,
+        name: String,
+        description: Option<String>,
+        snapshot: BaselineSnapshot,
+        created_by: Uuid,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            project_id,
+            name,
+            description,
+            snapshot,
+            created_by: Some(created_by),
+            created_at: Utc::now(),
+        }
+    }
+}
+
+/// The planned parts of a project: dates, budget, task estimates and milestones
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BaselineSnapshot {
+    pub budget: Option<Decimal>,
+    pub start_date: Option<DateTime<Utc>>,
+    pub end_date: Option<DateTime<Utc>>,
+    pub tasks: Vec<BaselineTask>,
+    pub milestones: Vec<BaselineMilestone>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BaselineTask {
+    pub id: Uuid,
+    pub title: String,
+    pub milestone_id: Option<Uuid>,
+    pub due_date: Option<DateTime<Utc>>,
+    pub estimated_hours: Option<f32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BaselineMilestone {
+    pub id: Uuid,
+    pub name: String,
+    pub due_date: Option<DateTime<Utc>>,
+}
+
+impl BaselineSnapshot {
+    pub fn capture(project: &Project, tasks: &[Task], milestones: &[Milestone]) -> Self {
+        Self {
+            budget: project.budget,
+            start_date: project.start_date,
+            end_date: project.end_date,
+            tasks: tasks
+                .iter()
+                .map(|t| BaselineTask {
+                    id: t.id,
+                    title: t.title.clone(),
+                    milestone_id: t.milestone_id,
+                    due_date: t.due_date,
+                    estimated_hours: t.estimated_hours,
+                })
+                .collect(),
+            milestones: milestones
+                .iter()
+                .map(|m| BaselineMilestone {
+                    id: m.id,
+                    name: m.name.clone(),
+                    due_date: m.due_date,
+                })
+                .collect(),
+        }
+    }
+
+    pub fn total_estimated_hours(&self) -> f32 {
+        self.tasks.iter().filter_map(|t| t.estimated_hours).sum()
+    }
+}
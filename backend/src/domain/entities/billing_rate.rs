@@ -0,0 +1,63 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::value_objects::UserRole;
+
+/// Hourly rate billed to the client for logged time. A rate applies to a
+/// project, a user, a role, or a project together with a user or a role,
+/// from its effective date until a newer rate of the same scope starts.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BillingRate {
+    pub id: Uuid,
+    pub project_id: Option<Uuid>,
+    pub user_id: Option<Uuid>,
+    pub role: Option<UserRole>,
+    pub hourly_rate: Decimal,
+    pub effective_from: NaiveDate,
+    pub created_at: DateTime<Utc>,
+}
+
+impl BillingRate {
+    pub fn new(
+        project_id: Option<Uuid>,
+        user_id: Option<Uuid>,
+        role: Option<UserRole>,
+        hourly_rate: Decimal,
+        effective_from: NaiveDate,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            project_id,
+            user_id,
+            role,
+            hourly_rate,
+            effective_from,
+            created_at: Utc::now(),
+        }
+    }
+
+    /// Whether the rate covers time the user logged on the project
+    pub fn matches(&self, project_id: Uuid, user_id: Uuid, role: &UserRole) -> bool {
+        self.project_id.is_none_or(|id| id == project_id)
+            && self.user_id.is_none_or(|id| id == user_id)
+            && self.role.as_ref().is_none_or(|r| r == role)
+    }
+
+    /// How narrowly the rate is scoped; narrower rates win. A project rate
+    /// outranks any rate without a project, and a user outranks a role.
+    pub fn specificity(&self) -> u8 {
+        let mut score = 0;
+        if self.project_id.is_some() {
+            score += 4;
+        }
+        if self.user_id.is_some() {
+            score += 2;
+        }
+        if self.role.is_some() {
+            score += 1;
+        }
+        score
+    }
+}
@@ -0,0 +1,181 @@
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Deserializer, Serialize};
+use uuid::Uuid;
+
+use crate::domain::value_objects::ChangeRequestStatus;
+
+/// Governed fields of a project. An absent field is left untouched, while
+/// `null` (`Some(None)`) clears it.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ChangeSet {
+    #[serde(
+        default,
+        deserialize_with = "present",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub budget: Option<Option<Decimal>>,
+    #[serde(
+        default,
+        deserialize_with = "present",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub end_date: Option<Option<DateTime<Utc>>>,
+    #[serde(default)]
+    pub milestone_dates: Vec<MilestoneDateChange>,
+}
+
+/// Tell a field sent as `null` apart from one left out
+fn present<'de, D, T>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Some)
+}
+
+impl ChangeSet {
+    pub fn is_empty(&self) -> bool {
+        self.budget.is_none() && self.end_date.is_none() && self.milestone_dates.is_empty()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MilestoneDateChange {
+    pub milestone_id: Uuid,
+    pub due_date: Option<DateTime<Utc>>,
+}
+
+/// A proposed change to the budget, end date or milestone dates of a
+/// governed project, applied once an approver signs it off
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChangeRequest {
+    pub id: Uuid,
+    pub project_id: Uuid,
+    pub proposed: ChangeSet,
+    /// Values of the same fields when the request was submitted
+    pub previous: ChangeSet,
+    pub justification: String,
+    pub status: ChangeRequestStatus,
+    pub requested_by: Option<Uuid>,
+    pub decided_by: Option<Uuid>,
+    pub decision_comment: Option<String>,
+    pub decided_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl ChangeRequest {
+    pub fn new(
+        project_id: Uuid,
+        proposed: ChangeSet,
+        previous: ChangeSet,
+        justification: String,
+        requested_by: Uuid,
+    ) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            project_id,
+            proposed,
+            previous,
+            justification,
+            status: ChangeRequestStatus::Submitted,
+            requested_by: Some(requested_by),
+            decided_by: None,
+            decision_comment: None,
+            decided_at: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    pub fn is_pending(&self) -> bool {
+        self.status == ChangeRequestStatus::Submitted
+    }
+
+    pub fn approve(&mut self, approver_id: Uuid, comment: Option<String>) {
+        self.decide(ChangeRequestStatus::Approved, Some(approver_id), comment);
+    }
+
+    pub fn reject(&mut self, approver_id: Uuid, comment: Option<String>) {
+        self.decide(ChangeRequestStatus::Rejected, Some(approver_id), comment);
+    }
+
+    /// Pulled back by the requester before a decision was made
+    pub fn withdraw(&mut self) {
+        self.decide(ChangeRequestStatus::Withdrawn, None, None);
+    }
+
+    fn decide(
+        &mut self,
+        status: ChangeRequestStatus,
+        decided_by: Option<Uuid>,
+        comment: Option<String>,
+    ) {
+        let now = Utc::now();
+        self.status = status;
+        self.decided_by = decided_by;
+        self.decision_comment = comment;
+        self.decided_at = Some(now);
+        self.updated_at = now;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_request() -> ChangeRequest {
+        let proposed = ChangeSet {
+            budget: Some(Some(Decimal::new(50_000, 0))),
+            ..Default::default()
+        };
+        ChangeRequest::new(
+            Uuid::new_v4(),
+            proposed,
+            ChangeSet::default(),
+            "Vendor quote came in higher".into(),
+            Uuid::new_v4(),
+        )
+    }
+
+    #[test]
+    fn test_change_set_is_empty() {
+        assert!(ChangeSet::default().is_empty());
+        assert!(!sample_request().proposed.is_empty());
+    }
+
+    #[test]
+    fn test_change_set_tells_cleared_from_unset() {
+        let changes: ChangeSet = serde_json::from_str(r#"{"end_date": null}"#).unwrap();
+        assert_eq!(changes.end_date, Some(None));
+        assert_eq!(changes.budget, None);
+        assert!(!changes.is_empty());
+
+        let json = serde_json::to_value(&changes).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({"end_date": null, "milestone_dates": []})
+        );
+        assert_eq!(serde_json::from_value::<ChangeSet>(json).unwrap(), changes);
+    }
+
+    #[test]
+    fn test_decision_closes_request() {
+        let approver = Uuid::new_v4();
+        let mut request = sample_request();
+        assert!(request.is_pending());
+
+        request.approve(approver, Some("Within contingency".into()));
+        assert!(!request.is_pending());
+        assert_eq!(request.status, ChangeRequestStatus::Approved);
+        assert_eq!(request.decided_by, Some(approver));
+        assert!(request.decided_at.is_some());
+
+        let mut withdrawn = sample_request();
+        withdrawn.withdraw();
+        assert_eq!(withdrawn.status, ChangeRequestStatus::Withdrawn);
+        assert!(withdrawn.decided_by.is_none());
+    }
+}
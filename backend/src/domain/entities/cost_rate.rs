@@ -0,0 +1,40 @@
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::value_objects::UserRole;
+
+/// Hourly cost of a user's time. A rate applies either to one user or to
+/// every user with a role; a user's own rate takes precedence.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CostRate {
+    pub id: Uuid,
+    pub user_id: Option<Uuid>,
+    pub role: Option<UserRole>,
+    pub hourly_rate: Decimal,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl CostRate {
+    pub fn for_user(user_id: Uuid, hourly_rate: Decimal) -> Self {
+        Self::new(Some(user_id), None, hourly_rate)
+    }
+
+    pub fn for_role(role: UserRole, hourly_rate: Decimal) -> Self {
+        Self::new(None, Some(role), hourly_rate)
+    }
+
+    fn new(user_id: Option<Uuid>, role: Option<UserRole>, hourly_rate: Decimal) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            user_id,
+            role,
+            hourly_rate,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+}
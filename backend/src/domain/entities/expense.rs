@@ -0,0 +1,45 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Expense {
+    pub id: Uuid,
+    pub project_id: Uuid,
+    pub amount: Decimal,
+    pub category: String,
+    pub description: Option<String>,
+    pub date: NaiveDate,
+    /// Attachment holding the receipt, uploaded to one of the project's tasks
+    pub receipt_attachment_id: Option<Uuid>,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Expense {
+    pub fn new(
+        project_id: Uuid,
+        amount: Decimal,
+        category: String,
+        description: Option<String>,
+        date: NaiveDate,
+        receipt_attachment_id: Option<Uuid>,
+        created_by: Uuid,
+    ) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            project_id,
+            amount,
+            category,
+            description,
+            date,
+            receipt_attachment_id,
+            created_by: Some(created_by),
+            created_at: now,
+            updated_at: now,
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// A named set of public holidays, e.g. one per country or office
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HolidayCalendar {
+    pub id: Uuid,
+    pub name: String,
+    /// Followed by users whose capacity doesn't name a calendar, and by project schedules
+    pub is_default: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl HolidayCalendar {
+    pub fn new(name: String, is_default: bool) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            name,
+            is_default,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Holiday {
+    pub id: Uuid,
+    pub calendar_id: Uuid,
+    pub date: NaiveDate,
+    pub name: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Holiday {
+    pub fn new(calendar_id: Uuid, date: NaiveDate, name: String) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            calendar_id,
+            date,
+            name,
+            created_at: Utc::now(),
+        }
+    }
+}
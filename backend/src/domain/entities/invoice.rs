@@ -0,0 +1,75 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::value_objects::UserRole;
+
+/// A billable, not yet invoiced time log with what is needed to price it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BillableEntry {
+    pub time_log_id: Uuid,
+    pub project_id: Uuid,
+    pub project_name: String,
+    pub task_id: Uuid,
+    pub task_title: String,
+    pub user_id: Uuid,
+    pub user_name: String,
+    pub user_role: UserRole,
+    pub date: NaiveDate,
+    pub hours: f32,
+    /// Last change of the time log; invoicing fails if it changed since
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Billable hours of one user on one task at one hourly rate
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InvoiceLine {
+    pub project_id: Uuid,
+    pub project_name: String,
+    pub task_id: Uuid,
+    pub task_title: String,
+    pub user_id: Uuid,
+    pub user_name: String,
+    pub hourly_rate: Decimal,
+    pub hours: f32,
+    pub amount: Decimal,
+}
+
+/// Billable time of a period, invoiced once. The lines are a snapshot of the
+/// rates in force at invoicing, so later rate changes don't alter it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Invoice {
+    pub id: Uuid,
+    /// Project the invoice was limited to, if any
+    pub project_id: Option<Uuid>,
+    pub period_start: NaiveDate,
+    pub period_end: NaiveDate,
+    pub total_hours: f32,
+    pub total_amount: Decimal,
+    pub lines: Vec<InvoiceLine>,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Invoice {
+    pub fn new(
+        project_id: Option<Uuid>,
+        period_start: NaiveDate,
+        period_end: NaiveDate,
+        lines: Vec<InvoiceLine>,
+        created_by: Uuid,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            project_id,
+            period_start,
+            period_end,
+            total_hours: lines.iter().map(|l| l.hours).sum(),
+            total_amount: lines.iter().map(|l| l.amount).sum(),
+            lines,
+            created_by: Some(created_by),
+            created_at: Utc::now(),
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::value_objects::MentionSourceType;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Mention {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub source_type: MentionSourceType,
+    pub source_id: Uuid,
+    pub task_id: Uuid,
+    pub mentioned_by: Uuid,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Mention {
+    pub fn new(
+        user_id: Uuid,
+        source_type: MentionSourceType,
+        source_id: Uuid,
+        task_id: Uuid,
+        mentioned_by: Uuid,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            user_id,
+            source_type,
+            source_id,
+            task_id,
+            mentioned_by,
+            created_at: Utc::now(),
+        }
+    }
+}
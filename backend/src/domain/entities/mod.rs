@@ -1,21 +1,63 @@
 mod activity_log;
 mod attachment;
+mod baseline;
+mod billing_rate;
+mod change_request;
+mod cost_rate;
+mod expense;
+mod holiday;
+mod invoice;
+mod mention;
 mod milestone;
 mod notification;
+mod portfolio;
 mod project;
+mod project_health;
+mod raid_item;
+mod resource_allocation;
+mod sprint;
+mod status_report;
 mod tag;
 mod task;
+mod task_dependency;
 mod team;
 mod time_log;
+mod time_off;
+mod timer;
+mod timesheet;
 mod user;
+mod user_capacity;
+mod watcher;
 
-pub use activity_log::{ActivityLog, ActivityLogWithDetails};
+pub use activity_log::{ActivityLog, ActivityLogWithDetails, FieldChange};
 pub use attachment::Attachment;
+pub use baseline::{BaselineMilestone, BaselineSnapshot, BaselineTask, ProjectBaseline};
+pub use billing_rate::BillingRate;
+pub use change_request::{ChangeRequest, ChangeSet, MilestoneDateChange};
+pub use cost_rate::CostRate;
+pub use expense::Expense;
+pub use holiday::{Holiday, HolidayCalendar};
+pub use invoice::{BillableEntry, Invoice, InvoiceLine};
+pub use mention::Mention;
 pub use milestone::Milestone;
 pub use notification::{Notification, NotificationType};
+pub use portfolio::Portfolio;
 pub use project::{Project, ProjectMember};
+pub use project_health::{HealthOverride, HealthSnapshot};
+pub use raid_item::RaidItem;
+pub use resource_allocation::ResourceAllocation;
+pub use sprint::Sprint;
+pub use status_report::{
+    ReportBudget, ReportMilestone, ReportRisk, ReportTask, StatusReport, StatusReportContent,
+};
 pub use tag::{Tag, TaskTag};
-pub use task::{Task, TaskComment};
+pub use task::{ActualHoursDrift, Task, TaskComment};
+pub use task_dependency::TaskDependency;
 pub use team::{Team, TeamMember};
-pub use time_log::TimeLog;
+pub use time_log::{DailyHours, TimeLog};
+pub use time_off::TimeOffRequest;
+pub use timer::Timer;
+pub use timesheet::Timesheet;
 pub use user::User;
+pub use user_capacity::UserCapacity;
+pub use watcher::Watcher;
@@ -12,6 +12,7 @@ pub enum NotificationType {
     ProjectUpdated,
     CommentAdded,
     Mention,
+    BudgetAlert,
     System,
 }
 
@@ -25,6 +26,7 @@ impl std::fmt::Display for NotificationType {
             NotificationType::ProjectUpdated => write!(f, "project_updated"),
             NotificationType::CommentAdded => write!(f, "comment_added"),
             NotificationType::Mention => write!(f, "mention"),
+            NotificationType::BudgetAlert => write!(f, "budget_alert"),
             NotificationType::System => write!(f, "system"),
         }
     }
@@ -42,6 +44,7 @@ impl std::str::FromStr for NotificationType {
             "project_updated" => Ok(NotificationType::ProjectUpdated),
             "comment_added" => Ok(NotificationType::CommentAdded),
             "mention" => Ok(NotificationType::Mention),
+            "budget_alert" => Ok(NotificationType::BudgetAlert),
             "system" => Ok(NotificationType::System),
             _ => Err(()),
         }
@@ -0,0 +1,46 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::value_objects::PortfolioKind;
+
+/// A portfolio or program grouping projects. Its owner is the program
+/// manager, who can read every project grouped below it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Portfolio {
+    pub id: Uuid,
+    pub name: String,
+    pub description: Option<String>,
+    pub kind: PortfolioKind,
+    /// Portfolio a program belongs to; always `None` for portfolios
+    pub parent_id: Option<Uuid>,
+    pub owner_id: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Portfolio {
+    pub fn new(
+        name: String,
+        description: Option<String>,
+        kind: PortfolioKind,
+        parent_id: Option<Uuid>,
+        owner_id: Uuid,
+    ) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            name,
+            description,
+            kind,
+            parent_id,
+            owner_id,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    pub fn is_portfolio(&self) -> bool {
+        self.kind == PortfolioKind::Portfolio
+    }
+}
@@ -16,8 +16,15 @@ pub struct Project {
     pub end_date: Option<DateTime<Utc>>,
     pub budget: Option<Decimal>,
     pub owner_id: Uuid,
+    /// Program or portfolio the project is grouped under
+    pub portfolio_id: Option<Uuid>,
+    /// Whether time logged on the project is billed by default
+    pub billable: bool,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    pub archived_at: Option<DateTime<Utc>>,
+    pub deleted_at: Option<DateTime<Utc>>,
+    pub deleted_by: Option<Uuid>,
 }
 
 impl Project {
@@ -43,8 +50,13 @@ impl Project {
             end_date,
             budget,
             owner_id,
+            portfolio_id: None,
+            billable: true,
             created_at: now,
             updated_at: now,
+            archived_at: None,
+            deleted_at: None,
+            deleted_by: None,
         }
     }
 
@@ -56,11 +68,27 @@ impl Project {
         matches!(self.status, ProjectStatus::Completed)
     }
 
+    /// Archived projects are read-only, including their tasks
+    pub fn is_archived(&self) -> bool {
+        self.archived_at.is_some()
+    }
+
     pub fn can_add_tasks(&self) -> bool {
-        !matches!(
-            self.status,
-            ProjectStatus::Completed | ProjectStatus::Cancelled
-        )
+        !self.is_archived()
+            && !matches!(
+                self.status,
+                ProjectStatus::Completed | ProjectStatus::Cancelled
+            )
+    }
+
+    pub fn archive(&mut self) {
+        self.archived_at = Some(Utc::now());
+        self.updated_at = Utc::now();
+    }
+
+    pub fn unarchive(&mut self) {
+        self.archived_at = None;
+        self.updated_at = Utc::now();
     }
 
     pub fn update_status(&mut self, status: ProjectStatus) {
@@ -89,3 +117,39 @@ impl ProjectMember {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_project(status: ProjectStatus) -> Project {
+        Project::new(
+            "Website".into(),
+            None,
+            Uuid::new_v4(),
+            Some(status),
+            None,
+            None,
+            None,
+            None,
+        )
+    }
+
+    #[test]
+    fn test_can_add_tasks_depends_on_status() {
+        assert!(sample_project(ProjectStatus::Active).can_add_tasks());
+        assert!(!sample_project(ProjectStatus::Completed).can_add_tasks());
+        assert!(!sample_project(ProjectStatus::Cancelled).can_add_tasks());
+    }
+
+    #[test]
+    fn test_archived_project_cannot_add_tasks() {
+        let mut project = sample_project(ProjectStatus::Active);
+        project.archive();
+        assert!(project.is_archived());
+        assert!(!project.can_add_tasks());
+
+        project.unarchive();
+        assert!(project.can_add_tasks());
+    }
+}
@@ -0,0 +1,44 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use uuid::Uuid;
+
+use crate::domain::value_objects::RagStatus;
+
+/// RAG status set by hand by the project owner, replacing the computed one
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HealthOverride {
+    pub project_id: Uuid,
+    pub status: RagStatus,
+    pub comment: String,
+    pub set_by: Option<Uuid>,
+    pub set_at: DateTime<Utc>,
+}
+
+impl HealthOverride {
+    pub fn new(project_id: Uuid, status: RagStatus, comment: String, set_by: Uuid) -> Self {
+        Self {
+            project_id,
+            status,
+            comment,
+            set_by: Some(set_by),
+            set_at: Utc::now(),
+        }
+    }
+}
+
+/// Health of a project recorded for one week
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HealthSnapshot {
+    pub id: Uuid,
+    pub project_id: Uuid,
+    /// Monday of the week the snapshot covers
+    pub week_start: NaiveDate,
+    /// Status in effect, taking any override into account
+    pub status: RagStatus,
+    pub computed_status: RagStatus,
+    pub score: i16,
+    pub factors: JsonValue,
+    pub overridden: bool,
+    pub recorded_at: DateTime<Utc>,
+}
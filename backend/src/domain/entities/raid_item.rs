@@ -0,0 +1,130 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::value_objects::{RaidStatus, RaidType};
+
+/// An entry of a project's RAID log: a risk, assumption, issue or decision
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RaidItem {
+    pub id: Uuid,
+    pub project_id: Uuid,
+    pub item_type: RaidType,
+    pub title: String,
+    pub description: Option<String>,
+    pub owner_id: Option<Uuid>,
+    pub status: RaidStatus,
+    /// Likelihood and impact on a 1-5 scale, for risks only
+    pub probability: Option<i16>,
+    pub impact: Option<i16>,
+    pub mitigation_plan: Option<String>,
+    pub review_date: Option<NaiveDate>,
+    pub task_ids: Vec<Uuid>,
+    /// Review date the last overdue reminder was sent for
+    #[serde(skip_serializing)]
+    pub reminded_for: Option<NaiveDate>,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl RaidItem {
+    pub fn new(
+        project_id: Uuid,
+        item_type: RaidType,
+        title: String,
+        description: Option<String>,
+        owner_id: Option<Uuid>,
+        created_by: Uuid,
+    ) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            project_id,
+            item_type,
+            title,
+            description,
+            owner_id,
+            status: RaidStatus::Open,
+            probability: None,
+            impact: None,
+            mitigation_plan: None,
+            review_date: None,
+            task_ids: Vec::new(),
+            reminded_for: None,
+            created_by: Some(created_by),
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    pub fn is_risk(&self) -> bool {
+        self.item_type == RaidType::Risk
+    }
+
+    pub fn is_open(&self) -> bool {
+        matches!(self.status, RaidStatus::Open | RaidStatus::Monitoring)
+    }
+
+    /// Probability times impact, when both are scored
+    pub fn risk_score(&self) -> Option<i16> {
+        Some(self.probability? * self.impact?)
+    }
+
+    /// Open item whose review date has passed and has not been reminded about yet
+    pub fn needs_review_reminder(&self, today: NaiveDate) -> bool {
+        self.is_open()
+            && self
+                .review_date
+                .is_some_and(|date| date < today && self.reminded_for != Some(date))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::test_dates::date;
+
+    #[test]
+    fn test_risk_score() {
+        let mut item = RaidItem::new(
+            Uuid::new_v4(),
+            RaidType::Risk,
+            "Vendor delay".into(),
+            None,
+            None,
+            Uuid::new_v4(),
+        );
+        assert_eq!(item.risk_score(), None);
+
+        item.probability = Some(4);
+        item.impact = Some(3);
+        assert_eq!(item.risk_score(), Some(12));
+    }
+
+    #[test]
+    fn test_needs_review_reminder_once_per_review_date() {
+        let mut item = RaidItem::new(
+            Uuid::new_v4(),
+            RaidType::Issue,
+            "Build server down".into(),
+            None,
+            None,
+            Uuid::new_v4(),
+        );
+        assert!(!item.needs_review_reminder(date(10)));
+
+        item.review_date = Some(date(10));
+        assert!(!item.needs_review_reminder(date(10)));
+        assert!(item.needs_review_reminder(date(11)));
+
+        item.reminded_for = Some(date(10));
+        assert!(!item.needs_review_reminder(date(11)));
+
+        item.review_date = Some(date(12));
+        assert!(item.needs_review_reminder(date(13)));
+
+        item.status = RaidStatus::Resolved;
+        assert!(!item.needs_review_reminder(date(13)));
+    }
+}
@@ -0,0 +1,112 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Planned share of a user's time on a project over a date range, made
+/// before any tasks exist. Either `percent` or `hours_per_week` is set.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResourceAllocation {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub project_id: Uuid,
+    pub start_date: NaiveDate,
+    /// Last allocated day, inclusive
+    pub end_date: NaiveDate,
+    /// Share of the user's capacity, 1 to 100
+    pub percent: Option<f32>,
+    pub hours_per_week: Option<f32>,
+    pub note: Option<String>,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    // Joined fields (populated from queries)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project_name: Option<String>,
+}
+
+impl ResourceAllocation {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        user_id: Uuid,
+        project_id: Uuid,
+        start_date: NaiveDate,
+        end_date: NaiveDate,
+        percent: Option<f32>,
+        hours_per_week: Option<f32>,
+        note: Option<String>,
+        created_by: Uuid,
+    ) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            user_id,
+            project_id,
+            start_date,
+            end_date,
+            percent,
+            hours_per_week,
+            note,
+            created_by: Some(created_by),
+            created_at: now,
+            updated_at: now,
+            user_name: None,
+            project_name: None,
+        }
+    }
+
+    pub fn covers(&self, date: NaiveDate) -> bool {
+        date >= self.start_date && date <= self.end_date
+    }
+
+    pub fn overlaps(&self, start: NaiveDate, end: NaiveDate) -> bool {
+        self.start_date <= end && self.end_date >= start
+    }
+
+    /// Hours planned on a day the user has `day_hours` of capacity, out of
+    /// `weekly_hours` over a full week. Fixed weekly hours follow the shape
+    /// of the user's week, so days off get none.
+    pub fn hours_on(&self, date: NaiveDate, day_hours: f32, weekly_hours: f32) -> f32 {
+        if !self.covers(date) {
+            return 0.0;
+        }
+        match (self.percent, self.hours_per_week) {
+            (Some(percent), _) => day_hours * percent / 100.0,
+            (None, Some(hours)) if weekly_hours > 0.0 => hours * day_hours / weekly_hours,
+            (None, Some(hours)) => hours / 7.0,
+            (None, None) => 0.0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::test_dates::date;
+
+    #[test]
+    fn test_hours_on_by_percent_or_weekly_hours() {
+        let half = ResourceAllocation::new(
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            date(4),
+            date(30),
+            Some(50.0),
+            None,
+            None,
+            Uuid::new_v4(),
+        );
+        assert_eq!(half.hours_on(date(4), 8.0, 40.0), 4.0);
+        assert_eq!(half.hours_on(date(3), 8.0, 40.0), 0.0);
+
+        let mut fixed = half.clone();
+        fixed.percent = None;
+        fixed.hours_per_week = Some(10.0);
+        assert_eq!(fixed.hours_on(date(4), 8.0, 40.0), 2.0);
+        // A holiday or weekend has no capacity and gets no allocated hours
+        assert_eq!(fixed.hours_on(date(9), 0.0, 40.0), 0.0);
+        assert!(fixed.overlaps(date(30), date(30)));
+        assert!(!fixed.overlaps(date(2), date(3)));
+    }
+}
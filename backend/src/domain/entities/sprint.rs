@@ -0,0 +1,113 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use uuid::Uuid;
+
+use crate::domain::value_objects::SprintState;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Sprint {
+    pub id: Uuid,
+    pub project_id: Uuid,
+    pub name: String,
+    pub goal: Option<String>,
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    pub state: SprintState,
+    /// Scope at the moment the sprint was started
+    pub committed_hours: Option<f32>,
+    pub committed_tasks: Option<i32>,
+    /// Work finished by the time the sprint was closed
+    pub completed_hours: Option<f32>,
+    pub completed_tasks: Option<i32>,
+    /// Burndown frozen at close, before unfinished tasks were rolled over
+    #[serde(skip_serializing)]
+    pub burndown: Option<JsonValue>,
+    pub started_at: Option<DateTime<Utc>>,
+    pub closed_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Sprint {
+    pub fn new(
+        project_id: Uuid,
+        name: String,
+        goal: Option<String>,
+        start_date: NaiveDate,
+        end_date: NaiveDate,
+    ) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            project_id,
+            name,
+            goal,
+            start_date,
+            end_date,
+            state: SprintState::Planned,
+            committed_hours: None,
+            committed_tasks: None,
+            completed_hours: None,
+            completed_tasks: None,
+            burndown: None,
+            started_at: None,
+            closed_at: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    pub fn is_planned(&self) -> bool {
+        self.state == SprintState::Planned
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.state == SprintState::Active
+    }
+
+    pub fn is_closed(&self) -> bool {
+        self.state == SprintState::Closed
+    }
+
+    pub fn start(&mut self, committed_hours: f32, committed_tasks: i32) {
+        self.state = SprintState::Active;
+        self.committed_hours = Some(committed_hours);
+        self.committed_tasks = Some(committed_tasks);
+        self.started_at = Some(Utc::now());
+        self.updated_at = Utc::now();
+    }
+
+    pub fn close(&mut self, completed_hours: f32, completed_tasks: i32, burndown: JsonValue) {
+        self.state = SprintState::Closed;
+        self.completed_hours = Some(completed_hours);
+        self.completed_tasks = Some(completed_tasks);
+        self.burndown = Some(burndown);
+        self.closed_at = Some(Utc::now());
+        self.updated_at = Utc::now();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::test_dates::date;
+
+    #[test]
+    fn test_sprint_lifecycle() {
+        let start = date(4);
+        let end = date(15);
+        let mut sprint = Sprint::new(Uuid::new_v4(), "Sprint 1".into(), None, start, end);
+        assert!(sprint.is_planned());
+
+        sprint.start(40.0, 5);
+        assert!(sprint.is_active());
+        assert_eq!(sprint.committed_tasks, Some(5));
+        assert!(sprint.started_at.is_some());
+
+        sprint.close(32.0, 4, JsonValue::Array(vec![]));
+        assert!(sprint.is_closed());
+        assert_eq!(sprint.completed_hours, Some(32.0));
+        assert!(sprint.burndown.is_some());
+    }
+}
@@ -0,0 +1,89 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::value_objects::RaidStatus;
+
+/// A saved project status report. Reports are never modified after creation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatusReport {
+    pub id: Uuid,
+    pub project_id: Uuid,
+    pub period_start: NaiveDate,
+    pub period_end: NaiveDate,
+    /// Free-text highlights written by the project manager
+    pub highlights: Option<String>,
+    pub content: StatusReportContent,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl StatusReport {
+    pub fn new(
+        project_id: Uuid,
+        period_start: NaiveDate,
+        period_end: NaiveDate,
+        highlights: Option<String>,
+        content: StatusReportContent,
+        created_by: Uuid,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            project_id,
+            period_start,
+            period_end,
+            highlights,
+            content,
+            created_by: Some(created_by),
+            created_at: Utc::now(),
+        }
+    }
+}
+
+/// Facts gathered for a report period
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct StatusReportContent {
+    pub project_name: String,
+    pub tasks_completed: Vec<ReportTask>,
+    pub tasks_started: Vec<ReportTask>,
+    pub newly_blocked: Vec<ReportTask>,
+    pub upcoming_milestones: Vec<ReportMilestone>,
+    pub hours_logged: f32,
+    pub budget: Option<ReportBudget>,
+    pub top_risks: Vec<ReportRisk>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReportTask {
+    pub id: Uuid,
+    pub title: String,
+    pub assignee_id: Option<Uuid>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReportMilestone {
+    pub id: Uuid,
+    pub name: String,
+    pub due_date: Option<DateTime<Utc>>,
+    /// Due before the end of the report period and not completed
+    pub overdue: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReportBudget {
+    pub budget: Decimal,
+    pub spent: Decimal,
+    pub remaining: Decimal,
+    pub percent_used: Option<Decimal>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReportRisk {
+    pub id: Uuid,
+    pub title: String,
+    pub status: RaidStatus,
+    pub probability: Option<i16>,
+    pub impact: Option<i16>,
+    pub score: Option<i16>,
+}
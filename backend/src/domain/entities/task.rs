@@ -2,6 +2,7 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use super::FieldChange;
 use crate::domain::value_objects::{Priority, TaskStatus};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -9,6 +10,7 @@ pub struct Task {
     pub id: Uuid,
     pub project_id: Uuid,
     pub milestone_id: Option<Uuid>,
+    pub sprint_id: Option<Uuid>,
     pub title: String,
     pub description: Option<String>,
     pub status: TaskStatus,
@@ -16,9 +18,15 @@ pub struct Task {
     pub assignee_id: Option<Uuid>,
     pub due_date: Option<DateTime<Utc>>,
     pub estimated_hours: Option<f32>,
+    /// Sum of the task's time logs; kept in sync by the time log repository
     pub actual_hours: Option<f32>,
+    /// Overrides the project's billable default when set
+    pub billable: Option<bool>,
+    pub completed_at: Option<DateTime<Utc>>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+    pub deleted_by: Option<Uuid>,
 }
 
 impl Task {
@@ -36,6 +44,7 @@ impl Task {
             id: Uuid::new_v4(),
             project_id,
             milestone_id: None,
+            sprint_id: None,
             title,
             description,
             status: TaskStatus::Todo,
@@ -44,8 +53,12 @@ impl Task {
             due_date,
             estimated_hours,
             actual_hours: None,
+            billable: None,
+            completed_at: None,
             created_at: now,
             updated_at: now,
+            deleted_at: None,
+            deleted_by: None,
         }
     }
 
@@ -57,6 +70,13 @@ impl Task {
         matches!(self.status, TaskStatus::Blocked)
     }
 
+    pub fn is_overdue(&self) -> bool {
+        match self.due_date {
+            Some(due_date) => !self.is_done() && Utc::now() > due_date,
+            None => false,
+        }
+    }
+
     pub fn can_transition_to(&self, new_status: &TaskStatus) -> bool {
         use TaskStatus::*;
         match (&self.status, new_status) {
@@ -69,7 +89,13 @@ impl Task {
         }
     }
 
+    /// Change status, stamping when the task was completed
     pub fn update_status(&mut self, status: TaskStatus) {
+        if status == TaskStatus::Done {
+            self.completed_at = self.completed_at.or(Some(Utc::now()));
+        } else {
+            self.completed_at = None;
+        }
         self.status = status;
         self.updated_at = Utc::now();
     }
@@ -79,12 +105,87 @@ impl Task {
         self.updated_at = Utc::now();
     }
 
-    pub fn log_hours(&mut self, hours: f32) {
-        self.actual_hours = Some(self.actual_hours.unwrap_or(0.0) + hours);
+    /// Field-level differences between this task and a newer version of it
+    pub fn diff(&self, other: &Task) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        push_change(&mut changes, "title", &self.title, &other.title);
+        push_change(
+            &mut changes,
+            "description",
+            &self.description,
+            &other.description,
+        );
+        push_change(&mut changes, "status", &self.status, &other.status);
+        push_change(&mut changes, "priority", &self.priority, &other.priority);
+        push_change(
+            &mut changes,
+            "assignee",
+            &self.assignee_id,
+            &other.assignee_id,
+        );
+        push_change(
+            &mut changes,
+            "milestone",
+            &self.milestone_id,
+            &other.milestone_id,
+        );
+        push_change(&mut changes, "due_date", &self.due_date, &other.due_date);
+        push_change(
+            &mut changes,
+            "estimated_hours",
+            &self.estimated_hours,
+            &other.estimated_hours,
+        );
+        push_change(
+            &mut changes,
+            "actual_hours",
+            &self.actual_hours,
+            &other.actual_hours,
+        );
+        push_change(&mut changes, "billable", &self.billable, &other.billable);
+        changes
+    }
+
+    /// Copy the editable fields of a previous version onto this task.
+    /// Actual hours are left alone since they follow the time logs.
+    pub fn restore_from(&mut self, version: &Task) {
+        self.title = version.title.clone();
+        self.description = version.description.clone();
+        self.update_status(version.status.clone());
+        self.priority = version.priority.clone();
+        self.assignee_id = version.assignee_id;
+        self.milestone_id = version.milestone_id;
+        self.due_date = version.due_date;
+        self.estimated_hours = version.estimated_hours;
+        self.billable = version.billable;
         self.updated_at = Utc::now();
     }
 }
 
+fn push_change<T: Serialize + PartialEq>(
+    changes: &mut Vec<FieldChange>,
+    field: &str,
+    from: &T,
+    to: &T,
+) {
+    if from != to {
+        changes.push(FieldChange::new(
+            field,
+            serde_json::to_value(from).unwrap_or_default(),
+            serde_json::to_value(to).unwrap_or_default(),
+        ));
+    }
+}
+
+/// A task whose stored actual hours differ from the sum of its time logs
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ActualHoursDrift {
+    pub task_id: Uuid,
+    pub title: String,
+    pub recorded: Option<f32>,
+    pub logged: Option<f32>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TaskComment {
     pub id: Uuid,
@@ -108,3 +209,68 @@ impl TaskComment {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_task() -> Task {
+        Task::new(
+            Uuid::new_v4(),
+            "Write docs".into(),
+            None,
+            None,
+            None,
+            None,
+            Some(4.0),
+        )
+    }
+
+    #[test]
+    fn test_diff_identical_tasks_is_empty() {
+        let task = sample_task();
+        assert!(task.diff(&task.clone()).is_empty());
+    }
+
+    #[test]
+    fn test_diff_reports_from_and_to() {
+        let before = sample_task();
+        let mut after = before.clone();
+        after.update_status(TaskStatus::InProgress);
+        after.estimated_hours = Some(6.0);
+
+        let changes = before.diff(&after);
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[0].field, "status");
+        assert_eq!(changes[0].from, serde_json::json!("Todo"));
+        assert_eq!(changes[0].to, serde_json::json!("inprogress"));
+        assert_eq!(changes[1].field, "estimated_hours");
+        assert_eq!(changes[1].to, serde_json::json!(6.0));
+    }
+
+    #[test]
+    fn test_update_status_tracks_completion() {
+        let mut task = sample_task();
+        task.update_status(TaskStatus::Done);
+        let completed_at = task.completed_at;
+        assert!(completed_at.is_some());
+
+        // Saving Done again keeps the original completion time
+        task.update_status(TaskStatus::Done);
+        assert_eq!(task.completed_at, completed_at);
+
+        task.update_status(TaskStatus::InProgress);
+        assert!(task.completed_at.is_none());
+    }
+
+    #[test]
+    fn test_restore_from_reverts_fields() {
+        let original = sample_task();
+        let mut task = original.clone();
+        task.title = "Renamed".into();
+        task.assign_to(Some(Uuid::new_v4()));
+
+        task.restore_from(&original);
+        assert!(original.diff(&task).is_empty());
+    }
+}
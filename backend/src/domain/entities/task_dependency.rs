@@ -0,0 +1,23 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// `task_id` cannot start before `depends_on_id` is finished
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskDependency {
+    pub id: Uuid,
+    pub task_id: Uuid,
+    pub depends_on_id: Uuid,
+    pub created_at: DateTime<Utc>,
+}
+
+impl TaskDependency {
+    pub fn new(task_id: Uuid, depends_on_id: Uuid) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            task_id,
+            depends_on_id,
+            created_at: Utc::now(),
+        }
+    }
+}
@@ -31,6 +31,21 @@ impl Team {
         self.lead_id = lead_id;
         self.updated_at = Utc::now();
     }
+
+    /// Whether `user_id` is the team's lead or holds the lead role among `members`
+    pub fn is_led_by(&self, members: &[TeamMember], user_id: Uuid) -> bool {
+        self.lead_id == Some(user_id) || members.iter().any(|m| m.user_id == user_id && m.is_lead())
+    }
+
+    /// Another lead to keep the team going once `user_id` stops leading it
+    pub fn successor(&self, members: &[TeamMember], user_id: Uuid) -> Option<Uuid> {
+        self.lead_id.filter(|id| *id != user_id).or_else(|| {
+            members
+                .iter()
+                .find(|m| m.is_lead() && m.user_id != user_id)
+                .map(|m| m.user_id)
+        })
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -60,4 +75,37 @@ impl TeamMember {
     pub fn promote_to_lead(&mut self) {
         self.role = TeamMemberRole::Lead;
     }
+
+    pub fn demote_to_member(&mut self) {
+        self.role = TeamMemberRole::Member;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_successor_prefers_team_lead_then_other_leads() {
+        let (lead, co_lead, member) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let team = Team::new("Core".into(), None, Some(lead));
+        let mut members = vec![
+            TeamMember::new(team.id, lead, Some(TeamMemberRole::Lead)),
+            TeamMember::new(team.id, member, None),
+        ];
+
+        assert!(team.is_led_by(&members, lead));
+        assert!(!team.is_led_by(&members, member));
+        assert_eq!(team.successor(&members, member), Some(lead));
+        assert_eq!(team.successor(&members, lead), None);
+
+        members[1].promote_to_lead();
+        members.push(TeamMember::new(
+            team.id,
+            co_lead,
+            Some(TeamMemberRole::Lead),
+        ));
+        assert_eq!(team.successor(&members, lead), Some(member));
+        assert_eq!(team.successor(&members, co_lead), Some(lead));
+    }
 }
@@ -10,6 +10,9 @@ pub struct TimeLog {
     pub hours: f32,
     pub date: NaiveDate,
     pub description: Option<String>,
+    pub billable: bool,
+    /// Invoice the log was billed on; invoiced logs are read-only
+    pub invoice_id: Option<Uuid>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
     // Joined fields (populated from queries)
@@ -21,6 +24,14 @@ pub struct TimeLog {
     pub user_name: Option<String>,
 }
 
+/// Hours one user logged on one day
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DailyHours {
+    pub user_id: Uuid,
+    pub date: NaiveDate,
+    pub hours: f32,
+}
+
 impl TimeLog {
     pub fn new(
         task_id: Uuid,
@@ -28,6 +39,7 @@ impl TimeLog {
         hours: f32,
         date: NaiveDate,
         description: Option<String>,
+        billable: bool,
     ) -> Self {
         let now = Utc::now();
         Self {
@@ -37,6 +49,8 @@ impl TimeLog {
             hours,
             date,
             description,
+            billable,
+            invoice_id: None,
             created_at: now,
             updated_at: now,
             task_name: None,
@@ -0,0 +1,133 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::value_objects::TimeOffStatus;
+
+/// Whole days of leave a user asks their lead or a manager to approve
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimeOffRequest {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub start_date: NaiveDate,
+    /// Last day off, inclusive
+    pub end_date: NaiveDate,
+    pub reason: Option<String>,
+    pub status: TimeOffStatus,
+    pub decided_by: Option<Uuid>,
+    pub decided_at: Option<DateTime<Utc>>,
+    pub comment: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl TimeOffRequest {
+    pub fn new(
+        user_id: Uuid,
+        start_date: NaiveDate,
+        end_date: NaiveDate,
+        reason: Option<String>,
+    ) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            user_id,
+            start_date,
+            end_date,
+            reason,
+            status: TimeOffStatus::Pending,
+            decided_by: None,
+            decided_at: None,
+            comment: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    pub fn is_pending(&self) -> bool {
+        self.status == TimeOffStatus::Pending
+    }
+
+    /// Pending and approved requests hold their days
+    pub fn is_active(&self) -> bool {
+        matches!(
+            self.status,
+            TimeOffStatus::Pending | TimeOffStatus::Approved
+        )
+    }
+
+    pub fn overlaps(&self, start: NaiveDate, end: NaiveDate) -> bool {
+        self.start_date <= end && self.end_date >= start
+    }
+
+    /// Days off, clipped to a range
+    pub fn days_within(
+        &self,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> impl Iterator<Item = NaiveDate> + '_ {
+        let last = self.end_date.min(end);
+        self.start_date
+            .max(start)
+            .iter_days()
+            .take_while(move |d| *d <= last)
+    }
+
+    pub fn approve(&mut self, approver_id: Uuid, comment: Option<String>) {
+        self.decide(TimeOffStatus::Approved, approver_id, comment);
+    }
+
+    pub fn reject(&mut self, approver_id: Uuid, comment: String) {
+        self.decide(TimeOffStatus::Rejected, approver_id, Some(comment));
+    }
+
+    /// Withdrawn by the user, before or after approval
+    pub fn cancel(&mut self) {
+        self.status = TimeOffStatus::Cancelled;
+        self.updated_at = Utc::now();
+    }
+
+    fn decide(&mut self, status: TimeOffStatus, approver_id: Uuid, comment: Option<String>) {
+        let now = Utc::now();
+        self.status = status;
+        self.decided_by = Some(approver_id);
+        self.decided_at = Some(now);
+        self.comment = comment;
+        self.updated_at = now;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::test_dates::date;
+
+    #[test]
+    fn test_days_within_clips_to_range() {
+        let request = TimeOffRequest::new(Uuid::new_v4(), date(10), date(14), None);
+        assert!(request.overlaps(date(14), date(20)));
+        assert!(!request.overlaps(date(15), date(20)));
+
+        let days: Vec<_> = request.days_within(date(12), date(31)).collect();
+        assert_eq!(days, vec![date(12), date(13), date(14)]);
+    }
+
+    #[test]
+    fn test_decisions() {
+        let mut request = TimeOffRequest::new(Uuid::new_v4(), date(1), date(2), None);
+        assert!(request.is_pending());
+        assert!(request.is_active());
+
+        request.approve(Uuid::new_v4(), None);
+        assert_eq!(request.status, TimeOffStatus::Approved);
+        assert!(request.is_active());
+
+        request.cancel();
+        assert!(!request.is_active());
+
+        let mut other = TimeOffRequest::new(Uuid::new_v4(), date(1), date(2), None);
+        other.reject(Uuid::new_v4(), "Release week".into());
+        assert!(!other.is_active());
+        assert_eq!(other.comment.as_deref(), Some("Release week"));
+    }
+}
@@ -0,0 +1,97 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// A user's running or paused stopwatch on a task. Each user has at most one;
+/// stopping it turns the tracked time into a time log.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Timer {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub task_id: Uuid,
+    pub description: Option<String>,
+    pub started_at: DateTime<Utc>,
+    /// Start of the current running stretch; `None` while paused
+    pub running_since: Option<DateTime<Utc>>,
+    /// Seconds tracked in stretches before the current one
+    pub accumulated_seconds: i64,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Timer {
+    pub fn start(user_id: Uuid, task_id: Uuid, description: Option<String>) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            user_id,
+            task_id,
+            description,
+            started_at: now,
+            running_since: Some(now),
+            accumulated_seconds: 0,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.running_since.is_some()
+    }
+
+    /// Total tracked seconds up to `now`
+    pub fn elapsed_seconds(&self, now: DateTime<Utc>) -> i64 {
+        let running = self
+            .running_since
+            .map(|since| (now - since).num_seconds().max(0))
+            .unwrap_or(0);
+        self.accumulated_seconds + running
+    }
+
+    pub fn pause(&mut self, now: DateTime<Utc>) {
+        self.accumulated_seconds = self.elapsed_seconds(now);
+        self.running_since = None;
+        self.updated_at = now;
+    }
+
+    pub fn resume(&mut self, now: DateTime<Utc>) {
+        if self.running_since.is_none() {
+            self.running_since = Some(now);
+            self.updated_at = now;
+        }
+    }
+
+    /// Moment the current stretch exceeds `max_hours`, if it is running
+    pub fn stale_at(&self, max_hours: i64) -> Option<DateTime<Utc>> {
+        self.running_since
+            .map(|since| since + Duration::hours(max_hours))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pause_and_resume_accumulate() {
+        let mut timer = Timer::start(Uuid::new_v4(), Uuid::new_v4(), None);
+        let t0 = timer.started_at;
+
+        timer.pause(t0 + Duration::minutes(30));
+        assert!(!timer.is_running());
+        assert_eq!(timer.elapsed_seconds(t0 + Duration::hours(5)), 1800);
+
+        timer.resume(t0 + Duration::hours(1));
+        assert_eq!(timer.elapsed_seconds(t0 + Duration::minutes(90)), 3600);
+    }
+
+    #[test]
+    fn test_stale_at_only_while_running() {
+        let mut timer = Timer::start(Uuid::new_v4(), Uuid::new_v4(), None);
+        let since = timer.running_since.unwrap();
+        assert_eq!(timer.stale_at(8), Some(since + Duration::hours(8)));
+
+        timer.pause(since + Duration::minutes(1));
+        assert_eq!(timer.stale_at(8), None);
+    }
+}
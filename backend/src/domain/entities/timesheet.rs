@@ -0,0 +1,128 @@
+use chrono::{DateTime, Datelike, Duration, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::value_objects::TimesheetStatus;
+
+/// A user's time logs for one Monday-to-Sunday week, submitted for approval
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Timesheet {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub week_start: NaiveDate,
+    pub status: TimesheetStatus,
+    pub submitted_at: Option<DateTime<Utc>>,
+    pub decided_by: Option<Uuid>,
+    pub decided_at: Option<DateTime<Utc>>,
+    pub comment: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Timesheet {
+    pub fn new(user_id: Uuid, date: NaiveDate) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            user_id,
+            week_start: Self::week_of(date),
+            status: TimesheetStatus::Draft,
+            submitted_at: None,
+            decided_by: None,
+            decided_at: None,
+            comment: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// Monday of the week containing `date`
+    pub fn week_of(date: NaiveDate) -> NaiveDate {
+        date - Duration::days(date.weekday().num_days_from_monday() as i64)
+    }
+
+    pub fn week_end(&self) -> NaiveDate {
+        self.week_start + Duration::days(6)
+    }
+
+    /// Logs of a submitted or approved week can't be changed
+    pub fn is_locked(&self) -> bool {
+        matches!(
+            self.status,
+            TimesheetStatus::Submitted | TimesheetStatus::Approved
+        )
+    }
+
+    pub fn can_submit(&self) -> bool {
+        matches!(
+            self.status,
+            TimesheetStatus::Draft | TimesheetStatus::Rejected
+        )
+    }
+
+    pub fn submit(&mut self) {
+        let now = Utc::now();
+        self.status = TimesheetStatus::Submitted;
+        self.submitted_at = Some(now);
+        self.decided_by = None;
+        self.decided_at = None;
+        self.comment = None;
+        self.updated_at = now;
+    }
+
+    pub fn approve(&mut self, approver_id: Uuid, comment: Option<String>) {
+        self.decide(TimesheetStatus::Approved, approver_id, comment);
+    }
+
+    /// Send the week back to the user, who can fix it and submit again
+    pub fn reject(&mut self, approver_id: Uuid, comment: String) {
+        self.decide(TimesheetStatus::Rejected, approver_id, Some(comment));
+    }
+
+    fn decide(&mut self, status: TimesheetStatus, approver_id: Uuid, comment: Option<String>) {
+        let now = Utc::now();
+        self.status = status;
+        self.decided_by = Some(approver_id);
+        self.decided_at = Some(now);
+        self.comment = comment;
+        self.updated_at = now;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::test_dates::date;
+
+    #[test]
+    fn test_week_of_is_monday() {
+        // 2024-03-06 is a Wednesday
+        assert_eq!(Timesheet::week_of(date(6)), date(4));
+        assert_eq!(Timesheet::week_of(date(4)), date(4));
+        assert_eq!(Timesheet::week_of(date(10)), date(4));
+
+        let sheet = Timesheet::new(Uuid::new_v4(), date(10));
+        assert_eq!(sheet.week_end(), date(10));
+    }
+
+    #[test]
+    fn test_lifecycle_locks_until_rejected() {
+        let mut sheet = Timesheet::new(Uuid::new_v4(), date(6));
+        assert!(sheet.can_submit());
+        assert!(!sheet.is_locked());
+
+        sheet.submit();
+        assert!(sheet.is_locked());
+        assert!(!sheet.can_submit());
+
+        sheet.reject(Uuid::new_v4(), "Missing Friday".into());
+        assert!(!sheet.is_locked());
+        assert!(sheet.can_submit());
+
+        sheet.submit();
+        assert!(sheet.comment.is_none());
+        sheet.approve(Uuid::new_v4(), None);
+        assert!(sheet.is_locked());
+        assert!(!sheet.can_submit());
+    }
+}
@@ -0,0 +1,72 @@
+use chrono::{DateTime, Datelike, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Working hours a user is available for
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserCapacity {
+    pub user_id: Uuid,
+    pub weekly_hours: f32,
+    /// Hours per weekday, Monday first; when unset the weekly hours are
+    /// spread evenly over Monday to Friday
+    pub day_hours: Option<Vec<f32>>,
+    /// Public holidays followed; the default calendar when unset
+    pub holiday_calendar_id: Option<Uuid>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl UserCapacity {
+    /// A schedule's days define the weekly total
+    pub fn new(user_id: Uuid, weekly_hours: f32, day_hours: Option<Vec<f32>>) -> Self {
+        let weekly_hours = day_hours
+            .as_ref()
+            .map(|days| days.iter().sum())
+            .unwrap_or(weekly_hours);
+        Self {
+            user_id,
+            weekly_hours,
+            day_hours,
+            holiday_calendar_id: None,
+            updated_at: Utc::now(),
+        }
+    }
+
+    pub fn hours_on(&self, date: NaiveDate) -> f32 {
+        self.weekday_hours(date.weekday().num_days_from_monday() as usize)
+    }
+
+    /// Hours on a weekday, counted from Monday as 0
+    pub fn weekday_hours(&self, weekday: usize) -> f32 {
+        match &self.day_hours {
+            Some(days) => days.get(weekday).copied().unwrap_or(0.0),
+            None if weekday < 5 => self.weekly_hours / 5.0,
+            None => 0.0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::test_dates::date;
+
+    #[test]
+    fn test_hours_on_uses_schedule_or_weekdays() {
+        // 2024-01-05 is a Friday
+        let friday = date(1);
+        let saturday = friday.succ_opt().unwrap();
+
+        let default = UserCapacity::new(Uuid::new_v4(), 40.0, None);
+        assert_eq!(default.hours_on(friday), 8.0);
+        assert_eq!(default.hours_on(saturday), 0.0);
+
+        let part_time = UserCapacity::new(
+            Uuid::new_v4(),
+            40.0,
+            Some(vec![8.0, 8.0, 4.0, 0.0, 0.0, 2.0, 0.0]),
+        );
+        assert_eq!(part_time.weekly_hours, 22.0);
+        assert_eq!(part_time.hours_on(friday), 0.0);
+        assert_eq!(part_time.hours_on(saturday), 2.0);
+    }
+}
@@ -0,0 +1,26 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::value_objects::WatchEntityType;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Watcher {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub entity_type: WatchEntityType,
+    pub entity_id: Uuid,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Watcher {
+    pub fn new(user_id: Uuid, entity_type: WatchEntityType, entity_id: Uuid) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            user_id,
+            entity_type,
+            entity_id,
+            created_at: Utc::now(),
+        }
+    }
+}
@@ -1,4 +1,5 @@
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
 use crate::domain::entities::{ActivityLog, ActivityLogWithDetails};
@@ -21,6 +22,21 @@ pub trait ActivityLogRepository: Send + Sync {
         user_id: Uuid,
         limit: i64,
     ) -> Result<Vec<ActivityLogWithDetails>, DomainError>;
+    /// Find the history of a single entity, oldest first
+    async fn find_by_entity(
+        &self,
+        entity_type: &str,
+        entity_id: Uuid,
+    ) -> Result<Vec<ActivityLogWithDetails>, DomainError>;
+    /// Find a project's entries for one entity type within `[from, to)`, oldest first
+    async fn find_by_project_between(
+        &self,
+        project_id: Uuid,
+        entity_type: &str,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<ActivityLog>, DomainError>;
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<ActivityLog>, DomainError>;
     async fn create(&self, log: &ActivityLog) -> Result<ActivityLog, DomainError>;
     async fn count(&self) -> Result<i64, DomainError>;
 }
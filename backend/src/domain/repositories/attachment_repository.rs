@@ -13,6 +13,18 @@ use crate::shared::DomainError;
 pub trait AttachmentRepository: Send + Sync {
     async fn find_by_id(&self, id: Uuid) -> Result<Option<Attachment>, DomainError>;
     async fn find_by_task(&self, task_id: Uuid) -> Result<Vec<Attachment>, DomainError>;
+    /// Find attachments of every task in a project
+    async fn find_by_project(&self, project_id: Uuid) -> Result<Vec<Attachment>, DomainError>;
+    /// Like `find_by_task`, but also for a trashed task or project
+    async fn find_by_task_including_deleted(
+        &self,
+        task_id: Uuid,
+    ) -> Result<Vec<Attachment>, DomainError>;
+    /// Like `find_by_project`, but also for trashed tasks or a trashed project
+    async fn find_by_project_including_deleted(
+        &self,
+        project_id: Uuid,
+    ) -> Result<Vec<Attachment>, DomainError>;
     async fn create(&self, attachment: &Attachment) -> Result<Attachment, DomainError>;
     async fn delete(&self, id: Uuid) -> Result<(), DomainError>;
     async fn delete_by_task(&self, task_id: Uuid) -> Result<(), DomainError>;
@@ -0,0 +1,14 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::entities::ProjectBaseline;
+use crate::shared::DomainError;
+
+#[async_trait]
+pub trait BaselineRepository: Send + Sync {
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<ProjectBaseline>, DomainError>;
+    /// Find baselines of a project, newest first
+    async fn find_by_project(&self, project_id: Uuid) -> Result<Vec<ProjectBaseline>, DomainError>;
+    async fn create(&self, baseline: &ProjectBaseline) -> Result<ProjectBaseline, DomainError>;
+    async fn delete(&self, id: Uuid) -> Result<(), DomainError>;
+}
@@ -0,0 +1,12 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::entities::BillingRate;
+use crate::shared::DomainError;
+
+#[async_trait]
+pub trait BillingRateRepository: Send + Sync {
+    async fn find_all(&self) -> Result<Vec<BillingRate>, DomainError>;
+    async fn create(&self, rate: &BillingRate) -> Result<BillingRate, DomainError>;
+    async fn delete(&self, id: Uuid) -> Result<(), DomainError>;
+}
@@ -0,0 +1,13 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::shared::DomainError;
+
+/// Remembers which budget thresholds a project owner was already alerted about
+#[async_trait]
+pub trait BudgetAlertRepository: Send + Sync {
+    /// Record a threshold as notified. Returns false if it already was.
+    async fn record(&self, project_id: Uuid, threshold: i32) -> Result<bool, DomainError>;
+    /// Forget every threshold not in `thresholds`, so it alerts again once re-crossed
+    async fn retain(&self, project_id: Uuid, thresholds: &[i32]) -> Result<(), DomainError>;
+}
@@ -0,0 +1,29 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::entities::ChangeRequest;
+use crate::domain::value_objects::ChangeRequestStatus;
+use crate::shared::DomainError;
+
+#[async_trait]
+pub trait ChangeRequestRepository: Send + Sync {
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<ChangeRequest>, DomainError>;
+    /// Requests of a project, newest first, optionally narrowed to one status
+    async fn find_by_project(
+        &self,
+        project_id: Uuid,
+        status: Option<ChangeRequestStatus>,
+    ) -> Result<Vec<ChangeRequest>, DomainError>;
+    async fn create(&self, request: &ChangeRequest) -> Result<ChangeRequest, DomainError>;
+    /// Store a rejection or withdrawal; fails with `Conflict` if the request
+    /// was already decided
+    async fn save_decision(&self, request: &ChangeRequest) -> Result<ChangeRequest, DomainError>;
+    /// Store an approval and apply the proposed values to the project and its
+    /// milestones in the same transaction
+    async fn approve_and_apply(
+        &self,
+        request: &ChangeRequest,
+    ) -> Result<ChangeRequest, DomainError>;
+    async fn find_approvers(&self, project_id: Uuid) -> Result<Vec<Uuid>, DomainError>;
+    async fn set_approvers(&self, project_id: Uuid, user_ids: &[Uuid]) -> Result<(), DomainError>;
+}
@@ -0,0 +1,13 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::entities::CostRate;
+use crate::shared::DomainError;
+
+#[async_trait]
+pub trait CostRateRepository: Send + Sync {
+    async fn find_all(&self) -> Result<Vec<CostRate>, DomainError>;
+    /// Insert the rate, replacing any existing rate for the same user or role
+    async fn upsert(&self, rate: &CostRate) -> Result<CostRate, DomainError>;
+    async fn delete(&self, id: Uuid) -> Result<(), DomainError>;
+}
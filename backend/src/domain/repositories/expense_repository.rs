@@ -0,0 +1,14 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::entities::Expense;
+use crate::shared::DomainError;
+
+#[async_trait]
+pub trait ExpenseRepository: Send + Sync {
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Expense>, DomainError>;
+    async fn find_by_project(&self, project_id: Uuid) -> Result<Vec<Expense>, DomainError>;
+    async fn create(&self, expense: &Expense) -> Result<Expense, DomainError>;
+    async fn update(&self, expense: &Expense) -> Result<Expense, DomainError>;
+    async fn delete(&self, id: Uuid) -> Result<(), DomainError>;
+}
@@ -0,0 +1,34 @@
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use uuid::Uuid;
+
+use crate::domain::entities::{Holiday, HolidayCalendar};
+use crate::shared::DomainError;
+
+#[async_trait]
+pub trait HolidayRepository: Send + Sync {
+    async fn find_calendars(&self) -> Result<Vec<HolidayCalendar>, DomainError>;
+    async fn find_calendar_by_id(&self, id: Uuid) -> Result<Option<HolidayCalendar>, DomainError>;
+    async fn find_default_calendar(&self) -> Result<Option<HolidayCalendar>, DomainError>;
+    /// Saving a default calendar clears the flag on every other calendar
+    async fn create_calendar(
+        &self,
+        calendar: &HolidayCalendar,
+    ) -> Result<HolidayCalendar, DomainError>;
+    async fn update_calendar(
+        &self,
+        calendar: &HolidayCalendar,
+    ) -> Result<HolidayCalendar, DomainError>;
+    async fn delete_calendar(&self, id: Uuid) -> Result<(), DomainError>;
+    async fn find_holidays(&self, calendar_id: Uuid) -> Result<Vec<Holiday>, DomainError>;
+    /// Holidays of the given calendars between two dates, inclusive
+    async fn find_holidays_in_range(
+        &self,
+        calendar_ids: &[Uuid],
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<Vec<Holiday>, DomainError>;
+    /// Insert holidays, renaming any the calendar already has on the same date
+    async fn upsert_holidays(&self, holidays: &[Holiday]) -> Result<Vec<Holiday>, DomainError>;
+    async fn delete_holiday(&self, id: Uuid) -> Result<(), DomainError>;
+}
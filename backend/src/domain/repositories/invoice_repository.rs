@@ -0,0 +1,28 @@
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use uuid::Uuid;
+
+use crate::domain::entities::{BillableEntry, Invoice};
+use crate::shared::DomainError;
+
+#[async_trait]
+pub trait InvoiceRepository: Send + Sync {
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Invoice>, DomainError>;
+    /// Find invoices, newest first, optionally only those of one project
+    async fn find_all(&self, project_id: Option<Uuid>) -> Result<Vec<Invoice>, DomainError>;
+    /// Find billable time logged in the period that isn't invoiced yet
+    async fn find_billable_entries(
+        &self,
+        start_date: NaiveDate,
+        end_date: NaiveDate,
+        project_id: Option<Uuid>,
+    ) -> Result<Vec<BillableEntry>, DomainError>;
+    /// Save the invoice and mark the time logs of its entries as invoiced,
+    /// failing with a conflict if any of them was changed or invoiced since
+    /// the entries were read
+    async fn create(
+        &self,
+        invoice: &Invoice,
+        entries: &[BillableEntry],
+    ) -> Result<Invoice, DomainError>;
+}
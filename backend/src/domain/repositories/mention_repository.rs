@@ -0,0 +1,17 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::entities::Mention;
+use crate::domain::value_objects::MentionSourceType;
+use crate::shared::DomainError;
+
+#[async_trait]
+pub trait MentionRepository: Send + Sync {
+    async fn find_by_source(
+        &self,
+        source_type: MentionSourceType,
+        source_id: Uuid,
+    ) -> Result<Vec<Mention>, DomainError>;
+    /// Store a mention, returning false if the user was already mentioned by the source
+    async fn create(&self, mention: &Mention) -> Result<bool, DomainError>;
+}
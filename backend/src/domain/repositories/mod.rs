@@ -1,19 +1,63 @@
 mod activity_log_repository;
 mod attachment_repository;
+mod baseline_repository;
+mod billing_rate_repository;
+mod budget_alert_repository;
+mod change_request_repository;
+mod cost_rate_repository;
+mod expense_repository;
+mod holiday_repository;
+mod invoice_repository;
+mod mention_repository;
 mod notification_repository;
+mod portfolio_repository;
+mod project_health_repository;
 mod project_repository;
+mod raid_item_repository;
+mod resource_allocation_repository;
+mod sprint_repository;
+mod status_report_repository;
 mod tag_repository;
+mod task_comment_repository;
+mod task_dependency_repository;
 mod task_repository;
 mod team_repository;
 mod time_log_repository;
+mod time_off_repository;
+mod timer_repository;
+mod timesheet_repository;
+mod user_capacity_repository;
 mod user_repository;
+mod watcher_repository;
 
 pub use activity_log_repository::ActivityLogRepository;
 pub use attachment_repository::AttachmentRepository;
+pub use baseline_repository::BaselineRepository;
+pub use billing_rate_repository::BillingRateRepository;
+pub use budget_alert_repository::BudgetAlertRepository;
+pub use change_request_repository::ChangeRequestRepository;
+pub use cost_rate_repository::CostRateRepository;
+pub use expense_repository::ExpenseRepository;
+pub use holiday_repository::HolidayRepository;
+pub use invoice_repository::InvoiceRepository;
+pub use mention_repository::MentionRepository;
 pub use notification_repository::NotificationRepository;
+pub use portfolio_repository::PortfolioRepository;
+pub use project_health_repository::ProjectHealthRepository;
 pub use project_repository::ProjectRepository;
+pub use raid_item_repository::RaidItemRepository;
+pub use resource_allocation_repository::ResourceAllocationRepository;
+pub use sprint_repository::SprintRepository;
+pub use status_report_repository::StatusReportRepository;
 pub use tag_repository::TagRepository;
+pub use task_comment_repository::TaskCommentRepository;
+pub use task_dependency_repository::TaskDependencyRepository;
 pub use task_repository::TaskRepository;
 pub use team_repository::TeamRepository;
-pub use time_log_repository::TimeLogRepository;
+pub use time_log_repository::{TimeLogFilter, TimeLogRepository};
+pub use time_off_repository::TimeOffRepository;
+pub use timer_repository::TimerRepository;
+pub use timesheet_repository::TimesheetRepository;
+pub use user_capacity_repository::UserCapacityRepository;
 pub use user_repository::UserRepository;
+pub use watcher_repository::WatcherRepository;
@@ -0,0 +1,20 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::entities::Portfolio;
+use crate::shared::DomainError;
+
+#[async_trait]
+pub trait PortfolioRepository: Send + Sync {
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Portfolio>, DomainError>;
+    async fn find_all(&self) -> Result<Vec<Portfolio>, DomainError>;
+    /// Find portfolios and programs the user manages, directly or through the parent portfolio
+    async fn find_managed_by(&self, user_id: Uuid) -> Result<Vec<Portfolio>, DomainError>;
+    /// Find programs belonging to a portfolio
+    async fn find_children(&self, id: Uuid) -> Result<Vec<Portfolio>, DomainError>;
+    /// Check if user owns the portfolio or program, or the portfolio above it
+    async fn is_manager(&self, id: Uuid, user_id: Uuid) -> Result<bool, DomainError>;
+    async fn create(&self, portfolio: &Portfolio) -> Result<Portfolio, DomainError>;
+    async fn update(&self, portfolio: &Portfolio) -> Result<Portfolio, DomainError>;
+    async fn delete(&self, id: Uuid) -> Result<(), DomainError>;
+}
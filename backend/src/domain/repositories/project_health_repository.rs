@@ -0,0 +1,24 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::entities::{HealthOverride, HealthSnapshot};
+use crate::shared::DomainError;
+
+#[async_trait]
+pub trait ProjectHealthRepository: Send + Sync {
+    async fn find_override(&self, project_id: Uuid) -> Result<Option<HealthOverride>, DomainError>;
+    async fn save_override(
+        &self,
+        health_override: &HealthOverride,
+    ) -> Result<HealthOverride, DomainError>;
+    async fn delete_override(&self, project_id: Uuid) -> Result<(), DomainError>;
+    /// Newest first, at most `limit` weeks
+    async fn find_snapshots(
+        &self,
+        project_id: Uuid,
+        limit: i64,
+    ) -> Result<Vec<HealthSnapshot>, DomainError>;
+    /// Insert the snapshot, replacing any already recorded for the same week
+    async fn save_snapshot(&self, snapshot: &HealthSnapshot)
+        -> Result<HealthSnapshot, DomainError>;
+}
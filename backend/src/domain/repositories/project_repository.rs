@@ -1,4 +1,5 @@
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
 use crate::domain::entities::{Milestone, Project, Task};
@@ -7,17 +8,48 @@ use crate::shared::DomainError;
 #[async_trait]
 pub trait ProjectRepository: Send + Sync {
     async fn find_by_id(&self, id: Uuid) -> Result<Option<Project>, DomainError>;
-    async fn find_all(&self) -> Result<Vec<Project>, DomainError>;
+    async fn find_all(&self, include_archived: bool) -> Result<Vec<Project>, DomainError>;
     async fn find_by_owner(&self, owner_id: Uuid) -> Result<Vec<Project>, DomainError>;
-    /// Find projects accessible by user (owner OR member)
-    async fn find_accessible_by_user(&self, user_id: Uuid) -> Result<Vec<Project>, DomainError>;
+    /// Find projects accessible by user (owner OR member OR program manager)
+    async fn find_accessible_by_user(
+        &self,
+        user_id: Uuid,
+        include_archived: bool,
+    ) -> Result<Vec<Project>, DomainError>;
     /// Check if user can access project (is owner OR member)
     async fn can_user_access(&self, project_id: Uuid, user_id: Uuid) -> Result<bool, DomainError>;
+    /// Check if user can read project (owner OR member OR program manager)
+    async fn can_user_view(&self, project_id: Uuid, user_id: Uuid) -> Result<bool, DomainError>;
     /// Check if user is owner of project
     async fn is_owner(&self, project_id: Uuid, user_id: Uuid) -> Result<bool, DomainError>;
+    /// Check if project has change approvers, making governed fields change-request only
+    async fn is_change_controlled(&self, project_id: Uuid) -> Result<bool, DomainError>;
     async fn create(&self, project: &Project) -> Result<Project, DomainError>;
     async fn update(&self, project: &Project) -> Result<Project, DomainError>;
+    /// Persist the archived state of a project
+    async fn set_archived(&self, project: &Project) -> Result<Project, DomainError>;
+    /// Check if the project containing the task is archived
+    async fn is_task_archived(&self, task_id: Uuid) -> Result<bool, DomainError>;
+    /// Move a project (and with it all of its tasks) to the trash
+    async fn soft_delete(&self, id: Uuid, deleted_by: Uuid) -> Result<(), DomainError>;
+    /// Take a project out of the trash
+    async fn restore(&self, id: Uuid) -> Result<Project, DomainError>;
+    /// Permanently delete a project, cascading to its tasks
     async fn delete(&self, id: Uuid) -> Result<(), DomainError>;
+    async fn find_deleted_by_id(&self, id: Uuid) -> Result<Option<Project>, DomainError>;
+    /// Find trashed projects deleted by user
+    async fn find_deleted_by_user(&self, user_id: Uuid) -> Result<Vec<Project>, DomainError>;
+    /// Find projects that have been in the trash since before the cutoff
+    async fn find_deleted_before(&self, cutoff: DateTime<Utc>)
+        -> Result<Vec<Project>, DomainError>;
+    /// Find non-deleted projects grouped directly under any of the portfolios
+    async fn find_by_portfolios(&self, portfolio_ids: &[Uuid])
+        -> Result<Vec<Project>, DomainError>;
+    async fn set_portfolio(
+        &self,
+        project_id: Uuid,
+        portfolio_id: Option<Uuid>,
+    ) -> Result<Project, DomainError>;
     async fn find_tasks(&self, project_id: Uuid) -> Result<Vec<Task>, DomainError>;
     async fn find_milestones(&self, project_id: Uuid) -> Result<Vec<Milestone>, DomainError>;
 }
@@ -0,0 +1,18 @@
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use uuid::Uuid;
+
+use crate::domain::entities::RaidItem;
+use crate::shared::DomainError;
+
+#[async_trait]
+pub trait RaidItemRepository: Send + Sync {
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<RaidItem>, DomainError>;
+    async fn find_by_project(&self, project_id: Uuid) -> Result<Vec<RaidItem>, DomainError>;
+    /// Open items whose review date is before `today`, across all projects
+    async fn find_review_overdue(&self, today: NaiveDate) -> Result<Vec<RaidItem>, DomainError>;
+    async fn create(&self, item: &RaidItem) -> Result<RaidItem, DomainError>;
+    async fn update(&self, item: &RaidItem) -> Result<RaidItem, DomainError>;
+    async fn mark_reminded(&self, id: Uuid, review_date: NaiveDate) -> Result<(), DomainError>;
+    async fn delete(&self, id: Uuid) -> Result<(), DomainError>;
+}
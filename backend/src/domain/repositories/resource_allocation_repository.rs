@@ -0,0 +1,38 @@
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use uuid::Uuid;
+
+use crate::domain::entities::ResourceAllocation;
+use crate::shared::DomainError;
+
+#[async_trait]
+pub trait ResourceAllocationRepository: Send + Sync {
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<ResourceAllocation>, DomainError>;
+    async fn find_by_project(
+        &self,
+        project_id: Uuid,
+    ) -> Result<Vec<ResourceAllocation>, DomainError>;
+    async fn find_by_user(&self, user_id: Uuid) -> Result<Vec<ResourceAllocation>, DomainError>;
+    /// Allocations overlapping a date range, of every user
+    async fn find_in_range(
+        &self,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<Vec<ResourceAllocation>, DomainError>;
+    /// Allocations of the given users overlapping a date range
+    async fn find_by_users_in_range(
+        &self,
+        user_ids: &[Uuid],
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<Vec<ResourceAllocation>, DomainError>;
+    async fn create(
+        &self,
+        allocation: &ResourceAllocation,
+    ) -> Result<ResourceAllocation, DomainError>;
+    async fn update(
+        &self,
+        allocation: &ResourceAllocation,
+    ) -> Result<ResourceAllocation, DomainError>;
+    async fn delete(&self, id: Uuid) -> Result<(), DomainError>;
+}
@@ -0,0 +1,24 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::entities::Sprint;
+use crate::shared::DomainError;
+
+#[async_trait]
+pub trait SprintRepository: Send + Sync {
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Sprint>, DomainError>;
+    async fn find_by_project(&self, project_id: Uuid) -> Result<Vec<Sprint>, DomainError>;
+    async fn find_active_by_project(&self, project_id: Uuid)
+        -> Result<Option<Sprint>, DomainError>;
+    /// Find the earliest planned sprint of a project, other than `exclude_id`
+    async fn find_next_planned(
+        &self,
+        project_id: Uuid,
+        exclude_id: Uuid,
+    ) -> Result<Option<Sprint>, DomainError>;
+    /// Find closed sprints, oldest first
+    async fn find_closed_by_project(&self, project_id: Uuid) -> Result<Vec<Sprint>, DomainError>;
+    async fn create(&self, sprint: &Sprint) -> Result<Sprint, DomainError>;
+    async fn update(&self, sprint: &Sprint) -> Result<Sprint, DomainError>;
+    async fn delete(&self, id: Uuid) -> Result<(), DomainError>;
+}
@@ -0,0 +1,14 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::entities::StatusReport;
+use crate::shared::DomainError;
+
+/// Status reports are immutable, so there is no update
+#[async_trait]
+pub trait StatusReportRepository: Send + Sync {
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<StatusReport>, DomainError>;
+    /// Newest period first
+    async fn find_by_project(&self, project_id: Uuid) -> Result<Vec<StatusReport>, DomainError>;
+    async fn create(&self, report: &StatusReport) -> Result<StatusReport, DomainError>;
+}
@@ -0,0 +1,14 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::entities::TaskComment;
+use crate::shared::DomainError;
+
+#[async_trait]
+pub trait TaskCommentRepository: Send + Sync {
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<TaskComment>, DomainError>;
+    async fn find_by_task(&self, task_id: Uuid) -> Result<Vec<TaskComment>, DomainError>;
+    async fn create(&self, comment: &TaskComment) -> Result<TaskComment, DomainError>;
+    async fn update(&self, comment: &TaskComment) -> Result<TaskComment, DomainError>;
+    async fn delete(&self, id: Uuid) -> Result<(), DomainError>;
+}
@@ -0,0 +1,15 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::entities::TaskDependency;
+use crate::shared::DomainError;
+
+#[async_trait]
+pub trait TaskDependencyRepository: Send + Sync {
+    /// Find all dependencies between tasks of a project
+    async fn find_by_project(&self, project_id: Uuid) -> Result<Vec<TaskDependency>, DomainError>;
+    /// Find the dependencies of a single task
+    async fn find_by_task(&self, task_id: Uuid) -> Result<Vec<TaskDependency>, DomainError>;
+    async fn create(&self, dependency: &TaskDependency) -> Result<TaskDependency, DomainError>;
+    async fn delete(&self, task_id: Uuid, depends_on_id: Uuid) -> Result<(), DomainError>;
+}
@@ -1,21 +1,37 @@
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
-use crate::domain::entities::Task;
+use crate::domain::entities::{ActualHoursDrift, Task};
 use crate::domain::value_objects::TaskStatus;
 use crate::shared::DomainError;
 
 #[async_trait]
 pub trait TaskRepository: Send + Sync {
     async fn find_by_id(&self, id: Uuid) -> Result<Option<Task>, DomainError>;
-    async fn find_all(&self) -> Result<Vec<Task>, DomainError>;
+    async fn find_all(&self, include_archived: bool) -> Result<Vec<Task>, DomainError>;
     async fn find_by_project(&self, project_id: Uuid) -> Result<Vec<Task>, DomainError>;
+    async fn find_by_sprint(&self, sprint_id: Uuid) -> Result<Vec<Task>, DomainError>;
+    /// Move a task into a sprint, or back to the backlog with `None`
+    async fn set_sprint(&self, task_id: Uuid, sprint_id: Option<Uuid>) -> Result<(), DomainError>;
+    /// Move every unfinished task of a sprint to another sprint (or the backlog)
+    async fn move_unfinished(
+        &self,
+        from_sprint_id: Uuid,
+        to_sprint_id: Option<Uuid>,
+    ) -> Result<u64, DomainError>;
     async fn find_by_assignee(&self, user_id: Uuid) -> Result<Vec<Task>, DomainError>;
     async fn find_by_status(&self, status: TaskStatus) -> Result<Vec<Task>, DomainError>;
     /// Find tasks from projects user can access (owner OR member)
-    async fn find_accessible_by_user(&self, user_id: Uuid) -> Result<Vec<Task>, DomainError>;
+    async fn find_accessible_by_user(
+        &self,
+        user_id: Uuid,
+        include_archived: bool,
+    ) -> Result<Vec<Task>, DomainError>;
     /// Check if user can access task (via project access)
     async fn can_user_access(&self, task_id: Uuid, user_id: Uuid) -> Result<bool, DomainError>;
+    /// Check if user can read task (project owner OR member OR program manager)
+    async fn can_user_view(&self, task_id: Uuid, user_id: Uuid) -> Result<bool, DomainError>;
     /// Check if user is owner of the project containing the task
     async fn is_project_owner(&self, task_id: Uuid, user_id: Uuid) -> Result<bool, DomainError>;
     /// Check if user can access project (for create task)
@@ -26,5 +42,34 @@ pub trait TaskRepository: Send + Sync {
     ) -> Result<bool, DomainError>;
     async fn create(&self, task: &Task) -> Result<Task, DomainError>;
     async fn update(&self, task: &Task) -> Result<Task, DomainError>;
+    /// Move a task to the trash
+    async fn soft_delete(&self, id: Uuid, deleted_by: Uuid) -> Result<(), DomainError>;
+    /// Take a task out of the trash
+    async fn restore(&self, id: Uuid) -> Result<Task, DomainError>;
+    /// Permanently delete a task
     async fn delete(&self, id: Uuid) -> Result<(), DomainError>;
+    async fn find_deleted_by_id(&self, id: Uuid) -> Result<Option<Task>, DomainError>;
+    async fn find_deleted_by_project(&self, project_id: Uuid) -> Result<Vec<Task>, DomainError>;
+    /// Find trashed tasks deleted by user (tasks inside a trashed project are excluded)
+    async fn find_deleted_by_user(&self, user_id: Uuid) -> Result<Vec<Task>, DomainError>;
+    /// Find tasks that have been in the trash since before the cutoff
+    async fn find_deleted_before(&self, cutoff: DateTime<Utc>) -> Result<Vec<Task>, DomainError>;
+    /// Open assigned tasks due between two instants whose assignee hasn't been
+    /// reminded about that due date
+    async fn find_due_unreminded(
+        &self,
+        from: DateTime<Utc>,
+        until: DateTime<Utc>,
+    ) -> Result<Vec<Task>, DomainError>;
+    async fn mark_due_reminded(
+        &self,
+        task_id: Uuid,
+        due_date: DateTime<Utc>,
+    ) -> Result<(), DomainError>;
+    /// Find tasks whose actual hours differ from the sum of their time logs,
+    /// resetting them to that sum unless `dry_run` is set
+    async fn reconcile_actual_hours(
+        &self,
+        dry_run: bool,
+    ) -> Result<Vec<ActualHoursDrift>, DomainError>;
 }
@@ -2,6 +2,7 @@ use async_trait::async_trait;
 use uuid::Uuid;
 
 use crate::domain::entities::{Team, TeamMember};
+use crate::domain::value_objects::TeamMemberRole;
 use crate::shared::DomainError;
 
 #[async_trait]
@@ -12,12 +13,28 @@ pub trait TeamRepository: Send + Sync {
     async fn find_accessible_by_user(&self, user_id: Uuid) -> Result<Vec<Team>, DomainError>;
     /// Check if user can access team (is lead OR member)
     async fn can_user_access(&self, team_id: Uuid, user_id: Uuid) -> Result<bool, DomainError>;
-    /// Check if user is lead of team
+    /// Check if user is lead of team (team lead OR member with the lead role)
     async fn is_lead(&self, team_id: Uuid, user_id: Uuid) -> Result<bool, DomainError>;
+    /// Leads of the teams the user is a member of, excluding the user
+    async fn find_lead_ids_of_member(&self, user_id: Uuid) -> Result<Vec<Uuid>, DomainError>;
+    /// Members of the teams the user leads, excluding the user
+    async fn find_member_ids_led_by(&self, lead_id: Uuid) -> Result<Vec<Uuid>, DomainError>;
     async fn create(&self, team: &Team) -> Result<Team, DomainError>;
+    /// Save name and description, the lead only changes with member roles
     async fn update(&self, team: &Team) -> Result<Team, DomainError>;
     async fn delete(&self, id: Uuid) -> Result<(), DomainError>;
     async fn find_members(&self, team_id: Uuid) -> Result<Vec<TeamMember>, DomainError>;
+    /// Add a member, a lead member also leads a team that has no lead yet
     async fn add_member(&self, member: &TeamMember) -> Result<TeamMember, DomainError>;
+    /// Change a member's role with the team locked, so concurrent changes
+    /// cannot leave it without a lead
+    async fn change_member_role(
+        &self,
+        team_id: Uuid,
+        user_id: Uuid,
+        role: TeamMemberRole,
+        transfer: bool,
+    ) -> Result<TeamMember, DomainError>;
+    /// Remove a member with the team locked, refusing to leave it without a lead
     async fn remove_member(&self, team_id: Uuid, user_id: Uuid) -> Result<(), DomainError>;
 }
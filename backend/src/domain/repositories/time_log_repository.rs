@@ -1,10 +1,22 @@
 use async_trait::async_trait;
 use chrono::NaiveDate;
+use serde::Deserialize;
 use uuid::Uuid;
 
-use crate::domain::entities::TimeLog;
+use crate::domain::entities::{DailyHours, TimeLog};
+use crate::domain::services::TimeLogPolicy;
 use crate::shared::DomainError;
 
+/// Narrows time log exports; unset fields match every log
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct TimeLogFilter {
+    pub user_id: Option<Uuid>,
+    pub project_id: Option<Uuid>,
+    pub task_id: Option<Uuid>,
+    pub start_date: Option<NaiveDate>,
+    pub end_date: Option<NaiveDate>,
+}
+
 #[async_trait]
 pub trait TimeLogRepository: Send + Sync {
     async fn find_by_id(&self, id: Uuid) -> Result<Option<TimeLog>, DomainError>;
@@ -15,13 +27,42 @@ pub trait TimeLogRepository: Send + Sync {
         end_date: Option<NaiveDate>,
     ) -> Result<Vec<TimeLog>, DomainError>;
     async fn find_by_task(&self, task_id: Uuid) -> Result<Vec<TimeLog>, DomainError>;
+    /// Find time logged on any task of a project
+    async fn find_by_project(&self, project_id: Uuid) -> Result<Vec<TimeLog>, DomainError>;
     async fn find_by_date_range(
         &self,
         user_id: Uuid,
         start_date: NaiveDate,
         end_date: NaiveDate,
     ) -> Result<Vec<TimeLog>, DomainError>;
-    async fn create(&self, time_log: &TimeLog) -> Result<TimeLog, DomainError>;
-    async fn update(&self, time_log: &TimeLog) -> Result<TimeLog, DomainError>;
+    /// Up to `limit` matching logs ordered by (date, id), strictly after the cursor
+    async fn find_page(
+        &self,
+        filter: &TimeLogFilter,
+        after: Option<(NaiveDate, Uuid)>,
+        limit: i64,
+    ) -> Result<Vec<TimeLog>, DomainError>;
+    /// Hours logged per user and day within the range
+    async fn sum_by_user_and_date(
+        &self,
+        start_date: NaiveDate,
+        end_date: NaiveDate,
+    ) -> Result<Vec<DailyHours>, DomainError>;
+    /// Insert a log; fails with `Conflict` if the week's timesheet is
+    /// submitted or approved, and with `InvalidFields` if the user's other
+    /// logs on that day leave no room under the policy's daily cap
+    async fn create(
+        &self,
+        time_log: &TimeLog,
+        policy: &TimeLogPolicy,
+    ) -> Result<TimeLog, DomainError>;
+    /// Store changes to a log, checking the weeks it moves between and the
+    /// daily cap like `create`
+    async fn update(
+        &self,
+        time_log: &TimeLog,
+        policy: &TimeLogPolicy,
+    ) -> Result<TimeLog, DomainError>;
+    /// Delete a log unless its week's timesheet is submitted or approved
     async fn delete(&self, id: Uuid) -> Result<(), DomainError>;
 }
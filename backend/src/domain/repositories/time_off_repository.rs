@@ -0,0 +1,27 @@
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use uuid::Uuid;
+
+use crate::domain::entities::TimeOffRequest;
+use crate::domain::value_objects::TimeOffStatus;
+use crate::shared::DomainError;
+
+#[async_trait]
+pub trait TimeOffRepository: Send + Sync {
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<TimeOffRequest>, DomainError>;
+    /// Requests of a user, latest first
+    async fn find_by_user(&self, user_id: Uuid) -> Result<Vec<TimeOffRequest>, DomainError>;
+    async fn find_by_status(
+        &self,
+        status: TimeOffStatus,
+    ) -> Result<Vec<TimeOffRequest>, DomainError>;
+    /// Pending and approved requests of the users overlapping a date range
+    async fn find_active_in_range(
+        &self,
+        user_ids: &[Uuid],
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<Vec<TimeOffRequest>, DomainError>;
+    async fn create(&self, request: &TimeOffRequest) -> Result<TimeOffRequest, DomainError>;
+    async fn update(&self, request: &TimeOffRequest) -> Result<TimeOffRequest, DomainError>;
+}
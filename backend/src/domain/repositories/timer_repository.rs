@@ -0,0 +1,20 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::entities::Timer;
+use crate::shared::DomainError;
+
+#[async_trait]
+pub trait TimerRepository: Send + Sync {
+    async fn find_by_user(&self, user_id: Uuid) -> Result<Option<Timer>, DomainError>;
+    /// Timers running without a break since before `cutoff`
+    async fn find_running_since_before(
+        &self,
+        cutoff: DateTime<Utc>,
+    ) -> Result<Vec<Timer>, DomainError>;
+    /// Fails with `AlreadyExists` if the user already has a timer
+    async fn create(&self, timer: &Timer) -> Result<Timer, DomainError>;
+    async fn update(&self, timer: &Timer) -> Result<Timer, DomainError>;
+    async fn delete(&self, id: Uuid) -> Result<(), DomainError>;
+}
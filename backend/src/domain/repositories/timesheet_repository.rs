@@ -0,0 +1,18 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::entities::Timesheet;
+use crate::domain::value_objects::TimesheetStatus;
+use crate::shared::DomainError;
+
+#[async_trait]
+pub trait TimesheetRepository: Send + Sync {
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Timesheet>, DomainError>;
+    /// Timesheets of a user, newest week first
+    async fn find_by_user(&self, user_id: Uuid) -> Result<Vec<Timesheet>, DomainError>;
+    async fn find_by_status(&self, status: TimesheetStatus) -> Result<Vec<Timesheet>, DomainError>;
+    /// Load the user's timesheet for the week, creating a draft if none exists
+    async fn find_or_create(&self, timesheet: &Timesheet) -> Result<Timesheet, DomainError>;
+    /// Save a status change, after the user's time log changes in flight
+    async fn update(&self, timesheet: &Timesheet) -> Result<Timesheet, DomainError>;
+}
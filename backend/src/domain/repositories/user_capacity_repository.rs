@@ -0,0 +1,13 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::entities::UserCapacity;
+use crate::shared::DomainError;
+
+#[async_trait]
+pub trait UserCapacityRepository: Send + Sync {
+    async fn find_all(&self) -> Result<Vec<UserCapacity>, DomainError>;
+    async fn find_by_user(&self, user_id: Uuid) -> Result<Option<UserCapacity>, DomainError>;
+    /// Insert the capacity, replacing the user's existing one
+    async fn upsert(&self, capacity: &UserCapacity) -> Result<UserCapacity, DomainError>;
+}
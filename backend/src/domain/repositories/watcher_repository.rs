@@ -0,0 +1,31 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::entities::Watcher;
+use crate::domain::value_objects::WatchEntityType;
+use crate::shared::DomainError;
+
+#[async_trait]
+pub trait WatcherRepository: Send + Sync {
+    async fn find_by_entity(
+        &self,
+        entity_type: WatchEntityType,
+        entity_id: Uuid,
+    ) -> Result<Vec<Watcher>, DomainError>;
+    /// Add a watcher, doing nothing if the user already watches the entity
+    async fn watch(&self, watcher: &Watcher) -> Result<(), DomainError>;
+    async fn unwatch(
+        &self,
+        user_id: Uuid,
+        entity_type: WatchEntityType,
+        entity_id: Uuid,
+    ) -> Result<(), DomainError>;
+    async fn is_watching(
+        &self,
+        user_id: Uuid,
+        entity_type: WatchEntityType,
+        entity_id: Uuid,
+    ) -> Result<bool, DomainError>;
+    /// Remove watchers whose task or project no longer exists
+    async fn delete_orphaned(&self) -> Result<u64, DomainError>;
+}
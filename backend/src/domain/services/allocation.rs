@@ -0,0 +1,213 @@
+use chrono::{Datelike, Duration, NaiveDate};
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::domain::entities::ResourceAllocation;
+use crate::domain::services::WorkCalendar;
+
+/// Hours allocated beyond capacity before a week counts as over-allocated,
+/// absorbing float rounding
+const OVER_ALLOCATION_TOLERANCE: f32 = 0.01;
+
+/// One project's part of a week's allocation
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ProjectShare {
+    pub project_id: Uuid,
+    pub project_name: Option<String>,
+    pub hours: f32,
+}
+
+/// Allocated hours against capacity over part of a calendar week
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AllocationWeek {
+    pub week_start: NaiveDate,
+    pub week_end: NaiveDate,
+    pub capacity_hours: f32,
+    pub allocated_hours: f32,
+    /// Allocated hours as a percentage of capacity; absent without capacity
+    pub allocated_percent: Option<f32>,
+    /// More is allocated than can be worked that week
+    pub over_allocated: bool,
+    pub projects: Vec<ProjectShare>,
+}
+
+impl AllocationWeek {
+    fn empty(week_start: NaiveDate, week_end: NaiveDate) -> Self {
+        Self {
+            week_start,
+            week_end,
+            capacity_hours: 0.0,
+            allocated_hours: 0.0,
+            allocated_percent: None,
+            over_allocated: false,
+            projects: Vec::new(),
+        }
+    }
+
+    fn allocate(&mut self, project_id: Uuid, project_name: &Option<String>, hours: f32) {
+        self.allocated_hours += hours;
+        match self
+            .projects
+            .iter_mut()
+            .find(|p| p.project_id == project_id)
+        {
+            Some(share) => share.hours += hours,
+            None => self.projects.push(ProjectShare {
+                project_id,
+                project_name: project_name.clone(),
+                hours,
+            }),
+        }
+    }
+
+    fn finish(mut self) -> Self {
+        self.allocated_percent = (self.capacity_hours > 0.0)
+            .then(|| (self.allocated_hours / self.capacity_hours * 1000.0).round() / 10.0);
+        self.over_allocated =
+            self.allocated_hours > self.capacity_hours + OVER_ALLOCATION_TOLERANCE;
+        self.projects.retain(|p| p.hours > 0.0);
+        self.projects.sort_by(|a, b| b.hours.total_cmp(&a.hours));
+        self
+    }
+}
+
+/// Buckets allocations into calendar weeks (Monday to Sunday) clipped to a
+/// date range, against each user's working hours
+pub struct AllocationCalculator {
+    start: NaiveDate,
+    end: NaiveDate,
+}
+
+impl AllocationCalculator {
+    pub fn new(start: NaiveDate, end: NaiveDate) -> Self {
+        Self { start, end }
+    }
+
+    /// Weekly buckets of one user; `allocations` must belong to the user.
+    /// Holidays and time off in `calendar` reduce both capacity and the
+    /// hours allocated on those days.
+    pub fn user_weeks(
+        &self,
+        calendar: &WorkCalendar,
+        allocations: &[ResourceAllocation],
+    ) -> Vec<AllocationWeek> {
+        let mut weeks = self.empty_weeks();
+        let weekly_hours = calendar.weekly_hours();
+
+        for date in self.start.iter_days().take_while(|d| *d <= self.end) {
+            let week = &mut weeks[self.week_index(date)];
+            let day_hours = calendar.hours_on(date);
+            week.capacity_hours += day_hours;
+            for allocation in allocations {
+                let hours = allocation.hours_on(date, day_hours, weekly_hours);
+                if hours > 0.0 {
+                    week.allocate(allocation.project_id, &allocation.project_name, hours);
+                }
+            }
+        }
+
+        weeks.into_iter().map(AllocationWeek::finish).collect()
+    }
+
+    /// Week by week sum of several users' buckets
+    pub fn combine(&self, members: &[Vec<AllocationWeek>]) -> Vec<AllocationWeek> {
+        let mut weeks = self.empty_weeks();
+        for member in members {
+            for (week, other) in weeks.iter_mut().zip(member) {
+                week.capacity_hours += other.capacity_hours;
+                for share in &other.projects {
+                    week.allocate(share.project_id, &share.project_name, share.hours);
+                }
+            }
+        }
+        weeks.into_iter().map(AllocationWeek::finish).collect()
+    }
+
+    fn empty_weeks(&self) -> Vec<AllocationWeek> {
+        let mut weeks = Vec::new();
+        let mut start = self.start;
+        while start <= self.end {
+            let sunday = monday_of(start) + Duration::days(6);
+            weeks.push(AllocationWeek::empty(start, sunday.min(self.end)));
+            start = sunday + Duration::days(1);
+        }
+        weeks
+    }
+
+    fn week_index(&self, date: NaiveDate) -> usize {
+        ((monday_of(date) - monday_of(self.start)).num_days() / 7) as usize
+    }
+}
+
+fn monday_of(date: NaiveDate) -> NaiveDate {
+    date - Duration::days(date.weekday().num_days_from_monday() as i64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::UserCapacity;
+    use crate::shared::test_dates::date;
+
+    fn allocation(
+        user_id: Uuid,
+        start: u32,
+        end: u32,
+        percent: Option<f32>,
+        hours: Option<f32>,
+    ) -> ResourceAllocation {
+        ResourceAllocation::new(
+            user_id,
+            Uuid::new_v4(),
+            date(start),
+            date(end),
+            percent,
+            hours,
+            None,
+            Uuid::new_v4(),
+        )
+    }
+
+    #[test]
+    fn test_overlapping_projects_over_allocate() {
+        let user_id = Uuid::new_v4();
+        let calendar = WorkCalendar::for_capacity(&UserCapacity::new(user_id, 40.0, None), []);
+        // Monday 2024-03-04 to Sunday 2024-03-17
+        let calc = AllocationCalculator::new(date(4), date(17));
+        let allocations = vec![
+            allocation(user_id, 4, 17, Some(60.0), None),
+            allocation(user_id, 11, 31, None, Some(20.0)),
+        ];
+
+        let weeks = calc.user_weeks(&calendar, &allocations);
+        assert_eq!(weeks.len(), 2);
+        assert_eq!(weeks[0].allocated_hours, 24.0);
+        assert_eq!(weeks[0].allocated_percent, Some(60.0));
+        assert!(!weeks[0].over_allocated);
+        assert_eq!(weeks[1].allocated_hours, 44.0);
+        assert_eq!(weeks[1].allocated_percent, Some(110.0));
+        assert!(weeks[1].over_allocated);
+        assert_eq!(weeks[1].projects[0].hours, 24.0);
+        assert_eq!(weeks[1].projects[1].hours, 20.0);
+
+        let team = calc.combine(&[weeks.clone(), weeks]);
+        assert_eq!(team[1].capacity_hours, 80.0);
+        assert_eq!(team[1].allocated_hours, 88.0);
+        assert_eq!(team[1].projects.len(), 2);
+        assert!(team[1].over_allocated);
+    }
+
+    #[test]
+    fn test_days_off_reduce_capacity_and_allocation() {
+        let user_id = Uuid::new_v4();
+        let capacity = UserCapacity::new(user_id, 40.0, None);
+        // Wednesday off
+        let calendar = WorkCalendar::for_capacity(&capacity, [date(6)]);
+        let calc = AllocationCalculator::new(date(4), date(10));
+
+        let weeks = calc.user_weeks(&calendar, &[allocation(user_id, 1, 31, None, Some(40.0))]);
+        assert_eq!(weeks[0].capacity_hours, 32.0);
+        assert_eq!(weeks[0].allocated_hours, 32.0);
+        assert!(!weeks[0].over_allocated);
+    }
+}
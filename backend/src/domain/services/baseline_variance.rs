@@ -0,0 +1,235 @@
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::Serialize;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::domain::entities::{BaselineSnapshot, BaselineTask};
+
+/// A task added to or removed from scope since the baseline
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ScopeChange {
+    pub task_id: Uuid,
+    pub title: String,
+    pub estimated_hours: Option<f32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct EstimateChange {
+    pub task_id: Uuid,
+    pub title: String,
+    pub baseline_hours: Option<f32>,
+    pub current_hours: Option<f32>,
+    pub delta_hours: f32,
+}
+
+/// A moved date of a task or milestone. Positive deltas are slips.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DateChange {
+    pub id: Uuid,
+    pub name: String,
+    pub baseline_date: Option<DateTime<Utc>>,
+    pub current_date: Option<DateTime<Utc>>,
+    /// Days between the two dates, when both are set
+    pub delta_days: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BaselineVariance {
+    pub budget_baseline: Option<Decimal>,
+    pub budget_current: Option<Decimal>,
+    pub budget_delta: Option<Decimal>,
+    pub end_date_delta_days: Option<i64>,
+    pub baseline_estimated_hours: f32,
+    pub current_estimated_hours: f32,
+    pub estimate_delta_hours: f32,
+    pub added_tasks: Vec<ScopeChange>,
+    pub removed_tasks: Vec<ScopeChange>,
+    pub estimate_changes: Vec<EstimateChange>,
+    pub task_date_changes: Vec<DateChange>,
+    pub slipped_milestones: Vec<DateChange>,
+}
+
+pub struct VarianceCalculator;
+
+impl VarianceCalculator {
+    pub fn compare(baseline: &BaselineSnapshot, current: &BaselineSnapshot) -> BaselineVariance {
+        let baseline_tasks: HashMap<Uuid, &BaselineTask> =
+            baseline.tasks.iter().map(|t| (t.id, t)).collect();
+        let current_ids: HashMap<Uuid, &BaselineTask> =
+            current.tasks.iter().map(|t| (t.id, t)).collect();
+
+        let added_tasks = current
+            .tasks
+            .iter()
+            .filter(|t| !baseline_tasks.contains_key(&t.id))
+            .map(scope_change)
+            .collect();
+        let removed_tasks = baseline
+            .tasks
+            .iter()
+            .filter(|t| !current_ids.contains_key(&t.id))
+            .map(scope_change)
+            .collect();
+
+        let mut estimate_changes = Vec::new();
+        let mut task_date_changes = Vec::new();
+        for task in &current.tasks {
+            let Some(planned) = baseline_tasks.get(&task.id) else {
+                continue;
+            };
+            if planned.estimated_hours != task.estimated_hours {
+                estimate_changes.push(EstimateChange {
+                    task_id: task.id,
+                    title: task.title.clone(),
+                    baseline_hours: planned.estimated_hours,
+                    current_hours: task.estimated_hours,
+                    delta_hours: task.estimated_hours.unwrap_or(0.0)
+                        - planned.estimated_hours.unwrap_or(0.0),
+                });
+            }
+            if planned.due_date != task.due_date {
+                task_date_changes.push(date_change(
+                    task.id,
+                    &task.title,
+                    planned.due_date,
+                    task.due_date,
+                ));
+            }
+        }
+
+        let current_milestones: HashMap<Uuid, _> =
+            current.milestones.iter().map(|m| (m.id, m)).collect();
+        let slipped_milestones = baseline
+            .milestones
+            .iter()
+            .filter_map(|planned| {
+                let milestone = current_milestones.get(&planned.id)?;
+                let change = date_change(
+                    milestone.id,
+                    &milestone.name,
+                    planned.due_date,
+                    milestone.due_date,
+                );
+                change.delta_days.is_some_and(|d| d > 0).then_some(change)
+            })
+            .collect();
+
+        let baseline_estimated_hours = baseline.total_estimated_hours();
+        let current_estimated_hours = current.total_estimated_hours();
+        BaselineVariance {
+            budget_baseline: baseline.budget,
+            budget_current: current.budget,
+            budget_delta: current.budget.zip(baseline.budget).map(|(c, b)| c - b),
+            end_date_delta_days: days_between(baseline.end_date, current.end_date),
+            baseline_estimated_hours,
+            current_estimated_hours,
+            estimate_delta_hours: current_estimated_hours - baseline_estimated_hours,
+            added_tasks,
+            removed_tasks,
+            estimate_changes,
+            task_date_changes,
+            slipped_milestones,
+        }
+    }
+}
+
+fn scope_change(task: &BaselineTask) -> ScopeChange {
+    ScopeChange {
+        task_id: task.id,
+        title: task.title.clone(),
+        estimated_hours: task.estimated_hours,
+    }
+}
+
+fn date_change(
+    id: Uuid,
+    name: &str,
+    baseline_date: Option<DateTime<Utc>>,
+    current_date: Option<DateTime<Utc>>,
+) -> DateChange {
+    DateChange {
+        id,
+        name: name.to_string(),
+        baseline_date,
+        current_date,
+        delta_days: days_between(baseline_date, current_date),
+    }
+}
+
+fn days_between(from: Option<DateTime<Utc>>, to: Option<DateTime<Utc>>) -> Option<i64> {
+    Some((to?.date_naive() - from?.date_naive()).num_days())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::BaselineMilestone;
+    use crate::shared::test_dates::noon;
+    use chrono::{Duration, TimeZone};
+
+    fn task(id: Uuid, hours: Option<f32>, due: Option<u32>) -> BaselineTask {
+        BaselineTask {
+            id,
+            title: "Task".into(),
+            milestone_id: None,
+            due_date: due.map(noon),
+            estimated_hours: hours,
+        }
+    }
+
+    #[test]
+    fn test_compare_scope_and_estimates() {
+        let (kept, removed, added) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let baseline = BaselineSnapshot {
+            budget: Some(Decimal::from(1000)),
+            tasks: vec![
+                task(kept, Some(8.0), Some(10)),
+                task(removed, Some(4.0), None),
+            ],
+            ..Default::default()
+        };
+        let current = BaselineSnapshot {
+            budget: Some(Decimal::from(1200)),
+            tasks: vec![
+                task(kept, Some(12.0), Some(13)),
+                task(added, Some(2.0), None),
+            ],
+            ..Default::default()
+        };
+
+        let variance = VarianceCalculator::compare(&baseline, &current);
+        assert_eq!(variance.budget_delta, Some(Decimal::from(200)));
+        assert_eq!(variance.added_tasks[0].task_id, added);
+        assert_eq!(variance.removed_tasks[0].task_id, removed);
+        assert_eq!(variance.estimate_changes[0].delta_hours, 4.0);
+        assert_eq!(variance.task_date_changes[0].delta_days, Some(3));
+        assert_eq!(variance.estimate_delta_hours, 2.0);
+    }
+
+    #[test]
+    fn test_compare_reports_only_slipped_milestones() {
+        let (late, early) = (Uuid::new_v4(), Uuid::new_v4());
+        let milestone = |id, due| BaselineMilestone {
+            id,
+            name: "Milestone".into(),
+            due_date: Some(due),
+        };
+        let baseline = BaselineSnapshot {
+            end_date: Some(noon(20)),
+            milestones: vec![milestone(late, noon(10)), milestone(early, noon(15))],
+            ..Default::default()
+        };
+        let current = BaselineSnapshot {
+            end_date: Some(noon(20) + Duration::days(5)),
+            milestones: vec![milestone(late, noon(12)), milestone(early, noon(14))],
+            ..Default::default()
+        };
+
+        let variance = VarianceCalculator::compare(&baseline, &current);
+        assert_eq!(variance.slipped_milestones.len(), 1);
+        assert_eq!(variance.slipped_milestones[0].id, late);
+        assert_eq!(variance.slipped_milestones[0].delta_days, Some(2));
+        assert_eq!(variance.end_date_delta_days, Some(5));
+    }
+}
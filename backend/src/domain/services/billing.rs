@@ -0,0 +1,167 @@
+use std::collections::BTreeMap;
+
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+use crate::domain::entities::{BillableEntry, BillingRate, InvoiceLine};
+use crate::domain::services::BudgetCalculator;
+
+/// Invoice lines priced from billable time, plus the time no rate covers
+#[derive(Debug, Default)]
+pub struct PricedEntries {
+    pub lines: Vec<InvoiceLine>,
+    pub unrated: Vec<BillableEntry>,
+}
+
+pub struct BillingCalculator;
+
+impl BillingCalculator {
+    /// Whether new time on a task is billable: the task's own setting, else
+    /// the project's
+    pub fn default_billable(project_billable: bool, task_billable: Option<bool>) -> bool {
+        task_billable.unwrap_or(project_billable)
+    }
+
+    /// Rate in force for an entry: among rates matching its project, user
+    /// and role that have started by its date, the most specific one, and
+    /// of those the most recent
+    pub fn rate_for<'a>(
+        entry: &BillableEntry,
+        rates: &'a [BillingRate],
+    ) -> Option<&'a BillingRate> {
+        rates
+            .iter()
+            .filter(|r| r.effective_from <= entry.date)
+            .filter(|r| r.matches(entry.project_id, entry.user_id, &entry.user_role))
+            .max_by_key(|r| (r.specificity(), r.effective_from))
+    }
+
+    /// Group entries into lines per project, task, user and rate, ordered by
+    /// project, task and user name
+    pub fn price(entries: &[BillableEntry], rates: &[BillingRate]) -> PricedEntries {
+        type LineKey = (String, Uuid, String, Uuid, String, Uuid, Decimal);
+        let mut grouped: BTreeMap<LineKey, InvoiceLine> = BTreeMap::new();
+        let mut unrated = Vec::new();
+
+        for entry in entries {
+            let Some(rate) = Self::rate_for(entry, rates) else {
+                unrated.push(entry.clone());
+                continue;
+            };
+            let key = (
+                entry.project_name.clone(),
+                entry.project_id,
+                entry.task_title.clone(),
+                entry.task_id,
+                entry.user_name.clone(),
+                entry.user_id,
+                rate.hourly_rate,
+            );
+            let line = grouped.entry(key).or_insert_with(|| InvoiceLine {
+                project_id: entry.project_id,
+                project_name: entry.project_name.clone(),
+                task_id: entry.task_id,
+                task_title: entry.task_title.clone(),
+                user_id: entry.user_id,
+                user_name: entry.user_name.clone(),
+                hourly_rate: rate.hourly_rate,
+                hours: 0.0,
+                amount: Decimal::ZERO,
+            });
+            line.hours += entry.hours;
+        }
+
+        let lines = grouped
+            .into_values()
+            .map(|mut line| {
+                line.amount = BudgetCalculator::labor_cost(line.hours, line.hourly_rate);
+                line
+            })
+            .collect();
+        PricedEntries { lines, unrated }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::UserRole;
+    use crate::shared::test_dates::date;
+    use chrono::{NaiveDate, Utc};
+
+    fn dec(s: &str) -> Decimal {
+        s.parse().unwrap()
+    }
+
+    fn entry(project_id: Uuid, user_id: Uuid, day: u32, hours: f32) -> BillableEntry {
+        BillableEntry {
+            time_log_id: Uuid::new_v4(),
+            project_id,
+            project_name: "Website".into(),
+            task_id: Uuid::nil(),
+            task_title: "Build".into(),
+            user_id,
+            user_name: "Ana".into(),
+            user_role: UserRole::Member,
+            date: date(day),
+            hours,
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_rate_for_prefers_specific_and_recent_rates() {
+        let project_id = Uuid::new_v4();
+        let user_id = Uuid::new_v4();
+        let rates = vec![
+            BillingRate::new(None, None, Some(UserRole::Member), dec("80"), date(1)),
+            BillingRate::new(None, Some(user_id), None, dec("100"), date(1)),
+            BillingRate::new(Some(project_id), None, None, dec("120"), date(10)),
+            BillingRate::new(Some(project_id), None, None, dec("130"), date(20)),
+        ];
+
+        let rate =
+            |e: &BillableEntry| BillingCalculator::rate_for(e, &rates).map(|r| r.hourly_rate);
+        assert_eq!(rate(&entry(project_id, user_id, 5, 1.0)), Some(dec("100")));
+        assert_eq!(rate(&entry(project_id, user_id, 15, 1.0)), Some(dec("120")));
+        assert_eq!(rate(&entry(project_id, user_id, 25, 1.0)), Some(dec("130")));
+        assert_eq!(
+            rate(&entry(Uuid::new_v4(), Uuid::new_v4(), 25, 1.0)),
+            Some(dec("80"))
+        );
+
+        let mut manager = entry(Uuid::new_v4(), Uuid::new_v4(), 25, 1.0);
+        manager.user_role = UserRole::Manager;
+        assert_eq!(rate(&manager), None);
+    }
+
+    #[test]
+    fn test_price_groups_lines_and_keeps_unrated_time() {
+        let project_id = Uuid::new_v4();
+        let user_id = Uuid::new_v4();
+        let rates = vec![
+            BillingRate::new(Some(project_id), None, None, dec("100"), date(1)),
+            BillingRate::new(Some(project_id), None, None, dec("110"), date(15)),
+        ];
+        let entries = vec![
+            entry(project_id, user_id, 2, 1.5),
+            entry(project_id, user_id, 3, 2.0),
+            entry(project_id, user_id, 16, 1.0),
+            entry(Uuid::new_v4(), user_id, 16, 4.0),
+        ];
+
+        let priced = BillingCalculator::price(&entries, &rates);
+        assert_eq!(priced.lines.len(), 2);
+        assert_eq!(priced.lines[0].hours, 3.5);
+        assert_eq!(priced.lines[0].amount, dec("350.00"));
+        assert_eq!(priced.lines[1].amount, dec("110.00"));
+        assert_eq!(priced.unrated.len(), 1);
+    }
+
+    #[test]
+    fn test_default_billable() {
+        assert!(BillingCalculator::default_billable(true, None));
+        assert!(!BillingCalculator::default_billable(true, Some(false)));
+        assert!(BillingCalculator::default_billable(false, Some(true)));
+    }
+}
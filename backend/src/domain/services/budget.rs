@@ -0,0 +1,190 @@
+use chrono::{Duration, NaiveDate};
+use rust_decimal::Decimal;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::domain::entities::{CostRate, TimeLog};
+use crate::domain::value_objects::UserRole;
+
+/// Hours of one time log priced at its user's cost rate; `cost` is `None`
+/// when neither the user nor their role has a rate
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LaborCost {
+    pub date: NaiveDate,
+    pub hours: f32,
+    pub cost: Option<Decimal>,
+}
+
+pub struct BudgetCalculator;
+
+impl BudgetCalculator {
+    /// Hourly rate for a user: their own rate, else their role's rate
+    pub fn rate_for(user_id: Uuid, role: &UserRole, rates: &[CostRate]) -> Option<Decimal> {
+        rates
+            .iter()
+            .find(|r| r.user_id == Some(user_id))
+            .or_else(|| rates.iter().find(|r| r.role.as_ref() == Some(role)))
+            .map(|r| r.hourly_rate)
+    }
+
+    /// Cost of logged hours at an hourly rate, rounded to cents
+    pub fn labor_cost(hours: f32, hourly_rate: Decimal) -> Decimal {
+        let hours = Decimal::try_from(hours).unwrap_or_default();
+        (hours * hourly_rate).round_dp(2)
+    }
+
+    /// Price time logs at their users' cost rates, given each user's role
+    pub fn price_logs(
+        logs: &[TimeLog],
+        roles: &HashMap<Uuid, UserRole>,
+        rates: &[CostRate],
+    ) -> Vec<LaborCost> {
+        logs.iter()
+            .map(|log| LaborCost {
+                date: log.date,
+                hours: log.hours,
+                cost: roles
+                    .get(&log.user_id)
+                    .and_then(|role| Self::rate_for(log.user_id, role, rates))
+                    .map(|rate| Self::labor_cost(log.hours, rate)),
+            })
+            .collect()
+    }
+
+    /// Share of the budget spent, as a percentage. `None` without a positive budget.
+    pub fn percent_used(spent: Decimal, budget: Decimal) -> Option<Decimal> {
+        if budget <= Decimal::ZERO {
+            return None;
+        }
+        Some((spent * Decimal::ONE_HUNDRED / budget).round_dp(2))
+    }
+
+    /// Thresholds (in percent of the budget) that `spent` has reached, ascending
+    pub fn reached_thresholds(spent: Decimal, budget: Decimal, thresholds: &[u32]) -> Vec<u32> {
+        let Some(percent) = Self::percent_used(spent, budget) else {
+            return Vec::new();
+        };
+        let mut reached: Vec<u32> = thresholds
+            .iter()
+            .copied()
+            .filter(|t| percent >= Decimal::from(*t))
+            .collect();
+        reached.sort_unstable();
+        reached.dedup();
+        reached
+    }
+
+    /// Average spend per day from `since` through `today`
+    pub fn burn_rate_per_day(spent: Decimal, since: NaiveDate, today: NaiveDate) -> Decimal {
+        let days = (today - since).num_days() + 1;
+        if days <= 0 {
+            return Decimal::ZERO;
+        }
+        (spent / Decimal::from(days)).round_dp(2)
+    }
+
+    /// Date the remaining budget runs out at the current burn rate
+    pub fn projected_exhaustion(
+        remaining: Decimal,
+        burn_rate_per_day: Decimal,
+        today: NaiveDate,
+    ) -> Option<NaiveDate> {
+        if burn_rate_per_day <= Decimal::ZERO {
+            return None;
+        }
+        if remaining <= Decimal::ZERO {
+            return Some(today);
+        }
+        let days = (remaining / burn_rate_per_day).ceil();
+        let days: i64 = days.try_into().ok()?;
+        today.checked_add_signed(Duration::days(days))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::test_dates::date;
+    use rust_decimal::Decimal;
+
+    fn dec(s: &str) -> Decimal {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_rate_for_prefers_user_rate() {
+        let user_id = Uuid::new_v4();
+        let rates = vec![
+            CostRate::for_role(UserRole::Member, dec("50")),
+            CostRate::for_user(user_id, dec("80")),
+        ];
+
+        assert_eq!(
+            BudgetCalculator::rate_for(user_id, &UserRole::Member, &rates),
+            Some(dec("80"))
+        );
+        assert_eq!(
+            BudgetCalculator::rate_for(Uuid::new_v4(), &UserRole::Member, &rates),
+            Some(dec("50"))
+        );
+        assert_eq!(
+            BudgetCalculator::rate_for(Uuid::new_v4(), &UserRole::Manager, &rates),
+            None
+        );
+    }
+
+    #[test]
+    fn test_labor_cost() {
+        assert_eq!(BudgetCalculator::labor_cost(1.5, dec("40")), dec("60.00"));
+        assert_eq!(BudgetCalculator::labor_cost(0.1, dec("100")), dec("10.00"));
+    }
+
+    #[test]
+    fn test_price_logs_leaves_unrated_users_unpriced() {
+        let (rated, unrated) = (Uuid::new_v4(), Uuid::new_v4());
+        let logs = vec![
+            TimeLog::new(Uuid::new_v4(), rated, 2.0, date(4), None, true),
+            TimeLog::new(Uuid::new_v4(), unrated, 3.0, date(5), None, true),
+        ];
+        let roles = HashMap::from([(rated, UserRole::Member), (unrated, UserRole::Manager)]);
+        let rates = vec![CostRate::for_role(UserRole::Member, dec("50"))];
+
+        let costs = BudgetCalculator::price_logs(&logs, &roles, &rates);
+        assert_eq!(costs[0].cost, Some(dec("100.00")));
+        assert_eq!(costs[1].cost, None);
+        assert_eq!(costs[1].hours, 3.0);
+        assert_eq!(costs[1].date, date(5));
+    }
+
+    #[test]
+    fn test_reached_thresholds() {
+        let thresholds = [90, 50, 75, 100];
+        assert_eq!(
+            BudgetCalculator::reached_thresholds(dec("800"), dec("1000"), &thresholds),
+            vec![50, 75]
+        );
+        assert_eq!(
+            BudgetCalculator::reached_thresholds(dec("1000"), dec("1000"), &thresholds),
+            vec![50, 75, 90, 100]
+        );
+        assert!(BudgetCalculator::reached_thresholds(dec("800"), dec("0"), &thresholds).is_empty());
+    }
+
+    #[test]
+    fn test_burn_rate_and_projection() {
+        let rate = BudgetCalculator::burn_rate_per_day(dec("1000"), date(1), date(10));
+        assert_eq!(rate, dec("100"));
+        assert_eq!(
+            BudgetCalculator::projected_exhaustion(dec("250"), rate, date(10)),
+            Some(date(13))
+        );
+        assert_eq!(
+            BudgetCalculator::projected_exhaustion(dec("-5"), rate, date(10)),
+            Some(date(10))
+        );
+        assert_eq!(
+            BudgetCalculator::projected_exhaustion(dec("250"), Decimal::ZERO, date(10)),
+            None
+        );
+    }
+}
@@ -0,0 +1,118 @@
+use chrono::{Duration, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+/// A unit of sprint scope: its estimate and the day it was completed, if any
+#[derive(Debug, Clone)]
+pub struct BurndownItem {
+    pub estimated_hours: f32,
+    pub completed_on: Option<NaiveDate>,
+}
+
+/// Remaining work at the end of one sprint day, next to the ideal line
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BurndownPoint {
+    pub date: NaiveDate,
+    pub remaining_hours: f32,
+    pub remaining_tasks: i64,
+    pub ideal_hours: f32,
+    pub ideal_tasks: f32,
+}
+
+pub struct BurndownCalculator;
+
+impl BurndownCalculator {
+    /// One point per day from `start` through `until` (capped at `end`).
+    /// The ideal line falls linearly from the full scope on `start` to zero on `end`.
+    pub fn compute(
+        start: NaiveDate,
+        end: NaiveDate,
+        until: NaiveDate,
+        items: &[BurndownItem],
+    ) -> Vec<BurndownPoint> {
+        let total_hours: f32 = items.iter().map(|i| i.estimated_hours).sum();
+        let total_tasks = items.len() as f32;
+        let span = (end - start).num_days().max(1) as f32;
+        let last = until.min(end);
+
+        let mut points = Vec::new();
+        let mut date = start;
+        while date <= last {
+            let remaining: Vec<&BurndownItem> = items
+                .iter()
+                .filter(|i| i.completed_on.is_none_or(|done| done > date))
+                .collect();
+            let progress = 1.0 - (date - start).num_days() as f32 / span;
+            points.push(BurndownPoint {
+                date,
+                remaining_hours: remaining.iter().map(|i| i.estimated_hours).sum(),
+                remaining_tasks: remaining.len() as i64,
+                ideal_hours: total_hours * progress,
+                ideal_tasks: total_tasks * progress,
+            });
+            date += Duration::days(1);
+        }
+        points
+    }
+
+    /// Mean of the given per-sprint values, or zero when there are none
+    pub fn average(values: &[f32]) -> f32 {
+        if values.is_empty() {
+            return 0.0;
+        }
+        values.iter().sum::<f32>() / values.len() as f32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::test_dates::date;
+
+    fn item(hours: f32, completed_on: Option<NaiveDate>) -> BurndownItem {
+        BurndownItem {
+            estimated_hours: hours,
+            completed_on,
+        }
+    }
+
+    #[test]
+    fn test_burndown_counts_work_completed_by_end_of_day() {
+        let items = vec![
+            item(4.0, Some(date(2))),
+            item(6.0, Some(date(3))),
+            item(2.0, None),
+        ];
+        let points = BurndownCalculator::compute(date(1), date(5), date(5), &items);
+
+        assert_eq!(points.len(), 5);
+        assert_eq!(points[0].remaining_hours, 12.0);
+        assert_eq!(points[1].remaining_hours, 8.0);
+        assert_eq!(points[2].remaining_hours, 2.0);
+        assert_eq!(points[2].remaining_tasks, 1);
+        assert_eq!(points[4].remaining_tasks, 1);
+    }
+
+    #[test]
+    fn test_burndown_ideal_line_reaches_zero_at_end() {
+        let items = vec![item(8.0, None), item(8.0, None)];
+        let points = BurndownCalculator::compute(date(1), date(5), date(5), &items);
+
+        assert_eq!(points[0].ideal_hours, 16.0);
+        assert_eq!(points[2].ideal_hours, 8.0);
+        assert_eq!(points[4].ideal_hours, 0.0);
+        assert_eq!(points[4].ideal_tasks, 0.0);
+    }
+
+    #[test]
+    fn test_burndown_stops_at_until() {
+        let points = BurndownCalculator::compute(date(1), date(14), date(3), &[item(1.0, None)]);
+        assert_eq!(points.len(), 3);
+        assert!(BurndownCalculator::compute(date(10), date(14), date(3), &[]).is_empty());
+    }
+
+    #[test]
+    fn test_average() {
+        assert_eq!(BurndownCalculator::average(&[]), 0.0);
+        assert_eq!(BurndownCalculator::average(&[10.0, 20.0]), 15.0);
+    }
+}
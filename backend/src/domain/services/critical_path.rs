@@ -0,0 +1,275 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use uuid::Uuid;
+
+use crate::shared::DomainError;
+
+/// Working hours that make up one day of task duration
+pub const HOURS_PER_DAY: f32 = 8.0;
+
+/// A task to be scheduled, with its duration and the tasks it depends on
+#[derive(Debug, Clone)]
+pub struct ScheduleNode {
+    pub id: Uuid,
+    pub duration_days: i64,
+    pub predecessors: Vec<Uuid>,
+}
+
+/// Critical path timing of a task, in whole days from the project start.
+/// Finish offsets are exclusive: a one-day task starting at day 0 finishes at day 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScheduledNode {
+    pub id: Uuid,
+    pub earliest_start: i64,
+    pub earliest_finish: i64,
+    pub latest_start: i64,
+    pub latest_finish: i64,
+    pub slack: i64,
+}
+
+impl ScheduledNode {
+    pub fn is_critical(&self) -> bool {
+        self.slack == 0
+    }
+}
+
+pub struct CriticalPathScheduler;
+
+impl CriticalPathScheduler {
+    /// Convert an hour estimate into whole days. Tasks without an estimate
+    /// still take one day so they show up on the schedule.
+    pub fn duration_days(estimated_hours: Option<f32>) -> i64 {
+        match estimated_hours {
+            Some(hours) if hours > 0.0 => (hours / HOURS_PER_DAY).ceil() as i64,
+            _ => 1,
+        }
+    }
+
+    /// Run a forward and backward pass over the dependency graph.
+    /// Results are returned in topological order; predecessors that are not
+    /// part of `nodes` are ignored.
+    pub fn schedule(nodes: &[ScheduleNode]) -> Result<Vec<ScheduledNode>, DomainError> {
+        let order = Self::topological_order(nodes)?;
+        let by_id: HashMap<Uuid, &ScheduleNode> = nodes.iter().map(|n| (n.id, n)).collect();
+
+        let mut successors: HashMap<Uuid, Vec<Uuid>> = HashMap::new();
+        for node in nodes {
+            for pred in Self::known_predecessors(node, &by_id) {
+                successors.entry(pred).or_default().push(node.id);
+            }
+        }
+
+        // Forward pass: earliest start is the latest finish of any predecessor
+        let mut earliest_finish: HashMap<Uuid, i64> = HashMap::new();
+        for id in &order {
+            let node = by_id[id];
+            let start = Self::known_predecessors(node, &by_id)
+                .map(|p| earliest_finish[&p])
+                .max()
+                .unwrap_or(0);
+            earliest_finish.insert(*id, start + node.duration_days);
+        }
+        let project_finish = earliest_finish.values().copied().max().unwrap_or(0);
+
+        // Backward pass: latest finish is the earliest latest-start of any successor
+        let mut latest_start: HashMap<Uuid, i64> = HashMap::new();
+        for id in order.iter().rev() {
+            let node = by_id[id];
+            let finish = successors
+                .get(id)
+                .into_iter()
+                .flatten()
+                .map(|s| latest_start[s])
+                .min()
+                .unwrap_or(project_finish);
+            latest_start.insert(*id, finish - node.duration_days);
+        }
+
+        Ok(order
+            .iter()
+            .map(|id| {
+                let duration = by_id[id].duration_days;
+                let earliest_finish = earliest_finish[id];
+                let latest_start = latest_start[id];
+                ScheduledNode {
+                    id: *id,
+                    earliest_start: earliest_finish - duration,
+                    earliest_finish,
+                    latest_start,
+                    latest_finish: latest_start + duration,
+                    slack: latest_start - (earliest_finish - duration),
+                }
+            })
+            .collect())
+    }
+
+    /// Order nodes so that every task comes after the tasks it depends on.
+    /// Fails if the dependencies contain a cycle.
+    pub fn topological_order(nodes: &[ScheduleNode]) -> Result<Vec<Uuid>, DomainError> {
+        let by_id: HashMap<Uuid, &ScheduleNode> = nodes.iter().map(|n| (n.id, n)).collect();
+        let mut in_degree: HashMap<Uuid, usize> = HashMap::new();
+        let mut successors: HashMap<Uuid, Vec<Uuid>> = HashMap::new();
+        for node in nodes {
+            in_degree.entry(node.id).or_insert(0);
+            for pred in Self::known_predecessors(node, &by_id) {
+                *in_degree.entry(node.id).or_insert(0) += 1;
+                successors.entry(pred).or_default().push(node.id);
+            }
+        }
+
+        // Seed in input order so the result is stable
+        let mut queue: VecDeque<Uuid> = nodes
+            .iter()
+            .filter(|n| in_degree[&n.id] == 0)
+            .map(|n| n.id)
+            .collect();
+        let mut order = Vec::with_capacity(nodes.len());
+        while let Some(id) = queue.pop_front() {
+            order.push(id);
+            for next in successors.get(&id).into_iter().flatten() {
+                let degree = in_degree.get_mut(next).expect("successor is a known node");
+                *degree -= 1;
+                if *degree == 0 {
+                    queue.push_back(*next);
+                }
+            }
+        }
+
+        if order.len() != in_degree.len() {
+            return Err(DomainError::ValidationError(
+                "Task dependencies contain a cycle".into(),
+            ));
+        }
+        Ok(order)
+    }
+
+    /// Check whether making `task_id` depend on `depends_on_id` would close a
+    /// cycle, given the existing `(task_id, depends_on_id)` edges.
+    pub fn creates_cycle(edges: &[(Uuid, Uuid)], task_id: Uuid, depends_on_id: Uuid) -> bool {
+        if task_id == depends_on_id {
+            return true;
+        }
+
+        // A cycle appears if task_id is already reachable from depends_on_id
+        let mut stack = vec![depends_on_id];
+        let mut visited = HashSet::new();
+        while let Some(current) = stack.pop() {
+            if current == task_id {
+                return true;
+            }
+            if !visited.insert(current) {
+                continue;
+            }
+            stack.extend(
+                edges
+                    .iter()
+                    .filter(|(task, _)| *task == current)
+                    .map(|(_, dep)| *dep),
+            );
+        }
+        false
+    }
+
+    fn known_predecessors<'a>(
+        node: &'a ScheduleNode,
+        by_id: &'a HashMap<Uuid, &ScheduleNode>,
+    ) -> impl Iterator<Item = Uuid> + 'a {
+        let mut seen = HashSet::new();
+        node.predecessors
+            .iter()
+            .copied()
+            .filter(move |p| *p != node.id && by_id.contains_key(p) && seen.insert(*p))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node(id: Uuid, duration_days: i64, predecessors: Vec<Uuid>) -> ScheduleNode {
+        ScheduleNode {
+            id,
+            duration_days,
+            predecessors,
+        }
+    }
+
+    fn find(result: &[ScheduledNode], id: Uuid) -> &ScheduledNode {
+        result.iter().find(|n| n.id == id).unwrap()
+    }
+
+    #[test]
+    fn test_duration_days_rounds_up_hours() {
+        assert_eq!(CriticalPathScheduler::duration_days(Some(8.0)), 1);
+        assert_eq!(CriticalPathScheduler::duration_days(Some(9.0)), 2);
+        assert_eq!(CriticalPathScheduler::duration_days(Some(0.0)), 1);
+        assert_eq!(CriticalPathScheduler::duration_days(None), 1);
+    }
+
+    #[test]
+    fn test_schedule_chain_is_fully_critical() {
+        let (a, b, c) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let result = CriticalPathScheduler::schedule(&[
+            node(c, 1, vec![b]),
+            node(b, 3, vec![a]),
+            node(a, 2, vec![]),
+        ])
+        .unwrap();
+
+        assert_eq!(
+            result.iter().map(|n| n.id).collect::<Vec<_>>(),
+            vec![a, b, c]
+        );
+        assert_eq!(find(&result, b).earliest_start, 2);
+        assert_eq!(find(&result, c).earliest_finish, 6);
+        assert!(result.iter().all(ScheduledNode::is_critical));
+    }
+
+    #[test]
+    fn test_schedule_parallel_branch_has_slack() {
+        let (start, long, short, end) = (
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+        );
+        let result = CriticalPathScheduler::schedule(&[
+            node(start, 1, vec![]),
+            node(long, 5, vec![start]),
+            node(short, 2, vec![start]),
+            node(end, 1, vec![long, short]),
+        ])
+        .unwrap();
+
+        let short = find(&result, short);
+        assert_eq!(short.earliest_start, 1);
+        assert_eq!(short.latest_start, 4);
+        assert_eq!(short.slack, 3);
+        assert!(!short.is_critical());
+        assert!(find(&result, long).is_critical());
+        assert_eq!(find(&result, end).earliest_start, 6);
+    }
+
+    #[test]
+    fn test_schedule_ignores_unknown_predecessors() {
+        let a = Uuid::new_v4();
+        let result = CriticalPathScheduler::schedule(&[node(a, 2, vec![Uuid::new_v4()])]).unwrap();
+        assert_eq!(find(&result, a).earliest_start, 0);
+    }
+
+    #[test]
+    fn test_schedule_rejects_cycle() {
+        let (a, b) = (Uuid::new_v4(), Uuid::new_v4());
+        let result = CriticalPathScheduler::schedule(&[node(a, 1, vec![b]), node(b, 1, vec![a])]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_creates_cycle() {
+        let (a, b, c) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        // b depends on a, c depends on b
+        let edges = vec![(b, a), (c, b)];
+        assert!(CriticalPathScheduler::creates_cycle(&edges, a, c));
+        assert!(CriticalPathScheduler::creates_cycle(&edges, a, a));
+        assert!(!CriticalPathScheduler::creates_cycle(&edges, c, a));
+    }
+}
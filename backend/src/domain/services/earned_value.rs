@@ -0,0 +1,224 @@
+use chrono::{Duration, NaiveDate};
+use serde::Serialize;
+
+/// A task's share of the plan: its estimate, the window over which its value
+/// is planned to be earned, and the day it was completed, if any. A task
+/// without a planned finish is unscheduled and adds nothing to planned value.
+#[derive(Debug, Clone)]
+pub struct EvmTask {
+    pub estimated_hours: f32,
+    pub planned_start: NaiveDate,
+    pub planned_finish: Option<NaiveDate>,
+    pub completed_on: Option<NaiveDate>,
+}
+
+/// Cost incurred on a given day, in the budget's unit
+#[derive(Debug, Clone, Copy)]
+pub struct EvmCost {
+    pub date: NaiveDate,
+    pub cost: f64,
+}
+
+/// Earned value metrics as of one date. Monetary values share the budget's unit.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct EvmSnapshot {
+    pub date: NaiveDate,
+    pub planned_value: f64,
+    pub earned_value: f64,
+    pub actual_cost: f64,
+    pub schedule_variance: f64,
+    pub cost_variance: f64,
+    pub spi: Option<f64>,
+    pub cpi: Option<f64>,
+    pub estimate_at_completion: Option<f64>,
+    pub estimate_to_complete: Option<f64>,
+}
+
+/// Earned value management over a budget spread across tasks by estimated
+/// hours. Value is earned on completion only (0/100 rule), and actual cost is
+/// whatever the caller priced the logged work at.
+pub struct EarnedValueCalculator {
+    budget_at_completion: f64,
+    hourly_value: f64,
+}
+
+impl EarnedValueCalculator {
+    /// Returns `None` unless both the budget and the total estimate are positive
+    pub fn new(budget_at_completion: f64, tasks: &[EvmTask]) -> Option<Self> {
+        let total_hours: f64 = tasks.iter().map(|t| t.estimated_hours as f64).sum();
+        if budget_at_completion <= 0.0 || total_hours <= 0.0 {
+            return None;
+        }
+        Some(Self {
+            budget_at_completion,
+            hourly_value: budget_at_completion / total_hours,
+        })
+    }
+
+    pub fn snapshot(&self, tasks: &[EvmTask], costs: &[EvmCost], as_of: NaiveDate) -> EvmSnapshot {
+        let planned_value: f64 = tasks
+            .iter()
+            .map(|t| self.task_value(t) * planned_fraction(t, as_of))
+            .sum();
+        let earned_value: f64 = tasks
+            .iter()
+            .filter(|t| t.completed_on.is_some_and(|done| done <= as_of))
+            .map(|t| self.task_value(t))
+            .sum();
+        let actual_cost: f64 = costs
+            .iter()
+            .filter(|c| c.date <= as_of)
+            .map(|c| c.cost)
+            .sum();
+
+        let spi = ratio(earned_value, planned_value);
+        let cpi = ratio(earned_value, actual_cost);
+        let estimate_at_completion = cpi.map(|cpi| self.budget_at_completion / cpi);
+
+        EvmSnapshot {
+            date: as_of,
+            planned_value: round(planned_value),
+            earned_value: round(earned_value),
+            actual_cost: round(actual_cost),
+            schedule_variance: round(earned_value - planned_value),
+            cost_variance: round(earned_value - actual_cost),
+            spi: spi.map(round),
+            cpi: cpi.map(round),
+            estimate_at_completion: estimate_at_completion.map(round),
+            estimate_to_complete: estimate_at_completion.map(|eac| round(eac - actual_cost)),
+        }
+    }
+
+    /// One snapshot at the end of every week from `start`, plus one at `as_of`
+    pub fn weekly_series(
+        &self,
+        tasks: &[EvmTask],
+        costs: &[EvmCost],
+        start: NaiveDate,
+        as_of: NaiveDate,
+    ) -> Vec<EvmSnapshot> {
+        let mut series = Vec::new();
+        let mut date = start + Duration::days(6);
+        while date < as_of {
+            series.push(self.snapshot(tasks, costs, date));
+            date += Duration::weeks(1);
+        }
+        if start <= as_of {
+            series.push(self.snapshot(tasks, costs, as_of));
+        }
+        series
+    }
+
+    fn task_value(&self, task: &EvmTask) -> f64 {
+        task.estimated_hours as f64 * self.hourly_value
+    }
+}
+
+/// Share of a task's value planned to be earned by the end of `as_of`
+fn planned_fraction(task: &EvmTask, as_of: NaiveDate) -> f64 {
+    let Some(planned_finish) = task.planned_finish else {
+        return 0.0;
+    };
+    if as_of >= planned_finish {
+        return 1.0;
+    }
+    if as_of < task.planned_start {
+        return 0.0;
+    }
+    let elapsed = (as_of - task.planned_start).num_days() + 1;
+    let total = (planned_finish - task.planned_start).num_days() + 1;
+    elapsed as f64 / total as f64
+}
+
+fn ratio(numerator: f64, denominator: f64) -> Option<f64> {
+    (denominator > 0.0).then(|| numerator / denominator)
+}
+
+fn round(value: f64) -> f64 {
+    (value * 100.0).round() / 100.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::test_dates::date;
+
+    fn task(hours: f32, start: u32, finish: u32, completed: Option<u32>) -> EvmTask {
+        EvmTask {
+            estimated_hours: hours,
+            planned_start: date(start),
+            planned_finish: Some(date(finish)),
+            completed_on: completed.map(date),
+        }
+    }
+
+    fn cost(day: u32, cost: f64) -> EvmCost {
+        EvmCost {
+            date: date(day),
+            cost,
+        }
+    }
+
+    #[test]
+    fn test_requires_budget_and_estimates() {
+        assert!(EarnedValueCalculator::new(0.0, &[task(8.0, 1, 10, None)]).is_none());
+        assert!(EarnedValueCalculator::new(1000.0, &[task(0.0, 1, 10, None)]).is_none());
+    }
+
+    #[test]
+    fn test_snapshot_metrics() {
+        // 1000 budget over 10 estimated hours: 100 per hour
+        let tasks = vec![task(4.0, 1, 4, Some(3)), task(6.0, 1, 10, None)];
+        let costs = vec![cost(2, 300.0), cost(3, 200.0), cost(20, 800.0)];
+        let evm = EarnedValueCalculator::new(1000.0, &tasks).unwrap();
+
+        let snapshot = evm.snapshot(&tasks, &costs, date(5));
+        // First task fully planned (400) + half of the second (300)
+        assert_eq!(snapshot.planned_value, 700.0);
+        assert_eq!(snapshot.earned_value, 400.0);
+        assert_eq!(snapshot.actual_cost, 500.0);
+        assert_eq!(snapshot.schedule_variance, -300.0);
+        assert_eq!(snapshot.cost_variance, -100.0);
+        assert_eq!(snapshot.spi, Some(0.57));
+        assert_eq!(snapshot.cpi, Some(0.8));
+        assert_eq!(snapshot.estimate_at_completion, Some(1250.0));
+        assert_eq!(snapshot.estimate_to_complete, Some(750.0));
+    }
+
+    #[test]
+    fn test_snapshot_before_any_work() {
+        let tasks = vec![task(8.0, 5, 10, None)];
+        let evm = EarnedValueCalculator::new(800.0, &tasks).unwrap();
+        let snapshot = evm.snapshot(&tasks, &[], date(1));
+
+        assert_eq!(snapshot.planned_value, 0.0);
+        assert_eq!(snapshot.spi, None);
+        assert_eq!(snapshot.cpi, None);
+        assert_eq!(snapshot.estimate_at_completion, None);
+    }
+
+    #[test]
+    fn test_unscheduled_tasks_earn_but_are_never_planned() {
+        let mut unscheduled = task(4.0, 1, 1, Some(3));
+        unscheduled.planned_finish = None;
+        let tasks = vec![task(4.0, 1, 4, None), unscheduled];
+        let evm = EarnedValueCalculator::new(800.0, &tasks).unwrap();
+        let snapshot = evm.snapshot(&tasks, &[], date(10));
+
+        assert_eq!(snapshot.planned_value, 400.0);
+        assert_eq!(snapshot.earned_value, 400.0);
+    }
+
+    #[test]
+    fn test_weekly_series_ends_at_as_of() {
+        let tasks = vec![task(8.0, 1, 20, None)];
+        let evm = EarnedValueCalculator::new(800.0, &tasks).unwrap();
+        let series = evm.weekly_series(&tasks, &[], date(1), date(17));
+
+        let dates: Vec<NaiveDate> = series.iter().map(|s| s.date).collect();
+        assert_eq!(dates, vec![date(7), date(14), date(17)]);
+        assert!(series
+            .windows(2)
+            .all(|w| w[0].planned_value <= w[1].planned_value));
+    }
+}
@@ -0,0 +1,153 @@
+pub struct MentionParser;
+
+impl MentionParser {
+    /// Extract unique `@handle` tokens from text, preserving first-seen order.
+    /// A handle may be a plain name (`@alice`, `@alice.smith`) or an email
+    /// (`@alice@example.com`). An `@` preceded by a word character is ignored
+    /// so that plain email addresses in text are not treated as mentions.
+    pub fn extract_handles(text: &str) -> Vec<String> {
+        let chars: Vec<char> = text.chars().collect();
+        let mut handles: Vec<String> = Vec::new();
+        let mut i = 0;
+
+        while i < chars.len() {
+            if chars[i] != '@' || (i > 0 && Self::is_handle_char(chars[i - 1])) {
+                i += 1;
+                continue;
+            }
+
+            let start = i + 1;
+            let mut end = start;
+            while end < chars.len() && (Self::is_handle_char(chars[end]) || chars[end] == '@') {
+                end += 1;
+            }
+
+            let handle: String = chars[start..end].iter().collect();
+            let handle = handle.trim_end_matches(['.', '-', '@']).to_string();
+            if !handle.is_empty()
+                && !handles
+                    .iter()
+                    .any(|h| h.eq_ignore_ascii_case(handle.as_str()))
+            {
+                handles.push(handle);
+            }
+            i = end.max(i + 1);
+        }
+
+        handles
+    }
+
+    /// Check whether a handle refers to a user, by email or by name.
+    /// Names are compared case-insensitively with spaces and punctuation
+    /// removed, so `@janedoe`, `@jane.doe` and `@Jane_Doe` all match "Jane Doe".
+    pub fn matches_user(handle: &str, name: &str, email: &str) -> bool {
+        if handle.contains('@') {
+            return handle.eq_ignore_ascii_case(email);
+        }
+        let normalized = Self::normalize(handle);
+        !normalized.is_empty() && normalized == Self::normalize(name)
+    }
+
+    fn is_handle_char(c: char) -> bool {
+        c.is_alphanumeric() || matches!(c, '.' | '_' | '-' | '+')
+    }
+
+    fn normalize(value: &str) -> String {
+        value
+            .chars()
+            .filter(|c| c.is_alphanumeric())
+            .flat_map(char::to_lowercase)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ============ Extract Handles Tests ============
+
+    #[test]
+    fn test_extract_single_handle() {
+        let handles = MentionParser::extract_handles("Hey @alice, please review");
+        assert_eq!(handles, vec!["alice"]);
+    }
+
+    #[test]
+    fn test_extract_email_handle() {
+        let handles = MentionParser::extract_handles("cc @dev1@pmo.local.");
+        assert_eq!(handles, vec!["dev1@pmo.local"]);
+    }
+
+    #[test]
+    fn test_extract_multiple_handles_deduplicated() {
+        let handles = MentionParser::extract_handles("@bob and @alice and @Bob again");
+        assert_eq!(handles, vec!["bob", "alice"]);
+    }
+
+    #[test]
+    fn test_plain_email_is_not_a_mention() {
+        let handles = MentionParser::extract_handles("send it to alice@example.com");
+        assert!(handles.is_empty());
+    }
+
+    #[test]
+    fn test_lone_at_sign_ignored() {
+        let handles = MentionParser::extract_handles("meet @ 5pm");
+        assert!(handles.is_empty());
+    }
+
+    #[test]
+    fn test_handle_with_punctuation_in_name() {
+        let handles = MentionParser::extract_handles("(@jane.doe)");
+        assert_eq!(handles, vec!["jane.doe"]);
+    }
+
+    // ============ Match User Tests ============
+
+    #[test]
+    fn test_matches_by_normalized_name() {
+        assert!(MentionParser::matches_user(
+            "janedoe",
+            "Jane Doe",
+            "jane@x.io"
+        ));
+        assert!(MentionParser::matches_user(
+            "Jane.Doe",
+            "Jane Doe",
+            "jane@x.io"
+        ));
+        assert!(MentionParser::matches_user(
+            "jane_doe",
+            "Jane Doe",
+            "jane@x.io"
+        ));
+    }
+
+    #[test]
+    fn test_matches_by_email_case_insensitive() {
+        assert!(MentionParser::matches_user(
+            "Jane@X.io",
+            "Jane Doe",
+            "jane@x.io"
+        ));
+    }
+
+    #[test]
+    fn test_does_not_match_partial_name() {
+        assert!(!MentionParser::matches_user(
+            "jane",
+            "Jane Doe",
+            "jane@x.io"
+        ));
+    }
+
+    #[test]
+    fn test_email_handle_does_not_match_name() {
+        assert!(!MentionParser::matches_user(
+            "janedoe@y.io",
+            "Jane Doe",
+            "jane@x.io"
+        ));
+    }
+}
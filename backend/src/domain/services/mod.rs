@@ -1,3 +1,39 @@
+mod allocation;
 mod auth_service;
+mod baseline_variance;
+mod billing;
+mod budget;
+mod burndown;
+mod critical_path;
+mod earned_value;
+mod mention_parser;
+mod portfolio_rollup;
+mod project_health;
+mod risk_matrix;
+mod status_report_renderer;
+mod team_leadership;
+mod time_log_policy;
+mod timer_rounding;
+mod utilization;
+mod work_calendar;
 
+pub use allocation::{AllocationCalculator, AllocationWeek, ProjectShare};
 pub use auth_service::AuthService;
+pub use baseline_variance::{
+    BaselineVariance, DateChange, EstimateChange, ScopeChange, VarianceCalculator,
+};
+pub use billing::{BillingCalculator, PricedEntries};
+pub use budget::{BudgetCalculator, LaborCost};
+pub use burndown::{BurndownCalculator, BurndownItem, BurndownPoint};
+pub use critical_path::{CriticalPathScheduler, ScheduleNode, ScheduledNode, HOURS_PER_DAY};
+pub use earned_value::{EarnedValueCalculator, EvmCost, EvmSnapshot, EvmTask};
+pub use mention_parser::MentionParser;
+pub use portfolio_rollup::{RagCounts, ResourceLoad, RollupCalculator, StatusCount};
+pub use project_health::{HealthAssessment, HealthCalculator, HealthFactor, HealthInputs};
+pub use risk_matrix::{RiskLevel, RiskLevelCounts, RiskMatrix, RiskMatrixCell, RISK_SCALE};
+pub use status_report_renderer::StatusReportRenderer;
+pub use team_leadership::TeamLeadership;
+pub use time_log_policy::TimeLogPolicy;
+pub use timer_rounding::{RoundingMode, TimerRounding};
+pub use utilization::{UtilizationCalculator, UtilizationWeek};
+pub use work_calendar::WorkCalendar;
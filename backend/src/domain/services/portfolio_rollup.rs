@@ -0,0 +1,177 @@
+use serde::Serialize;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::domain::entities::{Project, Task};
+use crate::domain::value_objects::{ProjectStatus, RagStatus};
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct StatusCount {
+    pub status: ProjectStatus,
+    pub count: usize,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct RagCounts {
+    pub green: usize,
+    pub amber: usize,
+    pub red: usize,
+}
+
+/// Open work assigned to one user across a set of projects
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ResourceLoad {
+    pub user_id: Uuid,
+    pub open_tasks: usize,
+    /// Estimated hours not yet logged on the open tasks
+    pub remaining_hours: f32,
+    pub projects: usize,
+}
+
+pub struct RollupCalculator;
+
+impl RollupCalculator {
+    /// Number of projects in each status, in lifecycle order, omitting empty ones
+    pub fn status_counts(projects: &[Project]) -> Vec<StatusCount> {
+        [
+            ProjectStatus::Planning,
+            ProjectStatus::Active,
+            ProjectStatus::OnHold,
+            ProjectStatus::Completed,
+            ProjectStatus::Cancelled,
+        ]
+        .into_iter()
+        .map(|status| StatusCount {
+            count: projects.iter().filter(|p| p.status == status).count(),
+            status,
+        })
+        .filter(|c| c.count > 0)
+        .collect()
+    }
+
+    pub fn rag_counts(statuses: &[RagStatus]) -> RagCounts {
+        let mut counts = RagCounts::default();
+        for status in statuses {
+            match status {
+                RagStatus::Green => counts.green += 1,
+                RagStatus::Amber => counts.amber += 1,
+                RagStatus::Red => counts.red += 1,
+            }
+        }
+        counts
+    }
+
+    /// Open assigned work per user, heaviest first
+    pub fn resource_load(tasks: &[Task]) -> Vec<ResourceLoad> {
+        let mut by_user: HashMap<Uuid, (usize, f32, Vec<Uuid>)> = HashMap::new();
+        for task in tasks.iter().filter(|t| !t.is_done()) {
+            let Some(user_id) = task.assignee_id else {
+                continue;
+            };
+            let entry = by_user.entry(user_id).or_default();
+            entry.0 += 1;
+            entry.1 +=
+                (task.estimated_hours.unwrap_or(0.0) - task.actual_hours.unwrap_or(0.0)).max(0.0);
+            if !entry.2.contains(&task.project_id) {
+                entry.2.push(task.project_id);
+            }
+        }
+
+        let mut load: Vec<ResourceLoad> = by_user
+            .into_iter()
+            .map(
+                |(user_id, (open_tasks, remaining_hours, projects))| ResourceLoad {
+                    user_id,
+                    open_tasks,
+                    remaining_hours,
+                    projects: projects.len(),
+                },
+            )
+            .collect();
+        load.sort_by(|a, b| {
+            b.remaining_hours
+                .total_cmp(&a.remaining_hours)
+                .then(b.open_tasks.cmp(&a.open_tasks))
+        });
+        load
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::{Priority, TaskStatus};
+
+    fn project(status: ProjectStatus) -> Project {
+        Project::new(
+            "Project".into(),
+            None,
+            Uuid::new_v4(),
+            Some(status),
+            None,
+            None,
+            None,
+            None,
+        )
+    }
+
+    fn task(project_id: Uuid, assignee: Option<Uuid>, estimate: f32, actual: f32) -> Task {
+        let mut task = Task::new(
+            project_id,
+            "Task".into(),
+            None,
+            Some(Priority::Medium),
+            assignee,
+            None,
+            Some(estimate),
+        );
+        task.actual_hours = Some(actual);
+        task
+    }
+
+    #[test]
+    fn test_status_counts() {
+        let projects = vec![
+            project(ProjectStatus::Active),
+            project(ProjectStatus::Active),
+            project(ProjectStatus::OnHold),
+        ];
+        assert_eq!(
+            RollupCalculator::status_counts(&projects),
+            vec![
+                StatusCount {
+                    status: ProjectStatus::Active,
+                    count: 2
+                },
+                StatusCount {
+                    status: ProjectStatus::OnHold,
+                    count: 1
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_resource_load_across_projects() {
+        let (alice, bob) = (Uuid::new_v4(), Uuid::new_v4());
+        let (first, second) = (Uuid::new_v4(), Uuid::new_v4());
+        let mut done = task(first, Some(bob), 40.0, 0.0);
+        done.status = TaskStatus::Done;
+        let tasks = vec![
+            task(first, Some(alice), 10.0, 4.0),
+            task(second, Some(alice), 8.0, 0.0),
+            task(first, Some(bob), 4.0, 6.0),
+            task(first, None, 20.0, 0.0),
+            done,
+        ];
+
+        let load = RollupCalculator::resource_load(&tasks);
+        assert_eq!(load.len(), 2);
+        assert_eq!(load[0].user_id, alice);
+        assert_eq!(load[0].remaining_hours, 14.0);
+        assert_eq!(load[0].projects, 2);
+        assert_eq!(load[1].user_id, bob);
+        assert_eq!(load[1].remaining_hours, 0.0);
+        assert_eq!(load[1].open_tasks, 1);
+    }
+}
@@ -0,0 +1,233 @@
+use serde::Serialize;
+
+use crate::domain::value_objects::RagStatus;
+
+/// Overdue share of open tasks at which a project turns amber / red
+const OVERDUE_AMBER: f64 = 0.10;
+const OVERDUE_RED: f64 = 0.25;
+/// Blocked share of open tasks at which a project turns amber / red
+const BLOCKED_AMBER: f64 = 0.10;
+const BLOCKED_RED: f64 = 0.20;
+/// Percentage points budget burn may run ahead of elapsed schedule
+const BURN_AHEAD_AMBER: f64 = 10.0;
+const BURN_AHEAD_RED: f64 = 20.0;
+
+/// Facts about a project the health model is computed from
+#[derive(Debug, Clone, Default)]
+pub struct HealthInputs {
+    pub open_tasks: usize,
+    pub overdue_tasks: usize,
+    pub blocked_tasks: usize,
+    pub overdue_milestones: usize,
+    /// Share of the budget spent, in percent
+    pub budget_used_percent: Option<f64>,
+    /// Share of the planned schedule elapsed, in percent
+    pub schedule_elapsed_percent: Option<f64>,
+    pub open_high_risks: usize,
+    pub open_critical_risks: usize,
+}
+
+/// Status of one dimension of the health model, with the reason for it
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct HealthFactor {
+    pub name: &'static str,
+    pub status: RagStatus,
+    pub detail: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct HealthAssessment {
+    /// Worst status among the factors
+    pub status: RagStatus,
+    /// 100 for a fully green project, lower for every amber or red factor
+    pub score: u8,
+    pub factors: Vec<HealthFactor>,
+}
+
+pub struct HealthCalculator;
+
+impl HealthCalculator {
+    pub fn assess(inputs: &HealthInputs) -> HealthAssessment {
+        let factors = vec![
+            schedule_factor(inputs),
+            budget_factor(inputs),
+            blocked_factor(inputs),
+            risk_factor(inputs),
+        ];
+        let status = factors
+            .iter()
+            .map(|f| f.status)
+            .max()
+            .unwrap_or(RagStatus::Green);
+        let penalty: u32 = factors
+            .iter()
+            .map(|f| match f.status {
+                RagStatus::Green => 0,
+                RagStatus::Amber => 15,
+                RagStatus::Red => 35,
+            })
+            .sum();
+
+        HealthAssessment {
+            status,
+            score: 100u32.saturating_sub(penalty) as u8,
+            factors,
+        }
+    }
+}
+
+fn schedule_factor(inputs: &HealthInputs) -> HealthFactor {
+    let overdue = ratio(inputs.overdue_tasks, inputs.open_tasks);
+    let status = if overdue >= OVERDUE_RED || inputs.overdue_milestones > 1 {
+        RagStatus::Red
+    } else if overdue >= OVERDUE_AMBER || inputs.overdue_milestones == 1 {
+        RagStatus::Amber
+    } else {
+        RagStatus::Green
+    };
+    HealthFactor {
+        name: "schedule",
+        status,
+        detail: format!(
+            "{} of {} open tasks and {} milestones overdue",
+            inputs.overdue_tasks, inputs.open_tasks, inputs.overdue_milestones
+        ),
+    }
+}
+
+fn budget_factor(inputs: &HealthInputs) -> HealthFactor {
+    let Some(used) = inputs.budget_used_percent else {
+        return HealthFactor {
+            name: "budget",
+            status: RagStatus::Green,
+            detail: "No budget set".into(),
+        };
+    };
+    let ahead = inputs
+        .schedule_elapsed_percent
+        .map(|elapsed| used - elapsed);
+    let status = if used > 100.0 || ahead.is_some_and(|a| a > BURN_AHEAD_RED) {
+        RagStatus::Red
+    } else if ahead.is_some_and(|a| a > BURN_AHEAD_AMBER) {
+        RagStatus::Amber
+    } else {
+        RagStatus::Green
+    };
+    let detail = match inputs.schedule_elapsed_percent {
+        Some(elapsed) => format!(
+            "{:.0}% of budget used with {:.0}% of schedule elapsed",
+            used, elapsed
+        ),
+        None => format!("{:.0}% of budget used", used),
+    };
+    HealthFactor {
+        name: "budget",
+        status,
+        detail,
+    }
+}
+
+fn blocked_factor(inputs: &HealthInputs) -> HealthFactor {
+    let blocked = ratio(inputs.blocked_tasks, inputs.open_tasks);
+    let status = if blocked >= BLOCKED_RED {
+        RagStatus::Red
+    } else if blocked >= BLOCKED_AMBER {
+        RagStatus::Amber
+    } else {
+        RagStatus::Green
+    };
+    HealthFactor {
+        name: "blocked_tasks",
+        status,
+        detail: format!(
+            "{} of {} open tasks blocked",
+            inputs.blocked_tasks, inputs.open_tasks
+        ),
+    }
+}
+
+fn risk_factor(inputs: &HealthInputs) -> HealthFactor {
+    let status = if inputs.open_critical_risks > 0 {
+        RagStatus::Red
+    } else if inputs.open_high_risks > 0 {
+        RagStatus::Amber
+    } else {
+        RagStatus::Green
+    };
+    HealthFactor {
+        name: "risks",
+        status,
+        detail: format!(
+            "{} critical and {} high open risks",
+            inputs.open_critical_risks, inputs.open_high_risks
+        ),
+    }
+}
+
+fn ratio(part: usize, total: usize) -> f64 {
+    if total == 0 {
+        return 0.0;
+    }
+    part as f64 / total as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_healthy_project_is_green() {
+        let assessment = HealthCalculator::assess(&HealthInputs {
+            open_tasks: 20,
+            overdue_tasks: 1,
+            budget_used_percent: Some(40.0),
+            schedule_elapsed_percent: Some(45.0),
+            ..Default::default()
+        });
+        assert_eq!(assessment.status, RagStatus::Green);
+        assert_eq!(assessment.score, 100);
+        assert_eq!(assessment.factors.len(), 4);
+    }
+
+    #[test]
+    fn test_worst_factor_wins() {
+        let assessment = HealthCalculator::assess(&HealthInputs {
+            open_tasks: 10,
+            blocked_tasks: 1,
+            open_critical_risks: 1,
+            ..Default::default()
+        });
+        assert_eq!(assessment.status, RagStatus::Red);
+        assert_eq!(assessment.score, 50);
+    }
+
+    #[test]
+    fn test_budget_burn_ahead_of_schedule() {
+        let assess = |used, elapsed| {
+            let inputs = HealthInputs {
+                budget_used_percent: Some(used),
+                schedule_elapsed_percent: elapsed,
+                ..Default::default()
+            };
+            budget_factor(&inputs).status
+        };
+        assert_eq!(assess(55.0, Some(50.0)), RagStatus::Green);
+        assert_eq!(assess(65.0, Some(50.0)), RagStatus::Amber);
+        assert_eq!(assess(75.0, Some(50.0)), RagStatus::Red);
+        assert_eq!(assess(101.0, None), RagStatus::Red);
+    }
+
+    #[test]
+    fn test_overdue_milestones() {
+        let status = |overdue_milestones| {
+            schedule_factor(&HealthInputs {
+                overdue_milestones,
+                ..Default::default()
+            })
+            .status
+        };
+        assert_eq!(status(0), RagStatus::Green);
+        assert_eq!(status(1), RagStatus::Amber);
+        assert_eq!(status(2), RagStatus::Red);
+    }
+}
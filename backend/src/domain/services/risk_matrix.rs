@@ -0,0 +1,152 @@
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::domain::entities::RaidItem;
+
+/// Upper bound of the probability and impact scales
+pub const RISK_SCALE: i16 = 5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RiskLevel {
+    Low,
+    Medium,
+    High,
+    Critical,
+}
+
+impl RiskLevel {
+    /// Level of a probability x impact score on the 5x5 scale
+    pub fn from_score(score: i16) -> Self {
+        match score {
+            ..=4 => RiskLevel::Low,
+            5..=9 => RiskLevel::Medium,
+            10..=16 => RiskLevel::High,
+            _ => RiskLevel::Critical,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RiskMatrixCell {
+    pub probability: i16,
+    pub impact: i16,
+    pub score: i16,
+    pub level: RiskLevel,
+    pub risk_ids: Vec<Uuid>,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct RiskLevelCounts {
+    pub low: usize,
+    pub medium: usize,
+    pub high: usize,
+    pub critical: usize,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RiskMatrix {
+    /// Every probability/impact combination, highest probability first
+    pub cells: Vec<RiskMatrixCell>,
+    pub by_level: RiskLevelCounts,
+    /// Open risks without both scores set
+    pub unscored: Vec<Uuid>,
+}
+
+impl RiskMatrix {
+    /// Place the open risks among `items` on the probability/impact grid
+    pub fn build(items: &[RaidItem]) -> Self {
+        let mut cells = Vec::new();
+        for probability in (1..=RISK_SCALE).rev() {
+            for impact in 1..=RISK_SCALE {
+                let score = probability * impact;
+                cells.push(RiskMatrixCell {
+                    probability,
+                    impact,
+                    score,
+                    level: RiskLevel::from_score(score),
+                    risk_ids: Vec::new(),
+                });
+            }
+        }
+
+        let mut by_level = RiskLevelCounts::default();
+        let mut unscored = Vec::new();
+        for item in items.iter().filter(|i| i.is_risk() && i.is_open()) {
+            let (Some(probability), Some(impact)) = (item.probability, item.impact) else {
+                unscored.push(item.id);
+                continue;
+            };
+            let index = ((RISK_SCALE - probability) * RISK_SCALE + impact - 1) as usize;
+            let Some(cell) = cells.get_mut(index) else {
+                unscored.push(item.id);
+                continue;
+            };
+            cell.risk_ids.push(item.id);
+            match cell.level {
+                RiskLevel::Low => by_level.low += 1,
+                RiskLevel::Medium => by_level.medium += 1,
+                RiskLevel::High => by_level.high += 1,
+                RiskLevel::Critical => by_level.critical += 1,
+            }
+        }
+
+        Self {
+            cells,
+            by_level,
+            unscored,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::{RaidStatus, RaidType};
+
+    fn item(item_type: RaidType, scores: Option<(i16, i16)>) -> RaidItem {
+        let mut item = RaidItem::new(
+            Uuid::new_v4(),
+            item_type,
+            "Item".into(),
+            None,
+            None,
+            Uuid::new_v4(),
+        );
+        item.probability = scores.map(|s| s.0);
+        item.impact = scores.map(|s| s.1);
+        item
+    }
+
+    #[test]
+    fn test_risk_level_from_score() {
+        assert_eq!(RiskLevel::from_score(1), RiskLevel::Low);
+        assert_eq!(RiskLevel::from_score(6), RiskLevel::Medium);
+        assert_eq!(RiskLevel::from_score(12), RiskLevel::High);
+        assert_eq!(RiskLevel::from_score(25), RiskLevel::Critical);
+    }
+
+    #[test]
+    fn test_build_places_open_risks() {
+        let high = item(RaidType::Risk, Some((4, 3)));
+        let low = item(RaidType::Risk, Some((1, 2)));
+        let unscored = item(RaidType::Risk, None);
+        let mut closed = item(RaidType::Risk, Some((5, 5)));
+        closed.status = RaidStatus::Closed;
+        let issue = item(RaidType::Issue, None);
+
+        let matrix =
+            RiskMatrix::build(&[high.clone(), low.clone(), unscored.clone(), closed, issue]);
+        assert_eq!(matrix.cells.len(), 25);
+        let cell = matrix
+            .cells
+            .iter()
+            .find(|c| c.probability == 4 && c.impact == 3)
+            .unwrap();
+        assert_eq!(cell.risk_ids, vec![high.id]);
+        assert_eq!(matrix.by_level.high, 1);
+        assert_eq!(matrix.by_level.low, 1);
+        assert_eq!(matrix.by_level.critical, 0);
+        assert_eq!(matrix.unscored, vec![unscored.id]);
+    }
+}
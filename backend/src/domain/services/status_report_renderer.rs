@@ -0,0 +1,240 @@
+use std::fmt::Write;
+
+use crate::domain::entities::{ReportMilestone, ReportRisk, ReportTask, StatusReport};
+
+/// Renders saved status reports for reading and emailing
+pub struct StatusReportRenderer;
+
+impl StatusReportRenderer {
+    pub fn markdown(report: &StatusReport) -> String {
+        let content = &report.content;
+        let mut out = String::new();
+        let _ = writeln!(out, "# Status report: {}", content.project_name);
+        let _ = writeln!(
+            out,
+            "\n**Period:** {} to {}",
+            report.period_start, report.period_end
+        );
+
+        out.push_str("\n## Highlights\n\n");
+        match report.highlights.as_deref().map(str::trim) {
+            Some(highlights) if !highlights.is_empty() => {
+                let _ = writeln!(out, "{}", highlights);
+            }
+            _ => out.push_str("_No highlights._\n"),
+        }
+
+        out.push_str("\n## Summary\n\n");
+        for line in summary_lines(report) {
+            let _ = writeln!(out, "- {}", line);
+        }
+
+        for (heading, tasks) in task_sections(report) {
+            let _ = writeln!(out, "\n## {} ({})\n", heading, tasks.len());
+            if tasks.is_empty() {
+                out.push_str("_None._\n");
+            }
+            for task in tasks {
+                let _ = writeln!(out, "- {}", task.title);
+            }
+        }
+
+        out.push_str("\n## Upcoming milestones\n\n");
+        if content.upcoming_milestones.is_empty() {
+            out.push_str("_None._\n");
+        }
+        for milestone in &content.upcoming_milestones {
+            let _ = writeln!(out, "- {}", milestone_line(milestone));
+        }
+
+        out.push_str("\n## Top risks\n\n");
+        if content.top_risks.is_empty() {
+            out.push_str("_No open risks._\n");
+        }
+        for risk in &content.top_risks {
+            let _ = writeln!(out, "- {}", risk_line(risk));
+        }
+        out
+    }
+
+    /// A standalone HTML document; all report text is escaped
+    pub fn html(report: &StatusReport) -> String {
+        let content = &report.content;
+        let mut out = String::from("<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n");
+        let _ = writeln!(
+            out,
+            "<title>Status report: {}</title>\n</head>\n<body>",
+            escape(&content.project_name)
+        );
+        let _ = writeln!(
+            out,
+            "<h1>Status report: {}</h1>\n<p><strong>Period:</strong> {} to {}</p>",
+            escape(&content.project_name),
+            report.period_start,
+            report.period_end
+        );
+
+        out.push_str("<h2>Highlights</h2>\n");
+        match report.highlights.as_deref().map(str::trim) {
+            Some(highlights) if !highlights.is_empty() => {
+                for paragraph in highlights.split("\n\n") {
+                    let _ = writeln!(
+                        out,
+                        "<p>{}</p>",
+                        escape(paragraph.trim()).replace('\n', "<br>")
+                    );
+                }
+            }
+            _ => out.push_str("<p><em>No highlights.</em></p>\n"),
+        }
+
+        out.push_str("<h2>Summary</h2>\n");
+        html_list(&mut out, summary_lines(report), "");
+
+        for (heading, tasks) in task_sections(report) {
+            let _ = writeln!(out, "<h2>{} ({})</h2>", heading, tasks.len());
+            html_list(&mut out, tasks.iter().map(|t| t.title.clone()), "None.");
+        }
+
+        out.push_str("<h2>Upcoming milestones</h2>\n");
+        html_list(
+            &mut out,
+            content.upcoming_milestones.iter().map(milestone_line),
+            "None.",
+        );
+
+        out.push_str("<h2>Top risks</h2>\n");
+        html_list(
+            &mut out,
+            content.top_risks.iter().map(risk_line),
+            "No open risks.",
+        );
+
+        out.push_str("</body>\n</html>\n");
+        out
+    }
+}
+
+fn summary_lines(report: &StatusReport) -> Vec<String> {
+    let content = &report.content;
+    let mut lines = vec![format!("Hours logged: {:.1}", content.hours_logged)];
+    lines.push(match &content.budget {
+        Some(budget) => match budget.percent_used {
+            Some(percent) => format!(
+                "Budget: {} spent of {} ({}%), {} remaining",
+                budget.spent, budget.budget, percent, budget.remaining
+            ),
+            None => format!("Budget: {} spent of {}", budget.spent, budget.budget),
+        },
+        None => "Budget: not set".to_string(),
+    });
+    lines
+}
+
+fn task_sections(report: &StatusReport) -> [(&'static str, &[ReportTask]); 3] {
+    let content = &report.content;
+    [
+        ("Completed tasks", &content.tasks_completed),
+        ("Started tasks", &content.tasks_started),
+        ("Newly blocked tasks", &content.newly_blocked),
+    ]
+}
+
+fn milestone_line(milestone: &ReportMilestone) -> String {
+    let due = milestone.due_date.map_or("no due date".to_string(), |d| {
+        format!("due {}", d.date_naive())
+    });
+    if milestone.overdue {
+        format!("{} ({}, overdue)", milestone.name, due)
+    } else {
+        format!("{} ({})", milestone.name, due)
+    }
+}
+
+fn risk_line(risk: &ReportRisk) -> String {
+    match (risk.probability, risk.impact, risk.score) {
+        (Some(probability), Some(impact), Some(score)) => format!(
+            "{} (probability {}, impact {}, score {})",
+            risk.title, probability, impact, score
+        ),
+        _ => format!("{} (not scored)", risk.title),
+    }
+}
+
+fn html_list(out: &mut String, items: impl IntoIterator<Item = String>, empty: &str) {
+    let items: Vec<String> = items.into_iter().collect();
+    if items.is_empty() {
+        let _ = writeln!(out, "<p><em>{}</em></p>", empty);
+        return;
+    }
+    out.push_str("<ul>\n");
+    for item in items {
+        let _ = writeln!(out, "<li>{}</li>", escape(&item));
+    }
+    out.push_str("</ul>\n");
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::StatusReportContent;
+    use crate::shared::test_dates::date;
+    use uuid::Uuid;
+
+    fn report() -> StatusReport {
+        let task = |title: &str| ReportTask {
+            id: Uuid::new_v4(),
+            title: title.into(),
+            assignee_id: None,
+        };
+        StatusReport::new(
+            Uuid::new_v4(),
+            date(4),
+            date(8),
+            Some("Shipped <beta> & onboarded pilot".into()),
+            StatusReportContent {
+                project_name: "Website".into(),
+                tasks_completed: vec![task("Login page"), task("Signup page")],
+                newly_blocked: vec![task("Payment provider")],
+                hours_logged: 42.5,
+                ..Default::default()
+            },
+            Uuid::new_v4(),
+        )
+    }
+
+    #[test]
+    fn test_markdown_sections() {
+        let markdown = StatusReportRenderer::markdown(&report());
+        assert!(markdown.starts_with("# Status report: Website\n"));
+        assert!(markdown.contains("**Period:** 2024-03-04 to 2024-03-08"));
+        assert!(markdown.contains("## Completed tasks (2)\n\n- Login page\n- Signup page\n"));
+        assert!(markdown.contains("## Started tasks (0)\n\n_None._"));
+        assert!(markdown.contains("- Hours logged: 42.5"));
+        assert!(markdown.contains("- Budget: not set"));
+        assert!(markdown.contains("_No open risks._"));
+    }
+
+    #[test]
+    fn test_html_escapes_text() {
+        let html = StatusReportRenderer::html(&report());
+        assert!(html.contains("<p>Shipped &lt;beta&gt; &amp; onboarded pilot</p>"));
+        assert!(html.contains("<h2>Newly blocked tasks (1)</h2>\n<ul>\n<li>Payment provider</li>"));
+        assert!(html.ends_with("</html>\n"));
+    }
+}
@@ -0,0 +1,122 @@
+use uuid::Uuid;
+
+use crate::domain::entities::{Team, TeamMember};
+use crate::domain::value_objects::TeamMemberRole;
+use crate::shared::DomainError;
+
+/// Keeps every team led while member roles change
+pub struct TeamLeadership;
+
+impl TeamLeadership {
+    /// Give `members[index]` a new role, keeping `lead_id` on one of the
+    /// team's leads. Returns every member whose role changed.
+    pub fn change_role(
+        team: &mut Team,
+        members: &mut [TeamMember],
+        index: usize,
+        role: TeamMemberRole,
+        transfer: bool,
+    ) -> Result<Vec<TeamMember>, DomainError> {
+        let user_id = members[index].user_id;
+        let mut changed = vec![];
+        match role {
+            TeamMemberRole::Lead if transfer => {
+                if let Some(previous) = team.lead_id.filter(|id| *id != user_id) {
+                    if let Some(lead) = members.iter_mut().find(|m| m.user_id == previous) {
+                        lead.demote_to_member();
+                        changed.push(lead.clone());
+                    }
+                }
+                team.set_lead(Some(user_id));
+            }
+            TeamMemberRole::Lead => {
+                if team.lead_id.is_none() {
+                    team.set_lead(Some(user_id));
+                }
+            }
+            TeamMemberRole::Member if transfer => {
+                return Err(DomainError::ValidationError(
+                    "Leadership can only be transferred to a lead".into(),
+                ));
+            }
+            TeamMemberRole::Member => Self::step_down(team, members, user_id)?,
+        }
+
+        let member = &mut members[index];
+        match role {
+            TeamMemberRole::Lead => member.promote_to_lead(),
+            TeamMemberRole::Member => member.demote_to_member(),
+        }
+        changed.push(member.clone());
+        Ok(changed)
+    }
+
+    /// Move `lead_id` off a user who stops leading the team, refusing to
+    /// leave the team without a lead
+    pub fn step_down(
+        team: &mut Team,
+        members: &[TeamMember],
+        user_id: Uuid,
+    ) -> Result<(), DomainError> {
+        if !team.is_led_by(members, user_id) {
+            return Ok(());
+        }
+
+        let successor = team.successor(members, user_id).ok_or_else(|| {
+            DomainError::Conflict(
+                "A team must keep a lead, promote or transfer to another member first".into(),
+            )
+        })?;
+        if team.lead_id == Some(user_id) {
+            team.set_lead(Some(successor));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn team_with(lead: Uuid, member: Uuid) -> (Team, Vec<TeamMember>) {
+        let team = Team::new("Core".into(), None, Some(lead));
+        let members = vec![
+            TeamMember::new(team.id, lead, Some(TeamMemberRole::Lead)),
+            TeamMember::new(team.id, member, None),
+        ];
+        (team, members)
+    }
+
+    #[test]
+    fn test_transfer_demotes_previous_lead() {
+        let (lead, member) = (Uuid::new_v4(), Uuid::new_v4());
+        let (mut team, mut members) = team_with(lead, member);
+
+        let changed =
+            TeamLeadership::change_role(&mut team, &mut members, 1, TeamMemberRole::Lead, true)
+                .unwrap();
+
+        assert_eq!(team.lead_id, Some(member));
+        assert_eq!(changed.len(), 2);
+        assert!(!members[0].is_lead());
+        assert!(members[1].is_lead());
+    }
+
+    #[test]
+    fn test_last_lead_cannot_step_down() {
+        let (lead, member) = (Uuid::new_v4(), Uuid::new_v4());
+        let (mut team, mut members) = team_with(lead, member);
+
+        let result =
+            TeamLeadership::change_role(&mut team, &mut members, 0, TeamMemberRole::Member, false);
+        assert!(matches!(result, Err(DomainError::Conflict(_))));
+        assert!(matches!(
+            TeamLeadership::step_down(&mut team, &members, lead),
+            Err(DomainError::Conflict(_))
+        ));
+
+        members[1].promote_to_lead();
+        TeamLeadership::step_down(&mut team, &members, lead).unwrap();
+        assert_eq!(team.lead_id, Some(member));
+    }
+}
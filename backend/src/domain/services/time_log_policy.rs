@@ -0,0 +1,166 @@
+use chrono::NaiveDate;
+
+use crate::shared::FieldError;
+
+/// Rules every time log must follow
+#[derive(Debug, Clone, Copy)]
+pub struct TimeLogPolicy {
+    /// Hours must be a multiple of this many minutes; 0 allows any amount
+    pub increment_minutes: u32,
+    /// Most hours a user may log on a single day
+    pub daily_cap_hours: f32,
+}
+
+impl TimeLogPolicy {
+    /// Check hours and date of a log, given the hours the user already has
+    /// on that date in other logs
+    pub fn check(
+        &self,
+        hours: f32,
+        date: NaiveDate,
+        today: NaiveDate,
+        other_hours_on_date: f32,
+    ) -> Vec<FieldError> {
+        let mut errors = Vec::new();
+
+        if !hours.is_finite() || hours <= 0.0 {
+            errors.push(FieldError::new(
+                "hours",
+                "not_positive",
+                "Hours must be greater than 0",
+            ));
+        } else if !self.is_whole_increment(hours) {
+            errors.push(FieldError::new(
+                "hours",
+                "invalid_increment",
+                format!(
+                    "Hours must be logged in steps of {} minutes",
+                    self.increment_minutes
+                ),
+            ));
+        }
+
+        errors.extend(self.check_daily_cap(hours, date, other_hours_on_date));
+
+        if date > today {
+            errors.push(FieldError::new(
+                "date",
+                "future_date",
+                "Time can't be logged on a future date",
+            ));
+        }
+
+        errors
+    }
+
+    /// Check a log against the daily cap, given the hours the user already
+    /// has on that date in other logs
+    pub fn check_daily_cap(
+        &self,
+        hours: f32,
+        date: NaiveDate,
+        other_hours_on_date: f32,
+    ) -> Option<FieldError> {
+        (hours.is_finite() && other_hours_on_date + hours > self.daily_cap_hours + f32::EPSILON)
+            .then(|| {
+                FieldError::new(
+                    "hours",
+                    "daily_cap_exceeded",
+                    format!(
+                        "At most {} hours can be logged per day; {} are already logged on {}",
+                        self.daily_cap_hours, other_hours_on_date, date
+                    ),
+                )
+            })
+    }
+
+    /// Check minutes a timer is rounded to, which must be a whole number of
+    /// increments for the rounded hours to be loggable
+    pub fn check_rounding(&self, round_to_minutes: u32) -> Option<FieldError> {
+        let whole = self.increment_minutes == 0
+            || (round_to_minutes > 0 && round_to_minutes.is_multiple_of(self.increment_minutes));
+        (!whole).then(|| {
+            FieldError::new(
+                "round_to_minutes",
+                "invalid_increment",
+                format!(
+                    "Timers can only round to multiples of {} minutes",
+                    self.increment_minutes
+                ),
+            )
+        })
+    }
+
+    fn is_whole_increment(&self, hours: f32) -> bool {
+        if self.increment_minutes == 0 {
+            return true;
+        }
+        let steps = f64::from(hours) * 60.0 / f64::from(self.increment_minutes);
+        (steps - steps.round()).abs() < 1e-3
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::test_dates::date;
+
+    const POLICY: TimeLogPolicy = TimeLogPolicy {
+        increment_minutes: 15,
+        daily_cap_hours: 10.0,
+    };
+
+    fn codes(errors: &[FieldError]) -> Vec<&'static str> {
+        errors.iter().map(|e| e.code).collect()
+    }
+
+    #[test]
+    fn test_hours_rules() {
+        assert!(POLICY.check(1.75, date(5), date(5), 0.0).is_empty());
+        assert_eq!(
+            codes(&POLICY.check(0.0, date(5), date(5), 0.0)),
+            ["not_positive"]
+        );
+        assert_eq!(
+            codes(&POLICY.check(-2.0, date(5), date(5), 0.0)),
+            ["not_positive"]
+        );
+        assert_eq!(
+            codes(&POLICY.check(1.1, date(5), date(5), 0.0)),
+            ["invalid_increment"]
+        );
+
+        let any = TimeLogPolicy {
+            increment_minutes: 0,
+            ..POLICY
+        };
+        assert!(any.check(1.1, date(5), date(5), 0.0).is_empty());
+    }
+
+    #[test]
+    fn test_rounding_must_be_whole_increments() {
+        assert!(POLICY.check_rounding(15).is_none());
+        assert!(POLICY.check_rounding(60).is_none());
+        assert!(POLICY.check_rounding(10).is_some());
+        assert!(POLICY.check_rounding(0).is_some());
+
+        let any = TimeLogPolicy {
+            increment_minutes: 0,
+            ..POLICY
+        };
+        assert!(any.check_rounding(10).is_none());
+    }
+
+    #[test]
+    fn test_daily_cap_and_future_dates() {
+        assert!(POLICY.check(2.0, date(5), date(5), 8.0).is_empty());
+        assert_eq!(
+            codes(&POLICY.check(2.25, date(5), date(5), 8.0)),
+            ["daily_cap_exceeded"]
+        );
+        assert_eq!(
+            codes(&POLICY.check(500.0, date(6), date(5), 0.0)),
+            ["daily_cap_exceeded", "future_date"]
+        );
+    }
+}
@@ -0,0 +1,63 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RoundingMode {
+    #[default]
+    Nearest,
+    Up,
+    Down,
+}
+
+/// Converts tracked seconds to loggable hours
+pub struct TimerRounding;
+
+impl TimerRounding {
+    /// Hours for `seconds` of tracked time. With an increment the result is a
+    /// multiple of it, otherwise it is rounded to the nearest hundredth.
+    pub fn hours(seconds: i64, increment_minutes: Option<u32>, mode: RoundingMode) -> f32 {
+        let seconds = seconds.max(0) as f64;
+        let hours = match increment_minutes.filter(|&m| m > 0) {
+            Some(minutes) => {
+                let step = f64::from(minutes) * 60.0;
+                let units = seconds / step;
+                let units = match mode {
+                    RoundingMode::Nearest => units.round(),
+                    RoundingMode::Up => units.ceil(),
+                    RoundingMode::Down => units.floor(),
+                };
+                units * step / 3600.0
+            }
+            None => (seconds / 36.0).round() / 100.0,
+        };
+        hours as f32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hours_without_increment() {
+        assert_eq!(TimerRounding::hours(5400, None, RoundingMode::Up), 1.5);
+        assert_eq!(TimerRounding::hours(100, None, RoundingMode::Nearest), 0.03);
+        assert_eq!(TimerRounding::hours(-10, None, RoundingMode::Nearest), 0.0);
+    }
+
+    #[test]
+    fn test_hours_with_increment() {
+        // 50 minutes in 15-minute steps
+        assert_eq!(
+            TimerRounding::hours(3000, Some(15), RoundingMode::Nearest),
+            0.75
+        );
+        assert_eq!(TimerRounding::hours(3000, Some(15), RoundingMode::Up), 1.0);
+        assert_eq!(
+            TimerRounding::hours(3000, Some(15), RoundingMode::Down),
+            0.75
+        );
+        assert_eq!(TimerRounding::hours(240, Some(6), RoundingMode::Down), 0.0);
+        assert_eq!(TimerRounding::hours(240, Some(6), RoundingMode::Up), 0.1);
+    }
+}
@@ -0,0 +1,239 @@
+use chrono::{Datelike, Duration, NaiveDate};
+use serde::Serialize;
+
+use crate::domain::entities::{DailyHours, Task};
+use crate::domain::services::WorkCalendar;
+
+/// Capacity against logged and planned hours over part of a calendar week
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct UtilizationWeek {
+    pub week_start: NaiveDate,
+    pub week_end: NaiveDate,
+    pub capacity_hours: f32,
+    pub logged_hours: f32,
+    pub planned_hours: f32,
+    /// Logged hours as a percentage of capacity; absent without capacity
+    pub utilization_percent: Option<f32>,
+    /// Planned hours as a percentage of capacity; absent without capacity
+    pub planned_percent: Option<f32>,
+}
+
+impl UtilizationWeek {
+    fn empty(week_start: NaiveDate, week_end: NaiveDate) -> Self {
+        Self {
+            week_start,
+            week_end,
+            capacity_hours: 0.0,
+            logged_hours: 0.0,
+            planned_hours: 0.0,
+            utilization_percent: None,
+            planned_percent: None,
+        }
+    }
+
+    fn add(&mut self, other: &UtilizationWeek) {
+        self.capacity_hours += other.capacity_hours;
+        self.logged_hours += other.logged_hours;
+        self.planned_hours += other.planned_hours;
+    }
+
+    fn with_percentages(mut self) -> Self {
+        let percent = |hours: f32| {
+            (self.capacity_hours > 0.0)
+                .then(|| (hours / self.capacity_hours * 1000.0).round() / 10.0)
+        };
+        self.utilization_percent = percent(self.logged_hours);
+        self.planned_percent = percent(self.planned_hours);
+        self
+    }
+}
+
+/// Buckets capacity, logged time and planned work into calendar weeks
+/// (Monday to Sunday) clipped to a date range
+pub struct UtilizationCalculator {
+    start: NaiveDate,
+    end: NaiveDate,
+    today: NaiveDate,
+}
+
+impl UtilizationCalculator {
+    pub fn new(start: NaiveDate, end: NaiveDate, today: NaiveDate) -> Self {
+        Self { start, end, today }
+    }
+
+    /// Weekly buckets of one user.
+    ///
+    /// `logged` and `tasks` must belong to the user. The remaining estimate of
+    /// each open task is planned evenly over the user's working days from today
+    /// through its due date; overdue or undated work is planned for today.
+    /// Holidays and time off in `calendar` have no capacity and get no work.
+    pub fn user_weeks(
+        &self,
+        calendar: &WorkCalendar,
+        logged: &[DailyHours],
+        tasks: &[Task],
+    ) -> Vec<UtilizationWeek> {
+        let mut weeks = self.empty_weeks();
+
+        for date in days(self.start, self.end) {
+            weeks[self.week_index(date)].capacity_hours += calendar.hours_on(date);
+        }
+        for entry in logged.iter().filter(|l| self.contains(l.date)) {
+            weeks[self.week_index(entry.date)].logged_hours += entry.hours;
+        }
+        for task in tasks.iter().filter(|t| !t.is_done()) {
+            let remaining =
+                (task.estimated_hours.unwrap_or(0.0) - task.actual_hours.unwrap_or(0.0)).max(0.0);
+            if remaining == 0.0 {
+                continue;
+            }
+            let due = task
+                .due_date
+                .map(|d| d.date_naive())
+                .filter(|d| *d > self.today)
+                .unwrap_or(self.today);
+            let mut working: Vec<NaiveDate> = days(self.today, due)
+                .filter(|d| calendar.is_working_day(*d))
+                .collect();
+            if working.is_empty() {
+                working.push(due);
+            }
+            let per_day = remaining / working.len() as f32;
+            for date in working.into_iter().filter(|d| self.contains(*d)) {
+                weeks[self.week_index(date)].planned_hours += per_day;
+            }
+        }
+
+        weeks.into_iter().map(|w| w.with_percentages()).collect()
+    }
+
+    /// Week by week sum of several users' buckets
+    pub fn combine(&self, members: &[Vec<UtilizationWeek>]) -> Vec<UtilizationWeek> {
+        let mut weeks = self.empty_weeks();
+        for member in members {
+            for (week, other) in weeks.iter_mut().zip(member) {
+                week.add(other);
+            }
+        }
+        weeks.into_iter().map(|w| w.with_percentages()).collect()
+    }
+
+    /// The whole range as a single bucket
+    pub fn total(&self, weeks: &[UtilizationWeek]) -> UtilizationWeek {
+        let mut total = UtilizationWeek::empty(self.start, self.end);
+        for week in weeks {
+            total.add(week);
+        }
+        total.with_percentages()
+    }
+
+    fn empty_weeks(&self) -> Vec<UtilizationWeek> {
+        let mut weeks = Vec::new();
+        let mut start = self.start;
+        while start <= self.end {
+            let sunday = monday_of(start) + Duration::days(6);
+            let end = sunday.min(self.end);
+            weeks.push(UtilizationWeek::empty(start, end));
+            start = sunday + Duration::days(1);
+        }
+        weeks
+    }
+
+    fn week_index(&self, date: NaiveDate) -> usize {
+        ((monday_of(date) - monday_of(self.start)).num_days() / 7) as usize
+    }
+
+    fn contains(&self, date: NaiveDate) -> bool {
+        date >= self.start && date <= self.end
+    }
+}
+
+fn monday_of(date: NaiveDate) -> NaiveDate {
+    date - Duration::days(date.weekday().num_days_from_monday() as i64)
+}
+
+fn days(start: NaiveDate, end: NaiveDate) -> impl Iterator<Item = NaiveDate> {
+    start.iter_days().take_while(move |d| *d <= end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::UserCapacity;
+    use crate::shared::test_dates::date;
+    use chrono::{TimeZone, Utc};
+    use uuid::Uuid;
+
+    #[test]
+    fn test_weeks_are_clipped_to_range() {
+        // Wednesday 2024-03-06 to Tuesday 2024-03-19
+        let calc = UtilizationCalculator::new(date(6), date(19), date(4));
+        let capacity = UserCapacity::new(Uuid::new_v4(), 40.0, None);
+        let weeks = calc.user_weeks(&WorkCalendar::for_capacity(&capacity, []), &[], &[]);
+
+        let bounds: Vec<_> = weeks.iter().map(|w| (w.week_start, w.week_end)).collect();
+        assert_eq!(
+            bounds,
+            vec![
+                (date(6), date(10)),
+                (date(11), date(17)),
+                (date(18), date(19))
+            ]
+        );
+        let capacity: Vec<_> = weeks.iter().map(|w| w.capacity_hours).collect();
+        assert_eq!(capacity, vec![24.0, 40.0, 16.0]);
+    }
+
+    #[test]
+    fn test_logged_and_planned_hours() {
+        let user_id = Uuid::new_v4();
+        let calc = UtilizationCalculator::new(date(4), date(17), date(11));
+        let capacity = UserCapacity::new(user_id, 40.0, None);
+        let calendar = WorkCalendar::for_capacity(&capacity, []);
+        let logged = vec![
+            DailyHours {
+                user_id,
+                date: date(5),
+                hours: 30.0,
+            },
+            DailyHours {
+                user_id,
+                date: date(8),
+                hours: 14.0,
+            },
+        ];
+        // 12 hours left, due Wednesday: planned over Mon-Wed of week two
+        let mut task = Task::new(
+            Uuid::new_v4(),
+            "Build".into(),
+            None,
+            None,
+            Some(user_id),
+            Some(Utc.with_ymd_and_hms(2024, 3, 13, 17, 0, 0).unwrap()),
+            Some(14.0),
+        );
+        task.actual_hours = Some(2.0);
+
+        let weeks = calc.user_weeks(&calendar, &logged, &[task.clone()]);
+        assert_eq!(weeks[0].logged_hours, 44.0);
+        assert_eq!(weeks[0].utilization_percent, Some(110.0));
+        assert_eq!(weeks[0].planned_hours, 0.0);
+        assert_eq!(weeks[1].planned_hours, 12.0);
+        assert_eq!(weeks[1].planned_percent, Some(30.0));
+
+        let team = calc.combine(&[weeks.clone(), weeks.clone()]);
+        assert_eq!(team[0].capacity_hours, 80.0);
+        assert_eq!(team[0].utilization_percent, Some(110.0));
+
+        let total = calc.total(&weeks);
+        assert_eq!(total.capacity_hours, 80.0);
+        assert_eq!(total.utilization_percent, Some(55.0));
+
+        // A day off on Tuesday moves its share of the work to Monday and Wednesday
+        let calendar = WorkCalendar::for_capacity(&capacity, [date(12)]);
+        let weeks = calc.user_weeks(&calendar, &logged, &[task]);
+        assert_eq!(weeks[1].capacity_hours, 32.0);
+        assert_eq!(weeks[1].planned_hours, 12.0);
+        assert_eq!(weeks[1].planned_percent, Some(37.5));
+    }
+}
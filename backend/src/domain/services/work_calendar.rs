@@ -0,0 +1,104 @@
+use std::collections::HashSet;
+
+use chrono::{Datelike, NaiveDate};
+
+use crate::domain::entities::UserCapacity;
+
+/// Working hours per weekday (Monday first) less holidays and time off
+#[derive(Debug, Clone)]
+pub struct WorkCalendar {
+    week: [f32; 7],
+    days_off: HashSet<NaiveDate>,
+}
+
+impl WorkCalendar {
+    pub fn new(week: [f32; 7], days_off: impl IntoIterator<Item = NaiveDate>) -> Self {
+        Self {
+            week,
+            days_off: days_off.into_iter().collect(),
+        }
+    }
+
+    pub fn for_capacity(
+        capacity: &UserCapacity,
+        days_off: impl IntoIterator<Item = NaiveDate>,
+    ) -> Self {
+        Self::new(
+            std::array::from_fn(|weekday| capacity.weekday_hours(weekday)),
+            days_off,
+        )
+    }
+
+    /// Monday to Friday, as project schedules are planned
+    pub fn weekdays(days_off: impl IntoIterator<Item = NaiveDate>) -> Self {
+        Self::new([1.0, 1.0, 1.0, 1.0, 1.0, 0.0, 0.0], days_off)
+    }
+
+    /// Hours of a week without days off
+    pub fn weekly_hours(&self) -> f32 {
+        self.week.iter().sum()
+    }
+
+    pub fn hours_on(&self, date: NaiveDate) -> f32 {
+        if self.days_off.contains(&date) {
+            return 0.0;
+        }
+        self.week[date.weekday().num_days_from_monday() as usize]
+    }
+
+    pub fn is_working_day(&self, date: NaiveDate) -> bool {
+        self.hours_on(date) > 0.0
+    }
+
+    /// The working day `offset` working days after the first one on or after
+    /// `start`. Without any working weekday, days are counted as they come.
+    pub fn working_day(&self, start: NaiveDate, offset: i64) -> NaiveDate {
+        if self.week.iter().all(|hours| *hours <= 0.0) {
+            return start + chrono::Duration::days(offset.max(0));
+        }
+        start
+            .iter_days()
+            .filter(|d| self.is_working_day(*d))
+            .nth(offset.max(0) as usize)
+            .unwrap_or(start)
+    }
+
+    /// Working days after `from` up to and including `to`
+    pub fn working_days_between(&self, from: NaiveDate, to: NaiveDate) -> i64 {
+        from.iter_days()
+            .skip(1)
+            .take_while(|d| *d <= to)
+            .filter(|d| self.is_working_day(*d))
+            .count() as i64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::test_dates::date;
+    use uuid::Uuid;
+
+    #[test]
+    fn test_working_day_skips_weekends_and_holidays() {
+        // 2024-03-22 is a Friday; the 27th and 28th are holidays
+        let calendar = WorkCalendar::weekdays([date(27), date(28)]);
+        assert_eq!(calendar.working_day(date(22), 0), date(22));
+        assert_eq!(calendar.working_day(date(23), 0), date(25));
+        assert_eq!(calendar.working_day(date(22), 2), date(26));
+        assert_eq!(calendar.working_day(date(22), 3), date(29));
+        assert_eq!(calendar.working_days_between(date(22), date(29)), 3);
+    }
+
+    #[test]
+    fn test_capacity_hours_exclude_days_off() {
+        let capacity = UserCapacity::new(Uuid::new_v4(), 30.0, None);
+        let calendar = WorkCalendar::for_capacity(&capacity, [date(25)]);
+        assert_eq!(calendar.hours_on(date(25)), 0.0);
+        assert_eq!(calendar.hours_on(date(26)), 6.0);
+        assert!(!calendar.is_working_day(date(24)));
+
+        let never = WorkCalendar::new([0.0; 7], []);
+        assert_eq!(never.working_day(date(22), 2), date(24));
+    }
+}
@@ -53,6 +53,110 @@ pub enum TeamMemberRole {
     Member,
 }
 
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, sqlx::Type)]
+#[sqlx(type_name = "watch_entity_type", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum WatchEntityType {
+    Task,
+    Project,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, sqlx::Type)]
+#[sqlx(type_name = "mention_source_type", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum MentionSourceType {
+    Task,
+    Comment,
+}
+
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, sqlx::Type)]
+#[sqlx(type_name = "sprint_state", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum SprintState {
+    #[default]
+    Planned,
+    Active,
+    Closed,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, sqlx::Type)]
+#[sqlx(type_name = "raid_type", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum RaidType {
+    Risk,
+    Assumption,
+    Issue,
+    Decision,
+}
+
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, sqlx::Type)]
+#[sqlx(type_name = "raid_status", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum RaidStatus {
+    #[default]
+    Open,
+    Monitoring,
+    Resolved,
+    Closed,
+}
+
+/// Level of a project grouping: portfolios contain programs, programs contain projects
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, sqlx::Type)]
+#[sqlx(type_name = "portfolio_kind", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum PortfolioKind {
+    Portfolio,
+    Program,
+}
+
+/// Lifecycle of a change request to a governed project
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, sqlx::Type)]
+#[sqlx(type_name = "change_request_status", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum ChangeRequestStatus {
+    #[default]
+    Submitted,
+    Approved,
+    Rejected,
+    Withdrawn,
+}
+
+/// Approval state of a user's weekly timesheet
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, sqlx::Type)]
+#[sqlx(type_name = "timesheet_status", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum TimesheetStatus {
+    #[default]
+    Draft,
+    Submitted,
+    Approved,
+    Rejected,
+}
+
+/// Approval state of a time off request
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, sqlx::Type)]
+#[sqlx(type_name = "time_off_status", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum TimeOffStatus {
+    #[default]
+    Pending,
+    Approved,
+    Rejected,
+    Cancelled,
+}
+
+/// Red/amber/green project health
+#[derive(
+    Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, sqlx::Type,
+)]
+#[sqlx(type_name = "rag_status", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum RagStatus {
+    Green,
+    Amber,
+    Red,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -237,6 +341,12 @@ mod tests {
         assert_eq!(status, TaskStatus::InProgress);
     }
 
+    #[test]
+    fn test_watch_entity_type_serialize_lowercase() {
+        let json = serde_json::to_string(&WatchEntityType::Project).unwrap();
+        assert_eq!(json, "\"project\"");
+    }
+
     #[test]
     fn test_priority_round_trip() {
         let priority = Priority::Critical;
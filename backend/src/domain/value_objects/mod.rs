@@ -3,5 +3,9 @@ mod enums;
 mod password;
 
 pub use email::Email;
-pub use enums::{Priority, ProjectStatus, TaskStatus, TeamMemberRole, UserRole};
+pub use enums::{
+    ChangeRequestStatus, MentionSourceType, PortfolioKind, Priority, ProjectStatus, RagStatus,
+    RaidStatus, RaidType, SprintState, TaskStatus, TeamMemberRole, TimeOffStatus, TimesheetStatus,
+    UserRole, WatchEntityType,
+};
 pub use password::PasswordValidator;
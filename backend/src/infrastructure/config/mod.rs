@@ -8,6 +8,20 @@ pub struct AppConfig {
     pub jwt_secret: String,
     pub jwt_expiration: i64,
     pub allowed_origins: Vec<String>,
+    /// Days a trashed project or task is kept before being purged
+    pub trash_retention_days: i64,
+    /// Percentages of a project budget that notify the owner when reached
+    pub budget_alert_thresholds: Vec<u32>,
+    /// Hours a timer may run without a break before it is stopped automatically
+    pub timer_auto_stop_hours: i64,
+    /// Minutes time logs must be a multiple of; 0 allows any amount
+    pub time_log_increment_minutes: u32,
+    /// Most hours a user may log on a single day
+    pub time_log_daily_cap_hours: f32,
+    /// Weekly working hours of users without a capacity of their own
+    pub default_weekly_hours: f32,
+    /// Working days before a task's due date its assignee is reminded
+    pub due_soon_working_days: i64,
 }
 
 impl AppConfig {
@@ -28,6 +42,39 @@ impl AppConfig {
                 .parse()
                 .expect("JWT_EXPIRATION must be a number"),
             allowed_origins,
+            trash_retention_days: std::env::var("TRASH_RETENTION_DAYS")
+                .unwrap_or_else(|_| "30".to_string())
+                .parse()
+                .expect("TRASH_RETENTION_DAYS must be a number"),
+            budget_alert_thresholds: std::env::var("BUDGET_ALERT_THRESHOLDS")
+                .unwrap_or_else(|_| "50,75,90,100".to_string())
+                .split(',')
+                .map(|s| {
+                    s.trim()
+                        .parse()
+                        .expect("BUDGET_ALERT_THRESHOLDS must be comma-separated numbers")
+                })
+                .collect(),
+            timer_auto_stop_hours: std::env::var("TIMER_AUTO_STOP_HOURS")
+                .unwrap_or_else(|_| "12".to_string())
+                .parse()
+                .expect("TIMER_AUTO_STOP_HOURS must be a number"),
+            time_log_increment_minutes: std::env::var("TIME_LOG_INCREMENT_MINUTES")
+                .unwrap_or_else(|_| "15".to_string())
+                .parse()
+                .expect("TIME_LOG_INCREMENT_MINUTES must be a number"),
+            time_log_daily_cap_hours: std::env::var("TIME_LOG_DAILY_CAP_HOURS")
+                .unwrap_or_else(|_| "24".to_string())
+                .parse()
+                .expect("TIME_LOG_DAILY_CAP_HOURS must be a number"),
+            default_weekly_hours: std::env::var("DEFAULT_WEEKLY_HOURS")
+                .unwrap_or_else(|_| "40".to_string())
+                .parse()
+                .expect("DEFAULT_WEEKLY_HOURS must be a number"),
+            due_soon_working_days: std::env::var("DUE_SOON_WORKING_DAYS")
+                .unwrap_or_else(|_| "2".to_string())
+                .parse()
+                .expect("DUE_SOON_WORKING_DAYS must be a number"),
         }
     }
 
@@ -1,19 +1,63 @@
 mod pg_activity_log_repository;
 mod pg_attachment_repository;
+mod pg_baseline_repository;
+mod pg_billing_rate_repository;
+mod pg_budget_alert_repository;
+mod pg_change_request_repository;
+mod pg_cost_rate_repository;
+mod pg_expense_repository;
+mod pg_holiday_repository;
+mod pg_invoice_repository;
+mod pg_mention_repository;
 mod pg_notification_repository;
+mod pg_portfolio_repository;
+mod pg_project_health_repository;
 mod pg_project_repository;
+mod pg_raid_item_repository;
+mod pg_resource_allocation_repository;
+mod pg_sprint_repository;
+mod pg_status_report_repository;
 mod pg_tag_repository;
+mod pg_task_comment_repository;
+mod pg_task_dependency_repository;
 mod pg_task_repository;
 mod pg_team_repository;
 mod pg_time_log_repository;
+mod pg_time_off_repository;
+mod pg_timer_repository;
+mod pg_timesheet_repository;
+mod pg_user_capacity_repository;
 mod pg_user_repository;
+mod pg_watcher_repository;
 
 pub use pg_activity_log_repository::PgActivityLogRepository;
 pub use pg_attachment_repository::PgAttachmentRepository;
+pub use pg_baseline_repository::PgBaselineRepository;
+pub use pg_billing_rate_repository::PgBillingRateRepository;
+pub use pg_budget_alert_repository::PgBudgetAlertRepository;
+pub use pg_change_request_repository::PgChangeRequestRepository;
+pub use pg_cost_rate_repository::PgCostRateRepository;
+pub use pg_expense_repository::PgExpenseRepository;
+pub use pg_holiday_repository::PgHolidayRepository;
+pub use pg_invoice_repository::PgInvoiceRepository;
+pub use pg_mention_repository::PgMentionRepository;
 pub use pg_notification_repository::PgNotificationRepository;
+pub use pg_portfolio_repository::PgPortfolioRepository;
+pub use pg_project_health_repository::PgProjectHealthRepository;
 pub use pg_project_repository::PgProjectRepository;
+pub use pg_raid_item_repository::PgRaidItemRepository;
+pub use pg_resource_allocation_repository::PgResourceAllocationRepository;
+pub use pg_sprint_repository::PgSprintRepository;
+pub use pg_status_report_repository::PgStatusReportRepository;
 pub use pg_tag_repository::PgTagRepository;
+pub use pg_task_comment_repository::PgTaskCommentRepository;
+pub use pg_task_dependency_repository::PgTaskDependencyRepository;
 pub use pg_task_repository::PgTaskRepository;
 pub use pg_team_repository::PgTeamRepository;
 pub use pg_time_log_repository::PgTimeLogRepository;
+pub use pg_time_off_repository::PgTimeOffRepository;
+pub use pg_timer_repository::PgTimerRepository;
+pub use pg_timesheet_repository::PgTimesheetRepository;
+pub use pg_user_capacity_repository::PgUserCapacityRepository;
 pub use pg_user_repository::PgUserRepository;
+pub use pg_watcher_repository::PgWatcherRepository;
@@ -99,6 +99,7 @@ impl ActivityLogRepository for PgActivityLogRepository {
             FROM activity_logs al
             LEFT JOIN users u ON al.user_id = u.id
             LEFT JOIN projects p ON al.project_id = p.id
+            WHERE p.deleted_at IS NULL
             ORDER BY al.created_at DESC
             LIMIT $1 OFFSET $2
             "#,
@@ -132,7 +133,7 @@ impl ActivityLogRepository for PgActivityLogRepository {
             FROM activity_logs al
             LEFT JOIN users u ON al.user_id = u.id
             LEFT JOIN projects p ON al.project_id = p.id
-            WHERE al.project_id = $1
+            WHERE p.deleted_at IS NULL AND al.project_id = $1
             ORDER BY al.created_at DESC
             LIMIT $2
             "#,
@@ -166,7 +167,7 @@ impl ActivityLogRepository for PgActivityLogRepository {
             FROM activity_logs al
             LEFT JOIN users u ON al.user_id = u.id
             LEFT JOIN projects p ON al.project_id = p.id
-            WHERE al.user_id = $1
+            WHERE p.deleted_at IS NULL AND al.user_id = $1
             ORDER BY al.created_at DESC
             LIMIT $2
             "#,
@@ -179,6 +180,81 @@ impl ActivityLogRepository for PgActivityLogRepository {
         Ok(rows.into_iter().map(Into::into).collect())
     }
 
+    async fn find_by_entity(
+        &self,
+        entity_type: &str,
+        entity_id: Uuid,
+    ) -> Result<Vec<ActivityLogWithDetails>, DomainError> {
+        let rows = sqlx::query_as::<_, ActivityLogWithDetailsRow>(
+            r#"
+            SELECT
+                al.id,
+                al.user_id,
+                u.name as user_name,
+                al.project_id,
+                p.name as project_name,
+                al.action,
+                al.entity_type,
+                al.entity_id,
+                al.details,
+                al.created_at
+            FROM activity_logs al
+            LEFT JOIN users u ON al.user_id = u.id
+            LEFT JOIN projects p ON al.project_id = p.id
+            WHERE p.deleted_at IS NULL AND al.entity_type = $1 AND al.entity_id = $2
+            ORDER BY al.created_at ASC
+            "#,
+        )
+        .bind(entity_type)
+        .bind(entity_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn find_by_project_between(
+        &self,
+        project_id: Uuid,
+        entity_type: &str,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<ActivityLog>, DomainError> {
+        let rows = sqlx::query_as::<_, ActivityLogRow>(
+            r#"
+            SELECT al.* FROM activity_logs al
+            INNER JOIN projects p ON al.project_id = p.id
+            WHERE al.project_id = $1 AND al.entity_type = $2
+              AND al.created_at >= $3 AND al.created_at < $4
+              AND p.deleted_at IS NULL
+            ORDER BY al.created_at ASC
+            "#,
+        )
+        .bind(project_id)
+        .bind(entity_type)
+        .bind(from)
+        .bind(to)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<ActivityLog>, DomainError> {
+        let row = sqlx::query_as::<_, ActivityLogRow>(
+            r#"
+            SELECT al.* FROM activity_logs al
+            LEFT JOIN projects p ON al.project_id = p.id
+            WHERE al.id = $1 AND p.deleted_at IS NULL
+            "#,
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.map(Into::into))
+    }
+
     async fn create(&self, log: &ActivityLog) -> Result<ActivityLog, DomainError> {
         let row = sqlx::query_as::<_, ActivityLogRow>(
             r#"
@@ -202,9 +278,15 @@ impl ActivityLogRepository for PgActivityLogRepository {
     }
 
     async fn count(&self) -> Result<i64, DomainError> {
-        let row: (i64,) = sqlx::query_as("SELECT COUNT(*) FROM activity_logs")
-            .fetch_one(&self.pool)
-            .await?;
+        let row: (i64,) = sqlx::query_as(
+            r#"
+            SELECT COUNT(*) FROM activity_logs al
+            LEFT JOIN projects p ON al.project_id = p.id
+            WHERE p.deleted_at IS NULL
+            "#,
+        )
+        .fetch_one(&self.pool)
+        .await?;
 
         Ok(row.0)
     }
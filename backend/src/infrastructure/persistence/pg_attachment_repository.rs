@@ -54,15 +54,59 @@ impl PgAttachmentRepository {
 #[async_trait]
 impl AttachmentRepository for PgAttachmentRepository {
     async fn find_by_id(&self, id: Uuid) -> Result<Option<Attachment>, DomainError> {
-        let row = sqlx::query_as::<_, AttachmentRow>("SELECT * FROM attachments WHERE id = $1")
-            .bind(id)
-            .fetch_optional(&self.pool)
-            .await?;
+        let row = sqlx::query_as::<_, AttachmentRow>(
+            r#"
+            SELECT a.* FROM attachments a
+            INNER JOIN tasks t ON a.task_id = t.id
+            INNER JOIN projects p ON t.project_id = p.id
+            WHERE a.id = $1 AND t.deleted_at IS NULL AND p.deleted_at IS NULL
+            "#,
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
 
         Ok(row.map(Into::into))
     }
 
     async fn find_by_task(&self, task_id: Uuid) -> Result<Vec<Attachment>, DomainError> {
+        let rows = sqlx::query_as::<_, AttachmentRow>(
+            r#"
+            SELECT a.* FROM attachments a
+            INNER JOIN tasks t ON a.task_id = t.id
+            INNER JOIN projects p ON t.project_id = p.id
+            WHERE a.task_id = $1 AND t.deleted_at IS NULL AND p.deleted_at IS NULL
+            ORDER BY a.created_at DESC
+            "#,
+        )
+        .bind(task_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn find_by_project(&self, project_id: Uuid) -> Result<Vec<Attachment>, DomainError> {
+        let rows = sqlx::query_as::<_, AttachmentRow>(
+            r#"
+            SELECT a.* FROM attachments a
+            INNER JOIN tasks t ON a.task_id = t.id
+            INNER JOIN projects p ON t.project_id = p.id
+            WHERE t.project_id = $1 AND t.deleted_at IS NULL AND p.deleted_at IS NULL
+            ORDER BY a.created_at DESC
+            "#,
+        )
+        .bind(project_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn find_by_task_including_deleted(
+        &self,
+        task_id: Uuid,
+    ) -> Result<Vec<Attachment>, DomainError> {
         let rows = sqlx::query_as::<_, AttachmentRow>(
             "SELECT * FROM attachments WHERE task_id = $1 ORDER BY created_at DESC",
         )
@@ -73,6 +117,25 @@ impl AttachmentRepository for PgAttachmentRepository {
         Ok(rows.into_iter().map(Into::into).collect())
     }
 
+    async fn find_by_project_including_deleted(
+        &self,
+        project_id: Uuid,
+    ) -> Result<Vec<Attachment>, DomainError> {
+        let rows = sqlx::query_as::<_, AttachmentRow>(
+            r#"
+            SELECT a.* FROM attachments a
+            INNER JOIN tasks t ON a.task_id = t.id
+            WHERE t.project_id = $1
+            ORDER BY a.created_at DESC
+            "#,
+        )
+        .bind(project_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
     async fn create(&self, attachment: &Attachment) -> Result<Attachment, DomainError> {
         let row = sqlx::query_as::<_, AttachmentRow>(
             r#"
@@ -0,0 +1,97 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::types::Json;
+use sqlx::{FromRow, PgPool};
+use uuid::Uuid;
+
+use crate::domain::entities::{BaselineSnapshot, ProjectBaseline};
+use crate::domain::repositories::BaselineRepository;
+use crate::shared::DomainError;
+
+#[derive(Debug, FromRow)]
+struct BaselineRow {
+    id: Uuid,
+    project_id: Uuid,
+    name: String,
+    description: Option<String>,
+    snapshot: Json<BaselineSnapshot>,
+    created_by: Option<Uuid>,
+    created_at: DateTime<Utc>,
+}
+
+impl From<BaselineRow> for ProjectBaseline {
+    fn from(row: BaselineRow) -> Self {
+        ProjectBaseline {
+            id: row.id,
+            project_id: row.project_id,
+            name: row.name,
+            description: row.description,
+            snapshot: row.snapshot.0,
+            created_by: row.created_by,
+            created_at: row.created_at,
+        }
+    }
+}
+
+pub struct PgBaselineRepository {
+    pool: PgPool,
+}
+
+impl PgBaselineRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl BaselineRepository for PgBaselineRepository {
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<ProjectBaseline>, DomainError> {
+        let row = sqlx::query_as::<_, BaselineRow>("SELECT * FROM project_baselines WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(row.map(Into::into))
+    }
+
+    async fn find_by_project(&self, project_id: Uuid) -> Result<Vec<ProjectBaseline>, DomainError> {
+        let rows = sqlx::query_as::<_, BaselineRow>(
+            "SELECT * FROM project_baselines WHERE project_id = $1 ORDER BY created_at DESC",
+        )
+        .bind(project_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn create(&self, baseline: &ProjectBaseline) -> Result<ProjectBaseline, DomainError> {
+        let row = sqlx::query_as::<_, BaselineRow>(
+            r#"
+            INSERT INTO project_baselines (id, project_id, name, description, snapshot, created_by, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            RETURNING *
+            "#,
+        )
+        .bind(baseline.id)
+        .bind(baseline.project_id)
+        .bind(&baseline.name)
+        .bind(&baseline.description)
+        .bind(Json(&baseline.snapshot))
+        .bind(baseline.created_by)
+        .bind(baseline.created_at)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(row.into())
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<(), DomainError> {
+        sqlx::query("DELETE FROM project_baselines WHERE id = $1")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+}
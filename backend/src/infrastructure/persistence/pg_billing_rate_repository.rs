@@ -0,0 +1,94 @@
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use sqlx::{FromRow, PgPool};
+use uuid::Uuid;
+
+use crate::domain::entities::BillingRate;
+use crate::domain::repositories::BillingRateRepository;
+use crate::domain::value_objects::UserRole;
+use crate::shared::DomainError;
+
+#[derive(Debug, FromRow)]
+struct BillingRateRow {
+    id: Uuid,
+    project_id: Option<Uuid>,
+    user_id: Option<Uuid>,
+    role: Option<UserRole>,
+    hourly_rate: Decimal,
+    effective_from: NaiveDate,
+    created_at: DateTime<Utc>,
+}
+
+impl From<BillingRateRow> for BillingRate {
+    fn from(row: BillingRateRow) -> Self {
+        BillingRate {
+            id: row.id,
+            project_id: row.project_id,
+            user_id: row.user_id,
+            role: row.role,
+            hourly_rate: row.hourly_rate,
+            effective_from: row.effective_from,
+            created_at: row.created_at,
+        }
+    }
+}
+
+pub struct PgBillingRateRepository {
+    pool: PgPool,
+}
+
+impl PgBillingRateRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl BillingRateRepository for PgBillingRateRepository {
+    async fn find_all(&self) -> Result<Vec<BillingRate>, DomainError> {
+        let rows = sqlx::query_as::<_, BillingRateRow>(
+            r#"
+            SELECT * FROM billing_rates
+            ORDER BY project_id NULLS LAST, user_id NULLS LAST, role NULLS LAST, effective_from DESC
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn create(&self, rate: &BillingRate) -> Result<BillingRate, DomainError> {
+        let row = sqlx::query_as::<_, BillingRateRow>(
+            r#"
+            INSERT INTO billing_rates (id, project_id, user_id, role, hourly_rate, effective_from, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            RETURNING *
+            "#,
+        )
+        .bind(rate.id)
+        .bind(rate.project_id)
+        .bind(rate.user_id)
+        .bind(&rate.role)
+        .bind(rate.hourly_rate)
+        .bind(rate.effective_from)
+        .bind(rate.created_at)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(row.into())
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<(), DomainError> {
+        let result = sqlx::query("DELETE FROM billing_rates WHERE id = $1")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(DomainError::NotFound("Billing rate not found".into()));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,47 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::domain::repositories::BudgetAlertRepository;
+use crate::shared::DomainError;
+
+pub struct PgBudgetAlertRepository {
+    pool: PgPool,
+}
+
+impl PgBudgetAlertRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl BudgetAlertRepository for PgBudgetAlertRepository {
+    async fn record(&self, project_id: Uuid, threshold: i32) -> Result<bool, DomainError> {
+        let result = sqlx::query(
+            r#"
+            INSERT INTO budget_alerts (project_id, threshold)
+            VALUES ($1, $2)
+            ON CONFLICT (project_id, threshold) DO NOTHING
+            "#,
+        )
+        .bind(project_id)
+        .bind(threshold)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn retain(&self, project_id: Uuid, thresholds: &[i32]) -> Result<(), DomainError> {
+        sqlx::query(
+            "DELETE FROM budget_alerts WHERE project_id = $1 AND NOT (threshold = ANY($2))",
+        )
+        .bind(project_id)
+        .bind(thresholds)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,214 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{types::Json, FromRow, PgPool};
+use uuid::Uuid;
+
+use crate::domain::entities::{ChangeRequest, ChangeSet};
+use crate::domain::repositories::ChangeRequestRepository;
+use crate::domain::value_objects::ChangeRequestStatus;
+use crate::shared::DomainError;
+
+#[derive(Debug, FromRow)]
+struct ChangeRequestRow {
+    id: Uuid,
+    project_id: Uuid,
+    proposed: Json<ChangeSet>,
+    previous: Json<ChangeSet>,
+    justification: String,
+    status: ChangeRequestStatus,
+    requested_by: Option<Uuid>,
+    decided_by: Option<Uuid>,
+    decision_comment: Option<String>,
+    decided_at: Option<DateTime<Utc>>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl From<ChangeRequestRow> for ChangeRequest {
+    fn from(row: ChangeRequestRow) -> Self {
+        ChangeRequest {
+            id: row.id,
+            project_id: row.project_id,
+            proposed: row.proposed.0,
+            previous: row.previous.0,
+            justification: row.justification,
+            status: row.status,
+            requested_by: row.requested_by,
+            decided_by: row.decided_by,
+            decision_comment: row.decision_comment,
+            decided_at: row.decided_at,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }
+    }
+}
+
+/// Only requests still awaiting a decision may be decided
+const UPDATE_DECISION: &str = r#"
+    UPDATE change_requests
+    SET status = $1, decided_by = $2, decision_comment = $3, decided_at = $4, updated_at = NOW()
+    WHERE id = $5 AND status = 'submitted'
+    RETURNING *
+"#;
+
+pub struct PgChangeRequestRepository {
+    pool: PgPool,
+}
+
+impl PgChangeRequestRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+fn already_decided() -> DomainError {
+    DomainError::Conflict("Change request has already been decided".into())
+}
+
+#[async_trait]
+impl ChangeRequestRepository for PgChangeRequestRepository {
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<ChangeRequest>, DomainError> {
+        let row =
+            sqlx::query_as::<_, ChangeRequestRow>("SELECT * FROM change_requests WHERE id = $1")
+                .bind(id)
+                .fetch_optional(&self.pool)
+                .await?;
+
+        Ok(row.map(Into::into))
+    }
+
+    async fn find_by_project(
+        &self,
+        project_id: Uuid,
+        status: Option<ChangeRequestStatus>,
+    ) -> Result<Vec<ChangeRequest>, DomainError> {
+        let rows = sqlx::query_as::<_, ChangeRequestRow>(
+            r#"
+            SELECT * FROM change_requests
+            WHERE project_id = $1 AND ($2::change_request_status IS NULL OR status = $2)
+            ORDER BY created_at DESC
+            "#,
+        )
+        .bind(project_id)
+        .bind(status)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn create(&self, request: &ChangeRequest) -> Result<ChangeRequest, DomainError> {
+        let row = sqlx::query_as::<_, ChangeRequestRow>(
+            r#"
+            INSERT INTO change_requests (id, project_id, proposed, previous, justification, status,
+                requested_by, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            RETURNING *
+            "#,
+        )
+        .bind(request.id)
+        .bind(request.project_id)
+        .bind(Json(&request.proposed))
+        .bind(Json(&request.previous))
+        .bind(&request.justification)
+        .bind(request.status)
+        .bind(request.requested_by)
+        .bind(request.created_at)
+        .bind(request.updated_at)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(row.into())
+    }
+
+    async fn save_decision(&self, request: &ChangeRequest) -> Result<ChangeRequest, DomainError> {
+        let row = sqlx::query_as::<_, ChangeRequestRow>(UPDATE_DECISION)
+            .bind(request.status)
+            .bind(request.decided_by)
+            .bind(&request.decision_comment)
+            .bind(request.decided_at)
+            .bind(request.id)
+            .fetch_optional(&self.pool)
+            .await?
+            .ok_or_else(already_decided)?;
+
+        Ok(row.into())
+    }
+
+    async fn approve_and_apply(
+        &self,
+        request: &ChangeRequest,
+    ) -> Result<ChangeRequest, DomainError> {
+        let mut tx = self.pool.begin().await?;
+        let row = sqlx::query_as::<_, ChangeRequestRow>(UPDATE_DECISION)
+            .bind(request.status)
+            .bind(request.decided_by)
+            .bind(&request.decision_comment)
+            .bind(request.decided_at)
+            .bind(request.id)
+            .fetch_optional(&mut *tx)
+            .await?
+            .ok_or_else(already_decided)?;
+
+        let changes = &request.proposed;
+        sqlx::query(
+            r#"
+            UPDATE projects
+            SET budget = CASE WHEN $1 THEN $2 ELSE budget END,
+                end_date = CASE WHEN $3 THEN $4 ELSE end_date END,
+                updated_at = NOW()
+            WHERE id = $5
+            "#,
+        )
+        .bind(changes.budget.is_some())
+        .bind(changes.budget.flatten())
+        .bind(changes.end_date.is_some())
+        .bind(changes.end_date.flatten())
+        .bind(request.project_id)
+        .execute(&mut *tx)
+        .await?;
+
+        for change in &changes.milestone_dates {
+            sqlx::query(
+                "UPDATE milestones SET due_date = $1, updated_at = NOW() WHERE id = $2 AND project_id = $3",
+            )
+            .bind(change.due_date)
+            .bind(change.milestone_id)
+            .bind(request.project_id)
+            .execute(&mut *tx)
+            .await?;
+        }
+        tx.commit().await?;
+
+        Ok(row.into())
+    }
+
+    async fn find_approvers(&self, project_id: Uuid) -> Result<Vec<Uuid>, DomainError> {
+        let ids = sqlx::query_scalar::<_, Uuid>(
+            "SELECT user_id FROM project_change_approvers WHERE project_id = $1 ORDER BY added_at",
+        )
+        .bind(project_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(ids)
+    }
+
+    async fn set_approvers(&self, project_id: Uuid, user_ids: &[Uuid]) -> Result<(), DomainError> {
+        let mut tx = self.pool.begin().await?;
+        sqlx::query("DELETE FROM project_change_approvers WHERE project_id = $1")
+            .bind(project_id)
+            .execute(&mut *tx)
+            .await?;
+        sqlx::query(
+            "INSERT INTO project_change_approvers (project_id, user_id) SELECT $1, UNNEST($2::uuid[])",
+        )
+        .bind(project_id)
+        .bind(user_ids)
+        .execute(&mut *tx)
+        .await?;
+        tx.commit().await?;
+
+        Ok(())
+    }
+}
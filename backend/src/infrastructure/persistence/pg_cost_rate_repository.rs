@@ -0,0 +1,96 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use sqlx::{FromRow, PgPool};
+use uuid::Uuid;
+
+use crate::domain::entities::CostRate;
+use crate::domain::repositories::CostRateRepository;
+use crate::domain::value_objects::UserRole;
+use crate::shared::DomainError;
+
+#[derive(Debug, FromRow)]
+struct CostRateRow {
+    id: Uuid,
+    user_id: Option<Uuid>,
+    role: Option<UserRole>,
+    hourly_rate: Decimal,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl From<CostRateRow> for CostRate {
+    fn from(row: CostRateRow) -> Self {
+        CostRate {
+            id: row.id,
+            user_id: row.user_id,
+            role: row.role,
+            hourly_rate: row.hourly_rate,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }
+    }
+}
+
+pub struct PgCostRateRepository {
+    pool: PgPool,
+}
+
+impl PgCostRateRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl CostRateRepository for PgCostRateRepository {
+    async fn find_all(&self) -> Result<Vec<CostRate>, DomainError> {
+        let rows = sqlx::query_as::<_, CostRateRow>(
+            "SELECT * FROM cost_rates ORDER BY role NULLS LAST, created_at ASC",
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn upsert(&self, rate: &CostRate) -> Result<CostRate, DomainError> {
+        let conflict_target = if rate.user_id.is_some() {
+            "user_id"
+        } else {
+            "role"
+        };
+        let query = format!(
+            r#"
+            INSERT INTO cost_rates (id, user_id, role, hourly_rate, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            ON CONFLICT ({}) DO UPDATE SET hourly_rate = EXCLUDED.hourly_rate, updated_at = NOW()
+            RETURNING *
+            "#,
+            conflict_target
+        );
+        let row = sqlx::query_as::<_, CostRateRow>(&query)
+            .bind(rate.id)
+            .bind(rate.user_id)
+            .bind(&rate.role)
+            .bind(rate.hourly_rate)
+            .bind(rate.created_at)
+            .bind(rate.updated_at)
+            .fetch_one(&self.pool)
+            .await?;
+
+        Ok(row.into())
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<(), DomainError> {
+        let result = sqlx::query("DELETE FROM cost_rates WHERE id = $1")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(DomainError::NotFound("Cost rate not found".into()));
+        }
+        Ok(())
+    }
+}
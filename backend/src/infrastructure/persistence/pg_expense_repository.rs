@@ -0,0 +1,128 @@
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use sqlx::{FromRow, PgPool};
+use uuid::Uuid;
+
+use crate::domain::entities::Expense;
+use crate::domain::repositories::ExpenseRepository;
+use crate::shared::DomainError;
+
+#[derive(Debug, FromRow)]
+struct ExpenseRow {
+    id: Uuid,
+    project_id: Uuid,
+    amount: Decimal,
+    category: String,
+    description: Option<String>,
+    date: NaiveDate,
+    receipt_attachment_id: Option<Uuid>,
+    created_by: Option<Uuid>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl From<ExpenseRow> for Expense {
+    fn from(row: ExpenseRow) -> Self {
+        Expense {
+            id: row.id,
+            project_id: row.project_id,
+            amount: row.amount,
+            category: row.category,
+            description: row.description,
+            date: row.date,
+            receipt_attachment_id: row.receipt_attachment_id,
+            created_by: row.created_by,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }
+    }
+}
+
+pub struct PgExpenseRepository {
+    pool: PgPool,
+}
+
+impl PgExpenseRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ExpenseRepository for PgExpenseRepository {
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Expense>, DomainError> {
+        let row = sqlx::query_as::<_, ExpenseRow>("SELECT * FROM expenses WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(row.map(Into::into))
+    }
+
+    async fn find_by_project(&self, project_id: Uuid) -> Result<Vec<Expense>, DomainError> {
+        let rows = sqlx::query_as::<_, ExpenseRow>(
+            "SELECT * FROM expenses WHERE project_id = $1 ORDER BY date DESC, created_at DESC",
+        )
+        .bind(project_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn create(&self, expense: &Expense) -> Result<Expense, DomainError> {
+        let row = sqlx::query_as::<_, ExpenseRow>(
+            r#"
+            INSERT INTO expenses (id, project_id, amount, category, description, date, receipt_attachment_id, created_by, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            RETURNING *
+            "#,
+        )
+        .bind(expense.id)
+        .bind(expense.project_id)
+        .bind(expense.amount)
+        .bind(&expense.category)
+        .bind(&expense.description)
+        .bind(expense.date)
+        .bind(expense.receipt_attachment_id)
+        .bind(expense.created_by)
+        .bind(expense.created_at)
+        .bind(expense.updated_at)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(row.into())
+    }
+
+    async fn update(&self, expense: &Expense) -> Result<Expense, DomainError> {
+        let row = sqlx::query_as::<_, ExpenseRow>(
+            r#"
+            UPDATE expenses
+            SET amount = $1, category = $2, description = $3, date = $4,
+                receipt_attachment_id = $5, updated_at = NOW()
+            WHERE id = $6
+            RETURNING *
+            "#,
+        )
+        .bind(expense.amount)
+        .bind(&expense.category)
+        .bind(&expense.description)
+        .bind(expense.date)
+        .bind(expense.receipt_attachment_id)
+        .bind(expense.id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(row.into())
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<(), DomainError> {
+        sqlx::query("DELETE FROM expenses WHERE id = $1")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,242 @@
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use sqlx::{FromRow, PgPool};
+use uuid::Uuid;
+
+use crate::domain::entities::{Holiday, HolidayCalendar};
+use crate::domain::repositories::HolidayRepository;
+use crate::shared::DomainError;
+
+#[derive(Debug, FromRow)]
+struct HolidayCalendarRow {
+    id: Uuid,
+    name: String,
+    is_default: bool,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl From<HolidayCalendarRow> for HolidayCalendar {
+    fn from(row: HolidayCalendarRow) -> Self {
+        HolidayCalendar {
+            id: row.id,
+            name: row.name,
+            is_default: row.is_default,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, FromRow)]
+struct HolidayRow {
+    id: Uuid,
+    calendar_id: Uuid,
+    date: NaiveDate,
+    name: String,
+    created_at: DateTime<Utc>,
+}
+
+impl From<HolidayRow> for Holiday {
+    fn from(row: HolidayRow) -> Self {
+        Holiday {
+            id: row.id,
+            calendar_id: row.calendar_id,
+            date: row.date,
+            name: row.name,
+            created_at: row.created_at,
+        }
+    }
+}
+
+pub struct PgHolidayRepository {
+    pool: PgPool,
+}
+
+impl PgHolidayRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl HolidayRepository for PgHolidayRepository {
+    async fn find_calendars(&self) -> Result<Vec<HolidayCalendar>, DomainError> {
+        let rows = sqlx::query_as::<_, HolidayCalendarRow>(
+            "SELECT * FROM holiday_calendars ORDER BY is_default DESC, name ASC",
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn find_calendar_by_id(&self, id: Uuid) -> Result<Option<HolidayCalendar>, DomainError> {
+        let row = sqlx::query_as::<_, HolidayCalendarRow>(
+            "SELECT * FROM holiday_calendars WHERE id = $1",
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.map(Into::into))
+    }
+
+    async fn find_default_calendar(&self) -> Result<Option<HolidayCalendar>, DomainError> {
+        let row = sqlx::query_as::<_, HolidayCalendarRow>(
+            "SELECT * FROM holiday_calendars WHERE is_default",
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.map(Into::into))
+    }
+
+    async fn create_calendar(
+        &self,
+        calendar: &HolidayCalendar,
+    ) -> Result<HolidayCalendar, DomainError> {
+        let mut tx = self.pool.begin().await?;
+
+        if calendar.is_default {
+            sqlx::query("UPDATE holiday_calendars SET is_default = FALSE WHERE is_default")
+                .execute(&mut *tx)
+                .await?;
+        }
+
+        let row = sqlx::query_as::<_, HolidayCalendarRow>(
+            r#"
+            INSERT INTO holiday_calendars (id, name, is_default, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5)
+            RETURNING *
+            "#,
+        )
+        .bind(calendar.id)
+        .bind(&calendar.name)
+        .bind(calendar.is_default)
+        .bind(calendar.created_at)
+        .bind(calendar.updated_at)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        Ok(row.into())
+    }
+
+    async fn update_calendar(
+        &self,
+        calendar: &HolidayCalendar,
+    ) -> Result<HolidayCalendar, DomainError> {
+        let mut tx = self.pool.begin().await?;
+
+        if calendar.is_default {
+            sqlx::query(
+                "UPDATE holiday_calendars SET is_default = FALSE WHERE is_default AND id <> $1",
+            )
+            .bind(calendar.id)
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        let row = sqlx::query_as::<_, HolidayCalendarRow>(
+            r#"
+            UPDATE holiday_calendars
+            SET name = $1, is_default = $2, updated_at = NOW()
+            WHERE id = $3
+            RETURNING *
+            "#,
+        )
+        .bind(&calendar.name)
+        .bind(calendar.is_default)
+        .bind(calendar.id)
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or_else(|| DomainError::NotFound("Holiday calendar not found".into()))?;
+
+        tx.commit().await?;
+        Ok(row.into())
+    }
+
+    async fn delete_calendar(&self, id: Uuid) -> Result<(), DomainError> {
+        let result = sqlx::query("DELETE FROM holiday_calendars WHERE id = $1")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(DomainError::NotFound("Holiday calendar not found".into()));
+        }
+        Ok(())
+    }
+
+    async fn find_holidays(&self, calendar_id: Uuid) -> Result<Vec<Holiday>, DomainError> {
+        let rows = sqlx::query_as::<_, HolidayRow>(
+            "SELECT * FROM holidays WHERE calendar_id = $1 ORDER BY date ASC",
+        )
+        .bind(calendar_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn find_holidays_in_range(
+        &self,
+        calendar_ids: &[Uuid],
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<Vec<Holiday>, DomainError> {
+        let rows = sqlx::query_as::<_, HolidayRow>(
+            r#"
+            SELECT * FROM holidays
+            WHERE calendar_id = ANY($1) AND date BETWEEN $2 AND $3
+            ORDER BY date ASC
+            "#,
+        )
+        .bind(calendar_ids)
+        .bind(start)
+        .bind(end)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn upsert_holidays(&self, holidays: &[Holiday]) -> Result<Vec<Holiday>, DomainError> {
+        let ids: Vec<Uuid> = holidays.iter().map(|h| h.id).collect();
+        let calendar_ids: Vec<Uuid> = holidays.iter().map(|h| h.calendar_id).collect();
+        let dates: Vec<NaiveDate> = holidays.iter().map(|h| h.date).collect();
+        let names: Vec<&str> = holidays.iter().map(|h| h.name.as_str()).collect();
+
+        let rows = sqlx::query_as::<_, HolidayRow>(
+            r#"
+            INSERT INTO holidays (id, calendar_id, date, name)
+            SELECT * FROM UNNEST($1::uuid[], $2::uuid[], $3::date[], $4::text[])
+            ON CONFLICT (calendar_id, date) DO UPDATE SET name = EXCLUDED.name
+            RETURNING *
+            "#,
+        )
+        .bind(&ids)
+        .bind(&calendar_ids)
+        .bind(&dates)
+        .bind(&names)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut holidays: Vec<Holiday> = rows.into_iter().map(Into::into).collect();
+        holidays.sort_by_key(|h| h.date);
+        Ok(holidays)
+    }
+
+    async fn delete_holiday(&self, id: Uuid) -> Result<(), DomainError> {
+        let result = sqlx::query("DELETE FROM holidays WHERE id = $1")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(DomainError::NotFound("Holiday not found".into()));
+        }
+        Ok(())
+    }
+}
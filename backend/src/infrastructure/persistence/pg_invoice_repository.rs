@@ -0,0 +1,192 @@
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use sqlx::{types::Json, FromRow, PgPool};
+use uuid::Uuid;
+
+use crate::domain::entities::{BillableEntry, Invoice, InvoiceLine};
+use crate::domain::repositories::InvoiceRepository;
+use crate::domain::value_objects::UserRole;
+use crate::shared::DomainError;
+
+#[derive(Debug, FromRow)]
+struct InvoiceRow {
+    id: Uuid,
+    project_id: Option<Uuid>,
+    period_start: NaiveDate,
+    period_end: NaiveDate,
+    total_hours: f32,
+    total_amount: Decimal,
+    lines: Json<Vec<InvoiceLine>>,
+    created_by: Option<Uuid>,
+    created_at: DateTime<Utc>,
+}
+
+impl From<InvoiceRow> for Invoice {
+    fn from(row: InvoiceRow) -> Self {
+        Invoice {
+            id: row.id,
+            project_id: row.project_id,
+            period_start: row.period_start,
+            period_end: row.period_end,
+            total_hours: row.total_hours,
+            total_amount: row.total_amount,
+            lines: row.lines.0,
+            created_by: row.created_by,
+            created_at: row.created_at,
+        }
+    }
+}
+
+#[derive(Debug, FromRow)]
+struct BillableEntryRow {
+    time_log_id: Uuid,
+    project_id: Uuid,
+    project_name: String,
+    task_id: Uuid,
+    task_title: String,
+    user_id: Uuid,
+    user_name: String,
+    user_role: UserRole,
+    date: NaiveDate,
+    hours: f32,
+    updated_at: DateTime<Utc>,
+}
+
+impl From<BillableEntryRow> for BillableEntry {
+    fn from(row: BillableEntryRow) -> Self {
+        BillableEntry {
+            time_log_id: row.time_log_id,
+            project_id: row.project_id,
+            project_name: row.project_name,
+            task_id: row.task_id,
+            task_title: row.task_title,
+            user_id: row.user_id,
+            user_name: row.user_name,
+            user_role: row.user_role,
+            date: row.date,
+            hours: row.hours,
+            updated_at: row.updated_at,
+        }
+    }
+}
+
+pub struct PgInvoiceRepository {
+    pool: PgPool,
+}
+
+impl PgInvoiceRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl InvoiceRepository for PgInvoiceRepository {
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Invoice>, DomainError> {
+        let row = sqlx::query_as::<_, InvoiceRow>("SELECT * FROM invoices WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(row.map(Into::into))
+    }
+
+    async fn find_all(&self, project_id: Option<Uuid>) -> Result<Vec<Invoice>, DomainError> {
+        let rows = sqlx::query_as::<_, InvoiceRow>(
+            r#"
+            SELECT * FROM invoices
+            WHERE $1::uuid IS NULL OR project_id = $1
+            ORDER BY created_at DESC
+            "#,
+        )
+        .bind(project_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn find_billable_entries(
+        &self,
+        start_date: NaiveDate,
+        end_date: NaiveDate,
+        project_id: Option<Uuid>,
+    ) -> Result<Vec<BillableEntry>, DomainError> {
+        let rows = sqlx::query_as::<_, BillableEntryRow>(
+            r#"
+            SELECT tl.id AS time_log_id, p.id AS project_id, p.name AS project_name,
+                   t.id AS task_id, t.title AS task_title,
+                   u.id AS user_id, u.name AS user_name, u.role AS user_role,
+                   tl.date, tl.hours, tl.updated_at
+            FROM time_logs tl
+            JOIN tasks t ON tl.task_id = t.id
+            JOIN projects p ON t.project_id = p.id
+            JOIN users u ON tl.user_id = u.id
+            WHERE tl.billable AND tl.invoice_id IS NULL
+              AND tl.date BETWEEN $1 AND $2
+              AND ($3::uuid IS NULL OR p.id = $3)
+              AND t.deleted_at IS NULL AND p.deleted_at IS NULL
+            ORDER BY p.name, t.title, u.name, tl.date
+            "#,
+        )
+        .bind(start_date)
+        .bind(end_date)
+        .bind(project_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn create(
+        &self,
+        invoice: &Invoice,
+        entries: &[BillableEntry],
+    ) -> Result<Invoice, DomainError> {
+        let mut tx = self.pool.begin().await?;
+
+        let row = sqlx::query_as::<_, InvoiceRow>(
+            r#"
+            INSERT INTO invoices (id, project_id, period_start, period_end, total_hours, total_amount, lines, created_by, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            RETURNING *
+            "#,
+        )
+        .bind(invoice.id)
+        .bind(invoice.project_id)
+        .bind(invoice.period_start)
+        .bind(invoice.period_end)
+        .bind(invoice.total_hours)
+        .bind(invoice.total_amount)
+        .bind(Json(&invoice.lines))
+        .bind(invoice.created_by)
+        .bind(invoice.created_at)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        // Only logs left as they were priced are marked
+        let marked = sqlx::query(
+            r#"
+            UPDATE time_logs tl SET invoice_id = $1
+            FROM UNNEST($2::uuid[], $3::timestamptz[]) AS priced(id, updated_at)
+            WHERE tl.id = priced.id AND tl.updated_at = priced.updated_at
+              AND tl.billable AND tl.invoice_id IS NULL
+            "#,
+        )
+        .bind(invoice.id)
+        .bind(entries.iter().map(|e| e.time_log_id).collect::<Vec<_>>())
+        .bind(entries.iter().map(|e| e.updated_at).collect::<Vec<_>>())
+        .execute(&mut *tx)
+        .await?;
+        if marked.rows_affected() != entries.len() as u64 {
+            return Err(DomainError::Conflict(
+                "Some of the time was changed or invoiced meanwhile; prepare the invoice again"
+                    .into(),
+            ));
+        }
+
+        tx.commit().await?;
+        Ok(row.into())
+    }
+}
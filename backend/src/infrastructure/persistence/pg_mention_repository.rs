@@ -0,0 +1,84 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgPool};
+use uuid::Uuid;
+
+use crate::domain::entities::Mention;
+use crate::domain::repositories::MentionRepository;
+use crate::domain::value_objects::MentionSourceType;
+use crate::shared::DomainError;
+
+#[derive(Debug, FromRow)]
+struct MentionRow {
+    id: Uuid,
+    user_id: Uuid,
+    source_type: MentionSourceType,
+    source_id: Uuid,
+    task_id: Uuid,
+    mentioned_by: Uuid,
+    created_at: DateTime<Utc>,
+}
+
+impl From<MentionRow> for Mention {
+    fn from(row: MentionRow) -> Self {
+        Mention {
+            id: row.id,
+            user_id: row.user_id,
+            source_type: row.source_type,
+            source_id: row.source_id,
+            task_id: row.task_id,
+            mentioned_by: row.mentioned_by,
+            created_at: row.created_at,
+        }
+    }
+}
+
+pub struct PgMentionRepository {
+    pool: PgPool,
+}
+
+impl PgMentionRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl MentionRepository for PgMentionRepository {
+    async fn find_by_source(
+        &self,
+        source_type: MentionSourceType,
+        source_id: Uuid,
+    ) -> Result<Vec<Mention>, DomainError> {
+        let rows = sqlx::query_as::<_, MentionRow>(
+            "SELECT * FROM mentions WHERE source_type = $1 AND source_id = $2 ORDER BY created_at ASC",
+        )
+        .bind(source_type)
+        .bind(source_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn create(&self, mention: &Mention) -> Result<bool, DomainError> {
+        let result = sqlx::query(
+            r#"
+            INSERT INTO mentions (id, user_id, source_type, source_id, task_id, mentioned_by, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            ON CONFLICT (user_id, source_type, source_id) DO NOTHING
+            "#,
+        )
+        .bind(mention.id)
+        .bind(mention.user_id)
+        .bind(mention.source_type)
+        .bind(mention.source_id)
+        .bind(mention.task_id)
+        .bind(mention.mentioned_by)
+        .bind(mention.created_at)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
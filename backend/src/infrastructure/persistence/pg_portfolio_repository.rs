@@ -0,0 +1,162 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgPool};
+use uuid::Uuid;
+
+use crate::domain::entities::Portfolio;
+use crate::domain::repositories::PortfolioRepository;
+use crate::domain::value_objects::PortfolioKind;
+use crate::shared::DomainError;
+
+#[derive(Debug, FromRow)]
+struct PortfolioRow {
+    id: Uuid,
+    name: String,
+    description: Option<String>,
+    kind: PortfolioKind,
+    parent_id: Option<Uuid>,
+    owner_id: Uuid,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl From<PortfolioRow> for Portfolio {
+    fn from(row: PortfolioRow) -> Self {
+        Portfolio {
+            id: row.id,
+            name: row.name,
+            description: row.description,
+            kind: row.kind,
+            parent_id: row.parent_id,
+            owner_id: row.owner_id,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }
+    }
+}
+
+pub struct PgPortfolioRepository {
+    pool: PgPool,
+}
+
+impl PgPortfolioRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl PortfolioRepository for PgPortfolioRepository {
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Portfolio>, DomainError> {
+        let row = sqlx::query_as::<_, PortfolioRow>("SELECT * FROM portfolios WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(row.map(Into::into))
+    }
+
+    async fn find_all(&self) -> Result<Vec<Portfolio>, DomainError> {
+        let rows =
+            sqlx::query_as::<_, PortfolioRow>("SELECT * FROM portfolios ORDER BY kind, name")
+                .fetch_all(&self.pool)
+                .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn find_managed_by(&self, user_id: Uuid) -> Result<Vec<Portfolio>, DomainError> {
+        let rows = sqlx::query_as::<_, PortfolioRow>(
+            r#"
+            SELECT g.* FROM portfolios g
+            LEFT JOIN portfolios parent ON g.parent_id = parent.id
+            WHERE g.owner_id = $1 OR parent.owner_id = $1
+            ORDER BY g.kind, g.name
+            "#,
+        )
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn find_children(&self, id: Uuid) -> Result<Vec<Portfolio>, DomainError> {
+        let rows = sqlx::query_as::<_, PortfolioRow>(
+            "SELECT * FROM portfolios WHERE parent_id = $1 ORDER BY name",
+        )
+        .bind(id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn is_manager(&self, id: Uuid, user_id: Uuid) -> Result<bool, DomainError> {
+        let result: Option<(i32,)> = sqlx::query_as(
+            r#"
+            SELECT 1 FROM portfolios g
+            LEFT JOIN portfolios parent ON g.parent_id = parent.id
+            WHERE g.id = $1 AND (g.owner_id = $2 OR parent.owner_id = $2)
+            LIMIT 1
+            "#,
+        )
+        .bind(id)
+        .bind(user_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(result.is_some())
+    }
+
+    async fn create(&self, portfolio: &Portfolio) -> Result<Portfolio, DomainError> {
+        let row = sqlx::query_as::<_, PortfolioRow>(
+            r#"
+            INSERT INTO portfolios (id, name, description, kind, parent_id, owner_id, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            RETURNING *
+            "#,
+        )
+        .bind(portfolio.id)
+        .bind(&portfolio.name)
+        .bind(&portfolio.description)
+        .bind(portfolio.kind)
+        .bind(portfolio.parent_id)
+        .bind(portfolio.owner_id)
+        .bind(portfolio.created_at)
+        .bind(portfolio.updated_at)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(row.into())
+    }
+
+    async fn update(&self, portfolio: &Portfolio) -> Result<Portfolio, DomainError> {
+        let row = sqlx::query_as::<_, PortfolioRow>(
+            r#"
+            UPDATE portfolios
+            SET name = $1, description = $2, parent_id = $3, owner_id = $4, updated_at = NOW()
+            WHERE id = $5
+            RETURNING *
+            "#,
+        )
+        .bind(&portfolio.name)
+        .bind(&portfolio.description)
+        .bind(portfolio.parent_id)
+        .bind(portfolio.owner_id)
+        .bind(portfolio.id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(row.into())
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<(), DomainError> {
+        sqlx::query("DELETE FROM portfolios WHERE id = $1")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,170 @@
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use serde_json::Value as JsonValue;
+use sqlx::{FromRow, PgPool};
+use uuid::Uuid;
+
+use crate::domain::entities::{HealthOverride, HealthSnapshot};
+use crate::domain::repositories::ProjectHealthRepository;
+use crate::domain::value_objects::RagStatus;
+use crate::shared::DomainError;
+
+#[derive(Debug, FromRow)]
+struct HealthOverrideRow {
+    project_id: Uuid,
+    status: RagStatus,
+    comment: String,
+    set_by: Option<Uuid>,
+    set_at: DateTime<Utc>,
+}
+
+impl From<HealthOverrideRow> for HealthOverride {
+    fn from(row: HealthOverrideRow) -> Self {
+        HealthOverride {
+            project_id: row.project_id,
+            status: row.status,
+            comment: row.comment,
+            set_by: row.set_by,
+            set_at: row.set_at,
+        }
+    }
+}
+
+#[derive(Debug, FromRow)]
+struct HealthSnapshotRow {
+    id: Uuid,
+    project_id: Uuid,
+    week_start: NaiveDate,
+    status: RagStatus,
+    computed_status: RagStatus,
+    score: i16,
+    factors: JsonValue,
+    overridden: bool,
+    recorded_at: DateTime<Utc>,
+}
+
+impl From<HealthSnapshotRow> for HealthSnapshot {
+    fn from(row: HealthSnapshotRow) -> Self {
+        HealthSnapshot {
+            id: row.id,
+            project_id: row.project_id,
+            week_start: row.week_start,
+            status: row.status,
+            computed_status: row.computed_status,
+            score: row.score,
+            factors: row.factors,
+            overridden: row.overridden,
+            recorded_at: row.recorded_at,
+        }
+    }
+}
+
+pub struct PgProjectHealthRepository {
+    pool: PgPool,
+}
+
+impl PgProjectHealthRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ProjectHealthRepository for PgProjectHealthRepository {
+    async fn find_override(&self, project_id: Uuid) -> Result<Option<HealthOverride>, DomainError> {
+        let row = sqlx::query_as::<_, HealthOverrideRow>(
+            "SELECT * FROM project_health_overrides WHERE project_id = $1",
+        )
+        .bind(project_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.map(Into::into))
+    }
+
+    async fn save_override(
+        &self,
+        health_override: &HealthOverride,
+    ) -> Result<HealthOverride, DomainError> {
+        let row = sqlx::query_as::<_, HealthOverrideRow>(
+            r#"
+            INSERT INTO project_health_overrides (project_id, status, comment, set_by, set_at)
+            VALUES ($1, $2, $3, $4, $5)
+            ON CONFLICT (project_id)
+            DO UPDATE SET status = EXCLUDED.status, comment = EXCLUDED.comment,
+                set_by = EXCLUDED.set_by, set_at = EXCLUDED.set_at
+            RETURNING *
+            "#,
+        )
+        .bind(health_override.project_id)
+        .bind(health_override.status)
+        .bind(&health_override.comment)
+        .bind(health_override.set_by)
+        .bind(health_override.set_at)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(row.into())
+    }
+
+    async fn delete_override(&self, project_id: Uuid) -> Result<(), DomainError> {
+        sqlx::query("DELETE FROM project_health_overrides WHERE project_id = $1")
+            .bind(project_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn find_snapshots(
+        &self,
+        project_id: Uuid,
+        limit: i64,
+    ) -> Result<Vec<HealthSnapshot>, DomainError> {
+        let rows = sqlx::query_as::<_, HealthSnapshotRow>(
+            r#"
+            SELECT * FROM project_health_snapshots
+            WHERE project_id = $1
+            ORDER BY week_start DESC
+            LIMIT $2
+            "#,
+        )
+        .bind(project_id)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn save_snapshot(
+        &self,
+        snapshot: &HealthSnapshot,
+    ) -> Result<HealthSnapshot, DomainError> {
+        let row = sqlx::query_as::<_, HealthSnapshotRow>(
+            r#"
+            INSERT INTO project_health_snapshots (id, project_id, week_start, status, computed_status,
+                score, factors, overridden, recorded_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            ON CONFLICT (project_id, week_start)
+            DO UPDATE SET status = EXCLUDED.status, computed_status = EXCLUDED.computed_status,
+                score = EXCLUDED.score, factors = EXCLUDED.factors,
+                overridden = EXCLUDED.overridden, recorded_at = EXCLUDED.recorded_at
+            RETURNING *
+            "#,
+        )
+        .bind(snapshot.id)
+        .bind(snapshot.project_id)
+        .bind(snapshot.week_start)
+        .bind(snapshot.status)
+        .bind(snapshot.computed_status)
+        .bind(snapshot.score)
+        .bind(&snapshot.factors)
+        .bind(snapshot.overridden)
+        .bind(snapshot.recorded_at)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(row.into())
+    }
+}
@@ -9,6 +9,15 @@ use crate::domain::repositories::ProjectRepository;
 use crate::domain::value_objects::{Priority, ProjectStatus, TaskStatus};
 use crate::shared::DomainError;
 
+/// Matches when user `$2` owns the project's program or the portfolio above it
+const MANAGES_PROGRAM: &str = r#"
+    EXISTS (
+        SELECT 1 FROM portfolios g
+        LEFT JOIN portfolios parent ON g.parent_id = parent.id
+        WHERE g.id = p.portfolio_id AND (g.owner_id = $2 OR parent.owner_id = $2)
+    )
+"#;
+
 #[derive(Debug, FromRow)]
 struct ProjectRow {
     id: Uuid,
@@ -20,8 +29,13 @@ struct ProjectRow {
     end_date: Option<DateTime<Utc>>,
     budget: Option<Decimal>,
     owner_id: Uuid,
+    portfolio_id: Option<Uuid>,
+    billable: bool,
     created_at: DateTime<Utc>,
     updated_at: DateTime<Utc>,
+    archived_at: Option<DateTime<Utc>>,
+    deleted_at: Option<DateTime<Utc>>,
+    deleted_by: Option<Uuid>,
 }
 
 impl From<ProjectRow> for Project {
@@ -36,8 +50,13 @@ impl From<ProjectRow> for Project {
             end_date: row.end_date,
             budget: row.budget,
             owner_id: row.owner_id,
+            portfolio_id: row.portfolio_id,
+            billable: row.billable,
             created_at: row.created_at,
             updated_at: row.updated_at,
+            archived_at: row.archived_at,
+            deleted_at: row.deleted_at,
+            deleted_by: row.deleted_by,
         }
     }
 }
@@ -47,6 +66,7 @@ struct TaskRow {
     id: Uuid,
     project_id: Uuid,
     milestone_id: Option<Uuid>,
+    sprint_id: Option<Uuid>,
     title: String,
     description: Option<String>,
     status: TaskStatus,
@@ -55,8 +75,12 @@ struct TaskRow {
     due_date: Option<DateTime<Utc>>,
     estimated_hours: Option<f32>,
     actual_hours: Option<f32>,
+    billable: Option<bool>,
+    completed_at: Option<DateTime<Utc>>,
     created_at: DateTime<Utc>,
     updated_at: DateTime<Utc>,
+    deleted_at: Option<DateTime<Utc>>,
+    deleted_by: Option<Uuid>,
 }
 
 impl From<TaskRow> for Task {
@@ -65,6 +89,7 @@ impl From<TaskRow> for Task {
             id: row.id,
             project_id: row.project_id,
             milestone_id: row.milestone_id,
+            sprint_id: row.sprint_id,
             title: row.title,
             description: row.description,
             status: row.status,
@@ -73,8 +98,12 @@ impl From<TaskRow> for Task {
             due_date: row.due_date,
             estimated_hours: row.estimated_hours,
             actual_hours: row.actual_hours,
+            billable: row.billable,
+            completed_at: row.completed_at,
             created_at: row.created_at,
             updated_at: row.updated_at,
+            deleted_at: row.deleted_at,
+            deleted_by: row.deleted_by,
         }
     }
 }
@@ -119,26 +148,34 @@ impl PgProjectRepository {
 #[async_trait]
 impl ProjectRepository for PgProjectRepository {
     async fn find_by_id(&self, id: Uuid) -> Result<Option<Project>, DomainError> {
-        let row = sqlx::query_as::<_, ProjectRow>("SELECT * FROM projects WHERE id = $1")
-            .bind(id)
-            .fetch_optional(&self.pool)
-            .await?;
+        let row = sqlx::query_as::<_, ProjectRow>(
+            "SELECT * FROM projects WHERE id = $1 AND deleted_at IS NULL",
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
 
         Ok(row.map(Into::into))
     }
 
-    async fn find_all(&self) -> Result<Vec<Project>, DomainError> {
-        let rows =
-            sqlx::query_as::<_, ProjectRow>("SELECT * FROM projects ORDER BY created_at DESC")
-                .fetch_all(&self.pool)
-                .await?;
+    async fn find_all(&self, include_archived: bool) -> Result<Vec<Project>, DomainError> {
+        let rows = sqlx::query_as::<_, ProjectRow>(
+            r#"
+            SELECT * FROM projects
+            WHERE deleted_at IS NULL AND ($1 OR archived_at IS NULL)
+            ORDER BY created_at DESC
+            "#,
+        )
+        .bind(include_archived)
+        .fetch_all(&self.pool)
+        .await?;
 
         Ok(rows.into_iter().map(Into::into).collect())
     }
 
     async fn find_by_owner(&self, owner_id: Uuid) -> Result<Vec<Project>, DomainError> {
         let rows = sqlx::query_as::<_, ProjectRow>(
-            "SELECT * FROM projects WHERE owner_id = $1 ORDER BY created_at DESC",
+            "SELECT * FROM projects WHERE owner_id = $1 AND deleted_at IS NULL ORDER BY created_at DESC",
         )
         .bind(owner_id)
         .fetch_all(&self.pool)
@@ -147,15 +184,23 @@ impl ProjectRepository for PgProjectRepository {
         Ok(rows.into_iter().map(Into::into).collect())
     }
 
-    async fn find_accessible_by_user(&self, user_id: Uuid) -> Result<Vec<Project>, DomainError> {
-        let rows = sqlx::query_as::<_, ProjectRow>(
+    async fn find_accessible_by_user(
+        &self,
+        user_id: Uuid,
+        include_archived: bool,
+    ) -> Result<Vec<Project>, DomainError> {
+        let rows = sqlx::query_as::<_, ProjectRow>(&format!(
             r#"
             SELECT DISTINCT p.* FROM projects p
             LEFT JOIN project_members pm ON p.id = pm.project_id
-            WHERE p.owner_id = $1 OR pm.user_id = $1
+            WHERE (p.owner_id = $2 OR pm.user_id = $2 OR {})
+              AND p.deleted_at IS NULL
+              AND ($1 OR p.archived_at IS NULL)
             ORDER BY p.created_at DESC
             "#,
-        )
+            MANAGES_PROGRAM
+        ))
+        .bind(include_archived)
         .bind(user_id)
         .fetch_all(&self.pool)
         .await?;
@@ -164,11 +209,12 @@ impl ProjectRepository for PgProjectRepository {
     }
 
     async fn can_user_access(&self, project_id: Uuid, user_id: Uuid) -> Result<bool, DomainError> {
-        let result: Option<(i64,)> = sqlx::query_as(
+        let result: Option<(i32,)> = sqlx::query_as(
             r#"
             SELECT 1 FROM projects p
             LEFT JOIN project_members pm ON p.id = pm.project_id
             WHERE p.id = $1 AND (p.owner_id = $2 OR pm.user_id = $2)
+              AND p.deleted_at IS NULL
             LIMIT 1
             "#,
         )
@@ -180,11 +226,41 @@ impl ProjectRepository for PgProjectRepository {
         Ok(result.is_some())
     }
 
+    async fn can_user_view(&self, project_id: Uuid, user_id: Uuid) -> Result<bool, DomainError> {
+        let result: Option<(i32,)> = sqlx::query_as(&format!(
+            r#"
+            SELECT 1 FROM projects p
+            LEFT JOIN project_members pm ON p.id = pm.project_id
+            WHERE p.id = $1 AND (p.owner_id = $2 OR pm.user_id = $2 OR {})
+              AND p.deleted_at IS NULL
+            LIMIT 1
+            "#,
+            MANAGES_PROGRAM
+        ))
+        .bind(project_id)
+        .bind(user_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(result.is_some())
+    }
+
     async fn is_owner(&self, project_id: Uuid, user_id: Uuid) -> Result<bool, DomainError> {
-        let result: Option<(i64,)> =
-            sqlx::query_as("SELECT 1 FROM projects WHERE id = $1 AND owner_id = $2 LIMIT 1")
+        let result: Option<(i32,)> = sqlx::query_as(
+            "SELECT 1 FROM projects WHERE id = $1 AND owner_id = $2 AND deleted_at IS NULL LIMIT 1",
+        )
+        .bind(project_id)
+        .bind(user_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(result.is_some())
+    }
+
+    async fn is_change_controlled(&self, project_id: Uuid) -> Result<bool, DomainError> {
+        let result: Option<(i32,)> =
+            sqlx::query_as("SELECT 1 FROM project_change_approvers WHERE project_id = $1 LIMIT 1")
                 .bind(project_id)
-                .bind(user_id)
                 .fetch_optional(&self.pool)
                 .await?;
 
@@ -194,8 +270,8 @@ impl ProjectRepository for PgProjectRepository {
     async fn create(&self, project: &Project) -> Result<Project, DomainError> {
         let row = sqlx::query_as::<_, ProjectRow>(
             r#"
-            INSERT INTO projects (id, name, description, status, priority, start_date, end_date, budget, owner_id, created_at, updated_at)
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+            INSERT INTO projects (id, name, description, status, priority, start_date, end_date, budget, owner_id, billable, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
             RETURNING *
             "#,
         )
@@ -208,6 +284,7 @@ impl ProjectRepository for PgProjectRepository {
         .bind(project.end_date)
         .bind(project.budget)
         .bind(project.owner_id)
+        .bind(project.billable)
         .bind(project.created_at)
         .bind(project.updated_at)
         .fetch_one(&self.pool)
@@ -221,8 +298,8 @@ impl ProjectRepository for PgProjectRepository {
             r#"
             UPDATE projects
             SET name = $1, description = $2, status = $3, priority = $4,
-                start_date = $5, end_date = $6, budget = $7, updated_at = NOW()
-            WHERE id = $8
+                start_date = $5, end_date = $6, budget = $7, billable = $8, updated_at = NOW()
+            WHERE id = $9 AND deleted_at IS NULL
             RETURNING *
             "#,
         )
@@ -233,6 +310,24 @@ impl ProjectRepository for PgProjectRepository {
         .bind(project.start_date)
         .bind(project.end_date)
         .bind(project.budget)
+        .bind(project.billable)
+        .bind(project.id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(row.into())
+    }
+
+    async fn set_archived(&self, project: &Project) -> Result<Project, DomainError> {
+        let row = sqlx::query_as::<_, ProjectRow>(
+            r#"
+            UPDATE projects
+            SET archived_at = $1, updated_at = NOW()
+            WHERE id = $2 AND deleted_at IS NULL
+            RETURNING *
+            "#,
+        )
+        .bind(project.archived_at)
         .bind(project.id)
         .fetch_one(&self.pool)
         .await?;
@@ -240,6 +335,50 @@ impl ProjectRepository for PgProjectRepository {
         Ok(row.into())
     }
 
+    async fn is_task_archived(&self, task_id: Uuid) -> Result<bool, DomainError> {
+        let result: Option<(i32,)> = sqlx::query_as(
+            r#"
+            SELECT 1 FROM tasks t
+            INNER JOIN projects p ON t.project_id = p.id
+            WHERE t.id = $1 AND p.archived_at IS NOT NULL
+            LIMIT 1
+            "#,
+        )
+        .bind(task_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(result.is_some())
+    }
+
+    async fn soft_delete(&self, id: Uuid, deleted_by: Uuid) -> Result<(), DomainError> {
+        sqlx::query(
+            "UPDATE projects SET deleted_at = NOW(), deleted_by = $1 WHERE id = $2 AND deleted_at IS NULL",
+        )
+        .bind(deleted_by)
+        .bind(id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn restore(&self, id: Uuid) -> Result<Project, DomainError> {
+        let row = sqlx::query_as::<_, ProjectRow>(
+            r#"
+            UPDATE projects
+            SET deleted_at = NULL, deleted_by = NULL, updated_at = NOW()
+            WHERE id = $1
+            RETURNING *
+            "#,
+        )
+        .bind(id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(row.into())
+    }
+
     async fn delete(&self, id: Uuid) -> Result<(), DomainError> {
         sqlx::query("DELETE FROM projects WHERE id = $1")
             .bind(id)
@@ -249,9 +388,84 @@ impl ProjectRepository for PgProjectRepository {
         Ok(())
     }
 
+    async fn find_deleted_by_id(&self, id: Uuid) -> Result<Option<Project>, DomainError> {
+        let row = sqlx::query_as::<_, ProjectRow>(
+            "SELECT * FROM projects WHERE id = $1 AND deleted_at IS NOT NULL",
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.map(Into::into))
+    }
+
+    async fn find_deleted_by_user(&self, user_id: Uuid) -> Result<Vec<Project>, DomainError> {
+        let rows = sqlx::query_as::<_, ProjectRow>(
+            "SELECT * FROM projects WHERE deleted_by = $1 AND deleted_at IS NOT NULL ORDER BY deleted_at DESC",
+        )
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn find_deleted_before(
+        &self,
+        cutoff: DateTime<Utc>,
+    ) -> Result<Vec<Project>, DomainError> {
+        let rows = sqlx::query_as::<_, ProjectRow>(
+            "SELECT * FROM projects WHERE deleted_at < $1 ORDER BY deleted_at ASC",
+        )
+        .bind(cutoff)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn find_by_portfolios(
+        &self,
+        portfolio_ids: &[Uuid],
+    ) -> Result<Vec<Project>, DomainError> {
+        let rows = sqlx::query_as::<_, ProjectRow>(
+            r#"
+            SELECT * FROM projects
+            WHERE portfolio_id = ANY($1) AND deleted_at IS NULL
+            ORDER BY name ASC
+            "#,
+        )
+        .bind(portfolio_ids)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn set_portfolio(
+        &self,
+        project_id: Uuid,
+        portfolio_id: Option<Uuid>,
+    ) -> Result<Project, DomainError> {
+        let row = sqlx::query_as::<_, ProjectRow>(
+            r#"
+            UPDATE projects
+            SET portfolio_id = $1, updated_at = NOW()
+            WHERE id = $2 AND deleted_at IS NULL
+            RETURNING *
+            "#,
+        )
+        .bind(portfolio_id)
+        .bind(project_id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(row.into())
+    }
+
     async fn find_tasks(&self, project_id: Uuid) -> Result<Vec<Task>, DomainError> {
         let rows = sqlx::query_as::<_, TaskRow>(
-            "SELECT * FROM tasks WHERE project_id = $1 ORDER BY created_at DESC",
+            "SELECT * FROM tasks WHERE project_id = $1 AND deleted_at IS NULL ORDER BY created_at DESC",
         )
         .bind(project_id)
         .fetch_all(&self.pool)
@@ -0,0 +1,212 @@
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use sqlx::{FromRow, PgPool, Postgres, Transaction};
+use uuid::Uuid;
+
+use crate::domain::entities::RaidItem;
+use crate::domain::repositories::RaidItemRepository;
+use crate::domain::value_objects::{RaidStatus, RaidType};
+use crate::shared::DomainError;
+
+/// Items with their linked tasks aggregated into `task_ids`
+const SELECT_ITEMS: &str = r#"
+    SELECT r.*,
+           COALESCE(ARRAY(SELECT t.task_id FROM raid_item_tasks t WHERE t.raid_item_id = r.id), '{}') AS task_ids
+    FROM raid_items r
+"#;
+
+#[derive(Debug, FromRow)]
+struct RaidItemRow {
+    id: Uuid,
+    project_id: Uuid,
+    item_type: RaidType,
+    title: String,
+    description: Option<String>,
+    owner_id: Option<Uuid>,
+    status: RaidStatus,
+    probability: Option<i16>,
+    impact: Option<i16>,
+    mitigation_plan: Option<String>,
+    review_date: Option<NaiveDate>,
+    task_ids: Vec<Uuid>,
+    reminded_for: Option<NaiveDate>,
+    created_by: Option<Uuid>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl From<RaidItemRow> for RaidItem {
+    fn from(row: RaidItemRow) -> Self {
+        RaidItem {
+            id: row.id,
+            project_id: row.project_id,
+            item_type: row.item_type,
+            title: row.title,
+            description: row.description,
+            owner_id: row.owner_id,
+            status: row.status,
+            probability: row.probability,
+            impact: row.impact,
+            mitigation_plan: row.mitigation_plan,
+            review_date: row.review_date,
+            task_ids: row.task_ids,
+            reminded_for: row.reminded_for,
+            created_by: row.created_by,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }
+    }
+}
+
+pub struct PgRaidItemRepository {
+    pool: PgPool,
+}
+
+impl PgRaidItemRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    async fn replace_tasks(
+        tx: &mut Transaction<'_, Postgres>,
+        item: &RaidItem,
+    ) -> Result<(), DomainError> {
+        sqlx::query("DELETE FROM raid_item_tasks WHERE raid_item_id = $1")
+            .bind(item.id)
+            .execute(&mut **tx)
+            .await?;
+        sqlx::query(
+            "INSERT INTO raid_item_tasks (raid_item_id, task_id) SELECT $1, UNNEST($2::uuid[])",
+        )
+        .bind(item.id)
+        .bind(&item.task_ids)
+        .execute(&mut **tx)
+        .await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl RaidItemRepository for PgRaidItemRepository {
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<RaidItem>, DomainError> {
+        let row = sqlx::query_as::<_, RaidItemRow>(&format!("{} WHERE r.id = $1", SELECT_ITEMS))
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(row.map(Into::into))
+    }
+
+    async fn find_by_project(&self, project_id: Uuid) -> Result<Vec<RaidItem>, DomainError> {
+        let rows = sqlx::query_as::<_, RaidItemRow>(&format!(
+            "{} WHERE r.project_id = $1 ORDER BY r.created_at DESC",
+            SELECT_ITEMS
+        ))
+        .bind(project_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn find_review_overdue(&self, today: NaiveDate) -> Result<Vec<RaidItem>, DomainError> {
+        let rows = sqlx::query_as::<_, RaidItemRow>(&format!(
+            r#"{}
+            JOIN projects p ON r.project_id = p.id
+            WHERE r.status IN ('open', 'monitoring')
+              AND p.deleted_at IS NULL
+              AND r.review_date < $1
+              AND r.reminded_for IS DISTINCT FROM r.review_date
+            ORDER BY r.review_date ASC"#,
+            SELECT_ITEMS
+        ))
+        .bind(today)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn create(&self, item: &RaidItem) -> Result<RaidItem, DomainError> {
+        let mut tx = self.pool.begin().await?;
+        sqlx::query(
+            r#"
+            INSERT INTO raid_items (id, project_id, item_type, title, description, owner_id, status,
+                probability, impact, mitigation_plan, review_date, created_by, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14)
+            "#,
+        )
+        .bind(item.id)
+        .bind(item.project_id)
+        .bind(item.item_type)
+        .bind(&item.title)
+        .bind(&item.description)
+        .bind(item.owner_id)
+        .bind(item.status)
+        .bind(item.probability)
+        .bind(item.impact)
+        .bind(&item.mitigation_plan)
+        .bind(item.review_date)
+        .bind(item.created_by)
+        .bind(item.created_at)
+        .bind(item.updated_at)
+        .execute(&mut *tx)
+        .await?;
+        Self::replace_tasks(&mut tx, item).await?;
+        tx.commit().await?;
+
+        self.find_by_id(item.id)
+            .await?
+            .ok_or_else(|| DomainError::InternalError("RAID item missing after insert".into()))
+    }
+
+    async fn update(&self, item: &RaidItem) -> Result<RaidItem, DomainError> {
+        let mut tx = self.pool.begin().await?;
+        sqlx::query(
+            r#"
+            UPDATE raid_items
+            SET item_type = $1, title = $2, description = $3, owner_id = $4, status = $5,
+                probability = $6, impact = $7, mitigation_plan = $8, review_date = $9,
+                updated_at = NOW()
+            WHERE id = $10
+            "#,
+        )
+        .bind(item.item_type)
+        .bind(&item.title)
+        .bind(&item.description)
+        .bind(item.owner_id)
+        .bind(item.status)
+        .bind(item.probability)
+        .bind(item.impact)
+        .bind(&item.mitigation_plan)
+        .bind(item.review_date)
+        .bind(item.id)
+        .execute(&mut *tx)
+        .await?;
+        Self::replace_tasks(&mut tx, item).await?;
+        tx.commit().await?;
+
+        self.find_by_id(item.id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("RAID item not found".into()))
+    }
+
+    async fn mark_reminded(&self, id: Uuid, review_date: NaiveDate) -> Result<(), DomainError> {
+        sqlx::query("UPDATE raid_items SET reminded_for = $1 WHERE id = $2")
+            .bind(review_date)
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<(), DomainError> {
+        sqlx::query("DELETE FROM raid_items WHERE id = $1")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,219 @@
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use sqlx::{FromRow, PgPool};
+use uuid::Uuid;
+
+use crate::domain::entities::ResourceAllocation;
+use crate::domain::repositories::ResourceAllocationRepository;
+use crate::shared::DomainError;
+
+/// Allocations with their user and project names, trashed projects excluded
+const SELECT_ALLOCATIONS: &str = r#"
+    SELECT a.*, u.name AS user_name, p.name AS project_name
+    FROM resource_allocations a
+    JOIN users u ON a.user_id = u.id
+    JOIN projects p ON a.project_id = p.id
+"#;
+
+#[derive(Debug, FromRow)]
+struct ResourceAllocationRow {
+    id: Uuid,
+    user_id: Uuid,
+    project_id: Uuid,
+    start_date: NaiveDate,
+    end_date: NaiveDate,
+    percent: Option<f32>,
+    hours_per_week: Option<f32>,
+    note: Option<String>,
+    created_by: Option<Uuid>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+    user_name: Option<String>,
+    project_name: Option<String>,
+}
+
+impl From<ResourceAllocationRow> for ResourceAllocation {
+    fn from(row: ResourceAllocationRow) -> Self {
+        ResourceAllocation {
+            id: row.id,
+            user_id: row.user_id,
+            project_id: row.project_id,
+            start_date: row.start_date,
+            end_date: row.end_date,
+            percent: row.percent,
+            hours_per_week: row.hours_per_week,
+            note: row.note,
+            created_by: row.created_by,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+            user_name: row.user_name,
+            project_name: row.project_name,
+        }
+    }
+}
+
+pub struct PgResourceAllocationRepository {
+    pool: PgPool,
+}
+
+impl PgResourceAllocationRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    async fn find_one(&self, id: Uuid) -> Result<ResourceAllocation, DomainError> {
+        self.find_by_id(id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("Allocation not found".into()))
+    }
+}
+
+#[async_trait]
+impl ResourceAllocationRepository for PgResourceAllocationRepository {
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<ResourceAllocation>, DomainError> {
+        let row = sqlx::query_as::<_, ResourceAllocationRow>(&format!(
+            "{} WHERE a.id = $1 AND p.deleted_at IS NULL",
+            SELECT_ALLOCATIONS
+        ))
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.map(Into::into))
+    }
+
+    async fn find_by_project(
+        &self,
+        project_id: Uuid,
+    ) -> Result<Vec<ResourceAllocation>, DomainError> {
+        let rows = sqlx::query_as::<_, ResourceAllocationRow>(&format!(
+            "{} WHERE a.project_id = $1 AND p.deleted_at IS NULL ORDER BY a.start_date ASC, u.name ASC",
+            SELECT_ALLOCATIONS
+        ))
+        .bind(project_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn find_by_user(&self, user_id: Uuid) -> Result<Vec<ResourceAllocation>, DomainError> {
+        let rows = sqlx::query_as::<_, ResourceAllocationRow>(&format!(
+            "{} WHERE a.user_id = $1 AND p.deleted_at IS NULL ORDER BY a.start_date DESC",
+            SELECT_ALLOCATIONS
+        ))
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn find_in_range(
+        &self,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<Vec<ResourceAllocation>, DomainError> {
+        let rows = sqlx::query_as::<_, ResourceAllocationRow>(&format!(
+            r#"{}
+            WHERE a.start_date <= $2 AND a.end_date >= $1 AND p.deleted_at IS NULL
+            ORDER BY a.start_date ASC"#,
+            SELECT_ALLOCATIONS
+        ))
+        .bind(start)
+        .bind(end)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn find_by_users_in_range(
+        &self,
+        user_ids: &[Uuid],
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<Vec<ResourceAllocation>, DomainError> {
+        let rows = sqlx::query_as::<_, ResourceAllocationRow>(&format!(
+            r#"{}
+            WHERE a.user_id = ANY($1) AND a.start_date <= $3 AND a.end_date >= $2
+              AND p.deleted_at IS NULL
+            ORDER BY a.start_date ASC"#,
+            SELECT_ALLOCATIONS
+        ))
+        .bind(user_ids)
+        .bind(start)
+        .bind(end)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn create(
+        &self,
+        allocation: &ResourceAllocation,
+    ) -> Result<ResourceAllocation, DomainError> {
+        sqlx::query(
+            r#"
+            INSERT INTO resource_allocations (id, user_id, project_id, start_date, end_date, percent,
+                hours_per_week, note, created_by, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+            "#,
+        )
+        .bind(allocation.id)
+        .bind(allocation.user_id)
+        .bind(allocation.project_id)
+        .bind(allocation.start_date)
+        .bind(allocation.end_date)
+        .bind(allocation.percent)
+        .bind(allocation.hours_per_week)
+        .bind(&allocation.note)
+        .bind(allocation.created_by)
+        .bind(allocation.created_at)
+        .bind(allocation.updated_at)
+        .execute(&self.pool)
+        .await?;
+
+        self.find_one(allocation.id).await
+    }
+
+    async fn update(
+        &self,
+        allocation: &ResourceAllocation,
+    ) -> Result<ResourceAllocation, DomainError> {
+        let result = sqlx::query(
+            r#"
+            UPDATE resource_allocations
+            SET start_date = $1, end_date = $2, percent = $3, hours_per_week = $4, note = $5,
+                updated_at = NOW()
+            WHERE id = $6
+            "#,
+        )
+        .bind(allocation.start_date)
+        .bind(allocation.end_date)
+        .bind(allocation.percent)
+        .bind(allocation.hours_per_week)
+        .bind(&allocation.note)
+        .bind(allocation.id)
+        .execute(&self.pool)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(DomainError::NotFound("Allocation not found".into()));
+        }
+        self.find_one(allocation.id).await
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<(), DomainError> {
+        let result = sqlx::query("DELETE FROM resource_allocations WHERE id = $1")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(DomainError::NotFound("Allocation not found".into()));
+        }
+        Ok(())
+    }
+}
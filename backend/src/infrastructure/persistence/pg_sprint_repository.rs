@@ -0,0 +1,195 @@
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use serde_json::Value as JsonValue;
+use sqlx::{FromRow, PgPool};
+use uuid::Uuid;
+
+use crate::domain::entities::Sprint;
+use crate::domain::repositories::SprintRepository;
+use crate::domain::value_objects::SprintState;
+use crate::shared::DomainError;
+
+#[derive(Debug, FromRow)]
+struct SprintRow {
+    id: Uuid,
+    project_id: Uuid,
+    name: String,
+    goal: Option<String>,
+    start_date: NaiveDate,
+    end_date: NaiveDate,
+    state: SprintState,
+    committed_hours: Option<f32>,
+    committed_tasks: Option<i32>,
+    completed_hours: Option<f32>,
+    completed_tasks: Option<i32>,
+    burndown: Option<JsonValue>,
+    started_at: Option<DateTime<Utc>>,
+    closed_at: Option<DateTime<Utc>>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl From<SprintRow> for Sprint {
+    fn from(row: SprintRow) -> Self {
+        Sprint {
+            id: row.id,
+            project_id: row.project_id,
+            name: row.name,
+            goal: row.goal,
+            start_date: row.start_date,
+            end_date: row.end_date,
+            state: row.state,
+            committed_hours: row.committed_hours,
+            committed_tasks: row.committed_tasks,
+            completed_hours: row.completed_hours,
+            completed_tasks: row.completed_tasks,
+            burndown: row.burndown,
+            started_at: row.started_at,
+            closed_at: row.closed_at,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }
+    }
+}
+
+pub struct PgSprintRepository {
+    pool: PgPool,
+}
+
+impl PgSprintRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl SprintRepository for PgSprintRepository {
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Sprint>, DomainError> {
+        let row = sqlx::query_as::<_, SprintRow>("SELECT * FROM sprints WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(row.map(Into::into))
+    }
+
+    async fn find_by_project(&self, project_id: Uuid) -> Result<Vec<Sprint>, DomainError> {
+        let rows = sqlx::query_as::<_, SprintRow>(
+            "SELECT * FROM sprints WHERE project_id = $1 ORDER BY start_date ASC, created_at ASC",
+        )
+        .bind(project_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn find_active_by_project(
+        &self,
+        project_id: Uuid,
+    ) -> Result<Option<Sprint>, DomainError> {
+        let row = sqlx::query_as::<_, SprintRow>(
+            "SELECT * FROM sprints WHERE project_id = $1 AND state = 'active'",
+        )
+        .bind(project_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.map(Into::into))
+    }
+
+    async fn find_next_planned(
+        &self,
+        project_id: Uuid,
+        exclude_id: Uuid,
+    ) -> Result<Option<Sprint>, DomainError> {
+        let row = sqlx::query_as::<_, SprintRow>(
+            r#"
+            SELECT * FROM sprints
+            WHERE project_id = $1 AND state = 'planned' AND id <> $2
+            ORDER BY start_date ASC, created_at ASC
+            LIMIT 1
+            "#,
+        )
+        .bind(project_id)
+        .bind(exclude_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.map(Into::into))
+    }
+
+    async fn find_closed_by_project(&self, project_id: Uuid) -> Result<Vec<Sprint>, DomainError> {
+        let rows = sqlx::query_as::<_, SprintRow>(
+            "SELECT * FROM sprints WHERE project_id = $1 AND state = 'closed' ORDER BY closed_at ASC",
+        )
+        .bind(project_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn create(&self, sprint: &Sprint) -> Result<Sprint, DomainError> {
+        let row = sqlx::query_as::<_, SprintRow>(
+            r#"
+            INSERT INTO sprints (id, project_id, name, goal, start_date, end_date, state, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            RETURNING *
+            "#,
+        )
+        .bind(sprint.id)
+        .bind(sprint.project_id)
+        .bind(&sprint.name)
+        .bind(&sprint.goal)
+        .bind(sprint.start_date)
+        .bind(sprint.end_date)
+        .bind(sprint.state)
+        .bind(sprint.created_at)
+        .bind(sprint.updated_at)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(row.into())
+    }
+
+    async fn update(&self, sprint: &Sprint) -> Result<Sprint, DomainError> {
+        let row = sqlx::query_as::<_, SprintRow>(
+            r#"
+            UPDATE sprints
+            SET name = $1, goal = $2, start_date = $3, end_date = $4, state = $5,
+                committed_hours = $6, committed_tasks = $7, completed_hours = $8,
+                completed_tasks = $9, burndown = $10, started_at = $11, closed_at = $12,
+                updated_at = NOW()
+            WHERE id = $13
+            RETURNING *
+            "#,
+        )
+        .bind(&sprint.name)
+        .bind(&sprint.goal)
+        .bind(sprint.start_date)
+        .bind(sprint.end_date)
+        .bind(sprint.state)
+        .bind(sprint.committed_hours)
+        .bind(sprint.committed_tasks)
+        .bind(sprint.completed_hours)
+        .bind(sprint.completed_tasks)
+        .bind(&sprint.burndown)
+        .bind(sprint.started_at)
+        .bind(sprint.closed_at)
+        .bind(sprint.id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(row.into())
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<(), DomainError> {
+        sqlx::query("DELETE FROM sprints WHERE id = $1")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+}
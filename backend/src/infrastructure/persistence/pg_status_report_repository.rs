@@ -0,0 +1,95 @@
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use sqlx::{types::Json, FromRow, PgPool};
+use uuid::Uuid;
+
+use crate::domain::entities::{StatusReport, StatusReportContent};
+use crate::domain::repositories::StatusReportRepository;
+use crate::shared::DomainError;
+
+#[derive(Debug, FromRow)]
+struct StatusReportRow {
+    id: Uuid,
+    project_id: Uuid,
+    period_start: NaiveDate,
+    period_end: NaiveDate,
+    highlights: Option<String>,
+    content: Json<StatusReportContent>,
+    created_by: Option<Uuid>,
+    created_at: DateTime<Utc>,
+}
+
+impl From<StatusReportRow> for StatusReport {
+    fn from(row: StatusReportRow) -> Self {
+        StatusReport {
+            id: row.id,
+            project_id: row.project_id,
+            period_start: row.period_start,
+            period_end: row.period_end,
+            highlights: row.highlights,
+            content: row.content.0,
+            created_by: row.created_by,
+            created_at: row.created_at,
+        }
+    }
+}
+
+pub struct PgStatusReportRepository {
+    pool: PgPool,
+}
+
+impl PgStatusReportRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl StatusReportRepository for PgStatusReportRepository {
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<StatusReport>, DomainError> {
+        let row =
+            sqlx::query_as::<_, StatusReportRow>("SELECT * FROM status_reports WHERE id = $1")
+                .bind(id)
+                .fetch_optional(&self.pool)
+                .await?;
+
+        Ok(row.map(Into::into))
+    }
+
+    async fn find_by_project(&self, project_id: Uuid) -> Result<Vec<StatusReport>, DomainError> {
+        let rows = sqlx::query_as::<_, StatusReportRow>(
+            r#"
+            SELECT * FROM status_reports
+            WHERE project_id = $1
+            ORDER BY period_end DESC, created_at DESC
+            "#,
+        )
+        .bind(project_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn create(&self, report: &StatusReport) -> Result<StatusReport, DomainError> {
+        let row = sqlx::query_as::<_, StatusReportRow>(
+            r#"
+            INSERT INTO status_reports (id, project_id, period_start, period_end, highlights, content, created_by, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            RETURNING *
+            "#,
+        )
+        .bind(report.id)
+        .bind(report.project_id)
+        .bind(report.period_start)
+        .bind(report.period_end)
+        .bind(&report.highlights)
+        .bind(Json(&report.content))
+        .bind(report.created_by)
+        .bind(report.created_at)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(row.into())
+    }
+}
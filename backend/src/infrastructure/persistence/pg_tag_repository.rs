@@ -145,7 +145,9 @@ impl TagRepository for PgTagRepository {
             r#"
             SELECT t.* FROM tags t
             INNER JOIN task_tags tt ON t.id = tt.tag_id
-            WHERE tt.task_id = $1
+            INNER JOIN tasks tk ON tt.task_id = tk.id
+            INNER JOIN projects p ON tk.project_id = p.id
+            WHERE tt.task_id = $1 AND tk.deleted_at IS NULL AND p.deleted_at IS NULL
             ORDER BY t.name ASC
             "#,
         )
@@ -157,10 +159,17 @@ impl TagRepository for PgTagRepository {
     }
 
     async fn find_tasks_by_tag(&self, tag_id: Uuid) -> Result<Vec<Uuid>, DomainError> {
-        let rows: Vec<(Uuid,)> = sqlx::query_as("SELECT task_id FROM task_tags WHERE tag_id = $1")
-            .bind(tag_id)
-            .fetch_all(&self.pool)
-            .await?;
+        let rows: Vec<(Uuid,)> = sqlx::query_as(
+            r#"
+            SELECT tt.task_id FROM task_tags tt
+            INNER JOIN tasks t ON tt.task_id = t.id
+            INNER JOIN projects p ON t.project_id = p.id
+            WHERE tt.tag_id = $1 AND t.deleted_at IS NULL AND p.deleted_at IS NULL
+            "#,
+        )
+        .bind(tag_id)
+        .fetch_all(&self.pool)
+        .await?;
 
         Ok(rows.into_iter().map(|r| r.0).collect())
     }
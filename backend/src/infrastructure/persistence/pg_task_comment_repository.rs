@@ -0,0 +1,123 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgPool};
+use uuid::Uuid;
+
+use crate::domain::entities::TaskComment;
+use crate::domain::repositories::TaskCommentRepository;
+use crate::shared::DomainError;
+
+#[derive(Debug, FromRow)]
+struct TaskCommentRow {
+    id: Uuid,
+    task_id: Uuid,
+    user_id: Uuid,
+    content: String,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl From<TaskCommentRow> for TaskComment {
+    fn from(row: TaskCommentRow) -> Self {
+        TaskComment {
+            id: row.id,
+            task_id: row.task_id,
+            user_id: row.user_id,
+            content: row.content,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }
+    }
+}
+
+pub struct PgTaskCommentRepository {
+    pool: PgPool,
+}
+
+impl PgTaskCommentRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl TaskCommentRepository for PgTaskCommentRepository {
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<TaskComment>, DomainError> {
+        let row = sqlx::query_as::<_, TaskCommentRow>(
+            r#"
+            SELECT c.* FROM task_comments c
+            INNER JOIN tasks t ON c.task_id = t.id
+            INNER JOIN projects p ON t.project_id = p.id
+            WHERE c.id = $1 AND t.deleted_at IS NULL AND p.deleted_at IS NULL
+            "#,
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.map(Into::into))
+    }
+
+    async fn find_by_task(&self, task_id: Uuid) -> Result<Vec<TaskComment>, DomainError> {
+        let rows = sqlx::query_as::<_, TaskCommentRow>(
+            r#"
+            SELECT c.* FROM task_comments c
+            INNER JOIN tasks t ON c.task_id = t.id
+            INNER JOIN projects p ON t.project_id = p.id
+            WHERE c.task_id = $1 AND t.deleted_at IS NULL AND p.deleted_at IS NULL
+            ORDER BY c.created_at ASC
+            "#,
+        )
+        .bind(task_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn create(&self, comment: &TaskComment) -> Result<TaskComment, DomainError> {
+        let row = sqlx::query_as::<_, TaskCommentRow>(
+            r#"
+            INSERT INTO task_comments (id, task_id, user_id, content, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            RETURNING *
+            "#,
+        )
+        .bind(comment.id)
+        .bind(comment.task_id)
+        .bind(comment.user_id)
+        .bind(&comment.content)
+        .bind(comment.created_at)
+        .bind(comment.updated_at)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(row.into())
+    }
+
+    async fn update(&self, comment: &TaskComment) -> Result<TaskComment, DomainError> {
+        let row = sqlx::query_as::<_, TaskCommentRow>(
+            r#"
+            UPDATE task_comments
+            SET content = $1, updated_at = NOW()
+            WHERE id = $2
+            RETURNING *
+            "#,
+        )
+        .bind(&comment.content)
+        .bind(comment.id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(row.into())
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<(), DomainError> {
+        sqlx::query("DELETE FROM task_comments WHERE id = $1")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,95 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgPool};
+use uuid::Uuid;
+
+use crate::domain::entities::TaskDependency;
+use crate::domain::repositories::TaskDependencyRepository;
+use crate::shared::DomainError;
+
+#[derive(Debug, FromRow)]
+struct TaskDependencyRow {
+    id: Uuid,
+    task_id: Uuid,
+    depends_on_id: Uuid,
+    created_at: DateTime<Utc>,
+}
+
+impl From<TaskDependencyRow> for TaskDependency {
+    fn from(row: TaskDependencyRow) -> Self {
+        TaskDependency {
+            id: row.id,
+            task_id: row.task_id,
+            depends_on_id: row.depends_on_id,
+            created_at: row.created_at,
+        }
+    }
+}
+
+pub struct PgTaskDependencyRepository {
+    pool: PgPool,
+}
+
+impl PgTaskDependencyRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl TaskDependencyRepository for PgTaskDependencyRepository {
+    async fn find_by_project(&self, project_id: Uuid) -> Result<Vec<TaskDependency>, DomainError> {
+        let rows = sqlx::query_as::<_, TaskDependencyRow>(
+            r#"
+            SELECT d.* FROM task_dependencies d
+            INNER JOIN tasks t ON d.task_id = t.id
+            WHERE t.project_id = $1
+            ORDER BY d.created_at ASC
+            "#,
+        )
+        .bind(project_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn find_by_task(&self, task_id: Uuid) -> Result<Vec<TaskDependency>, DomainError> {
+        let rows = sqlx::query_as::<_, TaskDependencyRow>(
+            "SELECT * FROM task_dependencies WHERE task_id = $1 ORDER BY created_at ASC",
+        )
+        .bind(task_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn create(&self, dependency: &TaskDependency) -> Result<TaskDependency, DomainError> {
+        let row = sqlx::query_as::<_, TaskDependencyRow>(
+            r#"
+            INSERT INTO task_dependencies (id, task_id, depends_on_id, created_at)
+            VALUES ($1, $2, $3, $4)
+            RETURNING *
+            "#,
+        )
+        .bind(dependency.id)
+        .bind(dependency.task_id)
+        .bind(dependency.depends_on_id)
+        .bind(dependency.created_at)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(row.into())
+    }
+
+    async fn delete(&self, task_id: Uuid, depends_on_id: Uuid) -> Result<(), DomainError> {
+        sqlx::query("DELETE FROM task_dependencies WHERE task_id = $1 AND depends_on_id = $2")
+            .bind(task_id)
+            .bind(depends_on_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+}
@@ -3,16 +3,43 @@ use chrono::{DateTime, Utc};
 use sqlx::{FromRow, PgPool};
 use uuid::Uuid;
 
-use crate::domain::entities::Task;
+use crate::domain::entities::{ActualHoursDrift, Task};
 use crate::domain::repositories::TaskRepository;
 use crate::domain::value_objects::{Priority, TaskStatus};
 use crate::shared::DomainError;
 
+/// Stored and logged hours of every task, trashed ones included
+const LOGGED_HOURS: &str = r#"
+    SELECT t.id AS task_id, t.title, t.actual_hours AS recorded,
+           (SELECT SUM(l.hours) FROM time_logs l WHERE l.task_id = t.id) AS logged
+    FROM tasks t
+"#;
+
+#[derive(Debug, FromRow)]
+struct ActualHoursDriftRow {
+    task_id: Uuid,
+    title: String,
+    recorded: Option<f32>,
+    logged: Option<f32>,
+}
+
+impl From<ActualHoursDriftRow> for ActualHoursDrift {
+    fn from(row: ActualHoursDriftRow) -> Self {
+        ActualHoursDrift {
+            task_id: row.task_id,
+            title: row.title,
+            recorded: row.recorded,
+            logged: row.logged,
+        }
+    }
+}
+
 #[derive(Debug, FromRow)]
 struct TaskRow {
     id: Uuid,
     project_id: Uuid,
     milestone_id: Option<Uuid>,
+    sprint_id: Option<Uuid>,
     title: String,
     description: Option<String>,
     status: TaskStatus,
@@ -21,8 +48,12 @@ struct TaskRow {
     due_date: Option<DateTime<Utc>>,
     estimated_hours: Option<f32>,
     actual_hours: Option<f32>,
+    billable: Option<bool>,
+    completed_at: Option<DateTime<Utc>>,
     created_at: DateTime<Utc>,
     updated_at: DateTime<Utc>,
+    deleted_at: Option<DateTime<Utc>>,
+    deleted_by: Option<Uuid>,
 }
 
 impl From<TaskRow> for Task {
@@ -31,6 +62,7 @@ impl From<TaskRow> for Task {
             id: row.id,
             project_id: row.project_id,
             milestone_id: row.milestone_id,
+            sprint_id: row.sprint_id,
             title: row.title,
             description: row.description,
             status: row.status,
@@ -39,8 +71,12 @@ impl From<TaskRow> for Task {
             due_date: row.due_date,
             estimated_hours: row.estimated_hours,
             actual_hours: row.actual_hours,
+            billable: row.billable,
+            completed_at: row.completed_at,
             created_at: row.created_at,
             updated_at: row.updated_at,
+            deleted_at: row.deleted_at,
+            deleted_by: row.deleted_by,
         }
     }
 }
@@ -58,25 +94,45 @@ impl PgTaskRepository {
 #[async_trait]
 impl TaskRepository for PgTaskRepository {
     async fn find_by_id(&self, id: Uuid) -> Result<Option<Task>, DomainError> {
-        let row = sqlx::query_as::<_, TaskRow>("SELECT * FROM tasks WHERE id = $1")
-            .bind(id)
-            .fetch_optional(&self.pool)
-            .await?;
+        let row = sqlx::query_as::<_, TaskRow>(
+            r#"
+            SELECT t.* FROM tasks t
+            INNER JOIN projects p ON t.project_id = p.id
+            WHERE t.id = $1 AND t.deleted_at IS NULL AND p.deleted_at IS NULL
+            "#,
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
 
         Ok(row.map(Into::into))
     }
 
-    async fn find_all(&self) -> Result<Vec<Task>, DomainError> {
-        let rows = sqlx::query_as::<_, TaskRow>("SELECT * FROM tasks ORDER BY created_at DESC")
-            .fetch_all(&self.pool)
-            .await?;
+    async fn find_all(&self, include_archived: bool) -> Result<Vec<Task>, DomainError> {
+        let rows = sqlx::query_as::<_, TaskRow>(
+            r#"
+            SELECT t.* FROM tasks t
+            INNER JOIN projects p ON t.project_id = p.id
+            WHERE t.deleted_at IS NULL AND p.deleted_at IS NULL
+              AND ($1 OR p.archived_at IS NULL)
+            ORDER BY t.created_at DESC
+            "#,
+        )
+        .bind(include_archived)
+        .fetch_all(&self.pool)
+        .await?;
 
         Ok(rows.into_iter().map(Into::into).collect())
     }
 
     async fn find_by_project(&self, project_id: Uuid) -> Result<Vec<Task>, DomainError> {
         let rows = sqlx::query_as::<_, TaskRow>(
-            "SELECT * FROM tasks WHERE project_id = $1 ORDER BY created_at DESC",
+            r#"
+            SELECT t.* FROM tasks t
+            INNER JOIN projects p ON t.project_id = p.id
+            WHERE t.project_id = $1 AND t.deleted_at IS NULL AND p.deleted_at IS NULL
+            ORDER BY t.created_at DESC
+            "#,
         )
         .bind(project_id)
         .fetch_all(&self.pool)
@@ -85,9 +141,62 @@ impl TaskRepository for PgTaskRepository {
         Ok(rows.into_iter().map(Into::into).collect())
     }
 
+    async fn find_by_sprint(&self, sprint_id: Uuid) -> Result<Vec<Task>, DomainError> {
+        let rows = sqlx::query_as::<_, TaskRow>(
+            r#"
+            SELECT t.* FROM tasks t
+            INNER JOIN projects p ON t.project_id = p.id
+            WHERE t.sprint_id = $1 AND t.deleted_at IS NULL AND p.deleted_at IS NULL
+            ORDER BY t.created_at ASC
+            "#,
+        )
+        .bind(sprint_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn set_sprint(&self, task_id: Uuid, sprint_id: Option<Uuid>) -> Result<(), DomainError> {
+        sqlx::query(
+            "UPDATE tasks SET sprint_id = $1, updated_at = NOW() WHERE id = $2 AND deleted_at IS NULL",
+        )
+        .bind(sprint_id)
+        .bind(task_id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn move_unfinished(
+        &self,
+        from_sprint_id: Uuid,
+        to_sprint_id: Option<Uuid>,
+    ) -> Result<u64, DomainError> {
+        let result = sqlx::query(
+            r#"
+            UPDATE tasks
+            SET sprint_id = $1, updated_at = NOW()
+            WHERE sprint_id = $2 AND status <> 'done' AND deleted_at IS NULL
+            "#,
+        )
+        .bind(to_sprint_id)
+        .bind(from_sprint_id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+
     async fn find_by_assignee(&self, user_id: Uuid) -> Result<Vec<Task>, DomainError> {
         let rows = sqlx::query_as::<_, TaskRow>(
-            "SELECT * FROM tasks WHERE assignee_id = $1 ORDER BY created_at DESC",
+            r#"
+            SELECT t.* FROM tasks t
+            INNER JOIN projects p ON t.project_id = p.id
+            WHERE t.assignee_id = $1 AND t.deleted_at IS NULL AND p.deleted_at IS NULL
+            ORDER BY t.created_at DESC
+            "#,
         )
         .bind(user_id)
         .fetch_all(&self.pool)
@@ -98,7 +207,12 @@ impl TaskRepository for PgTaskRepository {
 
     async fn find_by_status(&self, status: TaskStatus) -> Result<Vec<Task>, DomainError> {
         let rows = sqlx::query_as::<_, TaskRow>(
-            "SELECT * FROM tasks WHERE status = $1 ORDER BY created_at DESC",
+            r#"
+            SELECT t.* FROM tasks t
+            INNER JOIN projects p ON t.project_id = p.id
+            WHERE t.status = $1 AND t.deleted_at IS NULL AND p.deleted_at IS NULL
+            ORDER BY t.created_at DESC
+            "#,
         )
         .bind(status)
         .fetch_all(&self.pool)
@@ -107,17 +221,68 @@ impl TaskRepository for PgTaskRepository {
         Ok(rows.into_iter().map(Into::into).collect())
     }
 
-    async fn find_accessible_by_user(&self, user_id: Uuid) -> Result<Vec<Task>, DomainError> {
+    async fn find_due_unreminded(
+        &self,
+        from: DateTime<Utc>,
+        until: DateTime<Utc>,
+    ) -> Result<Vec<Task>, DomainError> {
+        let rows = sqlx::query_as::<_, TaskRow>(
+            r#"
+            SELECT t.* FROM tasks t
+            INNER JOIN projects p ON t.project_id = p.id
+            LEFT JOIN task_due_reminders r ON r.task_id = t.id
+            WHERE t.assignee_id IS NOT NULL AND t.status <> 'done'
+              AND t.due_date BETWEEN $1 AND $2
+              AND r.reminded_for IS DISTINCT FROM t.due_date
+              AND t.deleted_at IS NULL AND p.deleted_at IS NULL AND p.archived_at IS NULL
+            ORDER BY t.due_date ASC
+            "#,
+        )
+        .bind(from)
+        .bind(until)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn mark_due_reminded(
+        &self,
+        task_id: Uuid,
+        due_date: DateTime<Utc>,
+    ) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            INSERT INTO task_due_reminders (task_id, reminded_for) VALUES ($1, $2)
+            ON CONFLICT (task_id) DO UPDATE SET reminded_for = EXCLUDED.reminded_for
+            "#,
+        )
+        .bind(task_id)
+        .bind(due_date)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn find_accessible_by_user(
+        &self,
+        user_id: Uuid,
+        include_archived: bool,
+    ) -> Result<Vec<Task>, DomainError> {
         let rows = sqlx::query_as::<_, TaskRow>(
             r#"
             SELECT DISTINCT t.* FROM tasks t
             INNER JOIN projects p ON t.project_id = p.id
             LEFT JOIN project_members pm ON p.id = pm.project_id
-            WHERE p.owner_id = $1 OR pm.user_id = $1
+            WHERE (p.owner_id = $1 OR pm.user_id = $1)
+              AND t.deleted_at IS NULL AND p.deleted_at IS NULL
+              AND ($2 OR p.archived_at IS NULL)
             ORDER BY t.created_at DESC
             "#,
         )
         .bind(user_id)
+        .bind(include_archived)
         .fetch_all(&self.pool)
         .await?;
 
@@ -125,12 +290,35 @@ impl TaskRepository for PgTaskRepository {
     }
 
     async fn can_user_access(&self, task_id: Uuid, user_id: Uuid) -> Result<bool, DomainError> {
-        let result: Option<(i64,)> = sqlx::query_as(
+        let result: Option<(i32,)> = sqlx::query_as(
             r#"
             SELECT 1 FROM tasks t
             INNER JOIN projects p ON t.project_id = p.id
             LEFT JOIN project_members pm ON p.id = pm.project_id
             WHERE t.id = $1 AND (p.owner_id = $2 OR pm.user_id = $2)
+              AND t.deleted_at IS NULL AND p.deleted_at IS NULL
+            LIMIT 1
+            "#,
+        )
+        .bind(task_id)
+        .bind(user_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(result.is_some())
+    }
+
+    async fn can_user_view(&self, task_id: Uuid, user_id: Uuid) -> Result<bool, DomainError> {
+        let result: Option<(i32,)> = sqlx::query_as(
+            r#"
+            SELECT 1 FROM tasks t
+            INNER JOIN projects p ON t.project_id = p.id
+            LEFT JOIN project_members pm ON p.id = pm.project_id
+            LEFT JOIN portfolios g ON p.portfolio_id = g.id
+            LEFT JOIN portfolios parent ON g.parent_id = parent.id
+            WHERE t.id = $1
+              AND (p.owner_id = $2 OR pm.user_id = $2 OR g.owner_id = $2 OR parent.owner_id = $2)
+              AND t.deleted_at IS NULL AND p.deleted_at IS NULL
             LIMIT 1
             "#,
         )
@@ -143,11 +331,12 @@ impl TaskRepository for PgTaskRepository {
     }
 
     async fn is_project_owner(&self, task_id: Uuid, user_id: Uuid) -> Result<bool, DomainError> {
-        let result: Option<(i64,)> = sqlx::query_as(
+        let result: Option<(i32,)> = sqlx::query_as(
             r#"
             SELECT 1 FROM tasks t
             INNER JOIN projects p ON t.project_id = p.id
             WHERE t.id = $1 AND p.owner_id = $2
+              AND t.deleted_at IS NULL AND p.deleted_at IS NULL
             LIMIT 1
             "#,
         )
@@ -164,11 +353,12 @@ impl TaskRepository for PgTaskRepository {
         project_id: Uuid,
         user_id: Uuid,
     ) -> Result<bool, DomainError> {
-        let result: Option<(i64,)> = sqlx::query_as(
+        let result: Option<(i32,)> = sqlx::query_as(
             r#"
             SELECT 1 FROM projects p
             LEFT JOIN project_members pm ON p.id = pm.project_id
             WHERE p.id = $1 AND (p.owner_id = $2 OR pm.user_id = $2)
+              AND p.deleted_at IS NULL
             LIMIT 1
             "#,
         )
@@ -183,14 +373,15 @@ impl TaskRepository for PgTaskRepository {
     async fn create(&self, task: &Task) -> Result<Task, DomainError> {
         let row = sqlx::query_as::<_, TaskRow>(
             r#"
-            INSERT INTO tasks (id, project_id, milestone_id, title, description, status, priority, assignee_id, due_date, estimated_hours, actual_hours, created_at, updated_at)
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)
+            INSERT INTO tasks (id, project_id, milestone_id, sprint_id, title, description, status, priority, assignee_id, due_date, estimated_hours, actual_hours, billable, completed_at, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16)
             RETURNING *
             "#,
         )
         .bind(task.id)
         .bind(task.project_id)
         .bind(task.milestone_id)
+        .bind(task.sprint_id)
         .bind(&task.title)
         .bind(&task.description)
         .bind(&task.status)
@@ -199,6 +390,8 @@ impl TaskRepository for PgTaskRepository {
         .bind(task.due_date)
         .bind(task.estimated_hours)
         .bind(task.actual_hours)
+        .bind(task.billable)
+        .bind(task.completed_at)
         .bind(task.created_at)
         .bind(task.updated_at)
         .fetch_one(&self.pool)
@@ -213,8 +406,8 @@ impl TaskRepository for PgTaskRepository {
             UPDATE tasks
             SET title = $1, description = $2, status = $3, priority = $4,
                 assignee_id = $5, due_date = $6, estimated_hours = $7,
-                actual_hours = $8, milestone_id = $9, updated_at = NOW()
-            WHERE id = $10
+                milestone_id = $8, completed_at = $9, billable = $10, updated_at = NOW()
+            WHERE id = $11 AND deleted_at IS NULL
             RETURNING *
             "#,
         )
@@ -225,8 +418,9 @@ impl TaskRepository for PgTaskRepository {
         .bind(task.assignee_id)
         .bind(task.due_date)
         .bind(task.estimated_hours)
-        .bind(task.actual_hours)
         .bind(task.milestone_id)
+        .bind(task.completed_at)
+        .bind(task.billable)
         .bind(task.id)
         .fetch_one(&self.pool)
         .await?;
@@ -234,6 +428,34 @@ impl TaskRepository for PgTaskRepository {
         Ok(row.into())
     }
 
+    async fn soft_delete(&self, id: Uuid, deleted_by: Uuid) -> Result<(), DomainError> {
+        sqlx::query(
+            "UPDATE tasks SET deleted_at = NOW(), deleted_by = $1 WHERE id = $2 AND deleted_at IS NULL",
+        )
+        .bind(deleted_by)
+        .bind(id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn restore(&self, id: Uuid) -> Result<Task, DomainError> {
+        let row = sqlx::query_as::<_, TaskRow>(
+            r#"
+            UPDATE tasks
+            SET deleted_at = NULL, deleted_by = NULL, updated_at = NOW()
+            WHERE id = $1
+            RETURNING *
+            "#,
+        )
+        .bind(id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(row.into())
+    }
+
     async fn delete(&self, id: Uuid) -> Result<(), DomainError> {
         sqlx::query("DELETE FROM tasks WHERE id = $1")
             .bind(id)
@@ -242,4 +464,81 @@ impl TaskRepository for PgTaskRepository {
 
         Ok(())
     }
+
+    async fn find_deleted_by_id(&self, id: Uuid) -> Result<Option<Task>, DomainError> {
+        let row = sqlx::query_as::<_, TaskRow>(
+            "SELECT * FROM tasks WHERE id = $1 AND deleted_at IS NOT NULL",
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.map(Into::into))
+    }
+
+    async fn find_deleted_by_project(&self, project_id: Uuid) -> Result<Vec<Task>, DomainError> {
+        let rows = sqlx::query_as::<_, TaskRow>(
+            "SELECT * FROM tasks WHERE project_id = $1 AND deleted_at IS NOT NULL ORDER BY deleted_at DESC",
+        )
+        .bind(project_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn find_deleted_by_user(&self, user_id: Uuid) -> Result<Vec<Task>, DomainError> {
+        let rows = sqlx::query_as::<_, TaskRow>(
+            r#"
+            SELECT t.* FROM tasks t
+            INNER JOIN projects p ON t.project_id = p.id
+            WHERE t.deleted_by = $1 AND t.deleted_at IS NOT NULL AND p.deleted_at IS NULL
+            ORDER BY t.deleted_at DESC
+            "#,
+        )
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn find_deleted_before(&self, cutoff: DateTime<Utc>) -> Result<Vec<Task>, DomainError> {
+        let rows = sqlx::query_as::<_, TaskRow>(
+            "SELECT * FROM tasks WHERE deleted_at < $1 ORDER BY deleted_at ASC",
+        )
+        .bind(cutoff)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn reconcile_actual_hours(
+        &self,
+        dry_run: bool,
+    ) -> Result<Vec<ActualHoursDrift>, DomainError> {
+        let drift = if dry_run {
+            sqlx::query_as::<_, ActualHoursDriftRow>(&format!(
+                "SELECT * FROM ({}) s WHERE recorded IS DISTINCT FROM logged ORDER BY title",
+                LOGGED_HOURS
+            ))
+            .fetch_all(&self.pool)
+            .await?
+        } else {
+            sqlx::query_as::<_, ActualHoursDriftRow>(&format!(
+                r#"
+                UPDATE tasks t SET actual_hours = s.logged
+                FROM ({}) s
+                WHERE t.id = s.task_id AND s.recorded IS DISTINCT FROM s.logged
+                RETURNING s.task_id, s.title, s.recorded, s.logged
+                "#,
+                LOGGED_HOURS
+            ))
+            .fetch_all(&self.pool)
+            .await?
+        };
+
+        Ok(drift.into_iter().map(Into::into).collect())
+    }
 }
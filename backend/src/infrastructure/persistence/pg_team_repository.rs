@@ -1,10 +1,11 @@
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
-use sqlx::{FromRow, PgPool};
+use sqlx::{FromRow, PgConnection, PgPool};
 use uuid::Uuid;
 
 use crate::domain::entities::{Team, TeamMember};
 use crate::domain::repositories::TeamRepository;
+use crate::domain::services::TeamLeadership;
 use crate::domain::value_objects::TeamMemberRole;
 use crate::shared::DomainError;
 
@@ -98,7 +99,7 @@ impl TeamRepository for PgTeamRepository {
     }
 
     async fn can_user_access(&self, team_id: Uuid, user_id: Uuid) -> Result<bool, DomainError> {
-        let result: Option<(i64,)> = sqlx::query_as(
+        let result: Option<(i32,)> = sqlx::query_as(
             r#"
             SELECT 1 FROM teams t
             LEFT JOIN team_members tm ON t.id = tm.team_id
@@ -115,16 +116,52 @@ impl TeamRepository for PgTeamRepository {
     }
 
     async fn is_lead(&self, team_id: Uuid, user_id: Uuid) -> Result<bool, DomainError> {
-        let result: Option<(i64,)> =
-            sqlx::query_as("SELECT 1 FROM teams WHERE id = $1 AND lead_id = $2 LIMIT 1")
-                .bind(team_id)
-                .bind(user_id)
-                .fetch_optional(&self.pool)
-                .await?;
+        let result: Option<(i32,)> = sqlx::query_as(
+            r#"
+            SELECT 1 FROM teams t
+            LEFT JOIN team_members tm ON t.id = tm.team_id AND tm.role = 'lead'
+            WHERE t.id = $1 AND (t.lead_id = $2 OR tm.user_id = $2)
+            LIMIT 1
+            "#,
+        )
+        .bind(team_id)
+        .bind(user_id)
+        .fetch_optional(&self.pool)
+        .await?;
 
         Ok(result.is_some())
     }
 
+    async fn find_lead_ids_of_member(&self, user_id: Uuid) -> Result<Vec<Uuid>, DomainError> {
+        let ids = sqlx::query_scalar::<_, Uuid>(
+            r#"
+            SELECT DISTINCT t.lead_id FROM teams t
+            JOIN team_members tm ON t.id = tm.team_id
+            WHERE tm.user_id = $1 AND t.lead_id IS NOT NULL AND t.lead_id <> $1
+            "#,
+        )
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(ids)
+    }
+
+    async fn find_member_ids_led_by(&self, lead_id: Uuid) -> Result<Vec<Uuid>, DomainError> {
+        let ids = sqlx::query_scalar::<_, Uuid>(
+            r#"
+            SELECT DISTINCT tm.user_id FROM team_members tm
+            JOIN teams t ON t.id = tm.team_id
+            WHERE t.lead_id = $1 AND tm.user_id <> $1
+            "#,
+        )
+        .bind(lead_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(ids)
+    }
+
     async fn create(&self, team: &Team) -> Result<Team, DomainError> {
         let row = sqlx::query_as::<_, TeamRow>(
             r#"
@@ -149,14 +186,13 @@ impl TeamRepository for PgTeamRepository {
         let row = sqlx::query_as::<_, TeamRow>(
             r#"
             UPDATE teams
-            SET name = $1, description = $2, lead_id = $3, updated_at = NOW()
-            WHERE id = $4
+            SET name = $1, description = $2, updated_at = NOW()
+            WHERE id = $3
             RETURNING *
             "#,
         )
         .bind(&team.name)
         .bind(&team.description)
-        .bind(team.lead_id)
         .bind(team.id)
         .fetch_one(&self.pool)
         .await?;
@@ -185,6 +221,8 @@ impl TeamRepository for PgTeamRepository {
     }
 
     async fn add_member(&self, member: &TeamMember) -> Result<TeamMember, DomainError> {
+        let mut tx = self.pool.begin().await?;
+
         let row = sqlx::query_as::<_, TeamMemberRow>(
             r#"
             INSERT INTO team_members (id, team_id, user_id, role, joined_at)
@@ -197,19 +235,103 @@ impl TeamRepository for PgTeamRepository {
         .bind(member.user_id)
         .bind(&member.role)
         .bind(member.joined_at)
-        .fetch_one(&self.pool)
+        .fetch_one(&mut *tx)
         .await?;
 
+        if member.is_lead() {
+            sqlx::query(
+                "UPDATE teams SET lead_id = $1, updated_at = NOW() WHERE id = $2 AND lead_id IS NULL",
+            )
+            .bind(member.user_id)
+            .bind(member.team_id)
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        tx.commit().await?;
         Ok(row.into())
     }
 
+    async fn change_member_role(
+        &self,
+        team_id: Uuid,
+        user_id: Uuid,
+        role: TeamMemberRole,
+        transfer: bool,
+    ) -> Result<TeamMember, DomainError> {
+        let mut tx = self.pool.begin().await?;
+
+        let (mut team, mut members) = lock_team(&mut tx, team_id).await?;
+        let index = member_index(&members, user_id)?;
+        let changed = TeamLeadership::change_role(&mut team, &mut members, index, role, transfer)?;
+
+        for member in &changed {
+            sqlx::query("UPDATE team_members SET role = $1 WHERE team_id = $2 AND user_id = $3")
+                .bind(&member.role)
+                .bind(team.id)
+                .bind(member.user_id)
+                .execute(&mut *tx)
+                .await?;
+        }
+        set_lead(&mut tx, &team).await?;
+
+        tx.commit().await?;
+        Ok(members.swap_remove(index))
+    }
+
     async fn remove_member(&self, team_id: Uuid, user_id: Uuid) -> Result<(), DomainError> {
+        let mut tx = self.pool.begin().await?;
+
+        let (mut team, members) = lock_team(&mut tx, team_id).await?;
+        member_index(&members, user_id)?;
+        TeamLeadership::step_down(&mut team, &members, user_id)?;
+
         sqlx::query("DELETE FROM team_members WHERE team_id = $1 AND user_id = $2")
-            .bind(team_id)
+            .bind(team.id)
             .bind(user_id)
-            .execute(&self.pool)
+            .execute(&mut *tx)
             .await?;
+        set_lead(&mut tx, &team).await?;
 
+        tx.commit().await?;
         Ok(())
     }
 }
+
+/// Lock the team row and read its members, so role changes that must leave
+/// the team with a lead are applied one at a time
+async fn lock_team(
+    conn: &mut PgConnection,
+    team_id: Uuid,
+) -> Result<(Team, Vec<TeamMember>), DomainError> {
+    let team = sqlx::query_as::<_, TeamRow>("SELECT * FROM teams WHERE id = $1 FOR UPDATE")
+        .bind(team_id)
+        .fetch_optional(&mut *conn)
+        .await?
+        .ok_or_else(|| DomainError::NotFound("Team not found".into()))?;
+
+    let members = sqlx::query_as::<_, TeamMemberRow>(
+        "SELECT * FROM team_members WHERE team_id = $1 ORDER BY joined_at DESC",
+    )
+    .bind(team_id)
+    .fetch_all(&mut *conn)
+    .await?;
+
+    Ok((team.into(), members.into_iter().map(Into::into).collect()))
+}
+
+fn member_index(members: &[TeamMember], user_id: Uuid) -> Result<usize, DomainError> {
+    members
+        .iter()
+        .position(|m| m.user_id == user_id)
+        .ok_or_else(|| DomainError::NotFound("Team member not found".into()))
+}
+
+async fn set_lead(conn: &mut PgConnection, team: &Team) -> Result<(), DomainError> {
+    sqlx::query("UPDATE teams SET lead_id = $1, updated_at = NOW() WHERE id = $2")
+        .bind(team.lead_id)
+        .bind(team.id)
+        .execute(conn)
+        .await?;
+    Ok(())
+}
@@ -1,10 +1,11 @@
 use async_trait::async_trait;
 use chrono::{DateTime, NaiveDate, Utc};
-use sqlx::{FromRow, PgPool};
+use sqlx::{FromRow, PgConnection, PgPool};
 use uuid::Uuid;
 
-use crate::domain::entities::TimeLog;
-use crate::domain::repositories::TimeLogRepository;
+use crate::domain::entities::{DailyHours, TimeLog, Timesheet};
+use crate::domain::repositories::{TimeLogFilter, TimeLogRepository};
+use crate::domain::services::TimeLogPolicy;
 use crate::shared::DomainError;
 
 #[derive(Debug, FromRow)]
@@ -15,6 +16,8 @@ struct TimeLogRow {
     hours: f32,
     date: NaiveDate,
     description: Option<String>,
+    billable: bool,
+    invoice_id: Option<Uuid>,
     created_at: DateTime<Utc>,
     updated_at: DateTime<Utc>,
     task_name: Option<String>,
@@ -22,6 +25,23 @@ struct TimeLogRow {
     user_name: Option<String>,
 }
 
+#[derive(Debug, FromRow)]
+struct DailyHoursRow {
+    user_id: Uuid,
+    date: NaiveDate,
+    hours: f32,
+}
+
+impl From<DailyHoursRow> for DailyHours {
+    fn from(row: DailyHoursRow) -> Self {
+        DailyHours {
+            user_id: row.user_id,
+            date: row.date,
+            hours: row.hours,
+        }
+    }
+}
+
 impl From<TimeLogRow> for TimeLog {
     fn from(row: TimeLogRow) -> Self {
         TimeLog {
@@ -31,6 +51,8 @@ impl From<TimeLogRow> for TimeLog {
             hours: row.hours,
             date: row.date,
             description: row.description,
+            billable: row.billable,
+            invoice_id: row.invoice_id,
             created_at: row.created_at,
             updated_at: row.updated_at,
             task_name: row.task_name,
@@ -58,6 +80,8 @@ impl PgTimeLogRepository {
             tl.hours,
             tl.date,
             tl.description,
+            tl.billable,
+            tl.invoice_id,
             tl.created_at,
             tl.updated_at,
             t.title as task_name,
@@ -67,6 +91,7 @@ impl PgTimeLogRepository {
         JOIN tasks t ON tl.task_id = t.id
         JOIN projects p ON t.project_id = p.id
         JOIN users u ON tl.user_id = u.id
+        WHERE t.deleted_at IS NULL AND p.deleted_at IS NULL
         "#
     }
 }
@@ -74,7 +99,7 @@ impl PgTimeLogRepository {
 #[async_trait]
 impl TimeLogRepository for PgTimeLogRepository {
     async fn find_by_id(&self, id: Uuid) -> Result<Option<TimeLog>, DomainError> {
-        let query = format!("{} WHERE tl.id = $1", Self::base_query());
+        let query = format!("{} AND tl.id = $1", Self::base_query());
         let row = sqlx::query_as::<_, TimeLogRow>(&query)
             .bind(id)
             .fetch_optional(&self.pool)
@@ -91,19 +116,19 @@ impl TimeLogRepository for PgTimeLogRepository {
     ) -> Result<Vec<TimeLog>, DomainError> {
         let query = match (start_date, end_date) {
             (Some(_), Some(_)) => format!(
-                "{} WHERE tl.user_id = $1 AND tl.date >= $2 AND tl.date <= $3 ORDER BY tl.date DESC, tl.created_at DESC",
+                "{} AND tl.user_id = $1 AND tl.date >= $2 AND tl.date <= $3 ORDER BY tl.date DESC, tl.created_at DESC",
                 Self::base_query()
             ),
             (Some(_), None) => format!(
-                "{} WHERE tl.user_id = $1 AND tl.date >= $2 ORDER BY tl.date DESC, tl.created_at DESC",
+                "{} AND tl.user_id = $1 AND tl.date >= $2 ORDER BY tl.date DESC, tl.created_at DESC",
                 Self::base_query()
             ),
             (None, Some(_)) => format!(
-                "{} WHERE tl.user_id = $1 AND tl.date <= $2 ORDER BY tl.date DESC, tl.created_at DESC",
+                "{} AND tl.user_id = $1 AND tl.date <= $2 ORDER BY tl.date DESC, tl.created_at DESC",
                 Self::base_query()
             ),
             (None, None) => format!(
-                "{} WHERE tl.user_id = $1 ORDER BY tl.date DESC, tl.created_at DESC",
+                "{} AND tl.user_id = $1 ORDER BY tl.date DESC, tl.created_at DESC",
                 Self::base_query()
             ),
         };
@@ -144,7 +169,7 @@ impl TimeLogRepository for PgTimeLogRepository {
 
     async fn find_by_task(&self, task_id: Uuid) -> Result<Vec<TimeLog>, DomainError> {
         let query = format!(
-            "{} WHERE tl.task_id = $1 ORDER BY tl.date DESC, tl.created_at DESC",
+            "{} AND tl.task_id = $1 ORDER BY tl.date DESC, tl.created_at DESC",
             Self::base_query()
         );
         let rows = sqlx::query_as::<_, TimeLogRow>(&query)
@@ -155,6 +180,19 @@ impl TimeLogRepository for PgTimeLogRepository {
         Ok(rows.into_iter().map(Into::into).collect())
     }
 
+    async fn find_by_project(&self, project_id: Uuid) -> Result<Vec<TimeLog>, DomainError> {
+        let query = format!(
+            "{} AND t.project_id = $1 ORDER BY tl.date DESC, tl.created_at DESC",
+            Self::base_query()
+        );
+        let rows = sqlx::query_as::<_, TimeLogRow>(&query)
+            .bind(project_id)
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
     async fn find_by_date_range(
         &self,
         user_id: Uuid,
@@ -162,7 +200,7 @@ impl TimeLogRepository for PgTimeLogRepository {
         end_date: NaiveDate,
     ) -> Result<Vec<TimeLog>, DomainError> {
         let query = format!(
-            "{} WHERE tl.user_id = $1 AND tl.date >= $2 AND tl.date <= $3 ORDER BY tl.date DESC, tl.created_at DESC",
+            "{} AND tl.user_id = $1 AND tl.date >= $2 AND tl.date <= $3 ORDER BY tl.date DESC, tl.created_at DESC",
             Self::base_query()
         );
         let rows = sqlx::query_as::<_, TimeLogRow>(&query)
@@ -175,11 +213,78 @@ impl TimeLogRepository for PgTimeLogRepository {
         Ok(rows.into_iter().map(Into::into).collect())
     }
 
-    async fn create(&self, time_log: &TimeLog) -> Result<TimeLog, DomainError> {
+    async fn find_page(
+        &self,
+        filter: &TimeLogFilter,
+        after: Option<(NaiveDate, Uuid)>,
+        limit: i64,
+    ) -> Result<Vec<TimeLog>, DomainError> {
+        let query = format!(
+            r#"{}
+              AND ($1::uuid IS NULL OR tl.user_id = $1)
+              AND ($2::uuid IS NULL OR t.project_id = $2)
+              AND ($3::uuid IS NULL OR tl.task_id = $3)
+              AND ($4::date IS NULL OR tl.date >= $4)
+              AND ($5::date IS NULL OR tl.date <= $5)
+              AND ($6::date IS NULL OR (tl.date, tl.id) > ($6, $7))
+            ORDER BY tl.date, tl.id
+            LIMIT $8
+            "#,
+            Self::base_query()
+        );
+        let rows = sqlx::query_as::<_, TimeLogRow>(&query)
+            .bind(filter.user_id)
+            .bind(filter.project_id)
+            .bind(filter.task_id)
+            .bind(filter.start_date)
+            .bind(filter.end_date)
+            .bind(after.map(|(date, _)| date))
+            .bind(after.map(|(_, id)| id))
+            .bind(limit)
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn sum_by_user_and_date(
+        &self,
+        start_date: NaiveDate,
+        end_date: NaiveDate,
+    ) -> Result<Vec<DailyHours>, DomainError> {
+        let rows = sqlx::query_as::<_, DailyHoursRow>(
+            r#"
+            SELECT tl.user_id, tl.date, SUM(tl.hours)::REAL as hours
+            FROM time_logs tl
+            JOIN tasks t ON tl.task_id = t.id
+            JOIN projects p ON t.project_id = p.id
+            WHERE tl.date >= $1 AND tl.date <= $2
+              AND t.deleted_at IS NULL AND p.deleted_at IS NULL
+            GROUP BY tl.user_id, tl.date
+            "#,
+        )
+        .bind(start_date)
+        .bind(end_date)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn create(
+        &self,
+        time_log: &TimeLog,
+        policy: &TimeLogPolicy,
+    ) -> Result<TimeLog, DomainError> {
+        let mut tx = self.pool.begin().await?;
+        lock_task(&mut tx, time_log.task_id).await?;
+        ensure_week_open(&mut tx, time_log.user_id, time_log.date).await?;
+        ensure_under_daily_cap(&mut tx, time_log, policy).await?;
+
         sqlx::query(
             r#"
-            INSERT INTO time_logs (id, task_id, user_id, hours, date, description, created_at, updated_at)
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            INSERT INTO time_logs (id, task_id, user_id, hours, date, description, billable, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
             "#,
         )
         .bind(time_log.id)
@@ -188,43 +293,178 @@ impl TimeLogRepository for PgTimeLogRepository {
         .bind(time_log.hours)
         .bind(time_log.date)
         .bind(&time_log.description)
+        .bind(time_log.billable)
         .bind(time_log.created_at)
         .bind(time_log.updated_at)
-        .execute(&self.pool)
+        .execute(&mut *tx)
         .await?;
 
+        sync_actual_hours(&mut tx, time_log.task_id).await?;
+        tx.commit().await?;
+
         // Fetch the created time log with joined data
         self.find_by_id(time_log.id)
             .await?
             .ok_or_else(|| DomainError::NotFound("Time log not found after creation".into()))
     }
 
-    async fn update(&self, time_log: &TimeLog) -> Result<TimeLog, DomainError> {
+    async fn update(
+        &self,
+        time_log: &TimeLog,
+        policy: &TimeLogPolicy,
+    ) -> Result<TimeLog, DomainError> {
+        let mut tx = self.pool.begin().await?;
+        lock_task(&mut tx, time_log.task_id).await?;
+        let (_, user_id, date) = lock_log(&mut tx, time_log.id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("Time log not found".into()))?;
+        ensure_week_open(&mut tx, user_id, date).await?;
+        ensure_week_open(&mut tx, user_id, time_log.date).await?;
+        ensure_under_daily_cap(&mut tx, time_log, policy).await?;
+
         sqlx::query(
             r#"
             UPDATE time_logs
-            SET hours = $1, date = $2, description = $3, updated_at = NOW()
-            WHERE id = $4
+            SET hours = $1, date = $2, description = $3, billable = $4, updated_at = NOW()
+            WHERE id = $5
             "#,
         )
         .bind(time_log.hours)
         .bind(time_log.date)
         .bind(&time_log.description)
+        .bind(time_log.billable)
         .bind(time_log.id)
-        .execute(&self.pool)
+        .execute(&mut *tx)
         .await?;
 
+        sync_actual_hours(&mut tx, time_log.task_id).await?;
+        tx.commit().await?;
+
         self.find_by_id(time_log.id)
             .await?
             .ok_or_else(|| DomainError::NotFound("Time log not found after update".into()))
     }
 
     async fn delete(&self, id: Uuid) -> Result<(), DomainError> {
+        let mut tx = self.pool.begin().await?;
+        let Some((task_id, user_id, date)) = lock_log(&mut tx, id).await? else {
+            return Ok(());
+        };
+        lock_task(&mut tx, task_id).await?;
+        ensure_week_open(&mut tx, user_id, date).await?;
+
         sqlx::query("DELETE FROM time_logs WHERE id = $1")
             .bind(id)
-            .execute(&self.pool)
+            .execute(&mut *tx)
             .await?;
 
+        sync_actual_hours(&mut tx, task_id).await?;
+        tx.commit().await?;
+
         Ok(())
     }
 }
+
+/// Lock the task row so concurrent log changes on it recompute its total in turn
+async fn lock_task(conn: &mut PgConnection, task_id: Uuid) -> Result<(), DomainError> {
+    sqlx::query("SELECT 1 FROM tasks WHERE id = $1 FOR UPDATE")
+        .bind(task_id)
+        .execute(conn)
+        .await?;
+    Ok(())
+}
+
+/// Lock a stored log, returning its task, user and date
+async fn lock_log(
+    conn: &mut PgConnection,
+    id: Uuid,
+) -> Result<Option<(Uuid, Uuid, NaiveDate)>, DomainError> {
+    let row =
+        sqlx::query_as("SELECT task_id, user_id, date FROM time_logs WHERE id = $1 FOR UPDATE")
+            .bind(id)
+            .fetch_optional(conn)
+            .await?;
+    Ok(row)
+}
+
+/// Refuse log changes in a week whose timesheet is submitted or approved.
+/// The timesheet row is share-locked so it can't be submitted before the
+/// change commits. The user row is locked first, as a week without a
+/// timesheet has no row to lock; timesheet status changes take that lock too.
+async fn ensure_week_open(
+    conn: &mut PgConnection,
+    user_id: Uuid,
+    date: NaiveDate,
+) -> Result<(), DomainError> {
+    sqlx::query("SELECT 1 FROM users WHERE id = $1 FOR UPDATE")
+        .bind(user_id)
+        .execute(&mut *conn)
+        .await?;
+    let locked: Option<bool> = sqlx::query_scalar(
+        r#"
+        SELECT status IN ('submitted', 'approved') FROM timesheets
+        WHERE user_id = $1 AND week_start = $2
+        FOR SHARE
+        "#,
+    )
+    .bind(user_id)
+    .bind(Timesheet::week_of(date))
+    .fetch_optional(&mut *conn)
+    .await?;
+
+    if locked.unwrap_or(false) {
+        return Err(DomainError::Conflict(
+            "The timesheet for this week is submitted or approved; its time logs are locked".into(),
+        ));
+    }
+    Ok(())
+}
+
+/// Lock the user's other logs on the log's day and check the daily cap
+/// against their sum, so concurrent logs can't overshoot it together. The
+/// user row is locked first, as a day without logs has no rows to lock.
+async fn ensure_under_daily_cap(
+    conn: &mut PgConnection,
+    time_log: &TimeLog,
+    policy: &TimeLogPolicy,
+) -> Result<(), DomainError> {
+    sqlx::query("SELECT 1 FROM users WHERE id = $1 FOR UPDATE")
+        .bind(time_log.user_id)
+        .execute(&mut *conn)
+        .await?;
+    let (other_hours,): (f32,) = sqlx::query_as(
+        r#"
+        SELECT COALESCE(SUM(hours), 0)::REAL
+        FROM (
+            SELECT tl.hours
+            FROM time_logs tl
+            JOIN tasks t ON tl.task_id = t.id
+            JOIN projects p ON t.project_id = p.id
+            WHERE tl.user_id = $1 AND tl.date = $2 AND tl.id <> $3
+              AND t.deleted_at IS NULL AND p.deleted_at IS NULL
+            FOR UPDATE OF tl
+        ) logs
+        "#,
+    )
+    .bind(time_log.user_id)
+    .bind(time_log.date)
+    .bind(time_log.id)
+    .fetch_one(&mut *conn)
+    .await?;
+
+    match policy.check_daily_cap(time_log.hours, time_log.date, other_hours) {
+        Some(error) => Err(DomainError::InvalidFields(vec![error])),
+        None => Ok(()),
+    }
+}
+
+/// Recompute a task's actual hours from its time logs
+async fn sync_actual_hours(conn: &mut PgConnection, task_id: Uuid) -> Result<(), DomainError> {
+    sqlx::query(
+        "UPDATE tasks SET actual_hours = (SELECT SUM(hours) FROM time_logs WHERE task_id = $1) WHERE id = $1",
+    )
+    .bind(task_id)
+    .execute(conn)
+    .await?;
+    Ok(())
+}
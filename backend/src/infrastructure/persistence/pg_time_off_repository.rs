@@ -0,0 +1,156 @@
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use sqlx::{FromRow, PgPool};
+use uuid::Uuid;
+
+use crate::domain::entities::TimeOffRequest;
+use crate::domain::repositories::TimeOffRepository;
+use crate::domain::value_objects::TimeOffStatus;
+use crate::shared::DomainError;
+
+#[derive(Debug, FromRow)]
+struct TimeOffRequestRow {
+    id: Uuid,
+    user_id: Uuid,
+    start_date: NaiveDate,
+    end_date: NaiveDate,
+    reason: Option<String>,
+    status: TimeOffStatus,
+    decided_by: Option<Uuid>,
+    decided_at: Option<DateTime<Utc>>,
+    comment: Option<String>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl From<TimeOffRequestRow> for TimeOffRequest {
+    fn from(row: TimeOffRequestRow) -> Self {
+        TimeOffRequest {
+            id: row.id,
+            user_id: row.user_id,
+            start_date: row.start_date,
+            end_date: row.end_date,
+            reason: row.reason,
+            status: row.status,
+            decided_by: row.decided_by,
+            decided_at: row.decided_at,
+            comment: row.comment,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }
+    }
+}
+
+pub struct PgTimeOffRepository {
+    pool: PgPool,
+}
+
+impl PgTimeOffRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl TimeOffRepository for PgTimeOffRepository {
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<TimeOffRequest>, DomainError> {
+        let row =
+            sqlx::query_as::<_, TimeOffRequestRow>("SELECT * FROM time_off_requests WHERE id = $1")
+                .bind(id)
+                .fetch_optional(&self.pool)
+                .await?;
+
+        Ok(row.map(Into::into))
+    }
+
+    async fn find_by_user(&self, user_id: Uuid) -> Result<Vec<TimeOffRequest>, DomainError> {
+        let rows = sqlx::query_as::<_, TimeOffRequestRow>(
+            "SELECT * FROM time_off_requests WHERE user_id = $1 ORDER BY start_date DESC",
+        )
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn find_by_status(
+        &self,
+        status: TimeOffStatus,
+    ) -> Result<Vec<TimeOffRequest>, DomainError> {
+        let rows = sqlx::query_as::<_, TimeOffRequestRow>(
+            "SELECT * FROM time_off_requests WHERE status = $1 ORDER BY start_date ASC, created_at ASC",
+        )
+        .bind(status)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn find_active_in_range(
+        &self,
+        user_ids: &[Uuid],
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<Vec<TimeOffRequest>, DomainError> {
+        let rows = sqlx::query_as::<_, TimeOffRequestRow>(
+            r#"
+            SELECT * FROM time_off_requests
+            WHERE user_id = ANY($1) AND status IN ('pending', 'approved')
+              AND start_date <= $3 AND end_date >= $2
+            ORDER BY start_date ASC
+            "#,
+        )
+        .bind(user_ids)
+        .bind(start)
+        .bind(end)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn create(&self, request: &TimeOffRequest) -> Result<TimeOffRequest, DomainError> {
+        let row = sqlx::query_as::<_, TimeOffRequestRow>(
+            r#"
+            INSERT INTO time_off_requests (id, user_id, start_date, end_date, reason, status, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            RETURNING *
+            "#,
+        )
+        .bind(request.id)
+        .bind(request.user_id)
+        .bind(request.start_date)
+        .bind(request.end_date)
+        .bind(&request.reason)
+        .bind(request.status)
+        .bind(request.created_at)
+        .bind(request.updated_at)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(row.into())
+    }
+
+    async fn update(&self, request: &TimeOffRequest) -> Result<TimeOffRequest, DomainError> {
+        let row = sqlx::query_as::<_, TimeOffRequestRow>(
+            r#"
+            UPDATE time_off_requests
+            SET status = $1, decided_by = $2, decided_at = $3, comment = $4, updated_at = NOW()
+            WHERE id = $5
+            RETURNING *
+            "#,
+        )
+        .bind(request.status)
+        .bind(request.decided_by)
+        .bind(request.decided_at)
+        .bind(&request.comment)
+        .bind(request.id)
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or_else(|| DomainError::NotFound("Time off request not found".into()))?;
+
+        Ok(row.into())
+    }
+}
@@ -0,0 +1,127 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgPool};
+use uuid::Uuid;
+
+use crate::domain::entities::Timer;
+use crate::domain::repositories::TimerRepository;
+use crate::shared::DomainError;
+
+#[derive(Debug, FromRow)]
+struct TimerRow {
+    id: Uuid,
+    user_id: Uuid,
+    task_id: Uuid,
+    description: Option<String>,
+    started_at: DateTime<Utc>,
+    running_since: Option<DateTime<Utc>>,
+    accumulated_seconds: i64,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl From<TimerRow> for Timer {
+    fn from(row: TimerRow) -> Self {
+        Timer {
+            id: row.id,
+            user_id: row.user_id,
+            task_id: row.task_id,
+            description: row.description,
+            started_at: row.started_at,
+            running_since: row.running_since,
+            accumulated_seconds: row.accumulated_seconds,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }
+    }
+}
+
+pub struct PgTimerRepository {
+    pool: PgPool,
+}
+
+impl PgTimerRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl TimerRepository for PgTimerRepository {
+    async fn find_by_user(&self, user_id: Uuid) -> Result<Option<Timer>, DomainError> {
+        let row = sqlx::query_as::<_, TimerRow>("SELECT * FROM timers WHERE user_id = $1")
+            .bind(user_id)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(row.map(Into::into))
+    }
+
+    async fn find_running_since_before(
+        &self,
+        cutoff: DateTime<Utc>,
+    ) -> Result<Vec<Timer>, DomainError> {
+        let rows = sqlx::query_as::<_, TimerRow>(
+            "SELECT * FROM timers WHERE running_since < $1 ORDER BY running_since ASC",
+        )
+        .bind(cutoff)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn create(&self, timer: &Timer) -> Result<Timer, DomainError> {
+        let row = sqlx::query_as::<_, TimerRow>(
+            r#"
+            INSERT INTO timers (id, user_id, task_id, description, started_at, running_since,
+                accumulated_seconds, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            RETURNING *
+            "#,
+        )
+        .bind(timer.id)
+        .bind(timer.user_id)
+        .bind(timer.task_id)
+        .bind(&timer.description)
+        .bind(timer.started_at)
+        .bind(timer.running_since)
+        .bind(timer.accumulated_seconds)
+        .bind(timer.created_at)
+        .bind(timer.updated_at)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(row.into())
+    }
+
+    async fn update(&self, timer: &Timer) -> Result<Timer, DomainError> {
+        let row = sqlx::query_as::<_, TimerRow>(
+            r#"
+            UPDATE timers
+            SET description = $1, running_since = $2, accumulated_seconds = $3, updated_at = $4
+            WHERE id = $5
+            RETURNING *
+            "#,
+        )
+        .bind(&timer.description)
+        .bind(timer.running_since)
+        .bind(timer.accumulated_seconds)
+        .bind(timer.updated_at)
+        .bind(timer.id)
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or_else(|| DomainError::NotFound("Timer not found".into()))?;
+
+        Ok(row.into())
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<(), DomainError> {
+        sqlx::query("DELETE FROM timers WHERE id = $1")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+}
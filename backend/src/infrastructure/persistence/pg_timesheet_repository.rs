@@ -0,0 +1,144 @@
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use sqlx::{FromRow, PgPool};
+use uuid::Uuid;
+
+use crate::domain::entities::Timesheet;
+use crate::domain::repositories::TimesheetRepository;
+use crate::domain::value_objects::TimesheetStatus;
+use crate::shared::DomainError;
+
+#[derive(Debug, FromRow)]
+struct TimesheetRow {
+    id: Uuid,
+    user_id: Uuid,
+    week_start: NaiveDate,
+    status: TimesheetStatus,
+    submitted_at: Option<DateTime<Utc>>,
+    decided_by: Option<Uuid>,
+    decided_at: Option<DateTime<Utc>>,
+    comment: Option<String>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl From<TimesheetRow> for Timesheet {
+    fn from(row: TimesheetRow) -> Self {
+        Timesheet {
+            id: row.id,
+            user_id: row.user_id,
+            week_start: row.week_start,
+            status: row.status,
+            submitted_at: row.submitted_at,
+            decided_by: row.decided_by,
+            decided_at: row.decided_at,
+            comment: row.comment,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }
+    }
+}
+
+pub struct PgTimesheetRepository {
+    pool: PgPool,
+}
+
+impl PgTimesheetRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl TimesheetRepository for PgTimesheetRepository {
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Timesheet>, DomainError> {
+        let row = sqlx::query_as::<_, TimesheetRow>("SELECT * FROM timesheets WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(row.map(Into::into))
+    }
+
+    async fn find_by_user(&self, user_id: Uuid) -> Result<Vec<Timesheet>, DomainError> {
+        let rows = sqlx::query_as::<_, TimesheetRow>(
+            "SELECT * FROM timesheets WHERE user_id = $1 ORDER BY week_start DESC",
+        )
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn find_by_status(&self, status: TimesheetStatus) -> Result<Vec<Timesheet>, DomainError> {
+        let rows = sqlx::query_as::<_, TimesheetRow>(
+            "SELECT * FROM timesheets WHERE status = $1 ORDER BY week_start ASC, submitted_at ASC",
+        )
+        .bind(status)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn find_or_create(&self, timesheet: &Timesheet) -> Result<Timesheet, DomainError> {
+        sqlx::query(
+            r#"
+            INSERT INTO timesheets (id, user_id, week_start, status, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            ON CONFLICT (user_id, week_start) DO NOTHING
+            "#,
+        )
+        .bind(timesheet.id)
+        .bind(timesheet.user_id)
+        .bind(timesheet.week_start)
+        .bind(timesheet.status)
+        .bind(timesheet.created_at)
+        .bind(timesheet.updated_at)
+        .execute(&self.pool)
+        .await?;
+
+        let row = sqlx::query_as::<_, TimesheetRow>(
+            "SELECT * FROM timesheets WHERE user_id = $1 AND week_start = $2",
+        )
+        .bind(timesheet.user_id)
+        .bind(timesheet.week_start)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(row.into())
+    }
+
+    async fn update(&self, timesheet: &Timesheet) -> Result<Timesheet, DomainError> {
+        let mut tx = self.pool.begin().await?;
+        // Wait for the user's time log changes in flight, which check the
+        // status with the user row locked
+        sqlx::query("SELECT 1 FROM users WHERE id = $1 FOR SHARE")
+            .bind(timesheet.user_id)
+            .execute(&mut *tx)
+            .await?;
+
+        let row = sqlx::query_as::<_, TimesheetRow>(
+            r#"
+            UPDATE timesheets
+            SET status = $1, submitted_at = $2, decided_by = $3, decided_at = $4, comment = $5,
+                updated_at = NOW()
+            WHERE id = $6
+            RETURNING *
+            "#,
+        )
+        .bind(timesheet.status)
+        .bind(timesheet.submitted_at)
+        .bind(timesheet.decided_by)
+        .bind(timesheet.decided_at)
+        .bind(&timesheet.comment)
+        .bind(timesheet.id)
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or_else(|| DomainError::NotFound("Timesheet not found".into()))?;
+
+        tx.commit().await?;
+        Ok(row.into())
+    }
+}
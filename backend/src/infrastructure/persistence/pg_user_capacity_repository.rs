@@ -0,0 +1,85 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgPool};
+use uuid::Uuid;
+
+use crate::domain::entities::UserCapacity;
+use crate::domain::repositories::UserCapacityRepository;
+use crate::shared::DomainError;
+
+#[derive(Debug, FromRow)]
+struct UserCapacityRow {
+    user_id: Uuid,
+    weekly_hours: f32,
+    day_hours: Option<Vec<f32>>,
+    holiday_calendar_id: Option<Uuid>,
+    updated_at: DateTime<Utc>,
+}
+
+impl From<UserCapacityRow> for UserCapacity {
+    fn from(row: UserCapacityRow) -> Self {
+        UserCapacity {
+            user_id: row.user_id,
+            weekly_hours: row.weekly_hours,
+            day_hours: row.day_hours,
+            holiday_calendar_id: row.holiday_calendar_id,
+            updated_at: row.updated_at,
+        }
+    }
+}
+
+pub struct PgUserCapacityRepository {
+    pool: PgPool,
+}
+
+impl PgUserCapacityRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl UserCapacityRepository for PgUserCapacityRepository {
+    async fn find_all(&self) -> Result<Vec<UserCapacity>, DomainError> {
+        let rows = sqlx::query_as::<_, UserCapacityRow>("SELECT * FROM user_capacities")
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn find_by_user(&self, user_id: Uuid) -> Result<Option<UserCapacity>, DomainError> {
+        let row = sqlx::query_as::<_, UserCapacityRow>(
+            "SELECT * FROM user_capacities WHERE user_id = $1",
+        )
+        .bind(user_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.map(Into::into))
+    }
+
+    async fn upsert(&self, capacity: &UserCapacity) -> Result<UserCapacity, DomainError> {
+        let row = sqlx::query_as::<_, UserCapacityRow>(
+            r#"
+            INSERT INTO user_capacities (user_id, weekly_hours, day_hours, holiday_calendar_id, updated_at)
+            VALUES ($1, $2, $3, $4, $5)
+            ON CONFLICT (user_id) DO UPDATE
+            SET weekly_hours = EXCLUDED.weekly_hours,
+                day_hours = EXCLUDED.day_hours,
+                holiday_calendar_id = EXCLUDED.holiday_calendar_id,
+                updated_at = NOW()
+            RETURNING *
+            "#,
+        )
+        .bind(capacity.user_id)
+        .bind(capacity.weekly_hours)
+        .bind(&capacity.day_hours)
+        .bind(capacity.holiday_calendar_id)
+        .bind(capacity.updated_at)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(row.into())
+    }
+}
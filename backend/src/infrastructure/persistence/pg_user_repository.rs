@@ -73,6 +73,23 @@ impl UserRepository for PgUserRepository {
         Ok(rows.into_iter().map(Into::into).collect())
     }
 
+    async fn find_by_project(&self, project_id: Uuid) -> Result<Vec<User>, DomainError> {
+        let rows = sqlx::query_as::<_, UserRow>(
+            r#"
+            SELECT DISTINCT u.* FROM users u
+            LEFT JOIN project_members pm ON u.id = pm.user_id AND pm.project_id = $1
+            LEFT JOIN projects p ON u.id = p.owner_id AND p.id = $1
+            WHERE pm.id IS NOT NULL OR p.id IS NOT NULL
+            ORDER BY u.name ASC
+            "#,
+        )
+        .bind(project_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
     async fn create(&self, user: &User) -> Result<User, DomainError> {
         let row = sqlx::query_as::<_, UserRow>(
             r#"
@@ -0,0 +1,128 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgPool};
+use uuid::Uuid;
+
+use crate::domain::entities::Watcher;
+use crate::domain::repositories::WatcherRepository;
+use crate::domain::value_objects::WatchEntityType;
+use crate::shared::DomainError;
+
+#[derive(Debug, FromRow)]
+struct WatcherRow {
+    id: Uuid,
+    user_id: Uuid,
+    entity_type: WatchEntityType,
+    entity_id: Uuid,
+    created_at: DateTime<Utc>,
+}
+
+impl From<WatcherRow> for Watcher {
+    fn from(row: WatcherRow) -> Self {
+        Watcher {
+            id: row.id,
+            user_id: row.user_id,
+            entity_type: row.entity_type,
+            entity_id: row.entity_id,
+            created_at: row.created_at,
+        }
+    }
+}
+
+pub struct PgWatcherRepository {
+    pool: PgPool,
+}
+
+impl PgWatcherRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl WatcherRepository for PgWatcherRepository {
+    async fn find_by_entity(
+        &self,
+        entity_type: WatchEntityType,
+        entity_id: Uuid,
+    ) -> Result<Vec<Watcher>, DomainError> {
+        let rows = sqlx::query_as::<_, WatcherRow>(
+            "SELECT * FROM watchers WHERE entity_type = $1 AND entity_id = $2 ORDER BY created_at ASC",
+        )
+        .bind(entity_type)
+        .bind(entity_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn watch(&self, watcher: &Watcher) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            INSERT INTO watchers (id, user_id, entity_type, entity_id, created_at)
+            VALUES ($1, $2, $3, $4, $5)
+            ON CONFLICT (user_id, entity_type, entity_id) DO NOTHING
+            "#,
+        )
+        .bind(watcher.id)
+        .bind(watcher.user_id)
+        .bind(watcher.entity_type)
+        .bind(watcher.entity_id)
+        .bind(watcher.created_at)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn unwatch(
+        &self,
+        user_id: Uuid,
+        entity_type: WatchEntityType,
+        entity_id: Uuid,
+    ) -> Result<(), DomainError> {
+        sqlx::query(
+            "DELETE FROM watchers WHERE user_id = $1 AND entity_type = $2 AND entity_id = $3",
+        )
+        .bind(user_id)
+        .bind(entity_type)
+        .bind(entity_id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn is_watching(
+        &self,
+        user_id: Uuid,
+        entity_type: WatchEntityType,
+        entity_id: Uuid,
+    ) -> Result<bool, DomainError> {
+        let result: Option<(i32,)> = sqlx::query_as(
+            "SELECT 1 FROM watchers WHERE user_id = $1 AND entity_type = $2 AND entity_id = $3 LIMIT 1",
+        )
+        .bind(user_id)
+        .bind(entity_type)
+        .bind(entity_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(result.is_some())
+    }
+
+    async fn delete_orphaned(&self) -> Result<u64, DomainError> {
+        let result = sqlx::query(
+            r#"
+            DELETE FROM watchers w
+            WHERE (w.entity_type = 'task' AND NOT EXISTS (SELECT 1 FROM tasks t WHERE t.id = w.entity_id))
+               OR (w.entity_type = 'project' AND NOT EXISTS (SELECT 1 FROM projects p WHERE p.id = w.entity_id))
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+}
@@ -3,7 +3,7 @@
 
 use axum::{
     middleware,
-    routing::{delete, get, post, put},
+    routing::{delete, get, patch, post, put},
     Router,
 };
 use std::{path::PathBuf, sync::Arc, time::Duration};
@@ -18,24 +18,46 @@ mod presentation;
 mod shared;
 
 use application::services::{
-    ActivityAppService, AttachmentAppService, AuthAppService, NotificationAppService,
-    ProjectAppService, TagAppService, TaskAppService, TeamAppService, TimeLogAppService,
+    ActivityAppService, AllocationAppService, AttachmentAppService, AuthAppService,
+    BaselineAppService, BillingAppService, BudgetAppService, CalendarAppService,
+    CapacityAppService, ChangeRequestAppService, CommentAppService, EvmAppService,
+    HealthAppService, MentionAppService, NotificationAppService, PortfolioAppService,
+    ProjectAppService, RaidAppService, ScheduleAppService, SprintAppService,
+    StatusReportAppService, TagAppService, TaskAppService, TeamAppService, TimeLogAppService,
+    TimerAppService, TimesheetAppService, TrashAppService,
 };
+use domain::services::TimeLogPolicy;
 use infrastructure::{
     config::AppConfig,
     database,
     persistence::{
-        PgActivityLogRepository, PgAttachmentRepository, PgNotificationRepository,
-        PgProjectRepository, PgTagRepository, PgTaskRepository, PgTeamRepository,
-        PgTimeLogRepository, PgUserRepository,
+        PgActivityLogRepository, PgAttachmentRepository, PgBaselineRepository,
+        PgBillingRateRepository, PgBudgetAlertRepository, PgChangeRequestRepository,
+        PgCostRateRepository, PgExpenseRepository, PgHolidayRepository, PgInvoiceRepository,
+        PgMentionRepository, PgNotificationRepository, PgPortfolioRepository,
+        PgProjectHealthRepository, PgProjectRepository, PgRaidItemRepository,
+        PgResourceAllocationRepository, PgSprintRepository, PgStatusReportRepository,
+        PgTagRepository, PgTaskCommentRepository, PgTaskDependencyRepository, PgTaskRepository,
+        PgTeamRepository, PgTimeLogRepository, PgTimeOffRepository, PgTimerRepository,
+        PgTimesheetRepository, PgUserCapacityRepository, PgUserRepository, PgWatcherRepository,
     },
 };
 use presentation::handlers::{
-    activity_handler, attachment_handler, auth_handler, notification_handler, project_handler,
-    tag_handler, task_handler, team_handler, time_log_handler,
+    activity_handler, allocation_handler, attachment_handler, auth_handler, baseline_handler,
+    billing_handler, budget_handler, calendar_handler, capacity_handler, change_request_handler,
+    comment_handler, evm_handler, health_handler, notification_handler, portfolio_handler,
+    project_handler, raid_handler, schedule_handler, sprint_handler, status_report_handler,
+    tag_handler, task_handler, team_handler, time_log_handler, timer_handler, timesheet_handler,
+    trash_handler,
 };
 use presentation::middleware::auth_middleware;
 
+const TRASH_PURGE_INTERVAL: Duration = Duration::from_secs(60 * 60);
+const RAID_REMINDER_INTERVAL: Duration = Duration::from_secs(60 * 60);
+const HEALTH_SNAPSHOT_INTERVAL: Duration = Duration::from_secs(6 * 60 * 60);
+const TIMER_AUTO_STOP_INTERVAL: Duration = Duration::from_secs(15 * 60);
+const DUE_SOON_REMINDER_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
 #[tokio::main]
 async fn main() {
     // Initialize tracing
@@ -60,11 +82,33 @@ async fn main() {
     let project_repository = Arc::new(PgProjectRepository::new(pool.clone()));
     let task_repository = Arc::new(PgTaskRepository::new(pool.clone()));
     let team_repository = Arc::new(PgTeamRepository::new(pool.clone()));
+    let user_capacity_repository = Arc::new(PgUserCapacityRepository::new(pool.clone()));
+    let holiday_repository = Arc::new(PgHolidayRepository::new(pool.clone()));
+    let time_off_repository = Arc::new(PgTimeOffRepository::new(pool.clone()));
+    let allocation_repository = Arc::new(PgResourceAllocationRepository::new(pool.clone()));
     let activity_repository = Arc::new(PgActivityLogRepository::new(pool.clone()));
     let time_log_repository = Arc::new(PgTimeLogRepository::new(pool.clone()));
+    let timer_repository = Arc::new(PgTimerRepository::new(pool.clone()));
+    let timesheet_repository = Arc::new(PgTimesheetRepository::new(pool.clone()));
     let tag_repository = Arc::new(PgTagRepository::new(pool.clone()));
     let attachment_repository = Arc::new(PgAttachmentRepository::new(pool.clone()));
     let notification_repository = Arc::new(PgNotificationRepository::new(pool.clone()));
+    let watcher_repository = Arc::new(PgWatcherRepository::new(pool.clone()));
+    let mention_repository = Arc::new(PgMentionRepository::new(pool.clone()));
+    let comment_repository = Arc::new(PgTaskCommentRepository::new(pool.clone()));
+    let dependency_repository = Arc::new(PgTaskDependencyRepository::new(pool.clone()));
+    let sprint_repository = Arc::new(PgSprintRepository::new(pool.clone()));
+    let expense_repository = Arc::new(PgExpenseRepository::new(pool.clone()));
+    let baseline_repository = Arc::new(PgBaselineRepository::new(pool.clone()));
+    let raid_item_repository = Arc::new(PgRaidItemRepository::new(pool.clone()));
+    let health_repository = Arc::new(PgProjectHealthRepository::new(pool.clone()));
+    let portfolio_repository = Arc::new(PgPortfolioRepository::new(pool.clone()));
+    let status_report_repository = Arc::new(PgStatusReportRepository::new(pool.clone()));
+    let change_request_repository = Arc::new(PgChangeRequestRepository::new(pool.clone()));
+    let cost_rate_repository = Arc::new(PgCostRateRepository::new(pool.clone()));
+    let budget_alert_repository = Arc::new(PgBudgetAlertRepository::new(pool.clone()));
+    let billing_rate_repository = Arc::new(PgBillingRateRepository::new(pool.clone()));
+    let invoice_repository = Arc::new(PgInvoiceRepository::new(pool.clone()));
 
     // Setup upload directory
     let upload_dir =
@@ -75,18 +119,249 @@ async fn main() {
 
     // Create application services
     let auth_service = Arc::new(AuthAppService::new(
-        user_repository,
+        user_repository.clone(),
         config.jwt_secret.clone(),
         config.jwt_expiration,
     ));
-    let project_service = Arc::new(ProjectAppService::new(project_repository));
-    let task_service = Arc::new(TaskAppService::new(task_repository));
-    let team_service = Arc::new(TeamAppService::new(team_repository));
-    let activity_service = Arc::new(ActivityAppService::new(activity_repository));
-    let time_log_service = Arc::new(TimeLogAppService::new(time_log_repository));
-    let tag_service = Arc::new(TagAppService::new(tag_repository));
-    let attachment_service = Arc::new(AttachmentAppService::new(attachment_repository, upload_dir));
     let notification_service = Arc::new(NotificationAppService::new(notification_repository));
+    let project_service = Arc::new(ProjectAppService::new(
+        project_repository.clone(),
+        watcher_repository.clone(),
+        notification_service.clone(),
+    ));
+    let mention_service = Arc::new(MentionAppService::new(
+        mention_repository,
+        user_repository.clone(),
+        notification_service.clone(),
+    ));
+    let calendar_service = Arc::new(CalendarAppService::new(
+        holiday_repository,
+        time_off_repository,
+        user_capacity_repository.clone(),
+        user_repository.clone(),
+        team_repository.clone(),
+        notification_service.clone(),
+        config.default_weekly_hours,
+    ));
+    let task_service = Arc::new(TaskAppService::new(
+        task_repository.clone(),
+        activity_repository.clone(),
+        watcher_repository.clone(),
+        project_service.clone(),
+        mention_service.clone(),
+        notification_service.clone(),
+        calendar_service.clone(),
+        config.due_soon_working_days,
+    ));
+    let comment_service = Arc::new(CommentAppService::new(
+        comment_repository,
+        task_repository.clone(),
+        watcher_repository.clone(),
+        project_service.clone(),
+        mention_service,
+        notification_service.clone(),
+    ));
+    let schedule_service = Arc::new(ScheduleAppService::new(
+        task_repository.clone(),
+        dependency_repository,
+        project_service.clone(),
+        calendar_service.clone(),
+    ));
+    let sprint_service = Arc::new(SprintAppService::new(
+        sprint_repository,
+        task_repository.clone(),
+        project_service.clone(),
+    ));
+    let team_service = Arc::new(TeamAppService::new(team_repository.clone()));
+    let activity_service = Arc::new(ActivityAppService::new(activity_repository.clone()));
+    let budget_service = Arc::new(BudgetAppService::new(
+        expense_repository,
+        cost_rate_repository,
+        budget_alert_repository,
+        time_log_repository.clone(),
+        task_repository.clone(),
+        attachment_repository.clone(),
+        user_repository.clone(),
+        project_service.clone(),
+        notification_service.clone(),
+        config.budget_alert_thresholds.clone(),
+    ));
+    let baseline_service = Arc::new(BaselineAppService::new(
+        baseline_repository,
+        task_repository.clone(),
+        project_service.clone(),
+    ));
+    let raid_service = Arc::new(RaidAppService::new(
+        raid_item_repository,
+        task_repository.clone(),
+        project_service.clone(),
+        notification_service.clone(),
+    ));
+    let health_service = Arc::new(HealthAppService::new(
+        health_repository,
+        project_service.clone(),
+        budget_service.clone(),
+        raid_service.clone(),
+    ));
+    let portfolio_service = Arc::new(PortfolioAppService::new(
+        portfolio_repository,
+        project_repository.clone(),
+        user_repository.clone(),
+        project_service.clone(),
+        budget_service.clone(),
+        health_service.clone(),
+    ));
+    let status_report_service = Arc::new(StatusReportAppService::new(
+        status_report_repository,
+        activity_repository.clone(),
+        time_log_repository.clone(),
+        project_service.clone(),
+        budget_service.clone(),
+        raid_service.clone(),
+    ));
+    let change_request_service = Arc::new(ChangeRequestAppService::new(
+        change_request_repository,
+        activity_repository,
+        project_service.clone(),
+        notification_service.clone(),
+    ));
+    let evm_service = Arc::new(EvmAppService::new(
+        task_repository.clone(),
+        project_service.clone(),
+        budget_service.clone(),
+    ));
+    let time_log_service = Arc::new(TimeLogAppService::new(
+        time_log_repository.clone(),
+        task_repository.clone(),
+        project_service.clone(),
+        budget_service.clone(),
+        TimeLogPolicy {
+            increment_minutes: config.time_log_increment_minutes,
+            daily_cap_hours: config.time_log_daily_cap_hours,
+        },
+    ));
+    let timer_service = Arc::new(TimerAppService::new(
+        timer_repository,
+        task_repository.clone(),
+        project_service.clone(),
+        time_log_service.clone(),
+        notification_service.clone(),
+        config.timer_auto_stop_hours,
+    ));
+    let timesheet_service = Arc::new(TimesheetAppService::new(
+        timesheet_repository,
+        time_log_repository.clone(),
+        team_repository.clone(),
+        notification_service.clone(),
+    ));
+    let billing_service = Arc::new(BillingAppService::new(
+        billing_rate_repository,
+        invoice_repository,
+        user_repository.clone(),
+        project_service.clone(),
+    ));
+    let capacity_service = Arc::new(CapacityAppService::new(
+        user_capacity_repository,
+        user_repository.clone(),
+        team_repository.clone(),
+        task_repository.clone(),
+        time_log_repository,
+        calendar_service.clone(),
+        config.default_weekly_hours,
+    ));
+    let allocation_service = Arc::new(AllocationAppService::new(
+        allocation_repository,
+        user_repository,
+        team_repository,
+        project_service.clone(),
+        calendar_service.clone(),
+    ));
+    let tag_service = Arc::new(TagAppService::new(tag_repository, project_service.clone()));
+    let attachment_service = Arc::new(AttachmentAppService::new(
+        attachment_repository,
+        project_service.clone(),
+        upload_dir,
+    ));
+    let trash_service = Arc::new(TrashAppService::new(
+        project_repository,
+        task_repository,
+        watcher_repository,
+        attachment_service.clone(),
+        config.trash_retention_days,
+    ));
+
+    // Periodically purge items that outlived the trash retention period
+    let purge_service = trash_service.clone();
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(TRASH_PURGE_INTERVAL);
+        loop {
+            interval.tick().await;
+            match purge_service.purge_expired().await {
+                Ok(summary) if summary.projects > 0 || summary.tasks > 0 => tracing::info!(
+                    projects = summary.projects,
+                    tasks = summary.tasks,
+                    "Purged expired trash"
+                ),
+                Ok(_) => {}
+                Err(e) => tracing::error!("Failed to purge trash: {}", e),
+            }
+        }
+    });
+
+    // Periodically remind owners of RAID items whose review date has passed
+    let reminder_service = raid_service.clone();
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(RAID_REMINDER_INTERVAL);
+        loop {
+            interval.tick().await;
+            match reminder_service.send_review_reminders().await {
+                Ok(sent) if sent > 0 => tracing::info!(sent, "Sent RAID review reminders"),
+                Ok(_) => {}
+                Err(e) => tracing::error!("Failed to send RAID review reminders: {}", e),
+            }
+        }
+    });
+
+    // Periodically remind assignees of tasks due within a few working days
+    let due_reminder_service = task_service.clone();
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(DUE_SOON_REMINDER_INTERVAL);
+        loop {
+            interval.tick().await;
+            match due_reminder_service.send_due_soon_reminders().await {
+                Ok(sent) if sent > 0 => tracing::info!(sent, "Sent task due soon reminders"),
+                Ok(_) => {}
+                Err(e) => tracing::error!("Failed to send task due soon reminders: {}", e),
+            }
+        }
+    });
+
+    // Periodically record this week's health snapshot of every project
+    let snapshot_service = health_service.clone();
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(HEALTH_SNAPSHOT_INTERVAL);
+        loop {
+            interval.tick().await;
+            match snapshot_service.record_weekly_snapshots().await {
+                Ok(projects) => tracing::debug!(projects, "Recorded project health snapshots"),
+                Err(e) => tracing::error!("Failed to record project health: {}", e),
+            }
+        }
+    });
+
+    // Periodically stop timers left running past the configured limit
+    let auto_stop_service = timer_service.clone();
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(TIMER_AUTO_STOP_INTERVAL);
+        loop {
+            interval.tick().await;
+            match auto_stop_service.auto_stop_stale().await {
+                Ok(stopped) if stopped > 0 => tracing::info!(stopped, "Stopped stale timers"),
+                Ok(_) => {}
+                Err(e) => tracing::error!("Failed to stop stale timers: {}", e),
+            }
+        }
+    });
 
     // CORS configuration - restrict to allowed origins
     let cors = CorsLayer::new()
@@ -95,6 +370,7 @@ async fn main() {
             axum::http::Method::GET,
             axum::http::Method::POST,
             axum::http::Method::PUT,
+            axum::http::Method::PATCH,
             axum::http::Method::DELETE,
             axum::http::Method::OPTIONS,
         ]))
@@ -116,12 +392,30 @@ async fn main() {
                 auth_service,
                 project_service,
                 task_service,
+                comment_service,
+                schedule_service,
+                sprint_service,
                 team_service,
                 activity_service,
                 time_log_service,
+                timer_service,
+                timesheet_service,
+                budget_service,
+                billing_service,
+                capacity_service,
+                calendar_service,
+                allocation_service,
+                evm_service,
+                baseline_service,
+                raid_service,
+                health_service,
+                portfolio_service,
+                status_report_service,
+                change_request_service,
                 tag_service,
                 attachment_service,
                 notification_service,
+                trash_service,
             ),
         )
         .layer(cors)
@@ -145,12 +439,30 @@ fn api_routes(
     auth_service: Arc<AuthAppService>,
     project_service: Arc<ProjectAppService>,
     task_service: Arc<TaskAppService>,
+    comment_service: Arc<CommentAppService>,
+    schedule_service: Arc<ScheduleAppService>,
+    sprint_service: Arc<SprintAppService>,
     team_service: Arc<TeamAppService>,
     activity_service: Arc<ActivityAppService>,
     time_log_service: Arc<TimeLogAppService>,
+    timer_service: Arc<TimerAppService>,
+    timesheet_service: Arc<TimesheetAppService>,
+    budget_service: Arc<BudgetAppService>,
+    billing_service: Arc<BillingAppService>,
+    capacity_service: Arc<CapacityAppService>,
+    calendar_service: Arc<CalendarAppService>,
+    allocation_service: Arc<AllocationAppService>,
+    evm_service: Arc<EvmAppService>,
+    baseline_service: Arc<BaselineAppService>,
+    raid_service: Arc<RaidAppService>,
+    health_service: Arc<HealthAppService>,
+    portfolio_service: Arc<PortfolioAppService>,
+    status_report_service: Arc<StatusReportAppService>,
+    change_request_service: Arc<ChangeRequestAppService>,
     tag_service: Arc<TagAppService>,
     attachment_service: Arc<AttachmentAppService>,
     notification_service: Arc<NotificationAppService>,
+    trash_service: Arc<TrashAppService>,
 ) -> Router {
     // Public auth routes (no authentication required)
     let public_auth_routes = Router::new()
@@ -165,6 +477,14 @@ fn api_routes(
         .route("/projects/{id}", get(project_handler::get_project))
         .route("/projects/{id}", put(project_handler::update_project))
         .route("/projects/{id}", delete(project_handler::delete_project))
+        .route(
+            "/projects/{id}/archive",
+            post(project_handler::archive_project),
+        )
+        .route(
+            "/projects/{id}/unarchive",
+            post(project_handler::unarchive_project),
+        )
         .route(
             "/projects/{id}/tasks",
             get(project_handler::get_project_tasks),
@@ -173,6 +493,15 @@ fn api_routes(
             "/projects/{id}/milestones",
             get(project_handler::get_project_milestones),
         )
+        .route(
+            "/projects/{id}/watchers",
+            get(project_handler::get_project_watchers),
+        )
+        .route("/projects/{id}/watch", post(project_handler::watch_project))
+        .route(
+            "/projects/{id}/watch",
+            delete(project_handler::unwatch_project),
+        )
         .layer(middleware::from_fn(auth_middleware))
         .with_state(project_service);
 
@@ -180,12 +509,369 @@ fn api_routes(
     let task_routes = Router::new()
         .route("/tasks", get(task_handler::list_tasks))
         .route("/tasks", post(task_handler::create_task))
+        .route(
+            "/tasks/actual-hours/reconcile",
+            post(task_handler::reconcile_actual_hours),
+        )
         .route("/tasks/{id}", get(task_handler::get_task))
         .route("/tasks/{id}", put(task_handler::update_task))
         .route("/tasks/{id}", delete(task_handler::delete_task))
+        .route("/tasks/{id}/history", get(task_handler::get_task_history))
+        .route(
+            "/tasks/{id}/history/{history_id}/restore",
+            post(task_handler::restore_task_version),
+        )
+        .route("/tasks/{id}/watchers", get(task_handler::get_task_watchers))
+        .route("/tasks/{id}/watch", post(task_handler::watch_task))
+        .route("/tasks/{id}/watch", delete(task_handler::unwatch_task))
         .layer(middleware::from_fn(auth_middleware))
         .with_state(task_service);
 
+    // Protected comment routes
+    let comment_routes = Router::new()
+        .route(
+            "/tasks/{task_id}/comments",
+            get(comment_handler::get_task_comments),
+        )
+        .route(
+            "/tasks/{task_id}/comments",
+            post(comment_handler::add_comment),
+        )
+        .route("/comments/{id}", put(comment_handler::update_comment))
+        .route("/comments/{id}", delete(comment_handler::delete_comment))
+        .layer(middleware::from_fn(auth_middleware))
+        .with_state(comment_service);
+
+    // Protected schedule routes
+    let schedule_routes = Router::new()
+        .route(
+            "/projects/{id}/schedule",
+            get(schedule_handler::get_project_schedule),
+        )
+        .route(
+            "/tasks/{id}/dependencies",
+            get(schedule_handler::get_task_dependencies),
+        )
+        .route(
+            "/tasks/{id}/dependencies/{depends_on_id}",
+            post(schedule_handler::add_dependency),
+        )
+        .route(
+            "/tasks/{id}/dependencies/{depends_on_id}",
+            delete(schedule_handler::remove_dependency),
+        )
+        .layer(middleware::from_fn(auth_middleware))
+        .with_state(schedule_service);
+
+    // Protected sprint routes
+    let sprint_routes = Router::new()
+        .route("/projects/{id}/sprints", get(sprint_handler::list_sprints))
+        .route(
+            "/projects/{id}/sprints",
+            post(sprint_handler::create_sprint),
+        )
+        .route("/projects/{id}/velocity", get(sprint_handler::get_velocity))
+        .route("/sprints/{id}", get(sprint_handler::get_sprint))
+        .route("/sprints/{id}", put(sprint_handler::update_sprint))
+        .route("/sprints/{id}", delete(sprint_handler::delete_sprint))
+        .route("/sprints/{id}/start", post(sprint_handler::start_sprint))
+        .route("/sprints/{id}/close", post(sprint_handler::close_sprint))
+        .route("/sprints/{id}/tasks", get(sprint_handler::get_sprint_tasks))
+        .route(
+            "/sprints/{id}/tasks/{task_id}",
+            post(sprint_handler::add_sprint_task),
+        )
+        .route(
+            "/sprints/{id}/tasks/{task_id}",
+            delete(sprint_handler::remove_sprint_task),
+        )
+        .route("/sprints/{id}/burndown", get(sprint_handler::get_burndown))
+        .layer(middleware::from_fn(auth_middleware))
+        .with_state(sprint_service);
+
+    // Protected budget routes
+    let budget_routes = Router::new()
+        .route(
+            "/projects/{id}/budget",
+            get(budget_handler::get_budget_summary),
+        )
+        .route(
+            "/projects/{id}/expenses",
+            get(budget_handler::list_expenses),
+        )
+        .route(
+            "/projects/{id}/expenses",
+            post(budget_handler::create_expense),
+        )
+        .route("/expenses/{id}", get(budget_handler::get_expense))
+        .route("/expenses/{id}", put(budget_handler::update_expense))
+        .route("/expenses/{id}", delete(budget_handler::delete_expense))
+        .route("/cost-rates", get(budget_handler::list_cost_rates))
+        .route("/cost-rates", put(budget_handler::set_cost_rate))
+        .route("/cost-rates/{id}", delete(budget_handler::delete_cost_rate))
+        .layer(middleware::from_fn(auth_middleware))
+        .with_state(budget_service);
+
+    // Protected billing routes
+    let billing_routes = Router::new()
+        .route("/billing-rates", get(billing_handler::list_rates))
+        .route("/billing-rates", post(billing_handler::create_rate))
+        .route("/billing-rates/{id}", delete(billing_handler::delete_rate))
+        .route("/invoices", get(billing_handler::list_invoices))
+        .route("/invoices", post(billing_handler::create_invoice))
+        .route("/invoices/prepare", get(billing_handler::prepare_invoice))
+        .route("/invoices/{id}", get(billing_handler::get_invoice))
+        .layer(middleware::from_fn(auth_middleware))
+        .with_state(billing_service);
+
+    // Protected capacity routes
+    let capacity_routes = Router::new()
+        .route(
+            "/users/{user_id}/capacity",
+            get(capacity_handler::get_capacity),
+        )
+        .route(
+            "/users/{user_id}/capacity",
+            put(capacity_handler::set_capacity),
+        )
+        .route(
+            "/reports/utilization",
+            get(capacity_handler::utilization_report),
+        )
+        .layer(middleware::from_fn(auth_middleware))
+        .with_state(capacity_service);
+
+    // Protected holiday calendar, time off and availability routes
+    let calendar_routes = Router::new()
+        .route("/holiday-calendars", get(calendar_handler::list_calendars))
+        .route(
+            "/holiday-calendars",
+            post(calendar_handler::create_calendar),
+        )
+        .route(
+            "/holiday-calendars/{id}",
+            put(calendar_handler::update_calendar),
+        )
+        .route(
+            "/holiday-calendars/{id}",
+            delete(calendar_handler::delete_calendar),
+        )
+        .route(
+            "/holiday-calendars/{id}/holidays",
+            get(calendar_handler::list_holidays),
+        )
+        .route(
+            "/holiday-calendars/{id}/holidays",
+            post(calendar_handler::add_holiday),
+        )
+        .route(
+            "/holiday-calendars/{id}/import",
+            post(calendar_handler::import_holidays),
+        )
+        .route("/holidays/{id}", delete(calendar_handler::delete_holiday))
+        .route("/time-off", get(calendar_handler::list_my_time_off))
+        .route("/time-off", post(calendar_handler::request_time_off))
+        .route("/time-off/pending", get(calendar_handler::list_pending))
+        .route("/time-off/{id}", get(calendar_handler::get_time_off))
+        .route(
+            "/time-off/{id}/approve",
+            post(calendar_handler::approve_time_off),
+        )
+        .route(
+            "/time-off/{id}/reject",
+            post(calendar_handler::reject_time_off),
+        )
+        .route(
+            "/time-off/{id}/cancel",
+            post(calendar_handler::cancel_time_off),
+        )
+        .route(
+            "/teams/{id}/availability",
+            get(calendar_handler::team_availability),
+        )
+        .layer(middleware::from_fn(auth_middleware))
+        .with_state(calendar_service);
+
+    // Protected resource allocation routes
+    let allocation_routes = Router::new()
+        .route("/allocations", post(allocation_handler::create_allocation))
+        .route("/allocations/heatmap", get(allocation_handler::heatmap))
+        .route(
+            "/allocations/over-allocations",
+            get(allocation_handler::over_allocations),
+        )
+        .route("/allocations/{id}", get(allocation_handler::get_allocation))
+        .route(
+            "/allocations/{id}",
+            put(allocation_handler::update_allocation),
+        )
+        .route(
+            "/allocations/{id}",
+            delete(allocation_handler::delete_allocation),
+        )
+        .route(
+            "/projects/{id}/allocations",
+            get(allocation_handler::list_project_allocations),
+        )
+        .route(
+            "/users/{user_id}/allocations",
+            get(allocation_handler::list_user_allocations),
+        )
+        .route(
+            "/teams/{id}/allocations",
+            get(allocation_handler::team_allocation),
+        )
+        .layer(middleware::from_fn(auth_middleware))
+        .with_state(allocation_service);
+
+    // Protected earned value routes
+    let evm_routes = Router::new()
+        .route("/projects/{id}/evm", get(evm_handler::get_project_evm))
+        .layer(middleware::from_fn(auth_middleware))
+        .with_state(evm_service);
+
+    // Protected baseline routes
+    let baseline_routes = Router::new()
+        .route(
+            "/projects/{id}/baselines",
+            get(baseline_handler::list_baselines),
+        )
+        .route(
+            "/projects/{id}/baselines",
+            post(baseline_handler::create_baseline),
+        )
+        .route("/baselines/{id}", get(baseline_handler::get_baseline))
+        .route("/baselines/{id}", delete(baseline_handler::delete_baseline))
+        .route(
+            "/baselines/{id}/variance",
+            get(baseline_handler::get_variance),
+        )
+        .layer(middleware::from_fn(auth_middleware))
+        .with_state(baseline_service);
+
+    // Protected RAID routes
+    let raid_routes = Router::new()
+        .route("/projects/{id}/raid", get(raid_handler::list_items))
+        .route("/projects/{id}/raid", post(raid_handler::create_item))
+        .route(
+            "/projects/{id}/risk-matrix",
+            get(raid_handler::get_risk_matrix),
+        )
+        .route("/raid/{id}", get(raid_handler::get_item))
+        .route("/raid/{id}", put(raid_handler::update_item))
+        .route("/raid/{id}", delete(raid_handler::delete_item))
+        .layer(middleware::from_fn(auth_middleware))
+        .with_state(raid_service);
+
+    // Protected status report routes
+    let status_report_routes = Router::new()
+        .route(
+            "/projects/{id}/status-reports",
+            get(status_report_handler::list_reports),
+        )
+        .route(
+            "/projects/{id}/status-reports",
+            post(status_report_handler::create_report),
+        )
+        .route(
+            "/status-reports/{id}",
+            get(status_report_handler::get_report),
+        )
+        .layer(middleware::from_fn(auth_middleware))
+        .with_state(status_report_service);
+
+    // Protected change request routes
+    let change_request_routes = Router::new()
+        .route(
+            "/projects/{id}/change-approvers",
+            get(change_request_handler::get_approvers),
+        )
+        .route(
+            "/projects/{id}/change-approvers",
+            put(change_request_handler::set_approvers),
+        )
+        .route(
+            "/projects/{id}/change-requests",
+            get(change_request_handler::list_requests),
+        )
+        .route(
+            "/projects/{id}/change-requests",
+            post(change_request_handler::submit_request),
+        )
+        .route(
+            "/change-requests/{id}",
+            get(change_request_handler::get_request),
+        )
+        .route(
+            "/change-requests/{id}/history",
+            get(change_request_handler::get_request_history),
+        )
+        .route(
+            "/change-requests/{id}/approve",
+            post(change_request_handler::approve_request),
+        )
+        .route(
+            "/change-requests/{id}/reject",
+            post(change_request_handler::reject_request),
+        )
+        .route(
+            "/change-requests/{id}/withdraw",
+            post(change_request_handler::withdraw_request),
+        )
+        .layer(middleware::from_fn(auth_middleware))
+        .with_state(change_request_service);
+
+    // Protected project health routes
+    let health_routes = Router::new()
+        .route("/projects/health", get(health_handler::list_project_health))
+        .route(
+            "/projects/{id}/health",
+            get(health_handler::get_project_health),
+        )
+        .route(
+            "/projects/{id}/health/history",
+            get(health_handler::get_health_history),
+        )
+        .route(
+            "/projects/{id}/health/override",
+            put(health_handler::set_health_override),
+        )
+        .route(
+            "/projects/{id}/health/override",
+            delete(health_handler::clear_health_override),
+        )
+        .layer(middleware::from_fn(auth_middleware))
+        .with_state(health_service);
+
+    // Protected portfolio routes
+    let portfolio_routes = Router::new()
+        .route("/portfolios", get(portfolio_handler::list_portfolios))
+        .route("/portfolios", post(portfolio_handler::create_portfolio))
+        .route("/portfolios/{id}", get(portfolio_handler::get_portfolio))
+        .route("/portfolios/{id}", put(portfolio_handler::update_portfolio))
+        .route(
+            "/portfolios/{id}",
+            delete(portfolio_handler::delete_portfolio),
+        )
+        .route(
+            "/portfolios/{id}/projects",
+            get(portfolio_handler::get_portfolio_projects),
+        )
+        .route(
+            "/portfolios/{id}/projects/{project_id}",
+            put(portfolio_handler::add_portfolio_project),
+        )
+        .route(
+            "/portfolios/{id}/projects/{project_id}",
+            delete(portfolio_handler::remove_portfolio_project),
+        )
+        .route(
+            "/portfolios/{id}/rollup",
+            get(portfolio_handler::get_portfolio_rollup),
+        )
+        .layer(middleware::from_fn(auth_middleware))
+        .with_state(portfolio_service);
+
     // Protected team routes
     let team_routes = Router::new()
         .route("/teams", get(team_handler::list_teams))
@@ -195,6 +881,14 @@ fn api_routes(
         .route("/teams/{id}", delete(team_handler::delete_team))
         .route("/teams/{id}/members", get(team_handler::get_team_members))
         .route("/teams/{id}/members", post(team_handler::add_team_member))
+        .route(
+            "/teams/{id}/members/{user_id}",
+            patch(team_handler::update_team_member),
+        )
+        .route(
+            "/teams/{id}/members/{user_id}",
+            delete(team_handler::remove_team_member),
+        )
         .layer(middleware::from_fn(auth_middleware))
         .with_state(team_service);
 
@@ -208,6 +902,7 @@ fn api_routes(
     let time_log_routes = Router::new()
         .route("/time-logs", get(time_log_handler::list_my_time_logs))
         .route("/time-logs", post(time_log_handler::create_time_log))
+        .route("/time-logs/export", get(time_log_handler::export_time_logs))
         .route("/time-logs/{id}", get(time_log_handler::get_time_log))
         .route("/time-logs/{id}", put(time_log_handler::update_time_log))
         .route("/time-logs/{id}", delete(time_log_handler::delete_time_log))
@@ -222,6 +917,35 @@ fn api_routes(
         .layer(middleware::from_fn(auth_middleware))
         .with_state(time_log_service);
 
+    // Protected timer routes
+    let timer_routes = Router::new()
+        .route("/timer", get(timer_handler::get_current_timer))
+        .route("/timer", delete(timer_handler::discard_timer))
+        .route("/timer/start", post(timer_handler::start_timer))
+        .route("/timer/pause", post(timer_handler::pause_timer))
+        .route("/timer/resume", post(timer_handler::resume_timer))
+        .route("/timer/stop", post(timer_handler::stop_timer))
+        .layer(middleware::from_fn(auth_middleware))
+        .with_state(timer_service);
+
+    // Protected timesheet routes
+    let timesheet_routes = Router::new()
+        .route("/timesheets", get(timesheet_handler::list_my_timesheets))
+        .route("/timesheets/week", get(timesheet_handler::get_my_week))
+        .route("/timesheets/pending", get(timesheet_handler::list_pending))
+        .route("/timesheets/submit", post(timesheet_handler::submit_week))
+        .route("/timesheets/{id}", get(timesheet_handler::get_timesheet))
+        .route(
+            "/timesheets/{id}/approve",
+            post(timesheet_handler::approve_timesheet),
+        )
+        .route(
+            "/timesheets/{id}/reject",
+            post(timesheet_handler::reject_timesheet),
+        )
+        .layer(middleware::from_fn(auth_middleware))
+        .with_state(timesheet_service);
+
     // Protected tag routes
     let tag_routes = Router::new()
         .route("/tags", get(tag_handler::list_tags))
@@ -288,14 +1012,50 @@ fn api_routes(
         .layer(middleware::from_fn(auth_middleware))
         .with_state(notification_service);
 
+    // Protected trash routes
+    let trash_routes = Router::new()
+        .route("/trash", get(trash_handler::get_my_trash))
+        .route(
+            "/trash/projects/{id}/restore",
+            post(trash_handler::restore_project),
+        )
+        .route(
+            "/trash/tasks/{id}/restore",
+            post(trash_handler::restore_task),
+        )
+        .route(
+            "/projects/{id}/trash",
+            get(trash_handler::get_project_trash),
+        )
+        .layer(middleware::from_fn(auth_middleware))
+        .with_state(trash_service);
+
     Router::new()
         .merge(public_auth_routes)
         .merge(project_routes)
         .merge(task_routes)
+        .merge(comment_routes)
+        .merge(schedule_routes)
+        .merge(sprint_routes)
         .merge(team_routes)
         .merge(activity_routes)
         .merge(time_log_routes)
+        .merge(timer_routes)
+        .merge(timesheet_routes)
+        .merge(budget_routes)
+        .merge(billing_routes)
+        .merge(capacity_routes)
+        .merge(calendar_routes)
+        .merge(allocation_routes)
+        .merge(evm_routes)
+        .merge(baseline_routes)
+        .merge(raid_routes)
+        .merge(status_report_routes)
+        .merge(change_request_routes)
+        .merge(health_routes)
+        .merge(portfolio_routes)
         .merge(tag_routes)
         .merge(attachment_routes)
         .merge(notification_routes)
+        .merge(trash_routes)
 }
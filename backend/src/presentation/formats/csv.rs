@@ -0,0 +1,75 @@
+/// Builds RFC 4180 CSV documents for exports
+pub struct CsvWriter {
+    out: String,
+}
+
+impl CsvWriter {
+    pub fn new(headers: &[&str]) -> Self {
+        let mut writer = Self { out: String::new() };
+        writer.row(headers);
+        writer
+    }
+
+    pub fn row<S: AsRef<str>>(&mut self, fields: &[S]) {
+        self.out.push_str(&Self::line(fields));
+    }
+
+    pub fn finish(self) -> String {
+        self.out
+    }
+
+    /// One CSV record, with fields quoted where needed and a CRLF ending
+    pub fn line<S: AsRef<str>>(fields: &[S]) -> String {
+        let mut line = fields
+            .iter()
+            .map(|f| escape(f.as_ref()))
+            .collect::<Vec<_>>()
+            .join(",");
+        line.push_str("\r\n");
+        line
+    }
+}
+
+/// Quote a field where needed. Text a spreadsheet would read as a formula
+/// gets a leading `'`, so exported names and notes can't run as formulas.
+fn escape(field: &str) -> String {
+    let field = if is_formula(field) {
+        format!("'{}", field)
+    } else {
+        field.to_string()
+    };
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field
+    }
+}
+
+fn is_formula(field: &str) -> bool {
+    field.starts_with(['=', '+', '-', '@', '\t', '\r']) && field.parse::<f64>().is_err()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quotes_only_when_needed() {
+        let mut csv = CsvWriter::new(&["name", "note"]);
+        csv.row(&["Plain", "Says \"hi\", twice"]);
+        csv.row(&["Two\nlines", ""]);
+        assert_eq!(
+            csv.finish(),
+            "name,note\r\nPlain,\"Says \"\"hi\"\", twice\"\r\n\"Two\nlines\",\r\n"
+        );
+    }
+
+    #[test]
+    fn test_formulas_are_neutralized() {
+        let line = CsvWriter::line(&["=HYPERLINK(\"x\")", "+1+1", "@SUM(A1)", "-2+3", "-12.50"]);
+        assert_eq!(
+            line,
+            "\"'=HYPERLINK(\"\"x\"\")\",'+1+1,'@SUM(A1),'-2+3,-12.50\r\n"
+        );
+    }
+}
@@ -0,0 +1,303 @@
+use chrono::{DateTime, Duration, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+use ical::generator::{Emitter, IcalEventBuilder};
+use ical::ical_property;
+use ical::parser::ical::component::IcalEvent;
+use ical::property::Property;
+use ical::IcalParser;
+
+use crate::shared::DomainError;
+
+/// Longest event expanded into days when reading a calendar
+const MAX_EVENT_DAYS: i64 = 366;
+
+/// An all-day calendar entry
+#[derive(Debug, Clone)]
+pub struct ICalEvent {
+    pub uid: String,
+    pub stamp: DateTime<Utc>,
+    pub date: NaiveDate,
+    pub summary: String,
+    pub description: Option<String>,
+}
+
+/// Writes RFC 5545 calendars piece by piece for streaming
+pub struct ICalWriter;
+
+impl ICalWriter {
+    pub fn begin(name: &str) -> String {
+        let mut out = String::from("BEGIN:VCALENDAR\r\n");
+        for property in [
+            ical_property!("VERSION", "2.0"),
+            ical_property!("PRODID", "-//Percival//Percival PMO//EN"),
+            ical_property!("CALSCALE", "GREGORIAN"),
+            ical_property!("X-WR-CALNAME", escape(name)),
+        ] {
+            out.push_str(&property.generate());
+        }
+        out
+    }
+
+    pub fn event(event: &ICalEvent) -> String {
+        // The time zone only applies to local times, which all-day events lack
+        let mut builder = IcalEventBuilder::tzid("UTC")
+            .uid(escape(&event.uid))
+            .changed_utc(event.stamp.format("%Y%m%dT%H%M%SZ").to_string())
+            .start_day(event.date.format("%Y%m%d").to_string())
+            .end_day(
+                (event.date + Duration::days(1))
+                    .format("%Y%m%d")
+                    .to_string(),
+            )
+            .set(ical_property!("SUMMARY", escape(&event.summary)));
+        if let Some(description) = &event.description {
+            builder = builder.set(ical_property!("DESCRIPTION", escape(description)));
+        }
+        builder
+            .set(ical_property!("TRANSP", "TRANSPARENT"))
+            .build()
+            .generate()
+    }
+
+    pub fn end() -> String {
+        "END:VCALENDAR\r\n".to_string()
+    }
+}
+
+/// One day covered by an event of an imported calendar
+#[derive(Debug, Clone, PartialEq)]
+pub struct ICalDay {
+    pub date: NaiveDate,
+    pub summary: String,
+}
+
+/// Reads the days covered by the events of an RFC 5545 calendar
+pub struct ICalReader;
+
+impl ICalReader {
+    /// Events yield each day they cover, whether all-day or timed. Times are
+    /// taken as written, without converting time zones, and recurrence rules
+    /// are not expanded.
+    pub fn days(text: &str) -> Result<Vec<ICalDay>, DomainError> {
+        let mut days = Vec::new();
+        for calendar in IcalParser::new(text.as_bytes()) {
+            let calendar = calendar.map_err(|e| {
+                DomainError::ValidationError(format!("Not an iCalendar file: {}", e))
+            })?;
+            for event in &calendar.events {
+                EventFields::read(event)?.expand(&mut days)?;
+            }
+        }
+        Ok(days)
+    }
+}
+
+#[derive(Default)]
+struct EventFields {
+    start: Option<NaiveDateTime>,
+    end: Option<NaiveDateTime>,
+    duration: Option<Duration>,
+    summary: String,
+}
+
+impl EventFields {
+    fn read(event: &IcalEvent) -> Result<Self, DomainError> {
+        let mut fields = Self::default();
+        for property in &event.properties {
+            let value = property.value.as_deref().unwrap_or_default();
+            match property.name.as_str() {
+                "DTSTART" => fields.start = Some(parse_date_time(value)?),
+                "DTEND" => fields.end = Some(parse_date_time(value)?),
+                "DURATION" => fields.duration = Some(parse_duration(value)?),
+                "SUMMARY" => fields.summary = unescape(value),
+                _ => {}
+            }
+        }
+        Ok(fields)
+    }
+
+    /// Every day from the start up to the day the event ends on; an end at
+    /// midnight, like the exclusive end of an all-day event, doesn't count
+    fn expand(self, days: &mut Vec<ICalDay>) -> Result<(), DomainError> {
+        let start = self.start.ok_or_else(|| {
+            DomainError::ValidationError(format!("Event '{}' has no DTSTART", self.summary))
+        })?;
+        let end = self
+            .end
+            .or_else(|| self.duration.and_then(|d| start.checked_add_signed(d)));
+        let last = match end {
+            Some(end) if end > start => (end - Duration::seconds(1)).date(),
+            _ => start.date(),
+        };
+        if (last - start.date()).num_days() >= MAX_EVENT_DAYS {
+            return Err(DomainError::ValidationError(format!(
+                "Event '{}' is longer than {} days",
+                self.summary, MAX_EVENT_DAYS
+            )));
+        }
+        for date in start.date().iter_days().take_while(|d| *d <= last) {
+            days.push(ICalDay {
+                date,
+                summary: self.summary.clone(),
+            });
+        }
+        Ok(())
+    }
+}
+
+/// A DATE or DATE-TIME value as written, in the event's own time zone;
+/// a DATE starts at midnight
+fn parse_date_time(value: &str) -> Result<NaiveDateTime, DomainError> {
+    let date = value
+        .get(..8)
+        .and_then(|d| NaiveDate::parse_from_str(d, "%Y%m%d").ok());
+    let time = match value.get(8..) {
+        None | Some("") => Some(NaiveTime::MIN),
+        Some(time) => time
+            .strip_prefix('T')
+            .map(|t| t.trim_end_matches('Z'))
+            .and_then(|t| NaiveTime::parse_from_str(t, "%H%M%S").ok()),
+    };
+    date.zip(time)
+        .map(|(date, time)| date.and_time(time))
+        .ok_or_else(|| DomainError::ValidationError(format!("Invalid iCalendar date '{}'", value)))
+}
+
+/// A positive RFC 5545 duration such as `P1W`, `P2D` or `P1DT12H`
+fn parse_duration(value: &str) -> Result<Duration, DomainError> {
+    let invalid =
+        || DomainError::ValidationError(format!("Invalid iCalendar duration '{}'", value));
+    let rest = value.strip_prefix('+').unwrap_or(value);
+    let rest = rest.strip_prefix('P').ok_or_else(invalid)?;
+
+    let mut total = Duration::zero();
+    let mut number = String::new();
+    for c in rest.chars() {
+        if c.is_ascii_digit() {
+            number.push(c);
+            continue;
+        }
+        if c == 'T' && number.is_empty() {
+            continue;
+        }
+        let n: i64 = number.parse().map_err(|_| invalid())?;
+        number.clear();
+        let part = match c {
+            'W' => Duration::try_weeks(n),
+            'D' => Duration::try_days(n),
+            'H' => Duration::try_hours(n),
+            'M' => Duration::try_minutes(n),
+            'S' => Duration::try_seconds(n),
+            _ => None,
+        };
+        total = part
+            .and_then(|part| total.checked_add(&part))
+            .ok_or_else(invalid)?;
+    }
+    if number.is_empty() {
+        Ok(total)
+    } else {
+        Err(invalid())
+    }
+}
+
+fn unescape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') | Some('N') => out.push('\n'),
+            Some(other) => out.push(other),
+            None => out.push('\\'),
+        }
+    }
+    out
+}
+
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace("\r\n", "\\n")
+        .replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_event_is_escaped_and_folded() {
+        let event = ICalEvent {
+            uid: "log-1@percival".into(),
+            stamp: DateTime::from_timestamp(0, 0).unwrap(),
+            date: NaiveDate::from_ymd_opt(2024, 2, 29).unwrap(),
+            summary: "Design; review, v2".into(),
+            description: Some(format!("line one\n{}", "x".repeat(80))),
+        };
+        let ics = ICalWriter::event(&event);
+
+        assert!(ics.contains("DTSTAMP:19700101T000000Z\r\n"));
+        assert!(ics.contains("DTSTART;VALUE=DATE:20240229\r\nDTEND;VALUE=DATE:20240301\r\n"));
+        assert!(ics.contains("SUMMARY:Design\\; review\\, v2\r\n"));
+        assert!(ics.contains("DESCRIPTION:line one\\n"));
+        assert!(ics.lines().all(|line| line.len() <= 75));
+        assert!(ics.contains("\r\n x"));
+    }
+
+    #[test]
+    fn test_reader_expands_events_into_days() {
+        let ics = "BEGIN:VCALENDAR\r\nVERSION:2.0\r\n\
+            BEGIN:VEVENT\r\nDTSTART;VALUE=DATE:20241224\r\nDTEND;VALUE=DATE:20241227\r\n\
+            SUMMARY:Christmas\\, Boxing\r\n  Day\r\nEND:VEVENT\r\n\
+            BEGIN:VEVENT\r\nDTSTART;TZID=Europe/Berlin:20250101T090000\r\n\
+            DTEND;TZID=Europe/Berlin:20250101T120000\r\nSUMMARY:New Year\r\nEND:VEVENT\r\n\
+            END:VCALENDAR\r\n";
+        let days = ICalReader::days(ics).unwrap();
+
+        let dates: Vec<_> = days
+            .iter()
+            .map(|d| d.date.format("%m-%d").to_string())
+            .collect();
+        assert_eq!(dates, vec!["12-24", "12-25", "12-26", "01-01"]);
+        assert_eq!(days[0].summary, "Christmas, Boxing Day");
+        assert_eq!(days[3].summary, "New Year");
+
+        assert!(ICalReader::days("not a calendar").is_err());
+        assert!(
+            ICalReader::days("BEGIN:VCALENDAR\nBEGIN:VEVENT\nEND:VEVENT\nEND:VCALENDAR").is_err()
+        );
+    }
+
+    #[test]
+    fn test_reader_expands_timed_events_across_days() {
+        let ics = "BEGIN:VCALENDAR\r\n\
+            BEGIN:VEVENT\r\nDTSTART:20250428T180000Z\r\nDTEND:20250430T100000Z\r\n\
+            SUMMARY:Offsite\r\nEND:VEVENT\r\n\
+            BEGIN:VEVENT\r\nDTSTART:20250501T120000\r\nDTEND:20250503T000000\r\n\
+            SUMMARY:Ends at midnight\r\nEND:VEVENT\r\n\
+            BEGIN:VEVENT\r\nDTSTART;VALUE=DATE:20250512\r\nDURATION:P1W\r\n\
+            SUMMARY:Shutdown\r\nEND:VEVENT\r\n\
+            END:VCALENDAR\r\n";
+        let days = ICalReader::days(ics).unwrap();
+
+        let dates: Vec<_> = days
+            .iter()
+            .map(|d| d.date.format("%m-%d").to_string())
+            .collect();
+        assert_eq!(
+            dates,
+            vec![
+                "04-28", "04-29", "04-30", "05-01", "05-02", "05-12", "05-13", "05-14", "05-15",
+                "05-16", "05-17", "05-18"
+            ]
+        );
+
+        assert_eq!(parse_duration("P1DT12H").unwrap(), Duration::hours(36));
+        assert!(parse_duration("-P1D").is_err());
+        assert!(parse_duration("P99999999999999W").is_err());
+    }
+}
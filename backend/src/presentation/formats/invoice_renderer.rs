@@ -0,0 +1,23 @@
+use crate::domain::entities::InvoiceLine;
+use crate::presentation::formats::CsvWriter;
+
+/// Renders invoice lines for import into accounting tools
+pub struct InvoiceRenderer;
+
+impl InvoiceRenderer {
+    pub fn csv(lines: &[InvoiceLine]) -> String {
+        let mut csv =
+            CsvWriter::new(&["project", "task", "user", "hours", "hourly_rate", "amount"]);
+        for line in lines {
+            csv.row(&[
+                line.project_name.clone(),
+                line.task_title.clone(),
+                line.user_name.clone(),
+                line.hours.to_string(),
+                line.hourly_rate.to_string(),
+                line.amount.to_string(),
+            ]);
+        }
+        csv.finish()
+    }
+}
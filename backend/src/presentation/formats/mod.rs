@@ -0,0 +1,9 @@
+mod csv;
+mod ical;
+mod invoice_renderer;
+mod time_log_export;
+
+pub use csv::CsvWriter;
+pub use ical::{ICalDay, ICalEvent, ICalReader, ICalWriter};
+pub use invoice_renderer::InvoiceRenderer;
+pub use time_log_export::{ExportFormat, TimeLogExporter};
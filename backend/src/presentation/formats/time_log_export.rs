@@ -0,0 +1,223 @@
+use rust_xlsxwriter::{Format, Workbook, XlsxError};
+use serde::Deserialize;
+
+use crate::application::services::TimeLogEncoder;
+use crate::domain::entities::TimeLog;
+use crate::presentation::formats::{CsvWriter, ICalEvent, ICalWriter};
+use crate::shared::DomainError;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportFormat {
+    #[default]
+    Csv,
+    Xlsx,
+    #[serde(alias = "ical")]
+    Ics,
+}
+
+impl ExportFormat {
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "text/csv; charset=utf-8",
+            ExportFormat::Xlsx => {
+                "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet"
+            }
+            ExportFormat::Ics => "text/calendar; charset=utf-8",
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "csv",
+            ExportFormat::Xlsx => "xlsx",
+            ExportFormat::Ics => "ics",
+        }
+    }
+}
+
+const COLUMNS: [&str; 8] = [
+    "date",
+    "user_name",
+    "project_name",
+    "task_name",
+    "hours",
+    "billable",
+    "invoiced",
+    "description",
+];
+
+/// Encodes time logs in an export format, one page at a time. CSV and
+/// iCalendar rows are sent as they come; a spreadsheet is only complete once
+/// the workbook is saved, so XLSX rows are kept on disk until `finish`.
+pub enum TimeLogExporter {
+    Csv,
+    Xlsx(Box<XlsxSheet>),
+    Ics,
+}
+
+/// The workbook of an XLSX export and the next row to write
+pub struct XlsxSheet {
+    workbook: Workbook,
+    date_format: Format,
+    rows: u32,
+}
+
+impl TimeLogExporter {
+    pub fn new(format: ExportFormat) -> Self {
+        match format {
+            ExportFormat::Csv => Self::Csv,
+            ExportFormat::Xlsx => Self::Xlsx(Box::new(XlsxSheet {
+                workbook: Workbook::new(),
+                date_format: Format::new().set_num_format("yyyy-mm-dd"),
+                rows: 0,
+            })),
+            ExportFormat::Ics => Self::Ics,
+        }
+    }
+}
+
+impl TimeLogEncoder for TimeLogExporter {
+    fn start(&mut self) -> Result<Vec<u8>, DomainError> {
+        match self {
+            Self::Csv => Ok(CsvWriter::line(&COLUMNS).into_bytes()),
+            Self::Xlsx(xlsx) => {
+                let sheet = xlsx.workbook.add_worksheet_with_constant_memory();
+                sheet.set_name("Time logs").map_err(xlsx_error)?;
+                sheet
+                    .write_row_with_format(0, 0, COLUMNS, &Format::new().set_bold())
+                    .map_err(xlsx_error)?;
+                xlsx.rows = 1;
+                Ok(Vec::new())
+            }
+            Self::Ics => Ok(ICalWriter::begin("Time logs").into_bytes()),
+        }
+    }
+
+    fn write(&mut self, time_logs: &[TimeLog]) -> Result<Vec<u8>, DomainError> {
+        let mut out = Vec::new();
+        for log in time_logs {
+            let user = log.user_name.as_deref().unwrap_or_default();
+            let project = log.project_name.as_deref().unwrap_or_default();
+            let task = log.task_name.as_deref().unwrap_or_default();
+            let description = log.description.as_deref().unwrap_or_default();
+            match self {
+                Self::Csv => out.extend(
+                    CsvWriter::line(&[
+                        log.date.to_string().as_str(),
+                        user,
+                        project,
+                        task,
+                        &log.hours.to_string(),
+                        &log.billable.to_string(),
+                        &log.invoice_id.is_some().to_string(),
+                        description,
+                    ])
+                    .into_bytes(),
+                ),
+                Self::Xlsx(xlsx) => {
+                    let row = xlsx.rows;
+                    xlsx.workbook
+                        .worksheet_from_index(0)
+                        .and_then(|s| s.write_date_with_format(row, 0, log.date, &xlsx.date_format))
+                        .and_then(|s| s.write_string(row, 1, user))
+                        .and_then(|s| s.write_string(row, 2, project))
+                        .and_then(|s| s.write_string(row, 3, task))
+                        .and_then(|s| s.write_number(row, 4, log.hours))
+                        .and_then(|s| s.write_boolean(row, 5, log.billable))
+                        .and_then(|s| s.write_boolean(row, 6, log.invoice_id.is_some()))
+                        .and_then(|s| s.write_string(row, 7, description))
+                        .map_err(xlsx_error)?;
+                    xlsx.rows += 1;
+                }
+                Self::Ics => {
+                    let mut details = format!("Project: {}\nUser: {}", project, user);
+                    if !description.is_empty() {
+                        details.push_str("\n\n");
+                        details.push_str(description);
+                    }
+                    out.extend(
+                        ICalWriter::event(&ICalEvent {
+                            uid: format!("time-log-{}@percival", log.id),
+                            stamp: log.updated_at,
+                            date: log.date,
+                            summary: format!("{} ({}h)", task, log.hours),
+                            description: Some(details),
+                        })
+                        .into_bytes(),
+                    )
+                }
+            }
+        }
+        Ok(out)
+    }
+
+    fn finish(&mut self) -> Result<Vec<u8>, DomainError> {
+        match self {
+            Self::Csv => Ok(Vec::new()),
+            Self::Xlsx(xlsx) => xlsx.workbook.save_to_buffer().map_err(xlsx_error),
+            Self::Ics => Ok(ICalWriter::end().into_bytes()),
+        }
+    }
+}
+
+fn xlsx_error(e: XlsxError) -> DomainError {
+    DomainError::InternalError(format!("Failed to write spreadsheet: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::test_dates::date;
+    use uuid::Uuid;
+
+    fn log() -> TimeLog {
+        let mut log = TimeLog::new(
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            1.5,
+            date(4),
+            Some("Pairing, then review".into()),
+            true,
+        );
+        log.user_name = Some("Ana".into());
+        log.project_name = Some("Apollo".into());
+        log.task_name = Some("API".into());
+        log
+    }
+
+    fn export(format: ExportFormat) -> Vec<u8> {
+        let mut exporter = TimeLogExporter::new(format);
+        let mut out = exporter.start().unwrap();
+        out.extend(exporter.write(&[log()]).unwrap());
+        out.extend(exporter.finish().unwrap());
+        out
+    }
+
+    #[test]
+    fn test_csv_includes_joined_names() {
+        assert_eq!(
+            String::from_utf8(export(ExportFormat::Csv)).unwrap(),
+            "date,user_name,project_name,task_name,hours,billable,invoiced,description\r\n\
+             2024-03-04,Ana,Apollo,API,1.5,true,false,\"Pairing, then review\"\r\n"
+        );
+    }
+
+    #[test]
+    fn test_ics_has_one_event_per_log() {
+        let ics = String::from_utf8(export(ExportFormat::Ics)).unwrap();
+        assert!(ics.starts_with("BEGIN:VCALENDAR\r\n"));
+        assert!(ics.ends_with("END:VCALENDAR\r\n"));
+        assert_eq!(ics.matches("BEGIN:VEVENT").count(), 1);
+        assert!(ics.contains("SUMMARY:API (1.5h)\r\n"));
+    }
+
+    #[test]
+    fn test_xlsx_is_a_zip_package() {
+        let xlsx = export(ExportFormat::Xlsx);
+        assert!(xlsx.starts_with(b"PK\x03\x04"));
+        assert!(xlsx
+            .windows(b"xl/worksheets/sheet1.xml".len())
+            .any(|w| w == b"xl/worksheets/sheet1.xml"));
+    }
+}
@@ -0,0 +1,182 @@
+use axum::{
+    extract::{Path, Query, State},
+    Extension, Json,
+};
+use chrono::NaiveDate;
+use serde::Deserialize;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::application::commands::{CreateAllocationCommand, UpdateAllocationCommand};
+use crate::application::services::{
+    AllocationAppService, AllocationHeatmap, AllocationWithConflicts, OverAllocation,
+    TeamAllocation,
+};
+use crate::domain::entities::ResourceAllocation;
+use crate::domain::value_objects::UserRole;
+use crate::presentation::dto::ApiResponse;
+use crate::presentation::middleware::AuthUser;
+use crate::shared::DomainError;
+
+#[derive(Debug, Deserialize)]
+pub struct AllocationRangeQuery {
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+}
+
+/// POST /allocations - Allocate part of a user's time to a project (admin or manager)
+pub async fn create_allocation(
+    State(service): State<Arc<AllocationAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Json(cmd): Json<CreateAllocationCommand>,
+) -> Result<Json<ApiResponse<AllocationWithConflicts>>, DomainError> {
+    ensure_resource_manager(&auth_user)?;
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        allocated_user_id = %cmd.user_id,
+        project_id = %cmd.project_id,
+        "User creating resource allocation"
+    );
+    let allocation = service.create_allocation(cmd, auth_user.id).await?;
+    Ok(Json(ApiResponse::success(allocation)))
+}
+
+/// GET /allocations/:id - Get an allocation (resource managers or the allocated user)
+pub async fn get_allocation(
+    State(service): State<Arc<AllocationAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<ResourceAllocation>>, DomainError> {
+    let allocation = service.get_allocation(id).await?;
+
+    if !is_resource_manager(&auth_user) && allocation.user_id != auth_user.id {
+        return Err(DomainError::Forbidden(
+            "You don't have access to this allocation".into(),
+        ));
+    }
+
+    Ok(Json(ApiResponse::success(allocation)))
+}
+
+/// PUT /allocations/:id - Change an allocation's dates or size (admin or manager)
+pub async fn update_allocation(
+    State(service): State<Arc<AllocationAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+    Json(cmd): Json<UpdateAllocationCommand>,
+) -> Result<Json<ApiResponse<AllocationWithConflicts>>, DomainError> {
+    ensure_resource_manager(&auth_user)?;
+
+    tracing::info!(user_id = %auth_user.id, allocation_id = %id, "User updating resource allocation");
+    let allocation = service.update_allocation(id, cmd).await?;
+    Ok(Json(ApiResponse::success(allocation)))
+}
+
+/// DELETE /allocations/:id - Remove an allocation (admin or manager)
+pub async fn delete_allocation(
+    State(service): State<Arc<AllocationAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<()>>, DomainError> {
+    ensure_resource_manager(&auth_user)?;
+
+    tracing::info!(user_id = %auth_user.id, allocation_id = %id, "User deleting resource allocation");
+    service.delete_allocation(id).await?;
+    Ok(Json(ApiResponse::success(())))
+}
+
+/// GET /allocations/heatmap - Allocation per user per week (admin or manager)
+pub async fn heatmap(
+    State(service): State<Arc<AllocationAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Query(query): Query<AllocationRangeQuery>,
+) -> Result<Json<ApiResponse<AllocationHeatmap>>, DomainError> {
+    ensure_resource_manager(&auth_user)?;
+
+    let heatmap = service.heatmap(query.start_date, query.end_date).await?;
+    Ok(Json(ApiResponse::success(heatmap)))
+}
+
+/// GET /allocations/over-allocations - Weeks users are allocated beyond capacity (admin or manager)
+pub async fn over_allocations(
+    State(service): State<Arc<AllocationAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Query(query): Query<AllocationRangeQuery>,
+) -> Result<Json<ApiResponse<Vec<OverAllocation>>>, DomainError> {
+    ensure_resource_manager(&auth_user)?;
+
+    let conflicts = service
+        .over_allocations(query.start_date, query.end_date)
+        .await?;
+    Ok(Json(ApiResponse::success(conflicts)))
+}
+
+/// GET /projects/:id/allocations - People allocated to a project
+pub async fn list_project_allocations(
+    State(service): State<Arc<AllocationAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(project_id): Path<Uuid>,
+) -> Result<Json<ApiResponse<Vec<ResourceAllocation>>>, DomainError> {
+    // Check project access (resource managers can view all)
+    if !is_resource_manager(&auth_user)
+        && !service.can_view_project(project_id, auth_user.id).await?
+    {
+        return Err(DomainError::Forbidden(
+            "You don't have access to this project".into(),
+        ));
+    }
+
+    let allocations = service.list_project_allocations(project_id).await?;
+    Ok(Json(ApiResponse::success(allocations)))
+}
+
+/// GET /users/:user_id/allocations - A user's allocations (self, admin or manager)
+pub async fn list_user_allocations(
+    State(service): State<Arc<AllocationAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(user_id): Path<Uuid>,
+) -> Result<Json<ApiResponse<Vec<ResourceAllocation>>>, DomainError> {
+    if !is_resource_manager(&auth_user) && auth_user.id != user_id {
+        return Err(DomainError::Forbidden(
+            "You can only view your own allocations".into(),
+        ));
+    }
+
+    let allocations = service.list_user_allocations(user_id).await?;
+    Ok(Json(ApiResponse::success(allocations)))
+}
+
+/// GET /teams/:id/allocations - Weekly allocation of a team's members
+pub async fn team_allocation(
+    State(service): State<Arc<AllocationAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+    Query(query): Query<AllocationRangeQuery>,
+) -> Result<Json<ApiResponse<TeamAllocation>>, DomainError> {
+    // Check team access (resource managers can view all)
+    if !is_resource_manager(&auth_user) && !service.can_access_team(id, auth_user.id).await? {
+        return Err(DomainError::Forbidden(
+            "You don't have access to this team".into(),
+        ));
+    }
+
+    let allocation = service
+        .team_allocation(id, query.start_date, query.end_date)
+        .await?;
+    Ok(Json(ApiResponse::success(allocation)))
+}
+
+/// Admins and managers plan resources
+fn is_resource_manager(auth_user: &AuthUser) -> bool {
+    matches!(auth_user.role, UserRole::Admin | UserRole::Manager)
+}
+
+fn ensure_resource_manager(auth_user: &AuthUser) -> Result<(), DomainError> {
+    if !is_resource_manager(auth_user) {
+        return Err(DomainError::Forbidden(
+            "Only admin or a manager can plan allocations".into(),
+        ));
+    }
+    Ok(())
+}
@@ -0,0 +1,123 @@
+use axum::{
+    extract::{Path, State},
+    Extension, Json,
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::application::commands::CreateBaselineCommand;
+use crate::application::services::{BaselineAppService, VarianceReport};
+use crate::domain::entities::ProjectBaseline;
+use crate::domain::value_objects::UserRole;
+use crate::presentation::dto::ApiResponse;
+use crate::presentation::middleware::AuthUser;
+use crate::shared::DomainError;
+
+pub async fn list_baselines(
+    State(service): State<Arc<BaselineAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<Vec<ProjectBaseline>>>, DomainError> {
+    // Check access permission (admin can access all)
+    if auth_user.role != UserRole::Admin && !service.can_view_project(id, auth_user.id).await? {
+        return Err(DomainError::Forbidden(
+            "You don't have access to this project".into(),
+        ));
+    }
+
+    let baselines = service.list_baselines(id).await?;
+    Ok(Json(ApiResponse::success(baselines)))
+}
+
+pub async fn create_baseline(
+    State(service): State<Arc<BaselineAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+    Json(cmd): Json<CreateBaselineCommand>,
+) -> Result<Json<ApiResponse<ProjectBaseline>>, DomainError> {
+    // Only owner can freeze a baseline (admin can do all)
+    if auth_user.role != UserRole::Admin && !service.is_project_owner(id, auth_user.id).await? {
+        return Err(DomainError::Forbidden(
+            "Only project owner can create baselines".into(),
+        ));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        project_id = %id,
+        baseline_name = %cmd.name,
+        "User creating project baseline"
+    );
+    let baseline = service.create_baseline(id, auth_user.id, cmd).await?;
+    Ok(Json(ApiResponse::success(baseline)))
+}
+
+pub async fn get_baseline(
+    State(service): State<Arc<BaselineAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<ProjectBaseline>>, DomainError> {
+    let baseline = service.get_baseline(id).await?;
+
+    // Check access permission (admin can access all)
+    if auth_user.role != UserRole::Admin
+        && !service
+            .can_view_project(baseline.project_id, auth_user.id)
+            .await?
+    {
+        return Err(DomainError::Forbidden(
+            "You don't have access to this project".into(),
+        ));
+    }
+
+    Ok(Json(ApiResponse::success(baseline)))
+}
+
+pub async fn delete_baseline(
+    State(service): State<Arc<BaselineAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<()>>, DomainError> {
+    let baseline = service.get_baseline(id).await?;
+
+    // Only owner can delete a baseline (admin can do all)
+    if auth_user.role != UserRole::Admin
+        && !service
+            .is_project_owner(baseline.project_id, auth_user.id)
+            .await?
+    {
+        return Err(DomainError::Forbidden(
+            "Only project owner can delete baselines".into(),
+        ));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        baseline_id = %id,
+        "User deleting project baseline"
+    );
+    service.delete_baseline(id).await?;
+    Ok(Json(ApiResponse::ok("Baseline deleted")))
+}
+
+pub async fn get_variance(
+    State(service): State<Arc<BaselineAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<VarianceReport>>, DomainError> {
+    let baseline = service.get_baseline(id).await?;
+
+    // Check access permission (admin can access all)
+    if auth_user.role != UserRole::Admin
+        && !service
+            .can_view_project(baseline.project_id, auth_user.id)
+            .await?
+    {
+        return Err(DomainError::Forbidden(
+            "You don't have access to this project".into(),
+        ));
+    }
+
+    let report = service.get_variance(id).await?;
+    Ok(Json(ApiResponse::success(report)))
+}
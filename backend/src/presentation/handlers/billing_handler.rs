@@ -0,0 +1,166 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
+    Extension, Json,
+};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::application::commands::{CreateBillingRateCommand, PrepareInvoiceCommand};
+use crate::application::services::BillingAppService;
+use crate::domain::entities::{BillingRate, Invoice, InvoiceLine};
+use crate::domain::value_objects::UserRole;
+use crate::presentation::dto::ApiResponse;
+use crate::presentation::formats::InvoiceRenderer;
+use crate::presentation::middleware::{ensure_admin, AuthUser};
+use crate::shared::DomainError;
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum InvoiceFormat {
+    #[default]
+    Json,
+    Csv,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct InvoiceFormatQuery {
+    #[serde(default)]
+    pub format: InvoiceFormat,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ListInvoicesQuery {
+    pub project_id: Option<Uuid>,
+}
+
+/// GET /billing-rates - List the rate card
+pub async fn list_rates(
+    State(service): State<Arc<BillingAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+) -> Result<Json<ApiResponse<Vec<BillingRate>>>, DomainError> {
+    ensure_admin(&auth_user, "manage billing")?;
+
+    let rates = service.list_rates().await?;
+    Ok(Json(ApiResponse::success(rates)))
+}
+
+/// POST /billing-rates - Add a rate to the rate card
+pub async fn create_rate(
+    State(service): State<Arc<BillingAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Json(cmd): Json<CreateBillingRateCommand>,
+) -> Result<Json<ApiResponse<BillingRate>>, DomainError> {
+    ensure_admin(&auth_user, "manage billing")?;
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        project_id = ?cmd.project_id,
+        rate_user_id = ?cmd.user_id,
+        role = ?cmd.role,
+        effective_from = %cmd.effective_from,
+        "Admin adding billing rate"
+    );
+    let rate = service.create_rate(cmd).await?;
+    Ok(Json(ApiResponse::success(rate)))
+}
+
+/// DELETE /billing-rates/:id - Remove a rate from the rate card
+pub async fn delete_rate(
+    State(service): State<Arc<BillingAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<()>>, DomainError> {
+    ensure_admin(&auth_user, "manage billing")?;
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        billing_rate_id = %id,
+        "Admin deleting billing rate"
+    );
+    service.delete_rate(id).await?;
+    Ok(Json(ApiResponse::ok("Billing rate deleted")))
+}
+
+/// GET /invoices/prepare - Price uninvoiced billable time of a period
+pub async fn prepare_invoice(
+    State(service): State<Arc<BillingAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Query(cmd): Query<PrepareInvoiceCommand>,
+    Query(query): Query<InvoiceFormatQuery>,
+) -> Result<Response, DomainError> {
+    ensure_admin(&auth_user, "manage billing")?;
+
+    let preview = service.prepare_invoice(&cmd).await?;
+    let lines = preview.lines.clone();
+    let filename = format!("invoice-{}-{}.csv", cmd.start_date, cmd.end_date);
+    Ok(render(query.format, &lines, &filename, preview))
+}
+
+/// GET /invoices - List invoices
+pub async fn list_invoices(
+    State(service): State<Arc<BillingAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Query(query): Query<ListInvoicesQuery>,
+) -> Result<Json<ApiResponse<Vec<Invoice>>>, DomainError> {
+    ensure_admin(&auth_user, "manage billing")?;
+
+    let invoices = service.list_invoices(query.project_id).await?;
+    Ok(Json(ApiResponse::success(invoices)))
+}
+
+/// POST /invoices - Invoice a period's billable time, marking it as invoiced
+pub async fn create_invoice(
+    State(service): State<Arc<BillingAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Json(cmd): Json<PrepareInvoiceCommand>,
+) -> Result<Json<ApiResponse<Invoice>>, DomainError> {
+    ensure_admin(&auth_user, "manage billing")?;
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        project_id = ?cmd.project_id,
+        start_date = %cmd.start_date,
+        end_date = %cmd.end_date,
+        "Admin creating invoice"
+    );
+    let invoice = service.create_invoice(cmd, auth_user.id).await?;
+    Ok(Json(ApiResponse::success(invoice)))
+}
+
+/// GET /invoices/:id - Get an invoice as JSON or CSV
+pub async fn get_invoice(
+    State(service): State<Arc<BillingAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+    Query(query): Query<InvoiceFormatQuery>,
+) -> Result<Response, DomainError> {
+    ensure_admin(&auth_user, "manage billing")?;
+
+    let invoice = service.get_invoice(id).await?;
+    let lines = invoice.lines.clone();
+    let filename = format!("invoice-{}.csv", invoice.id);
+    Ok(render(query.format, &lines, &filename, invoice))
+}
+
+fn render<T: Serialize>(
+    format: InvoiceFormat,
+    lines: &[InvoiceLine],
+    filename: &str,
+    data: T,
+) -> Response {
+    match format {
+        InvoiceFormat::Json => Json(ApiResponse::success(data)).into_response(),
+        InvoiceFormat::Csv => Response::builder()
+            .status(StatusCode::OK)
+            .header(header::CONTENT_TYPE, "text/csv; charset=utf-8")
+            .header(
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"{}\"", filename),
+            )
+            .body(InvoiceRenderer::csv(lines).into())
+            .unwrap(),
+    }
+}
@@ -0,0 +1,190 @@
+use axum::{
+    extract::{Path, State},
+    Extension, Json,
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::application::commands::{
+    CreateExpenseCommand, SetCostRateCommand, UpdateExpenseCommand,
+};
+use crate::application::services::{BudgetAppService, BudgetSummary};
+use crate::domain::entities::{CostRate, Expense};
+use crate::domain::value_objects::UserRole;
+use crate::presentation::dto::ApiResponse;
+use crate::presentation::middleware::AuthUser;
+use crate::shared::DomainError;
+
+pub async fn get_budget_summary(
+    State(service): State<Arc<BudgetAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<BudgetSummary>>, DomainError> {
+    // Check access permission (admin can access all)
+    if auth_user.role != UserRole::Admin && !service.can_view_project(id, auth_user.id).await? {
+        return Err(DomainError::Forbidden(
+            "You don't have access to this project".into(),
+        ));
+    }
+
+    let summary = service.get_budget_summary(id).await?;
+    Ok(Json(ApiResponse::success(summary)))
+}
+
+pub async fn list_expenses(
+    State(service): State<Arc<BudgetAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<Vec<Expense>>>, DomainError> {
+    // Check access permission (admin can access all)
+    if auth_user.role != UserRole::Admin && !service.can_view_project(id, auth_user.id).await? {
+        return Err(DomainError::Forbidden(
+            "You don't have access to this project".into(),
+        ));
+    }
+
+    let expenses = service.list_expenses(id).await?;
+    Ok(Json(ApiResponse::success(expenses)))
+}
+
+pub async fn create_expense(
+    State(service): State<Arc<BudgetAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+    Json(cmd): Json<CreateExpenseCommand>,
+) -> Result<Json<ApiResponse<Expense>>, DomainError> {
+    // Check access permission (admin can access all)
+    if auth_user.role != UserRole::Admin && !service.can_access_project(id, auth_user.id).await? {
+        return Err(DomainError::Forbidden(
+            "You don't have access to this project".into(),
+        ));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        project_id = %id,
+        amount = %cmd.amount,
+        "User recording expense"
+    );
+    let expense = service.create_expense(id, auth_user.id, cmd).await?;
+    Ok(Json(ApiResponse::success(expense)))
+}
+
+pub async fn get_expense(
+    State(service): State<Arc<BudgetAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<Expense>>, DomainError> {
+    let expense = service.get_expense(id).await?;
+
+    // Check access permission (admin can access all)
+    if auth_user.role != UserRole::Admin
+        && !service
+            .can_view_project(expense.project_id, auth_user.id)
+            .await?
+    {
+        return Err(DomainError::Forbidden(
+            "You don't have access to this expense".into(),
+        ));
+    }
+
+    Ok(Json(ApiResponse::success(expense)))
+}
+
+pub async fn update_expense(
+    State(service): State<Arc<BudgetAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+    Json(cmd): Json<UpdateExpenseCommand>,
+) -> Result<Json<ApiResponse<Expense>>, DomainError> {
+    // Only the author or the project owner can change an expense (admin can change all)
+    if auth_user.role != UserRole::Admin && !service.can_manage_expense(id, auth_user.id).await? {
+        return Err(DomainError::Forbidden(
+            "You can only change your own expenses".into(),
+        ));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        expense_id = %id,
+        "User updating expense"
+    );
+    let expense = service.update_expense(id, cmd).await?;
+    Ok(Json(ApiResponse::success(expense)))
+}
+
+pub async fn delete_expense(
+    State(service): State<Arc<BudgetAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<()>>, DomainError> {
+    // Only the author or the project owner can change an expense (admin can change all)
+    if auth_user.role != UserRole::Admin && !service.can_manage_expense(id, auth_user.id).await? {
+        return Err(DomainError::Forbidden(
+            "You can only change your own expenses".into(),
+        ));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        expense_id = %id,
+        "User deleting expense"
+    );
+    service.delete_expense(id).await?;
+    Ok(Json(ApiResponse::ok("Expense deleted")))
+}
+
+pub async fn list_cost_rates(
+    State(service): State<Arc<BudgetAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+) -> Result<Json<ApiResponse<Vec<CostRate>>>, DomainError> {
+    if auth_user.role != UserRole::Admin {
+        return Err(DomainError::Forbidden(
+            "Only admin can manage cost rates".into(),
+        ));
+    }
+
+    let rates = service.list_cost_rates().await?;
+    Ok(Json(ApiResponse::success(rates)))
+}
+
+pub async fn set_cost_rate(
+    State(service): State<Arc<BudgetAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Json(cmd): Json<SetCostRateCommand>,
+) -> Result<Json<ApiResponse<CostRate>>, DomainError> {
+    if auth_user.role != UserRole::Admin {
+        return Err(DomainError::Forbidden(
+            "Only admin can manage cost rates".into(),
+        ));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        rate_user_id = ?cmd.user_id,
+        role = ?cmd.role,
+        "Admin setting cost rate"
+    );
+    let rate = service.set_cost_rate(cmd).await?;
+    Ok(Json(ApiResponse::success(rate)))
+}
+
+pub async fn delete_cost_rate(
+    State(service): State<Arc<BudgetAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<()>>, DomainError> {
+    if auth_user.role != UserRole::Admin {
+        return Err(DomainError::Forbidden(
+            "Only admin can manage cost rates".into(),
+        ));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        cost_rate_id = %id,
+        "Admin deleting cost rate"
+    );
+    service.delete_cost_rate(id).await?;
+    Ok(Json(ApiResponse::ok("Cost rate deleted")))
+}
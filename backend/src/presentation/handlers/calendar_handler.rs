@@ -0,0 +1,260 @@
+use axum::{
+    extract::{Path, Query, State},
+    Extension, Json,
+};
+use chrono::NaiveDate;
+use serde::Deserialize;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::application::commands::{
+    CreateHolidayCalendarCommand, CreateHolidayCommand, DecideTimeOffCommand,
+    RequestTimeOffCommand, UpdateHolidayCalendarCommand,
+};
+use crate::application::services::{CalendarAppService, TeamAvailability};
+use crate::domain::entities::{Holiday, HolidayCalendar, TimeOffRequest};
+use crate::domain::value_objects::UserRole;
+use crate::presentation::dto::ApiResponse;
+use crate::presentation::formats::ICalReader;
+use crate::presentation::middleware::{ensure_admin, AuthUser};
+use crate::shared::DomainError;
+
+#[derive(Debug, Deserialize)]
+pub struct AvailabilityQuery {
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+}
+
+/// GET /holiday-calendars - List holiday calendars
+pub async fn list_calendars(
+    State(service): State<Arc<CalendarAppService>>,
+) -> Result<Json<ApiResponse<Vec<HolidayCalendar>>>, DomainError> {
+    let calendars = service.list_calendars().await?;
+    Ok(Json(ApiResponse::success(calendars)))
+}
+
+/// POST /holiday-calendars - Create a holiday calendar (admin only)
+pub async fn create_calendar(
+    State(service): State<Arc<CalendarAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Json(cmd): Json<CreateHolidayCalendarCommand>,
+) -> Result<Json<ApiResponse<HolidayCalendar>>, DomainError> {
+    ensure_admin(&auth_user, "manage holiday calendars")?;
+
+    tracing::info!(user_id = %auth_user.id, name = %cmd.name, "Admin creating holiday calendar");
+    let calendar = service.create_calendar(cmd).await?;
+    Ok(Json(ApiResponse::success(calendar)))
+}
+
+/// PUT /holiday-calendars/:id - Rename a calendar or make it the default (admin only)
+pub async fn update_calendar(
+    State(service): State<Arc<CalendarAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+    Json(cmd): Json<UpdateHolidayCalendarCommand>,
+) -> Result<Json<ApiResponse<HolidayCalendar>>, DomainError> {
+    ensure_admin(&auth_user, "manage holiday calendars")?;
+
+    tracing::info!(user_id = %auth_user.id, calendar_id = %id, "Admin updating holiday calendar");
+    let calendar = service.update_calendar(id, cmd).await?;
+    Ok(Json(ApiResponse::success(calendar)))
+}
+
+/// DELETE /holiday-calendars/:id - Delete a calendar and its holidays (admin only)
+pub async fn delete_calendar(
+    State(service): State<Arc<CalendarAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<()>>, DomainError> {
+    ensure_admin(&auth_user, "manage holiday calendars")?;
+
+    tracing::info!(user_id = %auth_user.id, calendar_id = %id, "Admin deleting holiday calendar");
+    service.delete_calendar(id).await?;
+    Ok(Json(ApiResponse::success(())))
+}
+
+/// GET /holiday-calendars/:id/holidays - List a calendar's holidays
+pub async fn list_holidays(
+    State(service): State<Arc<CalendarAppService>>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<Vec<Holiday>>>, DomainError> {
+    let holidays = service.list_holidays(id).await?;
+    Ok(Json(ApiResponse::success(holidays)))
+}
+
+/// POST /holiday-calendars/:id/holidays - Add a holiday (admin only)
+pub async fn add_holiday(
+    State(service): State<Arc<CalendarAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+    Json(cmd): Json<CreateHolidayCommand>,
+) -> Result<Json<ApiResponse<Holiday>>, DomainError> {
+    ensure_admin(&auth_user, "manage holiday calendars")?;
+
+    tracing::info!(user_id = %auth_user.id, calendar_id = %id, date = %cmd.date, "Admin adding holiday");
+    let holiday = service.add_holiday(id, cmd).await?;
+    Ok(Json(ApiResponse::success(holiday)))
+}
+
+/// POST /holiday-calendars/:id/import - Import holidays from an iCalendar body (admin only)
+pub async fn import_holidays(
+    State(service): State<Arc<CalendarAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+    body: String,
+) -> Result<Json<ApiResponse<Vec<Holiday>>>, DomainError> {
+    ensure_admin(&auth_user, "manage holiday calendars")?;
+
+    tracing::info!(user_id = %auth_user.id, calendar_id = %id, "Admin importing holidays");
+    let days = ICalReader::days(&body)?
+        .into_iter()
+        .map(|day| (day.date, day.summary))
+        .collect();
+    let holidays = service.import_holidays(id, days).await?;
+    Ok(Json(ApiResponse::success(holidays)))
+}
+
+/// DELETE /holidays/:id - Remove a holiday (admin only)
+pub async fn delete_holiday(
+    State(service): State<Arc<CalendarAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<()>>, DomainError> {
+    ensure_admin(&auth_user, "manage holiday calendars")?;
+
+    tracing::info!(user_id = %auth_user.id, holiday_id = %id, "Admin deleting holiday");
+    service.delete_holiday(id).await?;
+    Ok(Json(ApiResponse::success(())))
+}
+
+/// GET /time-off - List current user's time off requests
+pub async fn list_my_time_off(
+    State(service): State<Arc<CalendarAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+) -> Result<Json<ApiResponse<Vec<TimeOffRequest>>>, DomainError> {
+    let requests = service.list_user_time_off(auth_user.id).await?;
+    Ok(Json(ApiResponse::success(requests)))
+}
+
+/// POST /time-off - Request time off for the current user
+pub async fn request_time_off(
+    State(service): State<Arc<CalendarAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Json(cmd): Json<RequestTimeOffCommand>,
+) -> Result<Json<ApiResponse<TimeOffRequest>>, DomainError> {
+    tracing::info!(
+        user_id = %auth_user.id,
+        start = %cmd.start_date,
+        end = %cmd.end_date,
+        "User requesting time off"
+    );
+    let request = service.request_time_off(auth_user.id, cmd).await?;
+    Ok(Json(ApiResponse::success(request)))
+}
+
+/// GET /time-off/pending - Pending requests the current user can decide
+pub async fn list_pending(
+    State(service): State<Arc<CalendarAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+) -> Result<Json<ApiResponse<Vec<TimeOffRequest>>>, DomainError> {
+    let requests = service.list_pending(auth_user.id, &auth_user.role).await?;
+    Ok(Json(ApiResponse::success(requests)))
+}
+
+/// GET /time-off/:id - Get a time off request (owner or approver)
+pub async fn get_time_off(
+    State(service): State<Arc<CalendarAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<TimeOffRequest>>, DomainError> {
+    let request = service.get_time_off(id).await?;
+
+    if request.user_id != auth_user.id
+        && !service
+            .can_approve(auth_user.id, &auth_user.role, request.user_id)
+            .await?
+    {
+        return Err(DomainError::Forbidden(
+            "You don't have access to this time off request".into(),
+        ));
+    }
+
+    Ok(Json(ApiResponse::success(request)))
+}
+
+/// POST /time-off/:id/approve - Approve a pending request
+pub async fn approve_time_off(
+    State(service): State<Arc<CalendarAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+    Json(cmd): Json<DecideTimeOffCommand>,
+) -> Result<Json<ApiResponse<TimeOffRequest>>, DomainError> {
+    ensure_approver(&service, id, &auth_user).await?;
+
+    tracing::info!(user_id = %auth_user.id, time_off_id = %id, "User approving time off");
+    let request = service.approve_time_off(id, auth_user.id, cmd).await?;
+    Ok(Json(ApiResponse::success(request)))
+}
+
+/// POST /time-off/:id/reject - Reject a pending request with a comment
+pub async fn reject_time_off(
+    State(service): State<Arc<CalendarAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+    Json(cmd): Json<DecideTimeOffCommand>,
+) -> Result<Json<ApiResponse<TimeOffRequest>>, DomainError> {
+    ensure_approver(&service, id, &auth_user).await?;
+
+    tracing::info!(user_id = %auth_user.id, time_off_id = %id, "User rejecting time off");
+    let request = service.reject_time_off(id, auth_user.id, cmd).await?;
+    Ok(Json(ApiResponse::success(request)))
+}
+
+/// POST /time-off/:id/cancel - Withdraw one of the current user's requests
+pub async fn cancel_time_off(
+    State(service): State<Arc<CalendarAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<TimeOffRequest>>, DomainError> {
+    tracing::info!(user_id = %auth_user.id, time_off_id = %id, "User cancelling time off");
+    let request = service.cancel_time_off(id, auth_user.id).await?;
+    Ok(Json(ApiResponse::success(request)))
+}
+
+/// GET /teams/:id/availability - Holidays and time off of a team's members
+pub async fn team_availability(
+    State(service): State<Arc<CalendarAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+    Query(query): Query<AvailabilityQuery>,
+) -> Result<Json<ApiResponse<TeamAvailability>>, DomainError> {
+    // Check team access (admin can view all)
+    if auth_user.role != UserRole::Admin && !service.can_access_team(id, auth_user.id).await? {
+        return Err(DomainError::Forbidden(
+            "You don't have access to this team".into(),
+        ));
+    }
+
+    let availability = service
+        .team_availability(id, query.start_date, query.end_date)
+        .await?;
+    Ok(Json(ApiResponse::success(availability)))
+}
+
+/// Managers, admins and the owner's team leads decide time off, never the owner
+async fn ensure_approver(
+    service: &CalendarAppService,
+    id: Uuid,
+    auth_user: &AuthUser,
+) -> Result<(), DomainError> {
+    let request = service.get_time_off(id).await?;
+    if !service
+        .can_approve(auth_user.id, &auth_user.role, request.user_id)
+        .await?
+    {
+        return Err(DomainError::Forbidden(
+            "Only a manager or the user's team lead can decide on this time off".into(),
+        ));
+    }
+    Ok(())
+}
@@ -0,0 +1,75 @@
+use axum::{
+    extract::{Path, Query, State},
+    Extension, Json,
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::application::commands::{SetCapacityCommand, UtilizationReportCommand};
+use crate::application::services::{CapacityAppService, UtilizationReport};
+use crate::domain::entities::UserCapacity;
+use crate::domain::value_objects::UserRole;
+use crate::presentation::dto::ApiResponse;
+use crate::presentation::middleware::AuthUser;
+use crate::shared::DomainError;
+
+/// GET /users/:user_id/capacity - Get a user's working hours
+pub async fn get_capacity(
+    State(service): State<Arc<CapacityAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(user_id): Path<Uuid>,
+) -> Result<Json<ApiResponse<UserCapacity>>, DomainError> {
+    // Users see their own capacity (admin can see all)
+    if auth_user.role != UserRole::Admin && auth_user.id != user_id {
+        return Err(DomainError::Forbidden(
+            "You can only view your own capacity".into(),
+        ));
+    }
+
+    let capacity = service.get_capacity(user_id).await?;
+    Ok(Json(ApiResponse::success(capacity)))
+}
+
+/// PUT /users/:user_id/capacity - Set a user's weekly hours or day schedule
+pub async fn set_capacity(
+    State(service): State<Arc<CapacityAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(user_id): Path<Uuid>,
+    Json(cmd): Json<SetCapacityCommand>,
+) -> Result<Json<ApiResponse<UserCapacity>>, DomainError> {
+    if auth_user.role != UserRole::Admin {
+        return Err(DomainError::Forbidden("Only admin can set capacity".into()));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        target_user_id = %user_id,
+        "Admin setting user capacity"
+    );
+    let capacity = service.set_capacity(user_id, cmd).await?;
+    Ok(Json(ApiResponse::success(capacity)))
+}
+
+/// GET /reports/utilization - Capacity against logged and planned hours
+///
+/// Admin can report on everyone; team leads on their own team.
+pub async fn utilization_report(
+    State(service): State<Arc<CapacityAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Query(cmd): Query<UtilizationReportCommand>,
+) -> Result<Json<ApiResponse<UtilizationReport>>, DomainError> {
+    if auth_user.role != UserRole::Admin {
+        let is_lead = match cmd.team_id {
+            Some(team_id) => service.is_team_lead(team_id, auth_user.id).await?,
+            None => false,
+        };
+        if !is_lead {
+            return Err(DomainError::Forbidden(
+                "Only admin or the team lead can view utilization".into(),
+            ));
+        }
+    }
+
+    let report = service.utilization_report(&cmd).await?;
+    Ok(Json(ApiResponse::success(report)))
+}
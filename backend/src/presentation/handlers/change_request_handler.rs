@@ -0,0 +1,191 @@
+use axum::{
+    extract::{Path, Query, State},
+    Extension, Json,
+};
+use serde::Deserialize;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::application::commands::{
+    CreateChangeRequestCommand, DecideChangeRequestCommand, SetChangeApproversCommand,
+};
+use crate::application::services::ChangeRequestAppService;
+use crate::domain::entities::{ActivityLogWithDetails, ChangeRequest};
+use crate::domain::value_objects::{ChangeRequestStatus, UserRole};
+use crate::presentation::dto::ApiResponse;
+use crate::presentation::middleware::AuthUser;
+use crate::shared::DomainError;
+
+#[derive(Debug, Deserialize)]
+pub struct ListChangeRequestsQuery {
+    pub status: Option<ChangeRequestStatus>,
+}
+
+pub async fn get_approvers(
+    State(service): State<Arc<ChangeRequestAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<Vec<Uuid>>>, DomainError> {
+    // Check access permission (admin can access all)
+    if auth_user.role != UserRole::Admin && !service.can_view_project(id, auth_user.id).await? {
+        return Err(DomainError::Forbidden(
+            "You don't have access to this project".into(),
+        ));
+    }
+
+    let approvers = service.get_approvers(id).await?;
+    Ok(Json(ApiResponse::success(approvers)))
+}
+
+pub async fn set_approvers(
+    State(service): State<Arc<ChangeRequestAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+    Json(cmd): Json<SetChangeApproversCommand>,
+) -> Result<Json<ApiResponse<Vec<Uuid>>>, DomainError> {
+    // Only project owner or admin can designate approvers
+    if auth_user.role != UserRole::Admin && !service.is_project_owner(id, auth_user.id).await? {
+        return Err(DomainError::Forbidden(
+            "Only project owner can set change approvers".into(),
+        ));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        project_id = %id,
+        approvers = cmd.user_ids.len(),
+        "User setting change approvers"
+    );
+    let approvers = service.set_approvers(id, auth_user.id, cmd).await?;
+    Ok(Json(ApiResponse::success(approvers)))
+}
+
+pub async fn list_requests(
+    State(service): State<Arc<ChangeRequestAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+    Query(query): Query<ListChangeRequestsQuery>,
+) -> Result<Json<ApiResponse<Vec<ChangeRequest>>>, DomainError> {
+    // Check access permission (admin can access all)
+    if auth_user.role != UserRole::Admin && !service.can_view_project(id, auth_user.id).await? {
+        return Err(DomainError::Forbidden(
+            "You don't have access to this project".into(),
+        ));
+    }
+
+    let requests = service.list_requests(id, query.status).await?;
+    Ok(Json(ApiResponse::success(requests)))
+}
+
+pub async fn submit_request(
+    State(service): State<Arc<ChangeRequestAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+    Json(cmd): Json<CreateChangeRequestCommand>,
+) -> Result<Json<ApiResponse<ChangeRequest>>, DomainError> {
+    // Check access permission (admin can access all)
+    if auth_user.role != UserRole::Admin && !service.can_access_project(id, auth_user.id).await? {
+        return Err(DomainError::Forbidden(
+            "You don't have access to this project".into(),
+        ));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        project_id = %id,
+        "User submitting change request"
+    );
+    let request = service.submit_request(id, auth_user.id, cmd).await?;
+    Ok(Json(ApiResponse::success(request)))
+}
+
+pub async fn get_request(
+    State(service): State<Arc<ChangeRequestAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<ChangeRequest>>, DomainError> {
+    let request = service.get_request(id).await?;
+
+    // Check access permission (admin can access all)
+    if auth_user.role != UserRole::Admin
+        && !service
+            .can_view_project(request.project_id, auth_user.id)
+            .await?
+    {
+        return Err(DomainError::Forbidden(
+            "You don't have access to this project".into(),
+        ));
+    }
+
+    Ok(Json(ApiResponse::success(request)))
+}
+
+pub async fn get_request_history(
+    State(service): State<Arc<ChangeRequestAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<Vec<ActivityLogWithDetails>>>, DomainError> {
+    let request = service.get_request(id).await?;
+
+    // Check access permission (admin can access all)
+    if auth_user.role != UserRole::Admin
+        && !service
+            .can_view_project(request.project_id, auth_user.id)
+            .await?
+    {
+        return Err(DomainError::Forbidden(
+            "You don't have access to this project".into(),
+        ));
+    }
+
+    let history = service.get_history(id).await?;
+    Ok(Json(ApiResponse::success(history)))
+}
+
+pub async fn approve_request(
+    State(service): State<Arc<ChangeRequestAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+    Json(cmd): Json<DecideChangeRequestCommand>,
+) -> Result<Json<ApiResponse<ChangeRequest>>, DomainError> {
+    tracing::info!(
+        user_id = %auth_user.id,
+        change_request_id = %id,
+        "User approving change request"
+    );
+    let request = service
+        .approve_request(id, auth_user.id, auth_user.role == UserRole::Admin, cmd)
+        .await?;
+    Ok(Json(ApiResponse::success(request)))
+}
+
+pub async fn reject_request(
+    State(service): State<Arc<ChangeRequestAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+    Json(cmd): Json<DecideChangeRequestCommand>,
+) -> Result<Json<ApiResponse<ChangeRequest>>, DomainError> {
+    tracing::info!(
+        user_id = %auth_user.id,
+        change_request_id = %id,
+        "User rejecting change request"
+    );
+    let request = service
+        .reject_request(id, auth_user.id, auth_user.role == UserRole::Admin, cmd)
+        .await?;
+    Ok(Json(ApiResponse::success(request)))
+}
+
+pub async fn withdraw_request(
+    State(service): State<Arc<ChangeRequestAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<ChangeRequest>>, DomainError> {
+    tracing::info!(
+        user_id = %auth_user.id,
+        change_request_id = %id,
+        "User withdrawing change request"
+    );
+    let request = service.withdraw_request(id, auth_user.id).await?;
+    Ok(Json(ApiResponse::success(request)))
+}
@@ -0,0 +1,88 @@
+use axum::{
+    extract::{Path, State},
+    Extension, Json,
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::application::commands::{CreateCommentCommand, UpdateCommentCommand};
+use crate::application::services::{CommentAppService, WithMentions};
+use crate::domain::entities::TaskComment;
+use crate::domain::value_objects::UserRole;
+use crate::presentation::dto::ApiResponse;
+use crate::presentation::middleware::AuthUser;
+use crate::shared::DomainError;
+
+pub async fn get_task_comments(
+    State(service): State<Arc<CommentAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(task_id): Path<Uuid>,
+) -> Result<Json<ApiResponse<Vec<TaskComment>>>, DomainError> {
+    // Check access permission (admin can access all)
+    if auth_user.role != UserRole::Admin && !service.can_user_view(task_id, auth_user.id).await? {
+        return Err(DomainError::Forbidden(
+            "You don't have access to this task".into(),
+        ));
+    }
+    let comments = service.get_task_comments(task_id).await?;
+    Ok(Json(ApiResponse::success(comments)))
+}
+
+pub async fn add_comment(
+    State(service): State<Arc<CommentAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(task_id): Path<Uuid>,
+    Json(cmd): Json<CreateCommentCommand>,
+) -> Result<Json<ApiResponse<WithMentions<TaskComment>>>, DomainError> {
+    // Check access permission (admin can access all)
+    if auth_user.role != UserRole::Admin && !service.can_user_access(task_id, auth_user.id).await? {
+        return Err(DomainError::Forbidden(
+            "You don't have access to this task".into(),
+        ));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        task_id = %task_id,
+        "User adding comment"
+    );
+    let comment = service.add_comment(task_id, cmd, auth_user.id).await?;
+    Ok(Json(ApiResponse::success(comment)))
+}
+
+pub async fn update_comment(
+    State(service): State<Arc<CommentAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+    Json(cmd): Json<UpdateCommentCommand>,
+) -> Result<Json<ApiResponse<WithMentions<TaskComment>>>, DomainError> {
+    tracing::info!(
+        user_id = %auth_user.id,
+        comment_id = %id,
+        "User updating comment"
+    );
+    let comment = service.update_comment(id, cmd, auth_user.id).await?;
+    Ok(Json(ApiResponse::success(comment)))
+}
+
+pub async fn delete_comment(
+    State(service): State<Arc<CommentAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<()>>, DomainError> {
+    // Only the author or admin can delete a comment
+    let comment = service.get_comment(id).await?;
+    if auth_user.role != UserRole::Admin && comment.user_id != auth_user.id {
+        return Err(DomainError::Forbidden(
+            "Only the author can delete this comment".into(),
+        ));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        comment_id = %id,
+        "User deleting comment"
+    );
+    service.delete_comment(id).await?;
+    Ok(Json(ApiResponse::ok("Comment deleted successfully")))
+}
@@ -0,0 +1,37 @@
+use axum::{
+    extract::{Path, Query, State},
+    Extension, Json,
+};
+use chrono::NaiveDate;
+use serde::Deserialize;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::application::services::{EvmAppService, ProjectEvm};
+use crate::domain::value_objects::UserRole;
+use crate::presentation::dto::ApiResponse;
+use crate::presentation::middleware::AuthUser;
+use crate::shared::DomainError;
+
+#[derive(Debug, Deserialize)]
+pub struct EvmQuery {
+    /// Date the metrics are computed for; defaults to today
+    pub as_of: Option<NaiveDate>,
+}
+
+pub async fn get_project_evm(
+    State(service): State<Arc<EvmAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+    Query(query): Query<EvmQuery>,
+) -> Result<Json<ApiResponse<ProjectEvm>>, DomainError> {
+    // Check access permission (admin can access all)
+    if auth_user.role != UserRole::Admin && !service.can_view_project(id, auth_user.id).await? {
+        return Err(DomainError::Forbidden(
+            "You don't have access to this project".into(),
+        ));
+    }
+
+    let evm = service.get_project_evm(id, query.as_of).await?;
+    Ok(Json(ApiResponse::success(evm)))
+}
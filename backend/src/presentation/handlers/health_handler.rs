@@ -0,0 +1,109 @@
+use axum::{
+    extract::{Path, Query, State},
+    Extension, Json,
+};
+use serde::Deserialize;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::application::commands::SetHealthOverrideCommand;
+use crate::application::services::{HealthAppService, ProjectHealth};
+use crate::domain::entities::HealthSnapshot;
+use crate::domain::value_objects::UserRole;
+use crate::presentation::dto::ApiResponse;
+use crate::presentation::middleware::AuthUser;
+use crate::shared::DomainError;
+
+#[derive(Debug, Deserialize)]
+pub struct HealthHistoryQuery {
+    /// Number of most recent weeks to return
+    pub weeks: Option<i64>,
+}
+
+pub async fn list_project_health(
+    State(service): State<Arc<HealthAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+) -> Result<Json<ApiResponse<Vec<ProjectHealth>>>, DomainError> {
+    // Admin can see all projects, others only see accessible ones
+    let health = service
+        .list_project_health(auth_user.id, auth_user.role == UserRole::Admin)
+        .await?;
+    Ok(Json(ApiResponse::success(health)))
+}
+
+pub async fn get_project_health(
+    State(service): State<Arc<HealthAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<ProjectHealth>>, DomainError> {
+    // Check access permission (admin can access all)
+    if auth_user.role != UserRole::Admin && !service.can_view_project(id, auth_user.id).await? {
+        return Err(DomainError::Forbidden(
+            "You don't have access to this project".into(),
+        ));
+    }
+
+    let health = service.get_project_health(id).await?;
+    Ok(Json(ApiResponse::success(health)))
+}
+
+pub async fn get_health_history(
+    State(service): State<Arc<HealthAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+    Query(query): Query<HealthHistoryQuery>,
+) -> Result<Json<ApiResponse<Vec<HealthSnapshot>>>, DomainError> {
+    // Check access permission (admin can access all)
+    if auth_user.role != UserRole::Admin && !service.can_view_project(id, auth_user.id).await? {
+        return Err(DomainError::Forbidden(
+            "You don't have access to this project".into(),
+        ));
+    }
+
+    let history = service.get_history(id, query.weeks).await?;
+    Ok(Json(ApiResponse::success(history)))
+}
+
+pub async fn set_health_override(
+    State(service): State<Arc<HealthAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+    Json(cmd): Json<SetHealthOverrideCommand>,
+) -> Result<Json<ApiResponse<ProjectHealth>>, DomainError> {
+    // Only owner can override project health (admin can do all)
+    if auth_user.role != UserRole::Admin && !service.is_project_owner(id, auth_user.id).await? {
+        return Err(DomainError::Forbidden(
+            "Only project owner can override project health".into(),
+        ));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        project_id = %id,
+        status = ?cmd.status,
+        "User overriding project health"
+    );
+    let health = service.set_override(id, auth_user.id, cmd).await?;
+    Ok(Json(ApiResponse::success(health)))
+}
+
+pub async fn clear_health_override(
+    State(service): State<Arc<HealthAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<ProjectHealth>>, DomainError> {
+    // Only owner can override project health (admin can do all)
+    if auth_user.role != UserRole::Admin && !service.is_project_owner(id, auth_user.id).await? {
+        return Err(DomainError::Forbidden(
+            "Only project owner can override project health".into(),
+        ));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        project_id = %id,
+        "User clearing project health override"
+    );
+    let health = service.clear_override(id).await?;
+    Ok(Json(ApiResponse::success(health)))
+}
@@ -1,9 +1,27 @@
 pub mod activity_handler;
+pub mod allocation_handler;
 pub mod attachment_handler;
 pub mod auth_handler;
+pub mod baseline_handler;
+pub mod billing_handler;
+pub mod budget_handler;
+pub mod calendar_handler;
+pub mod capacity_handler;
+pub mod change_request_handler;
+pub mod comment_handler;
+pub mod evm_handler;
+pub mod health_handler;
 pub mod notification_handler;
+pub mod portfolio_handler;
 pub mod project_handler;
+pub mod raid_handler;
+pub mod schedule_handler;
+pub mod sprint_handler;
+pub mod status_report_handler;
 pub mod tag_handler;
 pub mod task_handler;
 pub mod team_handler;
 pub mod time_log_handler;
+pub mod timer_handler;
+pub mod timesheet_handler;
+pub mod trash_handler;
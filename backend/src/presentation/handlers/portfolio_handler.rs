@@ -0,0 +1,189 @@
+use axum::{
+    extract::{Path, State},
+    Extension, Json,
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::application::commands::{CreatePortfolioCommand, UpdatePortfolioCommand};
+use crate::application::services::{PortfolioAppService, PortfolioRollup};
+use crate::domain::entities::{Portfolio, Project};
+use crate::domain::value_objects::UserRole;
+use crate::presentation::dto::ApiResponse;
+use crate::presentation::middleware::AuthUser;
+use crate::shared::DomainError;
+
+pub async fn list_portfolios(
+    State(service): State<Arc<PortfolioAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+) -> Result<Json<ApiResponse<Vec<Portfolio>>>, DomainError> {
+    // Admin can see all portfolios, others only the ones they manage
+    let portfolios = if auth_user.role == UserRole::Admin {
+        service.list_portfolios().await?
+    } else {
+        service.list_managed_portfolios(auth_user.id).await?
+    };
+    Ok(Json(ApiResponse::success(portfolios)))
+}
+
+pub async fn create_portfolio(
+    State(service): State<Arc<PortfolioAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Json(cmd): Json<CreatePortfolioCommand>,
+) -> Result<Json<ApiResponse<Portfolio>>, DomainError> {
+    if auth_user.role == UserRole::Member {
+        return Err(DomainError::Forbidden(
+            "Only managers can create portfolios and programs".into(),
+        ));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        kind = ?cmd.kind,
+        "User creating portfolio"
+    );
+    let portfolio = service.create_portfolio(auth_user.id, cmd).await?;
+    Ok(Json(ApiResponse::success(portfolio)))
+}
+
+pub async fn get_portfolio(
+    State(service): State<Arc<PortfolioAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<Portfolio>>, DomainError> {
+    // Check access permission (admin can access all)
+    if auth_user.role != UserRole::Admin && !service.is_manager(id, auth_user.id).await? {
+        return Err(DomainError::Forbidden(
+            "You don't have access to this portfolio".into(),
+        ));
+    }
+
+    let portfolio = service.get_portfolio(id).await?;
+    Ok(Json(ApiResponse::success(portfolio)))
+}
+
+pub async fn update_portfolio(
+    State(service): State<Arc<PortfolioAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+    Json(cmd): Json<UpdatePortfolioCommand>,
+) -> Result<Json<ApiResponse<Portfolio>>, DomainError> {
+    // Only a manager of the portfolio can update it (admin can do all)
+    if auth_user.role != UserRole::Admin && !service.is_manager(id, auth_user.id).await? {
+        return Err(DomainError::Forbidden(
+            "Only the portfolio manager can update this portfolio".into(),
+        ));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        portfolio_id = %id,
+        "User updating portfolio"
+    );
+    let portfolio = service.update_portfolio(id, cmd).await?;
+    Ok(Json(ApiResponse::success(portfolio)))
+}
+
+pub async fn delete_portfolio(
+    State(service): State<Arc<PortfolioAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<()>>, DomainError> {
+    // Only a manager of the portfolio can delete it (admin can do all)
+    if auth_user.role != UserRole::Admin && !service.is_manager(id, auth_user.id).await? {
+        return Err(DomainError::Forbidden(
+            "Only the portfolio manager can delete this portfolio".into(),
+        ));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        portfolio_id = %id,
+        "User deleting portfolio"
+    );
+    service.delete_portfolio(id).await?;
+    Ok(Json(ApiResponse::ok("Portfolio deleted")))
+}
+
+pub async fn get_portfolio_projects(
+    State(service): State<Arc<PortfolioAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<Vec<Project>>>, DomainError> {
+    // Check access permission (admin can access all)
+    if auth_user.role != UserRole::Admin && !service.is_manager(id, auth_user.id).await? {
+        return Err(DomainError::Forbidden(
+            "You don't have access to this portfolio".into(),
+        ));
+    }
+
+    let projects = service.get_projects(id).await?;
+    Ok(Json(ApiResponse::success(projects)))
+}
+
+pub async fn add_portfolio_project(
+    State(service): State<Arc<PortfolioAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path((id, project_id)): Path<(Uuid, Uuid)>,
+) -> Result<Json<ApiResponse<Project>>, DomainError> {
+    // Grouping a project grants its program managers read access, so both the
+    // portfolio manager and the project owner must agree (admin can do all)
+    if auth_user.role != UserRole::Admin
+        && !(service.is_manager(id, auth_user.id).await?
+            && service.is_project_owner(project_id, auth_user.id).await?)
+    {
+        return Err(DomainError::Forbidden(
+            "Only a portfolio manager who owns the project can add it".into(),
+        ));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        portfolio_id = %id,
+        project_id = %project_id,
+        "User adding project to portfolio"
+    );
+    let project = service.add_project(id, project_id).await?;
+    Ok(Json(ApiResponse::success(project)))
+}
+
+pub async fn remove_portfolio_project(
+    State(service): State<Arc<PortfolioAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path((id, project_id)): Path<(Uuid, Uuid)>,
+) -> Result<Json<ApiResponse<()>>, DomainError> {
+    // Either the portfolio manager or the project owner can ungroup a project
+    if auth_user.role != UserRole::Admin
+        && !service.is_manager(id, auth_user.id).await?
+        && !service.is_project_owner(project_id, auth_user.id).await?
+    {
+        return Err(DomainError::Forbidden(
+            "Only the portfolio manager or project owner can remove this project".into(),
+        ));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        portfolio_id = %id,
+        project_id = %project_id,
+        "User removing project from portfolio"
+    );
+    service.remove_project(id, project_id).await?;
+    Ok(Json(ApiResponse::ok("Project removed from portfolio")))
+}
+
+pub async fn get_portfolio_rollup(
+    State(service): State<Arc<PortfolioAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<PortfolioRollup>>, DomainError> {
+    // Check access permission (admin can access all)
+    if auth_user.role != UserRole::Admin && !service.is_manager(id, auth_user.id).await? {
+        return Err(DomainError::Forbidden(
+            "You don't have access to this portfolio".into(),
+        ));
+    }
+
+    let rollup = service.get_rollup(id).await?;
+    Ok(Json(ApiResponse::success(rollup)))
+}
@@ -1,27 +1,37 @@
 use axum::{
-    extract::{Path, State},
+    extract::{Path, Query, State},
     Extension, Json,
 };
+use serde::Deserialize;
 use std::sync::Arc;
 use uuid::Uuid;
 
 use crate::application::commands::{CreateProjectCommand, UpdateProjectCommand};
 use crate::application::services::ProjectAppService;
-use crate::domain::entities::{Milestone, Project, Task};
+use crate::domain::entities::{Milestone, Project, Task, Watcher};
 use crate::domain::value_objects::UserRole;
 use crate::presentation::dto::ApiResponse;
 use crate::presentation::middleware::AuthUser;
 use crate::shared::DomainError;
 
+#[derive(Debug, Deserialize)]
+pub struct ListProjectsQuery {
+    #[serde(default)]
+    pub include_archived: bool,
+}
+
 pub async fn list_projects(
     State(service): State<Arc<ProjectAppService>>,
     Extension(auth_user): Extension<AuthUser>,
+    Query(query): Query<ListProjectsQuery>,
 ) -> Result<Json<ApiResponse<Vec<Project>>>, DomainError> {
     // Admin can see all projects, others only see accessible ones
     let projects = if auth_user.role == UserRole::Admin {
-        service.list_projects().await?
+        service.list_projects(query.include_archived).await?
     } else {
-        service.list_accessible_projects(auth_user.id).await?
+        service
+            .list_accessible_projects(auth_user.id, query.include_archived)
+            .await?
     };
     Ok(Json(ApiResponse::success(projects)))
 }
@@ -32,7 +42,7 @@ pub async fn get_project(
     Path(id): Path<Uuid>,
 ) -> Result<Json<ApiResponse<Project>>, DomainError> {
     // Check access permission (admin can access all)
-    if auth_user.role != UserRole::Admin && !service.can_user_access(id, auth_user.id).await? {
+    if auth_user.role != UserRole::Admin && !service.can_user_view(id, auth_user.id).await? {
         return Err(DomainError::Forbidden(
             "You don't have access to this project".into(),
         ));
@@ -73,7 +83,7 @@ pub async fn update_project(
         project_id = %id,
         "User updating project"
     );
-    let project = service.update_project(id, cmd).await?;
+    let project = service.update_project(id, cmd, auth_user.id).await?;
     Ok(Json(ApiResponse::success(project)))
 }
 
@@ -94,8 +104,50 @@ pub async fn delete_project(
         project_id = %id,
         "User deleting project"
     );
-    service.delete_project(id).await?;
-    Ok(Json(ApiResponse::ok("Project deleted successfully")))
+    service.delete_project(id, auth_user.id).await?;
+    Ok(Json(ApiResponse::ok("Project moved to trash")))
+}
+
+pub async fn archive_project(
+    State(service): State<Arc<ProjectAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<Project>>, DomainError> {
+    // Only owner or admin can archive project
+    if auth_user.role != UserRole::Admin && !service.is_owner(id, auth_user.id).await? {
+        return Err(DomainError::Forbidden(
+            "Only project owner can archive this project".into(),
+        ));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        project_id = %id,
+        "User archiving project"
+    );
+    let project = service.archive_project(id, auth_user.id).await?;
+    Ok(Json(ApiResponse::success(project)))
+}
+
+pub async fn unarchive_project(
+    State(service): State<Arc<ProjectAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<Project>>, DomainError> {
+    // Only owner or admin can unarchive project
+    if auth_user.role != UserRole::Admin && !service.is_owner(id, auth_user.id).await? {
+        return Err(DomainError::Forbidden(
+            "Only project owner can unarchive this project".into(),
+        ));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        project_id = %id,
+        "User unarchiving project"
+    );
+    let project = service.unarchive_project(id, auth_user.id).await?;
+    Ok(Json(ApiResponse::success(project)))
 }
 
 pub async fn get_project_tasks(
@@ -104,7 +156,7 @@ pub async fn get_project_tasks(
     Path(id): Path<Uuid>,
 ) -> Result<Json<ApiResponse<Vec<Task>>>, DomainError> {
     // Check access permission (admin can access all)
-    if auth_user.role != UserRole::Admin && !service.can_user_access(id, auth_user.id).await? {
+    if auth_user.role != UserRole::Admin && !service.can_user_view(id, auth_user.id).await? {
         return Err(DomainError::Forbidden(
             "You don't have access to this project".into(),
         ));
@@ -119,7 +171,7 @@ pub async fn get_project_milestones(
     Path(id): Path<Uuid>,
 ) -> Result<Json<ApiResponse<Vec<Milestone>>>, DomainError> {
     // Check access permission (admin can access all)
-    if auth_user.role != UserRole::Admin && !service.can_user_access(id, auth_user.id).await? {
+    if auth_user.role != UserRole::Admin && !service.can_user_view(id, auth_user.id).await? {
         return Err(DomainError::Forbidden(
             "You don't have access to this project".into(),
         ));
@@ -127,3 +179,43 @@ pub async fn get_project_milestones(
     let milestones = service.get_project_milestones(id).await?;
     Ok(Json(ApiResponse::success(milestones)))
 }
+
+pub async fn get_project_watchers(
+    State(service): State<Arc<ProjectAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<Vec<Watcher>>>, DomainError> {
+    // Check access permission (admin can access all)
+    if auth_user.role != UserRole::Admin && !service.can_user_view(id, auth_user.id).await? {
+        return Err(DomainError::Forbidden(
+            "You don't have access to this project".into(),
+        ));
+    }
+    let watchers = service.get_project_watchers(id).await?;
+    Ok(Json(ApiResponse::success(watchers)))
+}
+
+pub async fn watch_project(
+    State(service): State<Arc<ProjectAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<()>>, DomainError> {
+    // Check access permission (admin can access all)
+    if auth_user.role != UserRole::Admin && !service.can_user_access(id, auth_user.id).await? {
+        return Err(DomainError::Forbidden(
+            "You don't have access to this project".into(),
+        ));
+    }
+    service.get_project(id).await?;
+    service.watch_project(id, auth_user.id).await?;
+    Ok(Json(ApiResponse::ok("Now watching project")))
+}
+
+pub async fn unwatch_project(
+    State(service): State<Arc<ProjectAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<()>>, DomainError> {
+    service.unwatch_project(id, auth_user.id).await?;
+    Ok(Json(ApiResponse::ok("Stopped watching project")))
+}
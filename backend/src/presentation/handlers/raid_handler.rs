@@ -0,0 +1,157 @@
+use axum::{
+    extract::{Path, Query, State},
+    Extension, Json,
+};
+use serde::Deserialize;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::application::commands::{CreateRaidItemCommand, UpdateRaidItemCommand};
+use crate::application::services::{ProjectRiskMatrix, RaidAppService};
+use crate::domain::entities::RaidItem;
+use crate::domain::value_objects::{RaidStatus, RaidType, UserRole};
+use crate::presentation::dto::ApiResponse;
+use crate::presentation::middleware::AuthUser;
+use crate::shared::DomainError;
+
+#[derive(Debug, Deserialize)]
+pub struct ListRaidItemsQuery {
+    #[serde(rename = "type")]
+    pub item_type: Option<RaidType>,
+    pub status: Option<RaidStatus>,
+}
+
+pub async fn list_items(
+    State(service): State<Arc<RaidAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+    Query(query): Query<ListRaidItemsQuery>,
+) -> Result<Json<ApiResponse<Vec<RaidItem>>>, DomainError> {
+    // Check access permission (admin can access all)
+    if auth_user.role != UserRole::Admin && !service.can_view_project(id, auth_user.id).await? {
+        return Err(DomainError::Forbidden(
+            "You don't have access to this project".into(),
+        ));
+    }
+
+    let items = service
+        .list_items(id, query.item_type, query.status)
+        .await?;
+    Ok(Json(ApiResponse::success(items)))
+}
+
+pub async fn create_item(
+    State(service): State<Arc<RaidAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+    Json(cmd): Json<CreateRaidItemCommand>,
+) -> Result<Json<ApiResponse<RaidItem>>, DomainError> {
+    // Check access permission (admin can access all)
+    if auth_user.role != UserRole::Admin && !service.can_access_project(id, auth_user.id).await? {
+        return Err(DomainError::Forbidden(
+            "You don't have access to this project".into(),
+        ));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        project_id = %id,
+        item_type = ?cmd.item_type,
+        "User creating RAID item"
+    );
+    let item = service.create_item(id, auth_user.id, cmd).await?;
+    Ok(Json(ApiResponse::success(item)))
+}
+
+pub async fn get_risk_matrix(
+    State(service): State<Arc<RaidAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<ProjectRiskMatrix>>, DomainError> {
+    // Check access permission (admin can access all)
+    if auth_user.role != UserRole::Admin && !service.can_view_project(id, auth_user.id).await? {
+        return Err(DomainError::Forbidden(
+            "You don't have access to this project".into(),
+        ));
+    }
+
+    let matrix = service.get_risk_matrix(id).await?;
+    Ok(Json(ApiResponse::success(matrix)))
+}
+
+pub async fn get_item(
+    State(service): State<Arc<RaidAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<RaidItem>>, DomainError> {
+    let item = service.get_item(id).await?;
+
+    // Check access permission (admin can access all)
+    if auth_user.role != UserRole::Admin
+        && !service
+            .can_view_project(item.project_id, auth_user.id)
+            .await?
+    {
+        return Err(DomainError::Forbidden(
+            "You don't have access to this project".into(),
+        ));
+    }
+
+    Ok(Json(ApiResponse::success(item)))
+}
+
+pub async fn update_item(
+    State(service): State<Arc<RaidAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+    Json(cmd): Json<UpdateRaidItemCommand>,
+) -> Result<Json<ApiResponse<RaidItem>>, DomainError> {
+    let item = service.get_item(id).await?;
+
+    // Check access permission (admin can access all)
+    if auth_user.role != UserRole::Admin
+        && !service
+            .can_access_project(item.project_id, auth_user.id)
+            .await?
+    {
+        return Err(DomainError::Forbidden(
+            "You don't have access to this project".into(),
+        ));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        raid_item_id = %id,
+        "User updating RAID item"
+    );
+    let item = service.update_item(id, cmd).await?;
+    Ok(Json(ApiResponse::success(item)))
+}
+
+pub async fn delete_item(
+    State(service): State<Arc<RaidAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<()>>, DomainError> {
+    let item = service.get_item(id).await?;
+
+    // Only the author or project owner can delete an item (admin can do all)
+    if auth_user.role != UserRole::Admin
+        && item.created_by != Some(auth_user.id)
+        && !service
+            .is_project_owner(item.project_id, auth_user.id)
+            .await?
+    {
+        return Err(DomainError::Forbidden(
+            "Only the author or project owner can delete this item".into(),
+        ));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        raid_item_id = %id,
+        "User deleting RAID item"
+    );
+    service.delete_item(id).await?;
+    Ok(Json(ApiResponse::ok("RAID item deleted")))
+}
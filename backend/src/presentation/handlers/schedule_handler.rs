@@ -0,0 +1,89 @@
+use axum::{
+    extract::{Path, State},
+    Extension, Json,
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::application::services::{ProjectSchedule, ScheduleAppService};
+use crate::domain::entities::TaskDependency;
+use crate::domain::value_objects::UserRole;
+use crate::presentation::dto::ApiResponse;
+use crate::presentation::middleware::AuthUser;
+use crate::shared::DomainError;
+
+pub async fn get_project_schedule(
+    State(service): State<Arc<ScheduleAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<ProjectSchedule>>, DomainError> {
+    // Check access permission (admin can access all)
+    if auth_user.role != UserRole::Admin && !service.can_view_project(id, auth_user.id).await? {
+        return Err(DomainError::Forbidden(
+            "You don't have access to this project".into(),
+        ));
+    }
+
+    let schedule = service.get_project_schedule(id).await?;
+    Ok(Json(ApiResponse::success(schedule)))
+}
+
+pub async fn get_task_dependencies(
+    State(service): State<Arc<ScheduleAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<Vec<TaskDependency>>>, DomainError> {
+    // Check access permission (admin can access all)
+    if auth_user.role != UserRole::Admin && !service.can_view_task(id, auth_user.id).await? {
+        return Err(DomainError::Forbidden(
+            "You don't have access to this task".into(),
+        ));
+    }
+
+    let dependencies = service.get_task_dependencies(id).await?;
+    Ok(Json(ApiResponse::success(dependencies)))
+}
+
+pub async fn add_dependency(
+    State(service): State<Arc<ScheduleAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path((id, depends_on_id)): Path<(Uuid, Uuid)>,
+) -> Result<Json<ApiResponse<TaskDependency>>, DomainError> {
+    // Check access permission (admin can access all)
+    if auth_user.role != UserRole::Admin && !service.can_access_task(id, auth_user.id).await? {
+        return Err(DomainError::Forbidden(
+            "You don't have access to this task".into(),
+        ));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        task_id = %id,
+        depends_on_id = %depends_on_id,
+        "User adding task dependency"
+    );
+    let dependency = service.add_dependency(id, depends_on_id).await?;
+    Ok(Json(ApiResponse::success(dependency)))
+}
+
+pub async fn remove_dependency(
+    State(service): State<Arc<ScheduleAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path((id, depends_on_id)): Path<(Uuid, Uuid)>,
+) -> Result<Json<ApiResponse<()>>, DomainError> {
+    // Check access permission (admin can access all)
+    if auth_user.role != UserRole::Admin && !service.can_access_task(id, auth_user.id).await? {
+        return Err(DomainError::Forbidden(
+            "You don't have access to this task".into(),
+        ));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        task_id = %id,
+        depends_on_id = %depends_on_id,
+        "User removing task dependency"
+    );
+    service.remove_dependency(id, depends_on_id).await?;
+    Ok(Json(ApiResponse::ok("Dependency removed")))
+}
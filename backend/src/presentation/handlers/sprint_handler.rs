@@ -0,0 +1,256 @@
+use axum::{
+    extract::{Path, Query, State},
+    Extension, Json,
+};
+use serde::Deserialize;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::application::commands::{CreateSprintCommand, UpdateSprintCommand};
+use crate::application::services::{SprintAppService, VelocityReport};
+use crate::domain::entities::{Sprint, Task};
+use crate::domain::services::BurndownPoint;
+use crate::domain::value_objects::UserRole;
+use crate::presentation::dto::ApiResponse;
+use crate::presentation::middleware::AuthUser;
+use crate::shared::DomainError;
+
+#[derive(Debug, Deserialize)]
+pub struct CloseSprintQuery {
+    /// Planned sprint that receives unfinished tasks; defaults to the next planned sprint
+    pub rollover_to: Option<Uuid>,
+}
+
+pub async fn list_sprints(
+    State(service): State<Arc<SprintAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<Vec<Sprint>>>, DomainError> {
+    // Check access permission (admin can access all)
+    if auth_user.role != UserRole::Admin && !service.can_view_project(id, auth_user.id).await? {
+        return Err(DomainError::Forbidden(
+            "You don't have access to this project".into(),
+        ));
+    }
+
+    let sprints = service.list_sprints(id).await?;
+    Ok(Json(ApiResponse::success(sprints)))
+}
+
+pub async fn create_sprint(
+    State(service): State<Arc<SprintAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+    Json(cmd): Json<CreateSprintCommand>,
+) -> Result<Json<ApiResponse<Sprint>>, DomainError> {
+    // Check access permission (admin can access all)
+    if auth_user.role != UserRole::Admin && !service.can_access_project(id, auth_user.id).await? {
+        return Err(DomainError::Forbidden(
+            "You don't have access to this project".into(),
+        ));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        project_id = %id,
+        sprint_name = %cmd.name,
+        "User creating sprint"
+    );
+    let sprint = service.create_sprint(id, cmd).await?;
+    Ok(Json(ApiResponse::success(sprint)))
+}
+
+pub async fn get_sprint(
+    State(service): State<Arc<SprintAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<Sprint>>, DomainError> {
+    // Check access permission (admin can access all)
+    if auth_user.role != UserRole::Admin && !service.can_view_sprint(id, auth_user.id).await? {
+        return Err(DomainError::Forbidden(
+            "You don't have access to this sprint".into(),
+        ));
+    }
+
+    let sprint = service.get_sprint(id).await?;
+    Ok(Json(ApiResponse::success(sprint)))
+}
+
+pub async fn update_sprint(
+    State(service): State<Arc<SprintAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+    Json(cmd): Json<UpdateSprintCommand>,
+) -> Result<Json<ApiResponse<Sprint>>, DomainError> {
+    // Check access permission (admin can access all)
+    if auth_user.role != UserRole::Admin && !service.can_access_sprint(id, auth_user.id).await? {
+        return Err(DomainError::Forbidden(
+            "You don't have access to this sprint".into(),
+        ));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        sprint_id = %id,
+        "User updating sprint"
+    );
+    let sprint = service.update_sprint(id, cmd).await?;
+    Ok(Json(ApiResponse::success(sprint)))
+}
+
+pub async fn delete_sprint(
+    State(service): State<Arc<SprintAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<()>>, DomainError> {
+    // Check access permission (admin can access all)
+    if auth_user.role != UserRole::Admin && !service.can_access_sprint(id, auth_user.id).await? {
+        return Err(DomainError::Forbidden(
+            "You don't have access to this sprint".into(),
+        ));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        sprint_id = %id,
+        "User deleting sprint"
+    );
+    service.delete_sprint(id).await?;
+    Ok(Json(ApiResponse::ok("Sprint deleted")))
+}
+
+pub async fn start_sprint(
+    State(service): State<Arc<SprintAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<Sprint>>, DomainError> {
+    // Check access permission (admin can access all)
+    if auth_user.role != UserRole::Admin && !service.can_access_sprint(id, auth_user.id).await? {
+        return Err(DomainError::Forbidden(
+            "You don't have access to this sprint".into(),
+        ));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        sprint_id = %id,
+        "User starting sprint"
+    );
+    let sprint = service.start_sprint(id).await?;
+    Ok(Json(ApiResponse::success(sprint)))
+}
+
+pub async fn close_sprint(
+    State(service): State<Arc<SprintAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+    Query(query): Query<CloseSprintQuery>,
+) -> Result<Json<ApiResponse<Sprint>>, DomainError> {
+    // Check access permission (admin can access all)
+    if auth_user.role != UserRole::Admin && !service.can_access_sprint(id, auth_user.id).await? {
+        return Err(DomainError::Forbidden(
+            "You don't have access to this sprint".into(),
+        ));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        sprint_id = %id,
+        rollover_to = ?query.rollover_to,
+        "User closing sprint"
+    );
+    let sprint = service.close_sprint(id, query.rollover_to).await?;
+    Ok(Json(ApiResponse::success(sprint)))
+}
+
+pub async fn get_sprint_tasks(
+    State(service): State<Arc<SprintAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<Vec<Task>>>, DomainError> {
+    // Check access permission (admin can access all)
+    if auth_user.role != UserRole::Admin && !service.can_view_sprint(id, auth_user.id).await? {
+        return Err(DomainError::Forbidden(
+            "You don't have access to this sprint".into(),
+        ));
+    }
+
+    let tasks = service.get_sprint_tasks(id).await?;
+    Ok(Json(ApiResponse::success(tasks)))
+}
+
+pub async fn add_sprint_task(
+    State(service): State<Arc<SprintAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path((id, task_id)): Path<(Uuid, Uuid)>,
+) -> Result<Json<ApiResponse<()>>, DomainError> {
+    // Check access permission (admin can access all)
+    if auth_user.role != UserRole::Admin && !service.can_access_sprint(id, auth_user.id).await? {
+        return Err(DomainError::Forbidden(
+            "You don't have access to this sprint".into(),
+        ));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        sprint_id = %id,
+        task_id = %task_id,
+        "User adding task to sprint"
+    );
+    service.add_task(id, task_id).await?;
+    Ok(Json(ApiResponse::ok("Task added to sprint")))
+}
+
+pub async fn remove_sprint_task(
+    State(service): State<Arc<SprintAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path((id, task_id)): Path<(Uuid, Uuid)>,
+) -> Result<Json<ApiResponse<()>>, DomainError> {
+    // Check access permission (admin can access all)
+    if auth_user.role != UserRole::Admin && !service.can_access_sprint(id, auth_user.id).await? {
+        return Err(DomainError::Forbidden(
+            "You don't have access to this sprint".into(),
+        ));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        sprint_id = %id,
+        task_id = %task_id,
+        "User removing task from sprint"
+    );
+    service.remove_task(id, task_id).await?;
+    Ok(Json(ApiResponse::ok("Task moved to backlog")))
+}
+
+pub async fn get_burndown(
+    State(service): State<Arc<SprintAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<Vec<BurndownPoint>>>, DomainError> {
+    // Check access permission (admin can access all)
+    if auth_user.role != UserRole::Admin && !service.can_view_sprint(id, auth_user.id).await? {
+        return Err(DomainError::Forbidden(
+            "You don't have access to this sprint".into(),
+        ));
+    }
+
+    let burndown = service.get_burndown(id).await?;
+    Ok(Json(ApiResponse::success(burndown)))
+}
+
+pub async fn get_velocity(
+    State(service): State<Arc<SprintAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<VelocityReport>>, DomainError> {
+    // Check access permission (admin can access all)
+    if auth_user.role != UserRole::Admin && !service.can_view_project(id, auth_user.id).await? {
+        return Err(DomainError::Forbidden(
+            "You don't have access to this project".into(),
+        ));
+    }
+
+    let velocity = service.get_velocity(id).await?;
+    Ok(Json(ApiResponse::success(velocity)))
+}
@@ -0,0 +1,111 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
+    Extension, Json,
+};
+use serde::Deserialize;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::application::commands::CreateStatusReportCommand;
+use crate::application::services::StatusReportAppService;
+use crate::domain::entities::StatusReport;
+use crate::domain::services::StatusReportRenderer;
+use crate::domain::value_objects::UserRole;
+use crate::presentation::dto::ApiResponse;
+use crate::presentation::middleware::AuthUser;
+use crate::shared::DomainError;
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ReportFormat {
+    #[default]
+    Json,
+    Markdown,
+    Html,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ReportFormatQuery {
+    #[serde(default)]
+    pub format: ReportFormat,
+}
+
+pub async fn list_reports(
+    State(service): State<Arc<StatusReportAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<Vec<StatusReport>>>, DomainError> {
+    // Check view permission (admin can access all)
+    if auth_user.role != UserRole::Admin && !service.can_view_project(id, auth_user.id).await? {
+        return Err(DomainError::Forbidden(
+            "You don't have access to this project".into(),
+        ));
+    }
+
+    let reports = service.list_reports(id).await?;
+    Ok(Json(ApiResponse::success(reports)))
+}
+
+pub async fn create_report(
+    State(service): State<Arc<StatusReportAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+    Json(cmd): Json<CreateStatusReportCommand>,
+) -> Result<Json<ApiResponse<StatusReport>>, DomainError> {
+    // Only project owner or admin can publish reports
+    if auth_user.role != UserRole::Admin && !service.is_project_owner(id, auth_user.id).await? {
+        return Err(DomainError::Forbidden(
+            "Only project owner can create status reports".into(),
+        ));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        project_id = %id,
+        period_start = %cmd.period_start,
+        period_end = %cmd.period_end,
+        "User creating status report"
+    );
+    let report = service.create_report(id, auth_user.id, cmd).await?;
+    Ok(Json(ApiResponse::success(report)))
+}
+
+pub async fn get_report(
+    State(service): State<Arc<StatusReportAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+    Query(query): Query<ReportFormatQuery>,
+) -> Result<Response, DomainError> {
+    let report = service.get_report(id).await?;
+
+    // Check view permission (admin can access all)
+    if auth_user.role != UserRole::Admin
+        && !service
+            .can_view_project(report.project_id, auth_user.id)
+            .await?
+    {
+        return Err(DomainError::Forbidden(
+            "You don't have access to this project".into(),
+        ));
+    }
+
+    let (content_type, body) = match query.format {
+        ReportFormat::Json => return Ok(Json(ApiResponse::success(report)).into_response()),
+        ReportFormat::Markdown => (
+            "text/markdown; charset=utf-8",
+            StatusReportRenderer::markdown(&report),
+        ),
+        ReportFormat::Html => (
+            "text/html; charset=utf-8",
+            StatusReportRenderer::html(&report),
+        ),
+    };
+
+    Ok(Response::builder()
+        .status(StatusCode::OK)
+        .header(header::CONTENT_TYPE, content_type)
+        .body(body.into())
+        .unwrap())
+}
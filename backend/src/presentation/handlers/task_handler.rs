@@ -1,27 +1,43 @@
 use axum::{
-    extract::{Path, State},
+    extract::{Path, Query, State},
     Extension, Json,
 };
+use serde::Deserialize;
 use std::sync::Arc;
 use uuid::Uuid;
 
 use crate::application::commands::{CreateTaskCommand, UpdateTaskCommand};
-use crate::application::services::TaskAppService;
-use crate::domain::entities::Task;
+use crate::application::services::{TaskAppService, TaskHistoryEntry, WithMentions};
+use crate::domain::entities::{ActualHoursDrift, Task, Watcher};
 use crate::domain::value_objects::UserRole;
 use crate::presentation::dto::ApiResponse;
 use crate::presentation::middleware::AuthUser;
 use crate::shared::DomainError;
 
+#[derive(Debug, Deserialize)]
+pub struct ListTasksQuery {
+    #[serde(default)]
+    pub include_archived: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ReconcileActualHoursQuery {
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
 pub async fn list_tasks(
     State(service): State<Arc<TaskAppService>>,
     Extension(auth_user): Extension<AuthUser>,
+    Query(query): Query<ListTasksQuery>,
 ) -> Result<Json<ApiResponse<Vec<Task>>>, DomainError> {
     // Admin can see all tasks, others only see tasks from accessible projects
     let tasks = if auth_user.role == UserRole::Admin {
-        service.list_tasks().await?
+        service.list_tasks(query.include_archived).await?
     } else {
-        service.list_accessible_tasks(auth_user.id).await?
+        service
+            .list_accessible_tasks(auth_user.id, query.include_archived)
+            .await?
     };
     Ok(Json(ApiResponse::success(tasks)))
 }
@@ -32,7 +48,7 @@ pub async fn get_task(
     Path(id): Path<Uuid>,
 ) -> Result<Json<ApiResponse<Task>>, DomainError> {
     // Check access permission (admin can access all)
-    if auth_user.role != UserRole::Admin && !service.can_user_access(id, auth_user.id).await? {
+    if auth_user.role != UserRole::Admin && !service.can_user_view(id, auth_user.id).await? {
         return Err(DomainError::Forbidden(
             "You don't have access to this task".into(),
         ));
@@ -45,7 +61,7 @@ pub async fn create_task(
     State(service): State<Arc<TaskAppService>>,
     Extension(auth_user): Extension<AuthUser>,
     Json(cmd): Json<CreateTaskCommand>,
-) -> Result<Json<ApiResponse<Task>>, DomainError> {
+) -> Result<Json<ApiResponse<WithMentions<Task>>>, DomainError> {
     // Check access to project (admin can access all)
     if auth_user.role != UserRole::Admin
         && !service
@@ -62,7 +78,7 @@ pub async fn create_task(
         project_id = %cmd.project_id,
         "User creating new task"
     );
-    let task = service.create_task(cmd).await?;
+    let task = service.create_task(cmd, auth_user.id).await?;
     Ok(Json(ApiResponse::success(task)))
 }
 
@@ -71,7 +87,7 @@ pub async fn update_task(
     Extension(auth_user): Extension<AuthUser>,
     Path(id): Path<Uuid>,
     Json(cmd): Json<UpdateTaskCommand>,
-) -> Result<Json<ApiResponse<Task>>, DomainError> {
+) -> Result<Json<ApiResponse<WithMentions<Task>>>, DomainError> {
     // Check access permission (admin can access all)
     if auth_user.role != UserRole::Admin && !service.can_user_access(id, auth_user.id).await? {
         return Err(DomainError::Forbidden(
@@ -84,7 +100,7 @@ pub async fn update_task(
         task_id = %id,
         "User updating task"
     );
-    let task = service.update_task(id, cmd).await?;
+    let task = service.update_task(id, cmd, auth_user.id).await?;
     Ok(Json(ApiResponse::success(task)))
 }
 
@@ -105,6 +121,106 @@ pub async fn delete_task(
         task_id = %id,
         "User deleting task"
     );
-    service.delete_task(id).await?;
-    Ok(Json(ApiResponse::ok("Task deleted successfully")))
+    service.delete_task(id, auth_user.id).await?;
+    Ok(Json(ApiResponse::ok("Task moved to trash")))
+}
+
+pub async fn get_task_history(
+    State(service): State<Arc<TaskAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<Vec<TaskHistoryEntry>>>, DomainError> {
+    // Check access permission (admin can access all)
+    if auth_user.role != UserRole::Admin && !service.can_user_view(id, auth_user.id).await? {
+        return Err(DomainError::Forbidden(
+            "You don't have access to this task".into(),
+        ));
+    }
+    let history = service.get_task_history(id).await?;
+    Ok(Json(ApiResponse::success(history)))
+}
+
+pub async fn restore_task_version(
+    State(service): State<Arc<TaskAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path((id, history_id)): Path<(Uuid, Uuid)>,
+) -> Result<Json<ApiResponse<Task>>, DomainError> {
+    // Only admin can restore previous versions
+    if auth_user.role != UserRole::Admin {
+        return Err(DomainError::Forbidden(
+            "Only admin can restore task versions".into(),
+        ));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        task_id = %id,
+        history_id = %history_id,
+        "Admin restoring task version"
+    );
+    let task = service
+        .restore_task_version(id, history_id, auth_user.id)
+        .await?;
+    Ok(Json(ApiResponse::success(task)))
+}
+
+pub async fn get_task_watchers(
+    State(service): State<Arc<TaskAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<Vec<Watcher>>>, DomainError> {
+    // Check access permission (admin can access all)
+    if auth_user.role != UserRole::Admin && !service.can_user_view(id, auth_user.id).await? {
+        return Err(DomainError::Forbidden(
+            "You don't have access to this task".into(),
+        ));
+    }
+    let watchers = service.get_task_watchers(id).await?;
+    Ok(Json(ApiResponse::success(watchers)))
+}
+
+pub async fn watch_task(
+    State(service): State<Arc<TaskAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<()>>, DomainError> {
+    // Check access permission (admin can access all)
+    if auth_user.role != UserRole::Admin && !service.can_user_access(id, auth_user.id).await? {
+        return Err(DomainError::Forbidden(
+            "You don't have access to this task".into(),
+        ));
+    }
+    service.get_task(id).await?;
+    service.watch_task(id, auth_user.id).await?;
+    Ok(Json(ApiResponse::ok("Now watching task")))
+}
+
+pub async fn unwatch_task(
+    State(service): State<Arc<TaskAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<()>>, DomainError> {
+    service.unwatch_task(id, auth_user.id).await?;
+    Ok(Json(ApiResponse::ok("Stopped watching task")))
+}
+
+pub async fn reconcile_actual_hours(
+    State(service): State<Arc<TaskAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Query(query): Query<ReconcileActualHoursQuery>,
+) -> Result<Json<ApiResponse<Vec<ActualHoursDrift>>>, DomainError> {
+    // Only admin can reconcile actual hours
+    if auth_user.role != UserRole::Admin {
+        return Err(DomainError::Forbidden(
+            "Only admin can reconcile actual hours".into(),
+        ));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        dry_run = query.dry_run,
+        "Admin reconciling task actual hours"
+    );
+    let drift = service.reconcile_actual_hours(query.dry_run).await?;
+    Ok(Json(ApiResponse::success(drift)))
 }
@@ -5,7 +5,9 @@ use axum::{
 use std::sync::Arc;
 use uuid::Uuid;
 
-use crate::application::commands::{AddTeamMemberCommand, CreateTeamCommand, UpdateTeamCommand};
+use crate::application::commands::{
+    AddTeamMemberCommand, CreateTeamCommand, UpdateTeamCommand, UpdateTeamMemberCommand,
+};
 use crate::application::services::TeamAppService;
 use crate::domain::entities::{Team, TeamMember};
 use crate::domain::value_objects::UserRole;
@@ -134,3 +136,54 @@ pub async fn add_team_member(
     let member = service.add_team_member(team_id, cmd).await?;
     Ok(Json(ApiResponse::success(member)))
 }
+
+/// PATCH /teams/:id/members/:user_id - Change a member's role or transfer leadership
+pub async fn update_team_member(
+    State(service): State<Arc<TeamAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path((team_id, user_id)): Path<(Uuid, Uuid)>,
+    Json(cmd): Json<UpdateTeamMemberCommand>,
+) -> Result<Json<ApiResponse<TeamMember>>, DomainError> {
+    // Only lead or admin can change member roles
+    if auth_user.role != UserRole::Admin && !service.is_lead(team_id, auth_user.id).await? {
+        return Err(DomainError::Forbidden(
+            "Only team lead can change member roles".into(),
+        ));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        team_id = %team_id,
+        member_id = %user_id,
+        role = ?cmd.role,
+        transfer = cmd.transfer,
+        "User updating team member role"
+    );
+    let member = service.update_team_member(team_id, user_id, cmd).await?;
+    Ok(Json(ApiResponse::success(member)))
+}
+
+/// DELETE /teams/:id/members/:user_id - Remove a member (lead, admin, or the member leaving)
+pub async fn remove_team_member(
+    State(service): State<Arc<TeamAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path((team_id, user_id)): Path<(Uuid, Uuid)>,
+) -> Result<Json<ApiResponse<()>>, DomainError> {
+    if auth_user.role != UserRole::Admin
+        && auth_user.id != user_id
+        && !service.is_lead(team_id, auth_user.id).await?
+    {
+        return Err(DomainError::Forbidden(
+            "Only team lead can remove members".into(),
+        ));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        team_id = %team_id,
+        member_id = %user_id,
+        "User removing team member"
+    );
+    service.remove_team_member(team_id, user_id).await?;
+    Ok(Json(ApiResponse::ok("Team member removed successfully")))
+}
@@ -1,15 +1,22 @@
 use axum::{
+    body::Body,
     extract::{Path, Query, State},
+    http::{header, StatusCode},
+    response::Response,
     Extension, Json,
 };
 use chrono::NaiveDate;
 use serde::Deserialize;
 use std::sync::Arc;
+use tokio_util::io::ReaderStream;
 use uuid::Uuid;
 
 use crate::application::services::{CreateTimeLogDto, TimeLogAppService, UpdateTimeLogDto};
 use crate::domain::entities::TimeLog;
+use crate::domain::repositories::TimeLogFilter;
+use crate::domain::value_objects::UserRole;
 use crate::presentation::dto::ApiResponse;
+use crate::presentation::formats::{ExportFormat, TimeLogExporter};
 use crate::presentation::middleware::AuthUser;
 use crate::shared::DomainError;
 
@@ -19,12 +26,19 @@ pub struct ListTimeLogsQuery {
     pub end_date: Option<NaiveDate>,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct ExportFormatQuery {
+    #[serde(default)]
+    pub format: ExportFormat,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct CreateTimeLogRequest {
     pub task_id: Uuid,
     pub hours: f32,
     pub date: NaiveDate,
     pub description: Option<String>,
+    pub billable: Option<bool>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -32,6 +46,7 @@ pub struct UpdateTimeLogRequest {
     pub hours: Option<f32>,
     pub date: Option<NaiveDate>,
     pub description: Option<String>,
+    pub billable: Option<bool>,
 }
 
 /// GET /time-logs - List current user's time logs
@@ -79,18 +94,83 @@ pub async fn list_user_time_logs(
     Ok(Json(ApiResponse::success(time_logs)))
 }
 
+/// GET /time-logs/export - Download time logs as CSV, XLSX or iCalendar
+///
+/// Admins may export anyone's time; other users may export a project they
+/// can view, otherwise only their own logs.
+pub async fn export_time_logs(
+    State(service): State<Arc<TimeLogAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Query(mut filter): Query<TimeLogFilter>,
+    Query(query): Query<ExportFormatQuery>,
+) -> Result<Response, DomainError> {
+    if auth_user.role != UserRole::Admin {
+        match filter.project_id {
+            Some(project_id) => {
+                if !service.can_view_project(project_id, auth_user.id).await? {
+                    return Err(DomainError::Forbidden(
+                        "You don't have access to this project".into(),
+                    ));
+                }
+            }
+            None => match filter.user_id {
+                Some(user_id) if user_id != auth_user.id => {
+                    return Err(DomainError::Forbidden(
+                        "You can only export your own time logs".into(),
+                    ));
+                }
+                _ => filter.user_id = Some(auth_user.id),
+            },
+        }
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        format = query.format.extension(),
+        "User exporting time logs"
+    );
+    let reader = service
+        .export_time_logs(filter, Box::new(TimeLogExporter::new(query.format)))
+        .await?;
+
+    Ok(Response::builder()
+        .status(StatusCode::OK)
+        .header(header::CONTENT_TYPE, query.format.content_type())
+        .header(
+            header::CONTENT_DISPOSITION,
+            format!(
+                "attachment; filename=\"time-logs.{}\"",
+                query.format.extension()
+            ),
+        )
+        .body(Body::from_stream(ReaderStream::new(reader)))
+        .unwrap())
+}
+
 /// POST /time-logs - Create a new time log
 pub async fn create_time_log(
     State(service): State<Arc<TimeLogAppService>>,
     Extension(auth_user): Extension<AuthUser>,
     Json(payload): Json<CreateTimeLogRequest>,
 ) -> Result<Json<ApiResponse<TimeLog>>, DomainError> {
+    // Check access to the task's project (admin can access all)
+    if auth_user.role != UserRole::Admin
+        && !service
+            .can_access_task(payload.task_id, auth_user.id)
+            .await?
+    {
+        return Err(DomainError::Forbidden(
+            "You don't have access to this task".into(),
+        ));
+    }
+
     let dto = CreateTimeLogDto {
         task_id: payload.task_id,
         user_id: auth_user.id,
         hours: payload.hours,
         date: payload.date,
         description: payload.description,
+        billable: payload.billable,
     };
 
     let time_log = service.create_time_log(dto).await?;
@@ -100,13 +180,17 @@ pub async fn create_time_log(
 /// PUT /time-logs/:id - Update a time log
 pub async fn update_time_log(
     State(service): State<Arc<TimeLogAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
     Path(id): Path<Uuid>,
     Json(payload): Json<UpdateTimeLogRequest>,
 ) -> Result<Json<ApiResponse<TimeLog>>, DomainError> {
+    ensure_own_time_log(&service, id, &auth_user).await?;
+
     let dto = UpdateTimeLogDto {
         hours: payload.hours,
         date: payload.date,
         description: payload.description,
+        billable: payload.billable,
     };
 
     let time_log = service.update_time_log(id, dto).await?;
@@ -116,8 +200,29 @@ pub async fn update_time_log(
 /// DELETE /time-logs/:id - Delete a time log
 pub async fn delete_time_log(
     State(service): State<Arc<TimeLogAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
     Path(id): Path<Uuid>,
 ) -> Result<Json<ApiResponse<()>>, DomainError> {
+    ensure_own_time_log(&service, id, &auth_user).await?;
+
     service.delete_time_log(id).await?;
     Ok(Json(ApiResponse::success(())))
 }
+
+/// Only the author of a time log can change it (admin can change all)
+async fn ensure_own_time_log(
+    service: &TimeLogAppService,
+    id: Uuid,
+    auth_user: &AuthUser,
+) -> Result<(), DomainError> {
+    let time_log = service
+        .get_time_log(id)
+        .await?
+        .ok_or_else(|| DomainError::NotFound(format!("Time log {} not found", id)))?;
+    if auth_user.role != UserRole::Admin && time_log.user_id != auth_user.id {
+        return Err(DomainError::Forbidden(
+            "You can only change your own time logs".into(),
+        ));
+    }
+    Ok(())
+}
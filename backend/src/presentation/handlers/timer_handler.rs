@@ -0,0 +1,69 @@
+use axum::{extract::State, Extension, Json};
+use std::sync::Arc;
+
+use crate::application::commands::{StartTimerCommand, StopTimerCommand};
+use crate::application::services::{TimerAppService, TimerState};
+use crate::domain::entities::TimeLog;
+use crate::presentation::dto::ApiResponse;
+use crate::presentation::middleware::AuthUser;
+use crate::shared::DomainError;
+
+/// GET /timer - Current user's active timer, if any
+pub async fn get_current_timer(
+    State(service): State<Arc<TimerAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+) -> Result<Json<ApiResponse<Option<TimerState>>>, DomainError> {
+    let timer = service.get_current(auth_user.id).await?;
+    Ok(Json(ApiResponse::success(timer)))
+}
+
+/// POST /timer/start - Start a timer on a task
+pub async fn start_timer(
+    State(service): State<Arc<TimerAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Json(cmd): Json<StartTimerCommand>,
+) -> Result<Json<ApiResponse<TimerState>>, DomainError> {
+    tracing::info!(user_id = %auth_user.id, task_id = %cmd.task_id, "User starting timer");
+    let timer = service.start_timer(auth_user.id, cmd).await?;
+    Ok(Json(ApiResponse::success(timer)))
+}
+
+/// POST /timer/pause - Pause the active timer
+pub async fn pause_timer(
+    State(service): State<Arc<TimerAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+) -> Result<Json<ApiResponse<TimerState>>, DomainError> {
+    let timer = service.pause_timer(auth_user.id).await?;
+    Ok(Json(ApiResponse::success(timer)))
+}
+
+/// POST /timer/resume - Resume a paused timer
+pub async fn resume_timer(
+    State(service): State<Arc<TimerAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+) -> Result<Json<ApiResponse<TimerState>>, DomainError> {
+    let timer = service.resume_timer(auth_user.id).await?;
+    Ok(Json(ApiResponse::success(timer)))
+}
+
+/// POST /timer/stop - Stop the active timer and log its time
+pub async fn stop_timer(
+    State(service): State<Arc<TimerAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    cmd: Option<Json<StopTimerCommand>>,
+) -> Result<Json<ApiResponse<TimeLog>>, DomainError> {
+    let cmd = cmd.map(|Json(cmd)| cmd).unwrap_or_default();
+    tracing::info!(user_id = %auth_user.id, "User stopping timer");
+    let time_log = service.stop_timer(auth_user.id, cmd).await?;
+    Ok(Json(ApiResponse::success(time_log)))
+}
+
+/// DELETE /timer - Discard the active timer without logging time
+pub async fn discard_timer(
+    State(service): State<Arc<TimerAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+) -> Result<Json<ApiResponse<()>>, DomainError> {
+    tracing::info!(user_id = %auth_user.id, "User discarding timer");
+    service.discard_timer(auth_user.id).await?;
+    Ok(Json(ApiResponse::ok("Timer discarded")))
+}
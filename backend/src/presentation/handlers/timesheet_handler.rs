@@ -0,0 +1,131 @@
+use axum::{
+    extract::{Path, Query, State},
+    Extension, Json,
+};
+use chrono::NaiveDate;
+use serde::Deserialize;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::application::commands::{DecideTimesheetCommand, SubmitTimesheetCommand};
+use crate::application::services::{TimesheetAppService, TimesheetWeek};
+use crate::domain::entities::Timesheet;
+use crate::presentation::dto::ApiResponse;
+use crate::presentation::middleware::AuthUser;
+use crate::shared::DomainError;
+
+#[derive(Debug, Deserialize)]
+pub struct TimesheetWeekQuery {
+    /// Any date within the week; defaults to today
+    pub date: Option<NaiveDate>,
+}
+
+/// GET /timesheets - List current user's timesheets
+pub async fn list_my_timesheets(
+    State(service): State<Arc<TimesheetAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+) -> Result<Json<ApiResponse<Vec<Timesheet>>>, DomainError> {
+    let timesheets = service.list_user_timesheets(auth_user.id).await?;
+    Ok(Json(ApiResponse::success(timesheets)))
+}
+
+/// GET /timesheets/week - Current user's timesheet for a week
+pub async fn get_my_week(
+    State(service): State<Arc<TimesheetAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Query(query): Query<TimesheetWeekQuery>,
+) -> Result<Json<ApiResponse<TimesheetWeek>>, DomainError> {
+    let week = service.get_week(auth_user.id, query.date).await?;
+    Ok(Json(ApiResponse::success(week)))
+}
+
+/// GET /timesheets/pending - Submitted timesheets the current user can approve
+pub async fn list_pending(
+    State(service): State<Arc<TimesheetAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+) -> Result<Json<ApiResponse<Vec<Timesheet>>>, DomainError> {
+    let timesheets = service.list_pending(auth_user.id, &auth_user.role).await?;
+    Ok(Json(ApiResponse::success(timesheets)))
+}
+
+/// POST /timesheets/submit - Submit a week of the current user's time for approval
+pub async fn submit_week(
+    State(service): State<Arc<TimesheetAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Json(cmd): Json<SubmitTimesheetCommand>,
+) -> Result<Json<ApiResponse<TimesheetWeek>>, DomainError> {
+    tracing::info!(
+        user_id = %auth_user.id,
+        week = %cmd.week_start,
+        "User submitting timesheet"
+    );
+    let week = service.submit_week(auth_user.id, cmd.week_start).await?;
+    Ok(Json(ApiResponse::success(week)))
+}
+
+/// GET /timesheets/:id - Timesheet with its time logs (owner or approver)
+pub async fn get_timesheet(
+    State(service): State<Arc<TimesheetAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<TimesheetWeek>>, DomainError> {
+    let week = service.get_timesheet_week(id).await?;
+
+    if week.timesheet.user_id != auth_user.id
+        && !service
+            .can_approve(auth_user.id, &auth_user.role, week.timesheet.user_id)
+            .await?
+    {
+        return Err(DomainError::Forbidden(
+            "You don't have access to this timesheet".into(),
+        ));
+    }
+
+    Ok(Json(ApiResponse::success(week)))
+}
+
+/// POST /timesheets/:id/approve - Approve a submitted timesheet
+pub async fn approve_timesheet(
+    State(service): State<Arc<TimesheetAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+    Json(cmd): Json<DecideTimesheetCommand>,
+) -> Result<Json<ApiResponse<Timesheet>>, DomainError> {
+    ensure_approver(&service, id, &auth_user).await?;
+
+    tracing::info!(user_id = %auth_user.id, timesheet_id = %id, "User approving timesheet");
+    let timesheet = service.approve_timesheet(id, auth_user.id, cmd).await?;
+    Ok(Json(ApiResponse::success(timesheet)))
+}
+
+/// POST /timesheets/:id/reject - Send a submitted timesheet back with a comment
+pub async fn reject_timesheet(
+    State(service): State<Arc<TimesheetAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+    Json(cmd): Json<DecideTimesheetCommand>,
+) -> Result<Json<ApiResponse<Timesheet>>, DomainError> {
+    ensure_approver(&service, id, &auth_user).await?;
+
+    tracing::info!(user_id = %auth_user.id, timesheet_id = %id, "User rejecting timesheet");
+    let timesheet = service.reject_timesheet(id, auth_user.id, cmd).await?;
+    Ok(Json(ApiResponse::success(timesheet)))
+}
+
+/// Managers, admins and the owner's team leads approve timesheets, never the owner
+async fn ensure_approver(
+    service: &TimesheetAppService,
+    id: Uuid,
+    auth_user: &AuthUser,
+) -> Result<(), DomainError> {
+    let timesheet = service.get_timesheet(id).await?;
+    if !service
+        .can_approve(auth_user.id, &auth_user.role, timesheet.user_id)
+        .await?
+    {
+        return Err(DomainError::Forbidden(
+            "Only a manager or the user's team lead can decide on this timesheet".into(),
+        ));
+    }
+    Ok(())
+}
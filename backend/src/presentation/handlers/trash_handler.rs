@@ -0,0 +1,85 @@
+use axum::{
+    extract::{Path, State},
+    Extension, Json,
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::application::services::{TrashAppService, TrashContents};
+use crate::domain::entities::{Project, Task};
+use crate::domain::value_objects::UserRole;
+use crate::presentation::dto::ApiResponse;
+use crate::presentation::middleware::AuthUser;
+use crate::shared::DomainError;
+
+pub async fn get_my_trash(
+    State(service): State<Arc<TrashAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+) -> Result<Json<ApiResponse<TrashContents>>, DomainError> {
+    let trash = service.get_user_trash(auth_user.id).await?;
+    Ok(Json(ApiResponse::success(trash)))
+}
+
+pub async fn get_project_trash(
+    State(service): State<Arc<TrashAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<Vec<Task>>>, DomainError> {
+    // Check access permission (admin can access all)
+    if auth_user.role != UserRole::Admin && !service.can_user_access(id, auth_user.id).await? {
+        return Err(DomainError::Forbidden(
+            "You don't have access to this project".into(),
+        ));
+    }
+
+    let tasks = service.get_project_trash(id).await?;
+    Ok(Json(ApiResponse::success(tasks)))
+}
+
+pub async fn restore_project(
+    State(service): State<Arc<TrashAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<Project>>, DomainError> {
+    // Only owner or admin can restore project
+    let project = service.get_deleted_project(id).await?;
+    if auth_user.role != UserRole::Admin && project.owner_id != auth_user.id {
+        return Err(DomainError::Forbidden(
+            "Only project owner can restore this project".into(),
+        ));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        project_id = %id,
+        "User restoring project from trash"
+    );
+    let project = service.restore_project(id).await?;
+    Ok(Json(ApiResponse::success(project)))
+}
+
+pub async fn restore_task(
+    State(service): State<Arc<TrashAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<Task>>, DomainError> {
+    // Only project owner or admin can restore tasks
+    let task = service.get_deleted_task(id).await?;
+    if auth_user.role != UserRole::Admin
+        && !service
+            .is_project_owner(task.project_id, auth_user.id)
+            .await?
+    {
+        return Err(DomainError::Forbidden(
+            "Only project owner can restore tasks".into(),
+        ));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        task_id = %id,
+        "User restoring task from trash"
+    );
+    let task = service.restore_task(id).await?;
+    Ok(Json(ApiResponse::success(task)))
+}
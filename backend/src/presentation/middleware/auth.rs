@@ -8,12 +8,22 @@ use jsonwebtoken::{decode, DecodingKey, Validation};
 use uuid::Uuid;
 
 use crate::application::services::Claims;
+use crate::domain::value_objects::UserRole;
+use crate::shared::DomainError;
 
 #[derive(Clone, Debug)]
 pub struct AuthUser {
     pub id: Uuid,
     pub email: String,
-    pub role: crate::domain::value_objects::UserRole,
+    pub role: UserRole,
+}
+
+/// Reject non-admins, naming what they tried to do, e.g. "manage billing"
+pub fn ensure_admin(auth_user: &AuthUser, action: &str) -> Result<(), DomainError> {
+    if auth_user.role != UserRole::Admin {
+        return Err(DomainError::Forbidden(format!("Only admin can {}", action)));
+    }
+    Ok(())
 }
 
 pub async fn auth_middleware(mut request: Request, next: Next) -> Result<Response, StatusCode> {
@@ -1,3 +1,3 @@
 mod auth;
 
-pub use auth::{auth_middleware, AuthUser};
+pub use auth::{auth_middleware, ensure_admin, AuthUser};
@@ -1,3 +1,4 @@
 pub mod dto;
+pub mod formats;
 pub mod handlers;
 pub mod middleware;
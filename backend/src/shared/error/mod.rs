@@ -6,11 +6,32 @@ use axum::{
 use serde::Serialize;
 use std::fmt;
 
+/// One broken rule of a rejected request, tied to the field that broke it
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FieldError {
+    pub field: &'static str,
+    pub code: &'static str,
+    pub message: String,
+}
+
+impl FieldError {
+    pub fn new(field: &'static str, code: &'static str, message: impl Into<String>) -> Self {
+        Self {
+            field,
+            code,
+            message: message.into(),
+        }
+    }
+}
+
 #[derive(Debug)]
 pub enum DomainError {
     ValidationError(String),
+    /// Validation failures reported field by field
+    InvalidFields(Vec<FieldError>),
     NotFound(String),
     AlreadyExists(String),
+    Conflict(String),
     Unauthorized(String),
     Forbidden(String),
     InternalError(String),
@@ -21,8 +42,12 @@ impl fmt::Display for DomainError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::ValidationError(msg) => write!(f, "Validation error: {}", msg),
+            Self::InvalidFields(errors) => {
+                write!(f, "Validation error: {}", joined_messages(errors))
+            }
             Self::NotFound(msg) => write!(f, "Not found: {}", msg),
             Self::AlreadyExists(msg) => write!(f, "Already exists: {}", msg),
+            Self::Conflict(msg) => write!(f, "Conflict: {}", msg),
             Self::Unauthorized(msg) => write!(f, "Unauthorized: {}", msg),
             Self::Forbidden(msg) => write!(f, "Forbidden: {}", msg),
             Self::InternalError(msg) => write!(f, "Internal error: {}", msg),
@@ -39,6 +64,8 @@ pub struct ErrorResponse {
     pub message: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub errors: Option<Vec<FieldError>>,
 }
 
 impl IntoResponse for DomainError {
@@ -47,10 +74,16 @@ impl IntoResponse for DomainError {
             DomainError::ValidationError(msg) => {
                 (StatusCode::BAD_REQUEST, "VALIDATION_ERROR", msg.clone())
             }
+            DomainError::InvalidFields(errors) => (
+                StatusCode::BAD_REQUEST,
+                "VALIDATION_ERROR",
+                joined_messages(errors),
+            ),
             DomainError::NotFound(msg) => (StatusCode::NOT_FOUND, "NOT_FOUND", msg.clone()),
             DomainError::AlreadyExists(msg) => {
                 (StatusCode::CONFLICT, "ALREADY_EXISTS", msg.clone())
             }
+            DomainError::Conflict(msg) => (StatusCode::CONFLICT, "CONFLICT", msg.clone()),
             DomainError::Unauthorized(msg) => {
                 (StatusCode::UNAUTHORIZED, "UNAUTHORIZED", msg.clone())
             }
@@ -71,12 +104,24 @@ impl IntoResponse for DomainError {
             success: false,
             message,
             code: Some(code.to_string()),
+            errors: match self {
+                DomainError::InvalidFields(errors) => Some(errors),
+                _ => None,
+            },
         });
 
         (status, body).into_response()
     }
 }
 
+fn joined_messages(errors: &[FieldError]) -> String {
+    errors
+        .iter()
+        .map(|e| e.message.as_str())
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
 impl From<sqlx::Error> for DomainError {
     fn from(err: sqlx::Error) -> Self {
         match err {
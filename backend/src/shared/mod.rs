@@ -1,3 +1,6 @@
 pub mod error;
+/// Dates shared by unit tests
+#[cfg(test)]
+pub mod test_dates;
 
-pub use error::DomainError;
+pub use error::{DomainError, FieldError};
@@ -0,0 +1,11 @@
+use chrono::{DateTime, NaiveDate, Utc};
+
+/// A day of March 2024, which starts on a Friday
+pub fn date(day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(2024, 3, day).unwrap()
+}
+
+/// Noon UTC on a day of March 2024
+pub fn noon(day: u32) -> DateTime<Utc> {
+    date(day).and_hms_opt(12, 0, 0).unwrap().and_utc()
+}
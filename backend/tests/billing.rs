@@ -0,0 +1,49 @@
+mod common;
+
+use chrono::NaiveDate;
+
+use percival_backend::domain::entities::Invoice;
+use percival_backend::domain::repositories::InvoiceRepository;
+use percival_backend::infrastructure::persistence::PgInvoiceRepository;
+use percival_backend::shared::DomainError;
+
+#[tokio::test]
+#[ignore = "needs DATABASE_URL"]
+async fn test_time_edited_after_pricing_is_not_invoiced() {
+    let pool = common::pool().await;
+    let user = common::user(&pool, "member").await;
+    let project = common::project(&pool, user, None).await;
+    let task = common::task(&pool, project).await;
+    let date = NaiveDate::from_ymd_opt(2024, 3, 4).unwrap();
+    let log = common::time_log(&pool, task, user, 2.0, date).await;
+
+    let repository = PgInvoiceRepository::new(pool.clone());
+    let entries = repository
+        .find_billable_entries(date, date, Some(project))
+        .await
+        .unwrap();
+    assert_eq!(entries.len(), 1);
+
+    sqlx::query("UPDATE time_logs SET hours = 8 WHERE id = $1")
+        .bind(log)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let invoice = Invoice::new(Some(project), date, date, Vec::new(), user);
+    let result = repository.create(&invoice, &entries).await;
+    assert!(matches!(result, Err(DomainError::Conflict(_))));
+    assert!(repository.find_by_id(invoice.id).await.unwrap().is_none());
+
+    let entries = repository
+        .find_billable_entries(date, date, Some(project))
+        .await
+        .unwrap();
+    assert_eq!(entries[0].hours, 8.0);
+    repository.create(&invoice, &entries).await.unwrap();
+    assert!(repository
+        .find_billable_entries(date, date, Some(project))
+        .await
+        .unwrap()
+        .is_empty());
+}
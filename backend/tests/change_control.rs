@@ -0,0 +1,40 @@
+mod common;
+
+use rust_decimal::Decimal;
+
+use percival_backend::domain::entities::{ChangeRequest, ChangeSet};
+use percival_backend::domain::repositories::ChangeRequestRepository;
+use percival_backend::infrastructure::persistence::PgChangeRequestRepository;
+
+#[tokio::test]
+#[ignore = "needs DATABASE_URL"]
+async fn test_approved_change_request_can_clear_budget() {
+    let pool = common::pool().await;
+    let owner = common::user(&pool, "manager").await;
+    let project = common::project(&pool, owner, Some(Decimal::from(1000))).await;
+    sqlx::query("UPDATE projects SET end_date = '2024-06-30' WHERE id = $1")
+        .bind(project)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let repository = PgChangeRequestRepository::new(pool.clone());
+    let proposed: ChangeSet = serde_json::from_str(r#"{"budget": null}"#).unwrap();
+    let previous = ChangeSet {
+        budget: Some(Some(Decimal::from(1000))),
+        ..Default::default()
+    };
+    let mut request = ChangeRequest::new(project, proposed, previous, "Descoped".into(), owner);
+    repository.create(&request).await.unwrap();
+    request.approve(owner, None);
+    repository.approve_and_apply(&request).await.unwrap();
+
+    let (budget, has_end_date): (Option<Decimal>, bool) =
+        sqlx::query_as("SELECT budget, end_date IS NOT NULL FROM projects WHERE id = $1")
+            .bind(project)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+    assert_eq!(budget, None);
+    assert!(has_end_date);
+}
@@ -0,0 +1,156 @@
+//! Fixtures for tests that run against a Postgres database loaded with
+//! `database/schema.sql`. They are `#[ignore]`d by default; run them with
+//! `DATABASE_URL=... cargo test -- --ignored`.
+
+#![allow(dead_code)]
+
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use sqlx::PgPool;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use percival_backend::application::services::{
+    BudgetAppService, NotificationAppService, ProjectAppService,
+};
+use percival_backend::infrastructure::persistence::{
+    PgAttachmentRepository, PgBudgetAlertRepository, PgCostRateRepository, PgExpenseRepository,
+    PgNotificationRepository, PgProjectRepository, PgTaskRepository, PgTimeLogRepository,
+    PgUserRepository, PgWatcherRepository,
+};
+
+pub async fn pool() -> PgPool {
+    let url = std::env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+    PgPool::connect(&url)
+        .await
+        .expect("Failed to connect to database")
+}
+
+pub fn notification_service(pool: &PgPool) -> Arc<NotificationAppService> {
+    Arc::new(NotificationAppService::new(Arc::new(
+        PgNotificationRepository::new(pool.clone()),
+    )))
+}
+
+pub fn project_service(pool: &PgPool) -> Arc<ProjectAppService> {
+    Arc::new(ProjectAppService::new(
+        Arc::new(PgProjectRepository::new(pool.clone())),
+        Arc::new(PgWatcherRepository::new(pool.clone())),
+        notification_service(pool),
+    ))
+}
+
+pub fn budget_service(pool: &PgPool) -> Arc<BudgetAppService> {
+    Arc::new(BudgetAppService::new(
+        Arc::new(PgExpenseRepository::new(pool.clone())),
+        Arc::new(PgCostRateRepository::new(pool.clone())),
+        Arc::new(PgBudgetAlertRepository::new(pool.clone())),
+        Arc::new(PgTimeLogRepository::new(pool.clone())),
+        Arc::new(PgTaskRepository::new(pool.clone())),
+        Arc::new(PgAttachmentRepository::new(pool.clone())),
+        Arc::new(PgUserRepository::new(pool.clone())),
+        project_service(pool),
+        notification_service(pool),
+        vec![],
+    ))
+}
+
+pub async fn user(pool: &PgPool, role: &str) -> Uuid {
+    let id = Uuid::new_v4();
+    sqlx::query(
+        "INSERT INTO users (id, email, password_hash, name, role) VALUES ($1, $2, '', $3, $4::user_role)",
+    )
+    .bind(id)
+    .bind(format!("{}@example.com", id))
+    .bind(format!("User {}", &id.to_string()[..8]))
+    .bind(role)
+    .execute(pool)
+    .await
+    .unwrap();
+    id
+}
+
+pub async fn project(pool: &PgPool, owner_id: Uuid, budget: Option<Decimal>) -> Uuid {
+    let id = Uuid::new_v4();
+    sqlx::query("INSERT INTO projects (id, name, owner_id, budget) VALUES ($1, 'Project', $2, $3)")
+        .bind(id)
+        .bind(owner_id)
+        .bind(budget)
+        .execute(pool)
+        .await
+        .unwrap();
+    id
+}
+
+/// A program owned by `owner_id`, who becomes its program manager
+pub async fn program(pool: &PgPool, owner_id: Uuid) -> Uuid {
+    let id = Uuid::new_v4();
+    sqlx::query(
+        "INSERT INTO portfolios (id, name, kind, owner_id) VALUES ($1, 'Program', 'program', $2)",
+    )
+    .bind(id)
+    .bind(owner_id)
+    .execute(pool)
+    .await
+    .unwrap();
+    id
+}
+
+pub async fn add_to_portfolio(pool: &PgPool, project_id: Uuid, portfolio_id: Uuid) {
+    sqlx::query("UPDATE projects SET portfolio_id = $1 WHERE id = $2")
+        .bind(portfolio_id)
+        .bind(project_id)
+        .execute(pool)
+        .await
+        .unwrap();
+}
+
+pub async fn task(pool: &PgPool, project_id: Uuid) -> Uuid {
+    let id = Uuid::new_v4();
+    sqlx::query("INSERT INTO tasks (id, project_id, title) VALUES ($1, $2, 'Task')")
+        .bind(id)
+        .bind(project_id)
+        .execute(pool)
+        .await
+        .unwrap();
+    id
+}
+
+pub async fn time_log(
+    pool: &PgPool,
+    task_id: Uuid,
+    user_id: Uuid,
+    hours: f32,
+    date: NaiveDate,
+) -> Uuid {
+    let id = Uuid::new_v4();
+    sqlx::query(
+        "INSERT INTO time_logs (id, task_id, user_id, hours, date) VALUES ($1, $2, $3, $4, $5)",
+    )
+    .bind(id)
+    .bind(task_id)
+    .bind(user_id)
+    .bind(hours)
+    .bind(date)
+    .execute(pool)
+    .await
+    .unwrap();
+    id
+}
+
+pub async fn cost_rate(pool: &PgPool, user_id: Uuid, hourly_rate: Decimal) {
+    sqlx::query("INSERT INTO cost_rates (user_id, hourly_rate) VALUES ($1, $2)")
+        .bind(user_id)
+        .bind(hourly_rate)
+        .execute(pool)
+        .await
+        .unwrap();
+}
+
+pub async fn trash_task(pool: &PgPool, task_id: Uuid) {
+    sqlx::query("UPDATE tasks SET deleted_at = NOW() WHERE id = $1")
+        .bind(task_id)
+        .execute(pool)
+        .await
+        .unwrap();
+}
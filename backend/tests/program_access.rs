@@ -0,0 +1,51 @@
+mod common;
+
+use axum::extract::{Path, State};
+use axum::Extension;
+use rust_decimal::Decimal;
+
+use percival_backend::domain::value_objects::UserRole;
+use percival_backend::presentation::handlers::budget_handler;
+use percival_backend::presentation::middleware::AuthUser;
+use percival_backend::shared::DomainError;
+
+#[tokio::test]
+#[ignore = "needs DATABASE_URL"]
+async fn test_program_manager_reads_budget_of_project_in_program() {
+    let pool = common::pool().await;
+    let program_manager = common::user(&pool, "manager").await;
+    let owner = common::user(&pool, "manager").await;
+    let program = common::program(&pool, program_manager).await;
+    let project = common::project(&pool, owner, Some(Decimal::from(500))).await;
+    let outside = common::project(&pool, owner, Some(Decimal::from(500))).await;
+    common::add_to_portfolio(&pool, project, program).await;
+
+    let budget_service = common::budget_service(&pool);
+    let auth_user = AuthUser {
+        id: program_manager,
+        email: "pm@example.com".into(),
+        role: UserRole::Manager,
+    };
+
+    let summary = budget_handler::get_budget_summary(
+        State(budget_service.clone()),
+        Extension(auth_user.clone()),
+        Path(project),
+    )
+    .await
+    .unwrap();
+    assert_eq!(summary.0.data.unwrap().budget, Some(Decimal::from(500)));
+
+    // Reading stays limited to the program, and writing still needs membership
+    let denied = budget_handler::get_budget_summary(
+        State(budget_service.clone()),
+        Extension(auth_user.clone()),
+        Path(outside),
+    )
+    .await;
+    assert!(matches!(denied, Err(DomainError::Forbidden(_))));
+    assert!(!budget_service
+        .can_access_project(project, program_manager)
+        .await
+        .unwrap());
+}
@@ -0,0 +1,250 @@
+mod common;
+
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use std::sync::Arc;
+
+use percival_backend::application::services::{AttachmentAppService, TrashAppService};
+
+use percival_backend::domain::repositories::{
+    ActivityLogRepository, AttachmentRepository, RaidItemRepository, TagRepository,
+    TaskCommentRepository, TimeLogFilter, TimeLogRepository,
+};
+use percival_backend::infrastructure::persistence::{
+    PgActivityLogRepository, PgAttachmentRepository, PgProjectRepository, PgRaidItemRepository,
+    PgTagRepository, PgTaskCommentRepository, PgTaskRepository, PgTimeLogRepository,
+    PgWatcherRepository,
+};
+
+#[tokio::test]
+#[ignore = "needs DATABASE_URL"]
+async fn test_trashed_task_hours_drop_out_of_budget_summary() {
+    let pool = common::pool().await;
+    let budget_service = common::budget_service(&pool);
+
+    let owner = common::user(&pool, "manager").await;
+    let worker = common::user(&pool, "member").await;
+    common::cost_rate(&pool, worker, Decimal::from(50)).await;
+    let project = common::project(&pool, owner, Some(Decimal::from(1000))).await;
+    let kept = common::task(&pool, project).await;
+    let trashed = common::task(&pool, project).await;
+    let date = NaiveDate::from_ymd_opt(2024, 3, 4).unwrap();
+    common::time_log(&pool, kept, worker, 2.0, date).await;
+    common::time_log(&pool, trashed, worker, 3.0, date).await;
+
+    let summary = budget_service.get_budget_summary(project).await.unwrap();
+    assert_eq!(summary.logged_hours, 5.0);
+    assert_eq!(summary.labor_cost, Decimal::from(250));
+
+    common::trash_task(&pool, trashed).await;
+
+    let summary = budget_service.get_budget_summary(project).await.unwrap();
+    assert_eq!(summary.logged_hours, 2.0);
+    assert_eq!(summary.labor_cost, Decimal::from(100));
+    assert_eq!(summary.remaining, Some(Decimal::from(900)));
+}
+
+#[tokio::test]
+#[ignore = "needs DATABASE_URL"]
+async fn test_trashed_task_children_are_hidden() {
+    let pool = common::pool().await;
+    let user = common::user(&pool, "member").await;
+    let project = common::project(&pool, user, None).await;
+    let task = common::task(&pool, project).await;
+    let date = NaiveDate::from_ymd_opt(2024, 3, 4).unwrap();
+    let log = common::time_log(&pool, task, user, 1.5, date).await;
+    let tag = uuid::Uuid::new_v4();
+    sqlx::query(
+        r#"
+        WITH tag AS (INSERT INTO tags (id, name) VALUES ($1, $1::text) RETURNING id)
+        INSERT INTO task_tags (task_id, tag_id) SELECT $2, id FROM tag
+        "#,
+    )
+    .bind(tag)
+    .bind(task)
+    .execute(&pool)
+    .await
+    .unwrap();
+    sqlx::query("INSERT INTO task_comments (task_id, user_id, content) VALUES ($1, $2, 'Hi')")
+        .bind(task)
+        .bind(user)
+        .execute(&pool)
+        .await
+        .unwrap();
+    sqlx::query(
+        r#"
+        INSERT INTO attachments (task_id, uploaded_by, filename, original_filename, content_type, size_bytes, storage_path)
+        VALUES ($1, $2, 'a.txt', 'a.txt', 'text/plain', 1, 'a.txt')
+        "#,
+    )
+    .bind(task)
+    .bind(user)
+    .execute(&pool)
+    .await
+    .unwrap();
+    sqlx::query(
+        "INSERT INTO activity_logs (user_id, project_id, action, entity_type, entity_id) VALUES ($1, $2, 'created', 'task', $3)",
+    )
+    .bind(user)
+    .bind(project)
+    .bind(task)
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    let time_logs = PgTimeLogRepository::new(pool.clone());
+    let comments = PgTaskCommentRepository::new(pool.clone());
+    let attachments = PgAttachmentRepository::new(pool.clone());
+    let tags = PgTagRepository::new(pool.clone());
+    let activity = PgActivityLogRepository::new(pool.clone());
+    let filter = TimeLogFilter {
+        user_id: Some(user),
+        start_date: Some(date),
+        end_date: Some(date),
+        ..Default::default()
+    };
+
+    assert!(time_logs.find_by_id(log).await.unwrap().is_some());
+    assert_eq!(
+        time_logs.find_page(&filter, None, 10).await.unwrap().len(),
+        1
+    );
+    assert_eq!(comments.find_by_task(task).await.unwrap().len(), 1);
+    assert_eq!(attachments.find_by_project(project).await.unwrap().len(), 1);
+    assert_eq!(tags.find_tasks_by_tag(tag).await.unwrap(), vec![task]);
+    assert_eq!(
+        activity.find_by_project(project, 10).await.unwrap().len(),
+        1
+    );
+
+    common::trash_task(&pool, task).await;
+
+    assert!(time_logs.find_by_id(log).await.unwrap().is_none());
+    assert!(time_logs
+        .find_page(&filter, None, 10)
+        .await
+        .unwrap()
+        .is_empty());
+    assert!(!time_logs
+        .sum_by_user_and_date(date, date)
+        .await
+        .unwrap()
+        .iter()
+        .any(|day| day.user_id == user));
+    assert!(comments.find_by_task(task).await.unwrap().is_empty());
+    assert!(attachments.find_by_task(task).await.unwrap().is_empty());
+    assert!(tags.find_tags_by_task(task).await.unwrap().is_empty());
+    assert!(tags.find_tasks_by_tag(tag).await.unwrap().is_empty());
+
+    sqlx::query("UPDATE projects SET deleted_at = NOW() WHERE id = $1")
+        .bind(project)
+        .execute(&pool)
+        .await
+        .unwrap();
+    assert!(activity
+        .find_by_project(project, 10)
+        .await
+        .unwrap()
+        .is_empty());
+}
+
+#[tokio::test]
+#[ignore = "needs DATABASE_URL"]
+async fn test_trashed_project_raid_items_need_no_review() {
+    let pool = common::pool().await;
+    let owner = common::user(&pool, "manager").await;
+    let kept = common::project(&pool, owner, None).await;
+    let trashed = common::project(&pool, owner, None).await;
+    let review_date = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+    for project in [kept, trashed] {
+        sqlx::query(
+            "INSERT INTO raid_items (project_id, item_type, title, review_date) VALUES ($1, 'risk', 'Risk', $2)",
+        )
+        .bind(project)
+        .bind(review_date)
+        .execute(&pool)
+        .await
+        .unwrap();
+    }
+    sqlx::query("UPDATE projects SET deleted_at = NOW() WHERE id = $1")
+        .bind(trashed)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let overdue = PgRaidItemRepository::new(pool.clone())
+        .find_review_overdue(review_date.succ_opt().unwrap())
+        .await
+        .unwrap();
+    assert!(overdue.iter().any(|item| item.project_id == kept));
+    assert!(!overdue.iter().any(|item| item.project_id == trashed));
+}
+
+#[tokio::test]
+#[ignore = "needs DATABASE_URL"]
+async fn test_purge_removes_attachment_files_of_trashed_items() {
+    let pool = common::pool().await;
+    let upload_dir = std::env::temp_dir().join(format!("percival-{}", uuid::Uuid::new_v4()));
+    let attachment_service = Arc::new(AttachmentAppService::new(
+        Arc::new(PgAttachmentRepository::new(pool.clone())),
+        common::project_service(&pool),
+        upload_dir.clone(),
+    ));
+    let trash_service = TrashAppService::new(
+        Arc::new(PgProjectRepository::new(pool.clone())),
+        Arc::new(PgTaskRepository::new(pool.clone())),
+        Arc::new(PgWatcherRepository::new(pool.clone())),
+        attachment_service,
+        30,
+    );
+
+    let user = common::user(&pool, "member").await;
+    let kept_project = common::project(&pool, user, None).await;
+    let trashed_task = common::task(&pool, kept_project).await;
+    let trashed_project = common::project(&pool, user, None).await;
+    let project_task = common::task(&pool, trashed_project).await;
+    let mut files = Vec::new();
+    for task in [trashed_task, project_task] {
+        let storage_path = format!("{}/a.txt", task);
+        std::fs::create_dir_all(upload_dir.join(task.to_string())).unwrap();
+        std::fs::write(upload_dir.join(&storage_path), "a").unwrap();
+        sqlx::query(
+            r#"
+            INSERT INTO attachments (task_id, uploaded_by, filename, original_filename, content_type, size_bytes, storage_path)
+            VALUES ($1, $2, 'a.txt', 'a.txt', 'text/plain', 1, $3)
+            "#,
+        )
+        .bind(task)
+        .bind(user)
+        .bind(&storage_path)
+        .execute(&pool)
+        .await
+        .unwrap();
+        files.push(upload_dir.join(storage_path));
+    }
+    sqlx::query("UPDATE tasks SET deleted_at = NOW() - INTERVAL '31 days' WHERE id = $1")
+        .bind(trashed_task)
+        .execute(&pool)
+        .await
+        .unwrap();
+    sqlx::query("UPDATE projects SET deleted_at = NOW() - INTERVAL '31 days' WHERE id = $1")
+        .bind(trashed_project)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let summary = trash_service.purge_expired().await.unwrap();
+
+    assert!(summary.tasks >= 1);
+    assert!(summary.projects >= 1);
+    for file in files {
+        assert!(!file.exists(), "{} was left on disk", file.display());
+    }
+    let remaining: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM tasks WHERE id = ANY($1)")
+        .bind(vec![trashed_task, project_task])
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(remaining, 0);
+    let _ = std::fs::remove_dir_all(upload_dir);
+}
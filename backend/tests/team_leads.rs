@@ -0,0 +1,55 @@
+mod common;
+
+use percival_backend::domain::repositories::TeamRepository;
+use percival_backend::domain::value_objects::TeamMemberRole;
+use percival_backend::infrastructure::persistence::PgTeamRepository;
+use percival_backend::shared::DomainError;
+
+#[tokio::test]
+#[ignore = "needs DATABASE_URL"]
+async fn test_concurrent_step_downs_keep_a_lead() {
+    let pool = common::pool().await;
+    let lead = common::user(&pool, "member").await;
+    let co_lead = common::user(&pool, "member").await;
+    let team = uuid::Uuid::new_v4();
+    sqlx::query("INSERT INTO teams (id, name, lead_id) VALUES ($1, 'Team', $2)")
+        .bind(team)
+        .bind(lead)
+        .execute(&pool)
+        .await
+        .unwrap();
+    for user in [lead, co_lead] {
+        sqlx::query("INSERT INTO team_members (team_id, user_id, role) VALUES ($1, $2, 'lead')")
+            .bind(team)
+            .bind(user)
+            .execute(&pool)
+            .await
+            .unwrap();
+    }
+
+    let mut handles = Vec::new();
+    for user in [lead, co_lead] {
+        let repository = PgTeamRepository::new(pool.clone());
+        handles.push(tokio::spawn(async move {
+            repository
+                .change_member_role(team, user, TeamMemberRole::Member, false)
+                .await
+        }));
+    }
+    let mut results = Vec::new();
+    for handle in handles {
+        results.push(handle.await.unwrap());
+    }
+
+    assert_eq!(results.iter().filter(|r| r.is_ok()).count(), 1);
+    assert!(results
+        .iter()
+        .filter_map(|r| r.as_ref().err())
+        .all(|e| matches!(e, DomainError::Conflict(_))));
+    let repository = PgTeamRepository::new(pool.clone());
+    let lead_id = repository.find_by_id(team).await.unwrap().unwrap().lead_id;
+    let members = repository.find_members(team).await.unwrap();
+    assert!(members
+        .iter()
+        .any(|m| m.is_lead() && Some(m.user_id) == lead_id));
+}
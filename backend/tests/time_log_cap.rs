@@ -0,0 +1,95 @@
+mod common;
+
+use chrono::NaiveDate;
+
+use percival_backend::domain::entities::TimeLog;
+use percival_backend::domain::repositories::TimeLogRepository;
+use percival_backend::domain::services::TimeLogPolicy;
+use percival_backend::infrastructure::persistence::PgTimeLogRepository;
+use percival_backend::shared::DomainError;
+
+#[tokio::test]
+#[ignore = "needs DATABASE_URL"]
+async fn test_concurrent_logs_cannot_overshoot_daily_cap() {
+    let pool = common::pool().await;
+    let user = common::user(&pool, "member").await;
+    let project = common::project(&pool, user, None).await;
+    let date = NaiveDate::from_ymd_opt(2024, 3, 4).unwrap();
+    let policy = TimeLogPolicy {
+        increment_minutes: 0,
+        daily_cap_hours: 10.0,
+    };
+
+    let mut handles = Vec::new();
+    for _ in 0..4 {
+        let task = common::task(&pool, project).await;
+        let repository = PgTimeLogRepository::new(pool.clone());
+        let log = TimeLog::new(task, user, 4.0, date, None, true);
+        handles.push(tokio::spawn(async move {
+            repository.create(&log, &policy).await
+        }));
+    }
+    let mut results = Vec::new();
+    for handle in handles {
+        results.push(handle.await.unwrap());
+    }
+
+    assert_eq!(results.iter().filter(|r| r.is_ok()).count(), 2);
+    assert!(results
+        .iter()
+        .filter_map(|r| r.as_ref().err())
+        .all(|e| matches!(e, DomainError::InvalidFields(_))));
+}
+
+#[tokio::test]
+#[ignore = "needs DATABASE_URL"]
+async fn test_logs_in_a_submitted_week_are_locked() {
+    let pool = common::pool().await;
+    let user = common::user(&pool, "member").await;
+    let project = common::project(&pool, user, None).await;
+    let task = common::task(&pool, project).await;
+    let (open_day, locked_day) = (
+        NaiveDate::from_ymd_opt(2024, 3, 4).unwrap(),
+        NaiveDate::from_ymd_opt(2024, 3, 11).unwrap(),
+    );
+    let policy = TimeLogPolicy {
+        increment_minutes: 0,
+        daily_cap_hours: 10.0,
+    };
+    let repository = PgTimeLogRepository::new(pool.clone());
+    let open = repository
+        .create(
+            &TimeLog::new(task, user, 1.0, open_day, None, true),
+            &policy,
+        )
+        .await
+        .unwrap();
+    let locked = common::time_log(&pool, task, user, 1.0, locked_day).await;
+    sqlx::query(
+        "INSERT INTO timesheets (user_id, week_start, status) VALUES ($1, $2, 'submitted')",
+    )
+    .bind(user)
+    .bind(locked_day)
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    let result = repository
+        .create(
+            &TimeLog::new(task, user, 1.0, locked_day, None, true),
+            &policy,
+        )
+        .await;
+    assert!(matches!(result, Err(DomainError::Conflict(_))));
+    let moved = TimeLog {
+        date: locked_day,
+        ..open.clone()
+    };
+    let result = repository.update(&moved, &policy).await;
+    assert!(matches!(result, Err(DomainError::Conflict(_))));
+    let result = repository.delete(locked).await;
+    assert!(matches!(result, Err(DomainError::Conflict(_))));
+    assert!(repository.find_by_id(locked).await.unwrap().is_some());
+
+    repository.delete(open.id).await.unwrap();
+}
@@ -11,6 +11,14 @@ CREATE TYPE project_status AS ENUM ('planning', 'active', 'onhold', 'completed',
 CREATE TYPE priority AS ENUM ('low', 'medium', 'high', 'critical');
 CREATE TYPE task_status AS ENUM ('todo', 'inprogress', 'review', 'done', 'blocked');
 CREATE TYPE team_member_role AS ENUM ('lead', 'member');
+CREATE TYPE sprint_state AS ENUM ('planned', 'active', 'closed');
+CREATE TYPE raid_type AS ENUM ('risk', 'assumption', 'issue', 'decision');
+CREATE TYPE raid_status AS ENUM ('open', 'monitoring', 'resolved', 'closed');
+CREATE TYPE rag_status AS ENUM ('green', 'amber', 'red');
+CREATE TYPE portfolio_kind AS ENUM ('portfolio', 'program');
+CREATE TYPE change_request_status AS ENUM ('submitted', 'approved', 'rejected', 'withdrawn');
+CREATE TYPE timesheet_status AS ENUM ('draft', 'submitted', 'approved', 'rejected');
+CREATE TYPE time_off_status AS ENUM ('pending', 'approved', 'rejected', 'cancelled');
 
 -- ==================== USERS TABLE ====================
 CREATE TABLE users (
@@ -49,6 +57,59 @@ CREATE TABLE team_members (
 CREATE INDEX idx_team_members_team ON team_members(team_id);
 CREATE INDEX idx_team_members_user ON team_members(user_id);
 
+-- ==================== HOLIDAY CALENDARS TABLE ====================
+-- Public holidays; users follow the default calendar unless their capacity names another
+CREATE TABLE holiday_calendars (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    name VARCHAR(255) NOT NULL,
+    is_default BOOLEAN NOT NULL DEFAULT FALSE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE UNIQUE INDEX idx_holiday_calendars_default ON holiday_calendars(is_default) WHERE is_default;
+
+-- ==================== HOLIDAYS TABLE ====================
+CREATE TABLE holidays (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    calendar_id UUID NOT NULL REFERENCES holiday_calendars(id) ON DELETE CASCADE,
+    date DATE NOT NULL,
+    name VARCHAR(255) NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE(calendar_id, date)
+);
+
+CREATE INDEX idx_holidays_date ON holidays(date);
+
+-- ==================== USER CAPACITIES TABLE ====================
+-- Users without a row get the configured default weekly hours, Monday to Friday
+CREATE TABLE user_capacities (
+    user_id UUID PRIMARY KEY REFERENCES users(id) ON DELETE CASCADE,
+    weekly_hours REAL NOT NULL CHECK (weekly_hours >= 0 AND weekly_hours <= 168),
+    -- Hours per weekday, Monday first
+    day_hours REAL[] CHECK (day_hours IS NULL OR array_length(day_hours, 1) = 7),
+    holiday_calendar_id UUID REFERENCES holiday_calendars(id) ON DELETE SET NULL,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- ==================== PORTFOLIOS TABLE ====================
+-- Groupings of projects: portfolios hold programs, programs hold projects.
+-- The owner is the program manager and can read every project below it.
+CREATE TABLE portfolios (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    name VARCHAR(255) NOT NULL,
+    description TEXT,
+    kind portfolio_kind NOT NULL,
+    parent_id UUID REFERENCES portfolios(id) ON DELETE SET NULL,
+    owner_id UUID NOT NULL REFERENCES users(id) ON DELETE RESTRICT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK (parent_id IS NULL OR kind = 'program')
+);
+
+CREATE INDEX idx_portfolios_parent ON portfolios(parent_id);
+CREATE INDEX idx_portfolios_owner ON portfolios(owner_id);
+
 -- ==================== PROJECTS TABLE ====================
 CREATE TABLE projects (
     id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
@@ -60,12 +121,19 @@ CREATE TABLE projects (
     end_date TIMESTAMPTZ,
     budget DECIMAL(15, 2),
     owner_id UUID NOT NULL REFERENCES users(id) ON DELETE RESTRICT,
+    portfolio_id UUID REFERENCES portfolios(id) ON DELETE SET NULL,
+    billable BOOLEAN NOT NULL DEFAULT TRUE,
     created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
-    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    archived_at TIMESTAMPTZ,
+    deleted_at TIMESTAMPTZ,
+    deleted_by UUID REFERENCES users(id) ON DELETE SET NULL
 );
 
 CREATE INDEX idx_projects_status ON projects(status);
 CREATE INDEX idx_projects_owner ON projects(owner_id);
+CREATE INDEX idx_projects_portfolio ON projects(portfolio_id);
+CREATE INDEX idx_projects_deleted_at ON projects(deleted_at) WHERE deleted_at IS NOT NULL;
 
 -- ==================== PROJECT MEMBERS TABLE ====================
 CREATE TABLE project_members (
@@ -94,11 +162,37 @@ CREATE TABLE milestones (
 
 CREATE INDEX idx_milestones_project ON milestones(project_id);
 
+-- ==================== SPRINTS TABLE ====================
+CREATE TABLE sprints (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    project_id UUID NOT NULL REFERENCES projects(id) ON DELETE CASCADE,
+    name VARCHAR(255) NOT NULL,
+    goal TEXT,
+    start_date DATE NOT NULL,
+    end_date DATE NOT NULL,
+    state sprint_state NOT NULL DEFAULT 'planned',
+    committed_hours REAL,
+    committed_tasks INTEGER,
+    completed_hours REAL,
+    completed_tasks INTEGER,
+    burndown JSONB,
+    started_at TIMESTAMPTZ,
+    closed_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK (end_date >= start_date)
+);
+
+CREATE INDEX idx_sprints_project ON sprints(project_id);
+-- At most one active sprint per project
+CREATE UNIQUE INDEX idx_sprints_active ON sprints(project_id) WHERE state = 'active';
+
 -- ==================== TASKS TABLE ====================
 CREATE TABLE tasks (
     id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
     project_id UUID NOT NULL REFERENCES projects(id) ON DELETE CASCADE,
     milestone_id UUID REFERENCES milestones(id) ON DELETE SET NULL,
+    sprint_id UUID REFERENCES sprints(id) ON DELETE SET NULL,
     title VARCHAR(255) NOT NULL,
     description TEXT,
     status task_status NOT NULL DEFAULT 'todo',
@@ -107,14 +201,20 @@ CREATE TABLE tasks (
     due_date TIMESTAMPTZ,
     estimated_hours REAL,
     actual_hours REAL,
+    billable BOOLEAN,
+    completed_at TIMESTAMPTZ,
     created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
-    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    deleted_at TIMESTAMPTZ,
+    deleted_by UUID REFERENCES users(id) ON DELETE SET NULL
 );
 
 CREATE INDEX idx_tasks_project ON tasks(project_id);
 CREATE INDEX idx_tasks_assignee ON tasks(assignee_id);
 CREATE INDEX idx_tasks_status ON tasks(status);
 CREATE INDEX idx_tasks_milestone ON tasks(milestone_id);
+CREATE INDEX idx_tasks_sprint ON tasks(sprint_id);
+CREATE INDEX idx_tasks_deleted_at ON tasks(deleted_at) WHERE deleted_at IS NOT NULL;
 
 -- ==================== TASK COMMENTS TABLE ====================
 CREATE TABLE task_comments (
@@ -143,6 +243,7 @@ CREATE TABLE activity_logs (
 CREATE INDEX idx_activity_logs_project ON activity_logs(project_id);
 CREATE INDEX idx_activity_logs_user ON activity_logs(user_id);
 CREATE INDEX idx_activity_logs_created ON activity_logs(created_at DESC);
+CREATE INDEX idx_activity_logs_entity ON activity_logs(entity_type, entity_id);
 
 -- ==================== UPDATED_AT TRIGGER ====================
 CREATE OR REPLACE FUNCTION update_updated_at_column()
@@ -160,6 +261,12 @@ CREATE TRIGGER update_users_updated_at BEFORE UPDATE ON users
 CREATE TRIGGER update_teams_updated_at BEFORE UPDATE ON teams
     FOR EACH ROW EXECUTE FUNCTION update_updated_at_column();
 
+CREATE TRIGGER update_holiday_calendars_updated_at BEFORE UPDATE ON holiday_calendars
+    FOR EACH ROW EXECUTE FUNCTION update_updated_at_column();
+
+CREATE TRIGGER update_portfolios_updated_at BEFORE UPDATE ON portfolios
+    FOR EACH ROW EXECUTE FUNCTION update_updated_at_column();
+
 CREATE TRIGGER update_projects_updated_at BEFORE UPDATE ON projects
     FOR EACH ROW EXECUTE FUNCTION update_updated_at_column();
 
@@ -172,6 +279,23 @@ CREATE TRIGGER update_tasks_updated_at BEFORE UPDATE ON tasks
 CREATE TRIGGER update_task_comments_updated_at BEFORE UPDATE ON task_comments
     FOR EACH ROW EXECUTE FUNCTION update_updated_at_column();
 
+-- ==================== INVOICES TABLE ====================
+-- Billable time invoiced for a period; lines are a snapshot taken at invoicing
+CREATE TABLE invoices (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    project_id UUID REFERENCES projects(id) ON DELETE SET NULL,
+    period_start DATE NOT NULL,
+    period_end DATE NOT NULL,
+    total_hours REAL NOT NULL,
+    total_amount DECIMAL(15, 2) NOT NULL,
+    lines JSONB NOT NULL DEFAULT '[]',
+    created_by UUID REFERENCES users(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK (period_end >= period_start)
+);
+
+CREATE INDEX idx_invoices_project ON invoices(project_id);
+
 -- ==================== TIME LOGS TABLE ====================
 CREATE TABLE time_logs (
     id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
@@ -180,11 +304,14 @@ CREATE TABLE time_logs (
     hours REAL NOT NULL,
     date DATE NOT NULL,
     description TEXT,
+    billable BOOLEAN NOT NULL DEFAULT TRUE,
+    invoice_id UUID REFERENCES invoices(id) ON DELETE SET NULL,
     created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
     updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
 );
 
 CREATE INDEX idx_time_logs_task ON time_logs(task_id);
+CREATE INDEX idx_time_logs_invoice ON time_logs(invoice_id);
 CREATE INDEX idx_time_logs_user ON time_logs(user_id);
 CREATE INDEX idx_time_logs_date ON time_logs(date);
 
@@ -242,7 +369,9 @@ CREATE TYPE notification_type AS ENUM (
     'task_due_soon',
     'project_updated',
     'comment_added',
-    'mention'
+    'mention',
+    'budget_alert',
+    'system'
 );
 
 CREATE TABLE notifications (
@@ -259,3 +388,317 @@ CREATE TABLE notifications (
 CREATE INDEX idx_notifications_user ON notifications(user_id);
 CREATE INDEX idx_notifications_user_unread ON notifications(user_id, is_read) WHERE is_read = false;
 CREATE INDEX idx_notifications_created ON notifications(created_at DESC);
+
+-- ==================== WATCHERS TABLE ====================
+CREATE TYPE watch_entity_type AS ENUM ('task', 'project');
+
+CREATE TABLE watchers (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    entity_type watch_entity_type NOT NULL,
+    entity_id UUID NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE(user_id, entity_type, entity_id)
+);
+
+CREATE INDEX idx_watchers_entity ON watchers(entity_type, entity_id);
+CREATE INDEX idx_watchers_user ON watchers(user_id);
+
+-- ==================== MENTIONS TABLE ====================
+CREATE TYPE mention_source_type AS ENUM ('task', 'comment');
+
+CREATE TABLE mentions (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    source_type mention_source_type NOT NULL,
+    source_id UUID NOT NULL,
+    task_id UUID NOT NULL REFERENCES tasks(id) ON DELETE CASCADE,
+    mentioned_by UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE(user_id, source_type, source_id)
+);
+
+CREATE INDEX idx_mentions_source ON mentions(source_type, source_id);
+CREATE INDEX idx_mentions_user ON mentions(user_id);
+
+-- ==================== TASK DEPENDENCIES TABLE ====================
+CREATE TABLE task_dependencies (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    task_id UUID NOT NULL REFERENCES tasks(id) ON DELETE CASCADE,
+    depends_on_id UUID NOT NULL REFERENCES tasks(id) ON DELETE CASCADE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE(task_id, depends_on_id),
+    CHECK (task_id <> depends_on_id)
+);
+
+CREATE INDEX idx_task_dependencies_task ON task_dependencies(task_id);
+CREATE INDEX idx_task_dependencies_depends_on ON task_dependencies(depends_on_id);
+
+-- ==================== EXPENSES TABLE ====================
+CREATE TABLE expenses (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    project_id UUID NOT NULL REFERENCES projects(id) ON DELETE CASCADE,
+    amount DECIMAL(15, 2) NOT NULL CHECK (amount > 0),
+    category VARCHAR(100) NOT NULL,
+    description TEXT,
+    date DATE NOT NULL,
+    receipt_attachment_id UUID REFERENCES attachments(id) ON DELETE SET NULL,
+    created_by UUID REFERENCES users(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_expenses_project ON expenses(project_id);
+CREATE INDEX idx_expenses_date ON expenses(date);
+
+-- ==================== COST RATES TABLE ====================
+-- Hourly cost per user, or per role for users without their own rate
+CREATE TABLE cost_rates (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    user_id UUID UNIQUE REFERENCES users(id) ON DELETE CASCADE,
+    role user_role UNIQUE,
+    hourly_rate DECIMAL(15, 2) NOT NULL CHECK (hourly_rate >= 0),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK ((user_id IS NULL) <> (role IS NULL))
+);
+
+-- ==================== BILLING RATES TABLE ====================
+-- Hourly rates billed to clients, scoped to a project, a user, a role or a
+-- project together with a user or role. A rate applies from its effective
+-- date until a newer rate for the same scope takes over.
+CREATE TABLE billing_rates (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    project_id UUID REFERENCES projects(id) ON DELETE CASCADE,
+    user_id UUID REFERENCES users(id) ON DELETE CASCADE,
+    role user_role,
+    hourly_rate DECIMAL(15, 2) NOT NULL CHECK (hourly_rate >= 0),
+    effective_from DATE NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK (user_id IS NULL OR role IS NULL),
+    CHECK (project_id IS NOT NULL OR user_id IS NOT NULL OR role IS NOT NULL)
+);
+
+CREATE UNIQUE INDEX idx_billing_rates_scope ON billing_rates(project_id, user_id, role, effective_from)
+    NULLS NOT DISTINCT;
+
+-- ==================== BUDGET ALERTS TABLE ====================
+-- Budget thresholds (percent) the project owner has already been notified about
+CREATE TABLE budget_alerts (
+    project_id UUID NOT NULL REFERENCES projects(id) ON DELETE CASCADE,
+    threshold INTEGER NOT NULL,
+    notified_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (project_id, threshold)
+);
+
+-- ==================== PROJECT BASELINES TABLE ====================
+CREATE TABLE project_baselines (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    project_id UUID NOT NULL REFERENCES projects(id) ON DELETE CASCADE,
+    name VARCHAR(255) NOT NULL,
+    description TEXT,
+    snapshot JSONB NOT NULL,
+    created_by UUID REFERENCES users(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE(project_id, name)
+);
+
+CREATE INDEX idx_project_baselines_project ON project_baselines(project_id);
+
+-- ==================== RAID ITEMS TABLE ====================
+-- Risks, assumptions, issues and decisions logged against a project
+CREATE TABLE raid_items (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    project_id UUID NOT NULL REFERENCES projects(id) ON DELETE CASCADE,
+    item_type raid_type NOT NULL,
+    title VARCHAR(255) NOT NULL,
+    description TEXT,
+    owner_id UUID REFERENCES users(id) ON DELETE SET NULL,
+    status raid_status NOT NULL DEFAULT 'open',
+    probability SMALLINT CHECK (probability BETWEEN 1 AND 5),
+    impact SMALLINT CHECK (impact BETWEEN 1 AND 5),
+    mitigation_plan TEXT,
+    review_date DATE,
+    reminded_for DATE,
+    created_by UUID REFERENCES users(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_raid_items_project ON raid_items(project_id);
+CREATE INDEX idx_raid_items_review_date ON raid_items(review_date) WHERE status IN ('open', 'monitoring');
+
+CREATE TRIGGER update_raid_items_updated_at BEFORE UPDATE ON raid_items
+    FOR EACH ROW EXECUTE FUNCTION update_updated_at_column();
+
+CREATE TABLE raid_item_tasks (
+    raid_item_id UUID NOT NULL REFERENCES raid_items(id) ON DELETE CASCADE,
+    task_id UUID NOT NULL REFERENCES tasks(id) ON DELETE CASCADE,
+    PRIMARY KEY (raid_item_id, task_id)
+);
+
+CREATE INDEX idx_raid_item_tasks_task ON raid_item_tasks(task_id);
+
+-- ==================== PROJECT HEALTH TABLES ====================
+-- Manual RAG status set by the project owner in place of the computed one
+CREATE TABLE project_health_overrides (
+    project_id UUID PRIMARY KEY REFERENCES projects(id) ON DELETE CASCADE,
+    status rag_status NOT NULL,
+    comment TEXT NOT NULL,
+    set_by UUID REFERENCES users(id) ON DELETE SET NULL,
+    set_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- One health record per project and week (week_start is a Monday)
+CREATE TABLE project_health_snapshots (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    project_id UUID NOT NULL REFERENCES projects(id) ON DELETE CASCADE,
+    week_start DATE NOT NULL,
+    status rag_status NOT NULL,
+    computed_status rag_status NOT NULL,
+    score SMALLINT NOT NULL,
+    factors JSONB NOT NULL,
+    overridden BOOLEAN NOT NULL DEFAULT FALSE,
+    recorded_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE(project_id, week_start)
+);
+
+CREATE INDEX idx_project_health_snapshots_project ON project_health_snapshots(project_id, week_start DESC);
+
+-- ==================== STATUS REPORTS TABLE ====================
+-- Immutable project status reports; content holds the facts gathered for the period
+CREATE TABLE status_reports (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    project_id UUID NOT NULL REFERENCES projects(id) ON DELETE CASCADE,
+    period_start DATE NOT NULL,
+    period_end DATE NOT NULL,
+    highlights TEXT,
+    content JSONB NOT NULL,
+    created_by UUID REFERENCES users(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK (period_end >= period_start)
+);
+
+CREATE INDEX idx_status_reports_project ON status_reports(project_id, period_end DESC);
+
+-- ==================== CHANGE CONTROL TABLES ====================
+-- A project with at least one designated approver is governed: its budget,
+-- end date and milestone dates only change through approved change requests
+CREATE TABLE project_change_approvers (
+    project_id UUID NOT NULL REFERENCES projects(id) ON DELETE CASCADE,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    added_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (project_id, user_id)
+);
+
+CREATE TABLE change_requests (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    project_id UUID NOT NULL REFERENCES projects(id) ON DELETE CASCADE,
+    proposed JSONB NOT NULL,
+    previous JSONB NOT NULL,
+    justification TEXT NOT NULL,
+    status change_request_status NOT NULL DEFAULT 'submitted',
+    requested_by UUID REFERENCES users(id) ON DELETE SET NULL,
+    decided_by UUID REFERENCES users(id) ON DELETE SET NULL,
+    decision_comment TEXT,
+    decided_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_change_requests_project ON change_requests(project_id, created_at DESC);
+
+CREATE TRIGGER update_change_requests_updated_at BEFORE UPDATE ON change_requests
+    FOR EACH ROW EXECUTE FUNCTION update_updated_at_column();
+
+-- ==================== TIMERS TABLE ====================
+-- Running or paused stopwatches; a row is removed once its time is logged
+CREATE TABLE timers (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    user_id UUID NOT NULL UNIQUE REFERENCES users(id) ON DELETE CASCADE,
+    task_id UUID NOT NULL REFERENCES tasks(id) ON DELETE CASCADE,
+    description TEXT,
+    started_at TIMESTAMPTZ NOT NULL,
+    running_since TIMESTAMPTZ,
+    accumulated_seconds BIGINT NOT NULL DEFAULT 0,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_timers_running_since ON timers(running_since);
+
+-- ==================== TIMESHEETS TABLE ====================
+-- Weekly approval of a user's time logs; week_start is always a Monday.
+-- Logs dated within a submitted or approved week are read-only.
+CREATE TABLE timesheets (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    week_start DATE NOT NULL CHECK (EXTRACT(ISODOW FROM week_start) = 1),
+    status timesheet_status NOT NULL DEFAULT 'draft',
+    submitted_at TIMESTAMPTZ,
+    decided_by UUID REFERENCES users(id) ON DELETE SET NULL,
+    decided_at TIMESTAMPTZ,
+    comment TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE(user_id, week_start)
+);
+
+CREATE INDEX idx_timesheets_status ON timesheets(status);
+
+CREATE TRIGGER update_timesheets_updated_at BEFORE UPDATE ON timesheets
+    FOR EACH ROW EXECUTE FUNCTION update_updated_at_column();
+
+-- ==================== TIME OFF REQUESTS TABLE ====================
+-- Whole days of leave; approved requests are non-working days for capacity and reminders
+CREATE TABLE time_off_requests (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    start_date DATE NOT NULL,
+    end_date DATE NOT NULL,
+    reason TEXT,
+    status time_off_status NOT NULL DEFAULT 'pending',
+    decided_by UUID REFERENCES users(id) ON DELETE SET NULL,
+    decided_at TIMESTAMPTZ,
+    comment TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK (end_date >= start_date)
+);
+
+CREATE INDEX idx_time_off_requests_user ON time_off_requests(user_id, start_date);
+CREATE INDEX idx_time_off_requests_status ON time_off_requests(status);
+
+CREATE TRIGGER update_time_off_requests_updated_at BEFORE UPDATE ON time_off_requests
+    FOR EACH ROW EXECUTE FUNCTION update_updated_at_column();
+
+-- ==================== TASK DUE REMINDERS TABLE ====================
+-- Due date each task's assignee was last reminded about
+CREATE TABLE task_due_reminders (
+    task_id UUID PRIMARY KEY REFERENCES tasks(id) ON DELETE CASCADE,
+    reminded_for TIMESTAMPTZ NOT NULL
+);
+
+-- ==================== RESOURCE ALLOCATIONS TABLE ====================
+-- Planned share of a user's time on a project, as a percentage of capacity or fixed weekly hours
+CREATE TABLE resource_allocations (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    project_id UUID NOT NULL REFERENCES projects(id) ON DELETE CASCADE,
+    start_date DATE NOT NULL,
+    end_date DATE NOT NULL,
+    percent REAL CHECK (percent > 0 AND percent <= 100),
+    hours_per_week REAL CHECK (hours_per_week > 0 AND hours_per_week <= 168),
+    note TEXT,
+    created_by UUID REFERENCES users(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK (end_date >= start_date),
+    CHECK ((percent IS NULL) <> (hours_per_week IS NULL))
+);
+
+CREATE INDEX idx_resource_allocations_user ON resource_allocations(user_id, start_date);
+CREATE INDEX idx_resource_allocations_project ON resource_allocations(project_id);
+
+CREATE TRIGGER update_resource_allocations_updated_at BEFORE UPDATE ON resource_allocations
+    FOR EACH ROW EXECUTE FUNCTION update_updated_at_column();